@@ -37,12 +37,16 @@ mod element;
 mod fill_value;
 pub mod storage_transformer;
 
+#[cfg(all(feature = "sharding", feature = "async"))]
+mod array_async_sharded_readable_ext;
 #[cfg(feature = "sharding")]
 mod array_sharded_ext;
 #[cfg(feature = "sharding")]
 mod array_sync_sharded_readable_ext;
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
+
+use itertools::Itertools;
 
 pub use self::{
     array_builder::ArrayBuilder,
@@ -62,7 +66,7 @@ pub use self::{
     codec::CodecChain,
     concurrency::RecommendedConcurrency,
     data_type::DataType,
-    element::{Element, ElementFixedLength, ElementOwned},
+    element::{validate_structured_element, Element, ElementFixedLength, ElementOwned},
     fill_value::FillValue,
     storage_transformer::StorageTransformerChain,
 };
@@ -81,22 +85,33 @@ pub use crate::metadata::{ArrayMetadata, ArrayShape, ChunkShape, DimensionName,
 pub type FillValueMetadata = crate::metadata::v3::array::fill_value::FillValueMetadataV3;
 
 pub use chunk_cache::array_chunk_cache_ext_sync::ArrayChunkCacheExt;
+#[cfg(feature = "async")]
+pub use chunk_cache::{
+    array_chunk_cache_ext_async::AsyncArrayChunkCacheExt, chunk_cache_lru_async::*, AsyncChunkCache,
+};
 pub use chunk_cache::{
     chunk_cache_lru::*, ChunkCache, ChunkCacheType, ChunkCacheTypeDecoded, ChunkCacheTypeEncoded,
 };
 
+#[cfg(all(feature = "sharding", feature = "async"))]
+pub use array_async_sharded_readable_ext::{
+    AsyncArrayShardedReadableExt, AsyncArrayShardedReadableExtCache,
+};
 #[cfg(feature = "sharding")]
 pub use array_sharded_ext::ArrayShardedExt;
 #[cfg(feature = "sharding")]
 pub use array_sync_sharded_readable_ext::{ArrayShardedReadableExt, ArrayShardedReadableExtCache};
-// TODO: Add AsyncArrayShardedReadableExt and AsyncArrayShardedReadableExtCache
 
 use crate::{
-    array_subset::{ArraySubset, IncompatibleDimensionalityError},
+    array_subset::{
+        ArraySubset, CoordinateSelection, CoordinateSelectionInSubset,
+        IncompatibleDimensionalityError, OrthogonalSelection, OrthogonalSelectionInSubset,
+        OrthogonalSelectionIndexer,
+    },
     config::MetadataConvertVersion,
     metadata::{v2_to_v3::array_metadata_v2_to_v3, v3::AdditionalFields},
-    node::{data_key, NodePath},
-    storage::StoreKey,
+    node::{data_key, meta_key_v2_array, meta_key_v3, NodePath},
+    storage::{StoreKey, StoreValueVersion},
 };
 
 /// An ND index to an element in an array.
@@ -184,8 +199,8 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 ///   - **Experimental**: `async_` prefix variants can be used with async stores (requires `async` feature).
 ///
 /// Additional methods are offered by extension traits:
-///  - [`ArrayShardedExt`] and [`ArrayShardedReadableExt`]: see [Reading Sharded Arrays](#reading-sharded-arrays)
-///  - [`ArrayChunkCacheExt`]: see [Chunk Caching](#chunk-caching)
+///  - [`ArrayShardedExt`], [`ArrayShardedReadableExt`], and [`AsyncArrayShardedReadableExt`]: see [Reading Sharded Arrays](#reading-sharded-arrays)
+///  - [`ArrayChunkCacheExt`] and [`AsyncArrayChunkCacheExt`]: see [Chunk Caching](#chunk-caching)
 ///
 /// ### Chunks and Array Subsets
 /// Several convenience methods are available for querying the underlying chunk grid:
@@ -298,6 +313,16 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 ///
 /// `zarrs` consumers can create custom caches by implementing the [`ChunkCache`] trait.
 ///
+/// **Experimental**: The [`AsyncArrayChunkCacheExt`] trait adds equivalent `async_` prefixed methods for async stores (requires `async` feature).
+/// These methods have a `cache` parameter that implements the [`AsyncChunkCache`] trait.
+/// The following async LRU chunk caches are provided by `zarrs`:
+///  - [`AsyncChunkCacheDecodedLruChunkLimit`]: a decoded chunk cache with a fixed chunk capacity.
+///  - [`AsyncChunkCacheEncodedLruChunkLimit`]: an encoded chunk cache with a fixed chunk capacity.
+///  - [`AsyncChunkCacheDecodedLruSizeLimit`]: a decoded chunk cache with a fixed size in bytes.
+///  - [`AsyncChunkCacheEncodedLruSizeLimit`]: an encoded chunk cache with a fixed size in bytes.
+///
+/// Concurrent retrievals of a chunk that is not in an async chunk cache are coalesced, so the chunk is only retrieved from the store once.
+///
 /// Chunk caching is likely to be effective for remote stores where redundant retrievals are costly.
 /// Chunk caching may not outperform disk caching with a filesystem store.
 /// The above caches use internal locking to support multithreading, which has a performance overhead.
@@ -324,6 +349,8 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 /// For unsharded arrays, these methods gracefully fallback to referencing standard chunks.
 /// Each method has a `cache` parameter ([`ArrayShardedReadableExtCache`]) that stores shard indexes so that they do not have to be repeatedly retrieved and decoded.
 ///
+/// The [`AsyncArrayShardedReadableExt`] trait provides `async_` prefixed variants of these methods for async stores, using an [`AsyncArrayShardedReadableExtCache`] (requires `async` feature).
+///
 /// ## Parallelism and Concurrency
 /// ### Sync API
 /// Codecs run in parallel using a dedicated threadpool.
@@ -370,6 +397,8 @@ pub struct Array<TStorage: ?Sized> {
     // additional_fields: AdditionalFields,
     /// Metadata used to create the array
     metadata: ArrayMetadata,
+    /// The versions of the stored metadata keys (e.g. `zarr.json`, or `.zarray` and `.zattrs`) when the array was opened, if known.
+    metadata_versions: HashMap<StoreKey, StoreValueVersion>,
 }
 
 impl<TStorage: ?Sized> Array<TStorage> {
@@ -439,6 +468,7 @@ impl<TStorage: ?Sized> Array<TStorage> {
             storage_transformers,
             dimension_names: metadata_v3.dimension_names,
             metadata,
+            metadata_versions: HashMap::new(),
         })
     }
 
@@ -448,6 +478,21 @@ impl<TStorage: ?Sized> Array<TStorage> {
         &self.path
     }
 
+    /// Get the version of the stored metadata when the array was opened.
+    ///
+    /// This is [`None`] if the array was not opened from a store or the store does not support versioned values.
+    /// For a Zarr V2 array, this is the version of the `.zarray` metadata.
+    ///
+    /// The version is updated by [`store_metadata_if_unchanged`](Array::store_metadata_if_unchanged).
+    #[must_use]
+    pub fn metadata_version(&self) -> Option<&StoreValueVersion> {
+        let key = match self.metadata {
+            ArrayMetadata::V3(_) => meta_key_v3(self.path()),
+            ArrayMetadata::V2(_) => meta_key_v2_array(self.path()),
+        };
+        self.metadata_versions.get(&key)
+    }
+
     /// Get the data type.
     #[must_use]
     pub const fn data_type(&self) -> &DataType {
@@ -770,6 +815,98 @@ impl<TStorage: ?Sized> Array<TStorage> {
             .chunks_in_array_subset(array_subset, self.shape())
     }
 
+    /// Return the chunks containing elements of an orthogonal `selection` and the selected elements within each chunk.
+    ///
+    /// Only chunks containing selected indices in every dimension are visited, rather than every chunk in the bounding subset of the selection.
+    fn orthogonal_selection_chunks(
+        &self,
+        selection: &OrthogonalSelection,
+    ) -> Result<Vec<(ArrayIndices, OrthogonalSelectionInSubset)>, ArrayError> {
+        if selection.is_empty() {
+            return Ok(vec![]);
+        }
+        let invalid_selection =
+            || ArrayError::InvalidOrthogonalSelection(selection.clone(), self.shape().to_vec());
+        let indexer = OrthogonalSelectionIndexer::new(selection);
+
+        // Find the chunk indices containing selected indices along each dimension, starting from an arbitrary selected element
+        let first_indices: ArrayIndices = indexer
+            .sorted_indices()
+            .iter()
+            .map(|sorted_indices| sorted_indices[0].0)
+            .collect();
+        let mut dimension_chunk_indices = Vec::with_capacity(first_indices.len());
+        for (dimension, sorted_indices) in indexer.sorted_indices().iter().enumerate() {
+            let mut indices = first_indices.clone();
+            let mut chunk_indices_dimension = Vec::new();
+            let mut position = 0;
+            while let Some((index, _)) = sorted_indices.get(position) {
+                indices[dimension] = *index;
+                let chunk_indices = self
+                    .chunk_grid()
+                    .chunk_indices(&indices, self.shape())?
+                    .ok_or_else(invalid_selection)?;
+                let chunk_end = self.chunk_subset(&chunk_indices)?.end_exc()[dimension];
+                chunk_indices_dimension.push(chunk_indices[dimension]);
+                position +=
+                    sorted_indices[position..].partition_point(|(index, _)| *index < chunk_end);
+            }
+            dimension_chunk_indices.push(chunk_indices_dimension);
+        }
+
+        let mut selection_chunks = Vec::new();
+        for chunk_indices in dimension_chunk_indices
+            .into_iter()
+            .multi_cartesian_product()
+        {
+            let chunk_subset = self.chunk_subset(&chunk_indices)?;
+            if let Some(selection_in_chunk) = indexer.in_subset(&chunk_subset) {
+                selection_chunks.push((chunk_indices, selection_in_chunk));
+            }
+        }
+        Ok(selection_chunks)
+    }
+
+    /// Return the chunks containing elements of a coordinate `selection` and the selected elements within each chunk.
+    fn coordinate_selection_chunks(
+        &self,
+        selection: &CoordinateSelection,
+    ) -> Result<Vec<(ArrayIndices, CoordinateSelectionInSubset)>, ArrayError> {
+        if selection.dimensionality() != self.dimensionality() {
+            return Err(IncompatibleDimensionalityError::new(
+                selection.dimensionality(),
+                self.dimensionality(),
+            )
+            .into());
+        }
+        let mut chunks: HashMap<ArrayIndices, Vec<(usize, ArrayIndices)>> = HashMap::new();
+        for (position, coordinates) in selection.coordinates().iter().enumerate() {
+            let invalid_coordinates =
+                || ArrayError::InvalidCoordinates(coordinates.clone(), self.shape().to_vec());
+            if !std::iter::zip(coordinates, self.shape()).all(|(index, shape)| index < shape) {
+                return Err(invalid_coordinates());
+            }
+            let chunk_indices = self
+                .chunk_grid()
+                .chunk_indices(coordinates, self.shape())?
+                .ok_or_else(invalid_coordinates)?;
+            let chunk_element_indices = self
+                .chunk_grid()
+                .chunk_element_indices(coordinates, self.shape())?
+                .ok_or_else(invalid_coordinates)?;
+            chunks
+                .entry(chunk_indices)
+                .or_default()
+                .push((position, chunk_element_indices));
+        }
+        Ok(chunks
+            .into_iter()
+            .map(|(chunk_indices, elements)| {
+                (chunk_indices, CoordinateSelectionInSubset::new(elements))
+            })
+            .collect())
+    }
+
     /// Calculate the recommended codec concurrency.
     fn recommended_codec_concurrency(
         &self,
@@ -799,6 +936,7 @@ impl<TStorage: ?Sized> Array<TStorage> {
                     storage_transformers: self.storage_transformers,
                     dimension_names: self.dimension_names,
                     metadata,
+                    metadata_versions: HashMap::new(),
                 })
             }
             ArrayMetadata::V3(_) => Ok(self),
@@ -936,7 +1074,7 @@ pub fn bytes_to_ndarray<T: bytemuck::Pod>(
 
 #[cfg(test)]
 mod tests {
-    use crate::storage::store::MemoryStore;
+    use crate::storage::{store::MemoryStore, ReadableStorageTraits, StorageError};
     use zarrs_filesystem::FilesystemStore;
 
     use super::*;
@@ -961,6 +1099,108 @@ mod tests {
         assert_eq!(array_other.metadata(), &stored_metadata);
     }
 
+    #[test]
+    fn array_structured() {
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Point {
+            id: u64,
+            x: f32,
+            y: f32,
+        }
+        unsafe impl bytemuck::Zeroable for Point {}
+        unsafe impl bytemuck::Pod for Point {}
+        crate::impl_element_structured!(Point, id: u64, x: f32, y: f32);
+
+        let data_type = DataType::Structured(
+            data_type::StructuredDataType::new([
+                ("id".to_string(), DataType::UInt64),
+                ("x".to_string(), DataType::Float32),
+                ("y".to_string(), DataType::Float32),
+            ])
+            .unwrap(),
+        );
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(
+            vec![4],
+            data_type,
+            vec![2].try_into().unwrap(),
+            FillValue::new(vec![0; 16]),
+        )
+        .array_to_bytes_codec(Arc::new(codec::BytesCodec::big()))
+        .build(store.clone(), "/")
+        .unwrap();
+        array.store_metadata().unwrap();
+        let points = vec![
+            Point {
+                id: 1,
+                x: 1.0,
+                y: 2.0,
+            },
+            Point {
+                id: 2,
+                x: 3.0,
+                y: 4.0,
+            },
+        ];
+        array.store_chunk_elements(&[1], &points).unwrap();
+
+        let array = Array::open(store, "/").unwrap();
+        assert_eq!(
+            array.retrieve_chunk_elements::<Point>(&[1]).unwrap(),
+            points
+        );
+        assert_eq!(
+            array.retrieve_chunk_elements::<Point>(&[0]).unwrap(),
+            vec![
+                Point {
+                    id: 0,
+                    x: 0.0,
+                    y: 0.0
+                };
+                2
+            ]
+        );
+        assert!(array.retrieve_chunk_elements::<u64>(&[0]).is_err());
+    }
+
+    #[test]
+    fn array_structured_v2() {
+        #[repr(C, packed)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Record {
+            x: f32,
+            id: u64,
+        }
+        unsafe impl bytemuck::Zeroable for Record {}
+        unsafe impl bytemuck::Pod for Record {}
+        crate::impl_element_structured!(Record, x: f32, id: u64);
+        use crate::storage::WritableStorageTraits;
+
+        let store = Arc::new(MemoryStore::new());
+        store
+            .set(
+                &crate::storage::StoreKey::new(".zarray").unwrap(),
+                br#"{
+                    "chunks": [2],
+                    "compressor": null,
+                    "dtype": [["x", "<f4"], ["id", "<u8"]],
+                    "fill_value": "AACAPwcAAAAAAAAA",
+                    "filters": null,
+                    "order": "C",
+                    "shape": [2],
+                    "zarr_format": 2
+                }"#
+                .to_vec()
+                .into(),
+            )
+            .unwrap();
+        let array = Array::open(store, "/").unwrap();
+        assert_eq!(array.data_type().fixed_size(), Some(12));
+        let records = array.retrieve_chunk_elements::<Record>(&[0]).unwrap();
+        assert_eq!(records, vec![Record { x: 1.0, id: 7 }; 2]);
+    }
+
     #[test]
     fn array_set_shape_and_attributes() {
         let store = MemoryStore::new();
@@ -993,6 +1233,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn array_store_metadata_if_unchanged() {
+        let store = Arc::new(MemoryStore::new());
+        let array_path = "/array";
+        let builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt8,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(0u8),
+        );
+        let mut array = builder.build(store.clone(), array_path).unwrap();
+        assert!(array.metadata_version().is_none());
+        array.store_metadata_if_unchanged().unwrap();
+        assert!(array.metadata_version().is_some());
+
+        // Creating an array that already exists fails
+        let mut array_new = builder.build(store.clone(), array_path).unwrap();
+        assert!(matches!(
+            array_new.store_metadata_if_unchanged(),
+            Err(StorageError::PreconditionFailed(_))
+        ));
+
+        // Concurrent attribute updates
+        let mut array_a = Array::open(store.clone(), array_path).unwrap();
+        let mut array_b = Array::open(store.clone(), array_path).unwrap();
+        assert_eq!(array_a.metadata_version(), array.metadata_version());
+        array_a
+            .attributes_mut()
+            .insert("a".to_string(), "apple".into());
+        array_a.store_metadata_if_unchanged().unwrap();
+        array_b
+            .attributes_mut()
+            .insert("b".to_string(), "banana".into());
+        assert!(matches!(
+            array_b.store_metadata_if_unchanged(),
+            Err(StorageError::PreconditionFailed(_))
+        ));
+
+        // Reopen and retry
+        let mut array_b = Array::open(store.clone(), array_path).unwrap();
+        array_b
+            .attributes_mut()
+            .insert("b".to_string(), "banana".into());
+        array_b.store_metadata_if_unchanged().unwrap();
+        let array = Array::open(store, array_path).unwrap();
+        assert!(array.attributes().contains_key("a"));
+        assert!(array.attributes().contains_key("b"));
+    }
+
+    #[test]
+    fn array_store_metadata_if_unchanged_v2() {
+        let store = Arc::new(MemoryStore::new());
+        let array_path = "/array";
+        let mut array = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt8,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .zarr_version(crate::metadata::ZarrVersion::V2)
+        .build(store.clone(), array_path)
+        .unwrap();
+        array.store_metadata_if_unchanged().unwrap();
+
+        // Concurrent attribute updates only change .zattrs
+        let mut array_a = Array::open(store.clone(), array_path).unwrap();
+        let mut array_b = Array::open(store.clone(), array_path).unwrap();
+        array_a
+            .attributes_mut()
+            .insert("a".to_string(), "apple".into());
+        array_a.store_metadata_if_unchanged().unwrap();
+        array_b
+            .attributes_mut()
+            .insert("b".to_string(), "banana".into());
+        assert!(matches!(
+            array_b.store_metadata_if_unchanged(),
+            Err(StorageError::PreconditionFailed(_))
+        ));
+
+        // Reopen and retry
+        let mut array_b = Array::open(store.clone(), array_path).unwrap();
+        array_b
+            .attributes_mut()
+            .insert("b".to_string(), "banana".into());
+        array_b.store_metadata_if_unchanged().unwrap();
+        let mut array = Array::open(store.clone(), array_path).unwrap();
+        assert!(array.attributes().contains_key("a"));
+        assert!(array.attributes().contains_key("b"));
+
+        // Converting the metadata version is not supported
+        let options = ArrayMetadataOptions::default()
+            .with_metadata_convert_version(crate::config::MetadataConvertVersion::V3);
+        assert!(matches!(
+            array.store_metadata_if_unchanged_opt(&options),
+            Err(StorageError::Unsupported(_))
+        ));
+
+        // Removing all attributes erases .zattrs
+        let options = ArrayMetadataOptions::default().with_include_zarrs_metadata(false);
+        array.attributes_mut().clear();
+        array.store_metadata_if_unchanged_opt(&options).unwrap();
+        assert!(store
+            .get(&crate::node::meta_key_v2_attributes(array.path()))
+            .unwrap()
+            .is_none());
+        let array = Array::open(store, array_path).unwrap();
+        assert!(array.attributes().is_empty());
+    }
+
     #[test]
     fn array_subset_round_trip() {
         let store = Arc::new(MemoryStore::default());
@@ -4,10 +4,17 @@ use futures::{StreamExt, TryStreamExt};
 use unsafe_cell_slice::UnsafeCellSlice;
 
 use crate::{
-    array_subset::ArraySubset,
+    array::ArrayIndices,
+    array_subset::{
+        ArraySubset, CoordinateSelection, CoordinateSelectionInSubset, OrthogonalSelection,
+        OrthogonalSelectionInSubset,
+    },
     config::MetadataRetrieveVersion,
     node::{meta_key_v2_array, meta_key_v2_attributes, meta_key_v3, NodePath},
-    storage::{AsyncBytes, AsyncReadableStorageTraits, StorageError, StorageHandle},
+    storage::{
+        AsyncBytes, AsyncReadableStorageTraits, StorageError, StorageHandle, StoreKey,
+        StoreValueVersion,
+    },
 };
 
 use super::{
@@ -47,29 +54,55 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         if let MetadataRetrieveVersion::Default | MetadataRetrieveVersion::V3 = version {
             // Try V3
             let key_v3 = meta_key_v3(&node_path);
-            if let Some(metadata) = storage.get(&key_v3).await? {
-                let metadata: ArrayMetadataV3 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v3, err.to_string()))?;
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata));
+            if let Some((metadata, metadata_version)) =
+                async_get_versioned_or_get(&*storage, &key_v3).await?
+            {
+                let metadata: ArrayMetadataV3 =
+                    serde_json::from_slice(&metadata).map_err(|err| {
+                        StorageError::InvalidMetadata(key_v3.clone(), err.to_string())
+                    })?;
+                let mut array =
+                    Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata))?;
+                if let Some(metadata_version) = metadata_version {
+                    array.metadata_versions.insert(key_v3, metadata_version);
+                }
+                return Ok(array);
             }
         }
 
         if let MetadataRetrieveVersion::Default | MetadataRetrieveVersion::V2 = version {
             // Try V2
             let key_v2 = meta_key_v2_array(&node_path);
-            if let Some(metadata) = storage.get(&key_v2).await? {
-                let mut metadata: ArrayMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v2, err.to_string()))?;
+            if let Some((metadata, metadata_version)) =
+                async_get_versioned_or_get(&*storage, &key_v2).await?
+            {
+                let mut metadata: ArrayMetadataV2 =
+                    serde_json::from_slice(&metadata).map_err(|err| {
+                        StorageError::InvalidMetadata(key_v2.clone(), err.to_string())
+                    })?;
 
                 let attributes_key = meta_key_v2_attributes(&node_path);
-                let attributes = storage.get(&attributes_key).await?;
-                if let Some(attributes) = attributes {
+                let mut attributes_version = None;
+                if let Some((attributes, version)) =
+                    async_get_versioned_or_get(&*storage, &attributes_key).await?
+                {
                     metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
+                        StorageError::InvalidMetadata(attributes_key.clone(), err.to_string())
                     })?;
+                    attributes_version = version;
                 }
 
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata));
+                let mut array =
+                    Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata))?;
+                if let Some(metadata_version) = metadata_version {
+                    array.metadata_versions.insert(key_v2, metadata_version);
+                }
+                if let Some(attributes_version) = attributes_version {
+                    array
+                        .metadata_versions
+                        .insert(attributes_key, attributes_version);
+                }
+                return Ok(array);
             }
         }
 
@@ -262,6 +295,57 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
             .await
     }
 
+    /// Async variant of [`retrieve_orthogonal_selection`](Array::retrieve_orthogonal_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_orthogonal_selection(
+        &self,
+        selection: &OrthogonalSelection,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        self.async_retrieve_orthogonal_selection_opt(selection, &CodecOptions::default())
+            .await
+    }
+
+    /// Async variant of [`retrieve_orthogonal_selection_elements`](Array::retrieve_orthogonal_selection_elements).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_orthogonal_selection_elements<T: ElementOwned + Send + Sync>(
+        &self,
+        selection: &OrthogonalSelection,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.async_retrieve_orthogonal_selection_elements_opt(selection, &CodecOptions::default())
+            .await
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Async variant of [`retrieve_orthogonal_selection_ndarray`](Array::retrieve_orthogonal_selection_ndarray).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_orthogonal_selection_ndarray<T: ElementOwned + Send + Sync>(
+        &self,
+        selection: &OrthogonalSelection,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        self.async_retrieve_orthogonal_selection_ndarray_opt(selection, &CodecOptions::default())
+            .await
+    }
+
+    /// Async variant of [`retrieve_coordinate_selection`](Array::retrieve_coordinate_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_coordinate_selection(
+        &self,
+        selection: &CoordinateSelection,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        self.async_retrieve_coordinate_selection_opt(selection, &CodecOptions::default())
+            .await
+    }
+
+    /// Async variant of [`retrieve_coordinate_selection_elements`](Array::retrieve_coordinate_selection_elements).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_coordinate_selection_elements<T: ElementOwned + Send + Sync>(
+        &self,
+        selection: &CoordinateSelection,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.async_retrieve_coordinate_selection_elements_opt(selection, &CodecOptions::default())
+            .await
+    }
+
     /// Async variant of [`partial_decoder`](Array::partial_decoder).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     pub async fn async_partial_decoder(
@@ -728,6 +812,204 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         elements_to_ndarray(array_subset.shape(), elements)
     }
 
+    /// Async variant of [`retrieve_orthogonal_selection_opt`](Array::retrieve_orthogonal_selection_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_orthogonal_selection_opt(
+        &self,
+        selection: &OrthogonalSelection,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        if !selection.inbounds(self.shape()) {
+            return Err(ArrayError::InvalidOrthogonalSelection(
+                selection.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        if let Some(array_subset) = selection.to_array_subset() {
+            return self
+                .async_retrieve_array_subset_opt(&array_subset, options)
+                .await;
+        }
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "orthogonal selection of a variable sized data type".to_string(),
+            ));
+        };
+
+        // Allocate the output
+        let size_output = selection.num_elements_usize() * data_type_size;
+        if size_output == 0 {
+            return Ok(ArrayBytes::new_flen(vec![]));
+        }
+        let mut output = Vec::with_capacity(size_output);
+
+        // Find the chunks with selected elements
+        let chunks = self.orthogonal_selection_chunks(selection)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        {
+            let output = UnsafeCellSlice::new_from_vec_with_spare_capacity(&mut output);
+            let selection_shape = selection.shape();
+            let retrieve_chunk = |(chunk_indices, selection_in_chunk): (
+                ArrayIndices,
+                OrthogonalSelectionInSubset,
+            )| {
+                let options = options.clone();
+                let output = &output;
+                let selection_shape = &selection_shape;
+                async move {
+                    let chunk_subset_bytes = self
+                        .async_retrieve_chunk_subset_opt(
+                            &chunk_indices,
+                            selection_in_chunk.subset(),
+                            &options,
+                        )
+                        .await?
+                        .into_fixed()?;
+                    // SAFETY: each element of the selection is in exactly one chunk
+                    unsafe {
+                        selection_in_chunk.copy_to_selection(
+                            &chunk_subset_bytes,
+                            output,
+                            selection_shape,
+                            data_type_size,
+                        );
+                    }
+                    Ok::<_, ArrayError>(())
+                }
+            };
+            futures::stream::iter(chunks)
+                .map(Ok)
+                .try_for_each_concurrent(Some(chunk_concurrent_limit), retrieve_chunk)
+                .await?;
+        }
+        unsafe { output.set_len(size_output) };
+        Ok(ArrayBytes::from(output))
+    }
+
+    /// Async variant of [`retrieve_orthogonal_selection_elements_opt`](Array::retrieve_orthogonal_selection_elements_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_orthogonal_selection_elements_opt<T: ElementOwned + Send + Sync>(
+        &self,
+        selection: &OrthogonalSelection,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        let bytes = self
+            .async_retrieve_orthogonal_selection_opt(selection, options)
+            .await?;
+        let elements = T::from_array_bytes(self.data_type(), bytes)?;
+        Ok(elements)
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Async variant of [`retrieve_orthogonal_selection_ndarray_opt`](Array::retrieve_orthogonal_selection_ndarray_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_orthogonal_selection_ndarray_opt<T: ElementOwned + Send + Sync>(
+        &self,
+        selection: &OrthogonalSelection,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        let elements = self
+            .async_retrieve_orthogonal_selection_elements_opt(selection, options)
+            .await?;
+        elements_to_ndarray(&selection.shape(), elements)
+    }
+
+    /// Async variant of [`retrieve_coordinate_selection_opt`](Array::retrieve_coordinate_selection_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_coordinate_selection_opt(
+        &self,
+        selection: &CoordinateSelection,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "coordinate selection of a variable sized data type".to_string(),
+            ));
+        };
+
+        // Find the chunks with selected elements
+        let chunks = self.coordinate_selection_chunks(selection)?;
+
+        // Allocate the output
+        let size_output = selection.num_elements_usize() * data_type_size;
+        if size_output == 0 {
+            return Ok(ArrayBytes::new_flen(vec![]));
+        }
+        let mut output = Vec::with_capacity(size_output);
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        {
+            let output = UnsafeCellSlice::new_from_vec_with_spare_capacity(&mut output);
+            let retrieve_chunk = |(chunk_indices, selection_in_chunk): (
+                ArrayIndices,
+                CoordinateSelectionInSubset,
+            )| {
+                let options = options.clone();
+                let output = &output;
+                async move {
+                    let chunk_subset_bytes = self
+                        .async_retrieve_chunk_subset_opt(
+                            &chunk_indices,
+                            selection_in_chunk.subset(),
+                            &options,
+                        )
+                        .await?
+                        .into_fixed()?;
+                    // SAFETY: each element of the selection is in exactly one chunk
+                    unsafe {
+                        selection_in_chunk.copy_to_selection(
+                            &chunk_subset_bytes,
+                            output,
+                            data_type_size,
+                        );
+                    }
+                    Ok::<_, ArrayError>(())
+                }
+            };
+            futures::stream::iter(chunks)
+                .map(Ok)
+                .try_for_each_concurrent(Some(chunk_concurrent_limit), retrieve_chunk)
+                .await?;
+        }
+        unsafe { output.set_len(size_output) };
+        Ok(ArrayBytes::from(output))
+    }
+
+    /// Async variant of [`retrieve_coordinate_selection_elements_opt`](Array::retrieve_coordinate_selection_elements_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_coordinate_selection_elements_opt<T: ElementOwned + Send + Sync>(
+        &self,
+        selection: &CoordinateSelection,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        let bytes = self
+            .async_retrieve_coordinate_selection_opt(selection, options)
+            .await?;
+        let elements = T::from_array_bytes(self.data_type(), bytes)?;
+        Ok(elements)
+    }
+
     /// Async variant of [`retrieve_chunk_subset_opt`](Array::retrieve_chunk_subset_opt).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     pub async fn async_retrieve_chunk_subset_opt(
@@ -881,3 +1163,15 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
             .await?)
     }
 }
+
+/// Retrieve the value and version of `key`, falling back to an unversioned retrieval if the store does not support versioned values.
+async fn async_get_versioned_or_get<TStorage: ?Sized + AsyncReadableStorageTraits>(
+    storage: &TStorage,
+    key: &StoreKey,
+) -> Result<Option<(AsyncBytes, Option<StoreValueVersion>)>, StorageError> {
+    match storage.get_versioned(key).await {
+        Ok(value) => Ok(value.map(|(bytes, version)| (bytes, Some(version)))),
+        Err(StorageError::Unsupported(_)) => Ok(storage.get(key).await?.map(|bytes| (bytes, None))),
+        Err(err) => Err(err),
+    }
+}
@@ -1,12 +1,25 @@
+use std::{borrow::Cow, sync::Arc};
+
 use futures::{StreamExt, TryStreamExt};
 
 use crate::{
-    array::ArrayBytes, array_subset::ArraySubset, storage::AsyncReadableWritableStorageTraits,
+    array::{ArrayBytes, ArrayIndices, DataTypeSize},
+    array_subset::{
+        ArraySubset, CoordinateSelection, CoordinateSelectionInSubset, OrthogonalSelection,
+        OrthogonalSelectionInSubset,
+    },
+    storage::{AsyncBytes, AsyncReadableWritableStorageTraits, StorageError, StorageHandle},
 };
 
 use super::{
-    array_bytes::update_array_bytes, codec::options::CodecOptions,
-    concurrency::concurrency_chunks_and_codec, Array, ArrayError, Element,
+    array_bytes::update_array_bytes,
+    array_sync_readable_writable::{ChunkVersion, CONDITIONAL_CHUNK_UPDATE_ATTEMPTS},
+    codec::{
+        options::CodecOptions, ArrayToBytesCodecTraits, AsyncArrayPartialEncoderTraits,
+        AsyncStoragePartialDecoder, AsyncStoragePartialEncoder,
+    },
+    concurrency::concurrency_chunks_and_codec,
+    Array, ArrayError, ArraySize, Element,
 };
 
 impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TStorage> {
@@ -110,6 +123,85 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         .await
     }
 
+    /// Async variant of [`store_orthogonal_selection`](Array::store_orthogonal_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_orthogonal_selection<'a>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>> + Send,
+    ) -> Result<(), ArrayError> {
+        self.async_store_orthogonal_selection_opt(
+            selection,
+            selection_bytes,
+            &CodecOptions::default(),
+        )
+        .await
+    }
+
+    /// Async variant of [`store_orthogonal_selection_elements`](Array::store_orthogonal_selection_elements).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_orthogonal_selection_elements<T: Element + Send + Sync>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_elements: &[T],
+    ) -> Result<(), ArrayError> {
+        self.async_store_orthogonal_selection_elements_opt(
+            selection,
+            selection_elements,
+            &CodecOptions::default(),
+        )
+        .await
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Async variant of [`store_orthogonal_selection_ndarray`](Array::store_orthogonal_selection_ndarray).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_orthogonal_selection_ndarray<
+        T: Element + Send + Sync,
+        D: ndarray::Dimension,
+    >(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_array: impl Into<ndarray::Array<T, D>> + Send,
+    ) -> Result<(), ArrayError> {
+        self.async_store_orthogonal_selection_ndarray_opt(
+            selection,
+            selection_array,
+            &CodecOptions::default(),
+        )
+        .await
+    }
+
+    /// Async variant of [`store_coordinate_selection`](Array::store_coordinate_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_coordinate_selection<'a>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>> + Send,
+    ) -> Result<(), ArrayError> {
+        self.async_store_coordinate_selection_opt(
+            selection,
+            selection_bytes,
+            &CodecOptions::default(),
+        )
+        .await
+    }
+
+    /// Async variant of [`store_coordinate_selection_elements`](Array::store_coordinate_selection_elements).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_coordinate_selection_elements<T: Element + Send + Sync>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_elements: &[T],
+    ) -> Result<(), ArrayError> {
+        self.async_store_coordinate_selection_elements_opt(
+            selection,
+            selection_elements,
+            &CodecOptions::default(),
+        )
+        .await
+    }
+
     /////////////////////////////////////////////////////////////////////////////
     // Advanced methods
     /////////////////////////////////////////////////////////////////////////////
@@ -145,33 +237,233 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
             let chunk_subset_bytes = chunk_subset_bytes.into();
             chunk_subset_bytes.validate(chunk_subset.num_elements(), self.data_type().size())?;
 
-            // Lock the chunk
-            // let key = self.chunk_key(chunk_indices);
-            // let mutex = self.storage.mutex(&key).await?;
-            // let _lock = mutex.lock();
-
-            // TODO: Add async partial encoding
+            if options.experimental_partial_encoding() {
+                let partial_encoder = self.async_partial_encoder(chunk_indices, options).await?;
+                Ok(partial_encoder
+                    .partial_encode(&[(chunk_subset, chunk_subset_bytes)], options)
+                    .await?)
+            } else {
+                self.async_update_chunk_opt(
+                    chunk_indices,
+                    |chunk_bytes_old| {
+                        Ok(unsafe {
+                            update_array_bytes(
+                                chunk_bytes_old,
+                                &chunk_shape,
+                                chunk_subset,
+                                &chunk_subset_bytes,
+                                self.data_type().size(),
+                            )
+                        })
+                    },
+                    options,
+                )
+                .await
+            }
+        }
+    }
 
-            // Decode the entire chunk
+    /// Async variant of [`update_chunk_opt`](Array::update_chunk_opt).
+    async fn async_update_chunk_opt(
+        &self,
+        chunk_indices: &[u64],
+        update: impl Fn(ArrayBytes<'_>) -> Result<ArrayBytes<'_>, ArrayError> + Send + Sync,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        if !options.conditional_chunk_updates() {
             let chunk_bytes_old = self
                 .async_retrieve_chunk_opt(chunk_indices, options)
                 .await?;
+            let chunk_bytes_new = update(chunk_bytes_old)?;
+            return self
+                .async_store_chunk_opt(chunk_indices, chunk_bytes_new, options)
+                .await;
+        }
+
+        for _ in 0..CONDITIONAL_CHUNK_UPDATE_ATTEMPTS {
+            // Decode the entire chunk
+            let (chunk_bytes_old, version) = self
+                .async_retrieve_chunk_versioned_opt(chunk_indices, options)
+                .await?;
 
             // Update the chunk
-            let chunk_bytes_new = unsafe {
-                update_array_bytes(
-                    chunk_bytes_old,
-                    &chunk_shape,
-                    chunk_subset,
-                    &chunk_subset_bytes,
-                    self.data_type().size(),
-                )
+            let chunk_bytes_new = update(chunk_bytes_old)?;
+
+            // Store the updated chunk, retrying if it was concurrently modified
+            match self
+                .async_store_chunk_if_version_opt(chunk_indices, chunk_bytes_new, version, options)
+                .await
+            {
+                Err(ArrayError::StorageError(StorageError::PreconditionFailed(_))) => {}
+                result => return result,
+            }
+        }
+        Err(StorageError::PreconditionFailed(self.chunk_key(chunk_indices)).into())
+    }
+
+    /// Async variant of [`store_chunk_selection_opt`](Array::store_chunk_selection_opt).
+    async fn async_store_chunk_selection_opt(
+        &self,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        dense: bool,
+        copy_from_selection: impl Fn(&mut [u8]) + Send + Sync,
+        data_type_size: usize,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        if dense || options.experimental_partial_encoding() {
+            let mut chunk_subset_bytes = if dense {
+                vec![0; chunk_subset.num_elements_usize() * data_type_size]
+            } else {
+                // Elements of the chunk subset that are not selected are retained
+                self.async_retrieve_chunk_subset_opt(chunk_indices, chunk_subset, options)
+                    .await?
+                    .into_fixed()?
+                    .into_owned()
             };
+            copy_from_selection(&mut chunk_subset_bytes);
+            self.async_store_chunk_subset_opt(
+                chunk_indices,
+                chunk_subset,
+                chunk_subset_bytes,
+                options,
+            )
+            .await
+        } else {
+            // Update the selected elements of the decoded chunk, so that it is only decoded once
+            let chunk_shape = self
+                .chunk_grid()
+                .chunk_shape_u64(chunk_indices, self.shape())?
+                .ok_or_else(|| ArrayError::InvalidChunkGridIndicesError(chunk_indices.to_vec()))?;
+            self.async_update_chunk_opt(
+                chunk_indices,
+                |chunk_bytes_old| {
+                    let mut chunk_subset_bytes = chunk_bytes_old
+                        .extract_array_subset(chunk_subset, &chunk_shape, self.data_type())?
+                        .into_fixed()?
+                        .into_owned();
+                    copy_from_selection(&mut chunk_subset_bytes);
+                    Ok(unsafe {
+                        update_array_bytes(
+                            chunk_bytes_old,
+                            &chunk_shape,
+                            chunk_subset,
+                            &ArrayBytes::new_flen(chunk_subset_bytes),
+                            self.data_type().size(),
+                        )
+                    })
+                },
+                options,
+            )
+            .await
+        }
+    }
+
+    /// Async variant of [`retrieve_chunk_versioned_opt`](Array::retrieve_chunk_versioned_opt).
+    async fn async_retrieve_chunk_versioned_opt(
+        &self,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<(ArrayBytes<'_>, ChunkVersion), ArrayError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_async_readable_transformer(storage_handle)
+            .await?;
+        match storage_transformer
+            .get_versioned(&self.chunk_key(chunk_indices))
+            .await
+        {
+            Ok(Some((chunk_encoded, version))) => {
+                let chunk_encoded: Vec<u8> = chunk_encoded.into();
+                let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+                let chunk_bytes = self
+                    .codecs()
+                    .decode(Cow::Owned(chunk_encoded), &chunk_representation, options)
+                    .map_err(ArrayError::CodecError)?;
+                chunk_bytes.validate(
+                    chunk_representation.num_elements(),
+                    chunk_representation.data_type().size(),
+                )?;
+                Ok((chunk_bytes, ChunkVersion::Version(version)))
+            }
+            Ok(None) => {
+                let chunk_shape = self.chunk_shape(chunk_indices)?;
+                let array_size =
+                    ArraySize::new(self.data_type().size(), chunk_shape.num_elements_u64());
+                Ok((
+                    ArrayBytes::new_fill_value(array_size, self.fill_value()),
+                    ChunkVersion::Missing,
+                ))
+            }
+            Err(StorageError::Unsupported(_)) => Ok((
+                self.async_retrieve_chunk_opt(chunk_indices, options)
+                    .await?,
+                ChunkVersion::Unversioned,
+            )),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Async variant of [`store_chunk_if_version_opt`](Array::store_chunk_if_version_opt).
+    async fn async_store_chunk_if_version_opt(
+        &self,
+        chunk_indices: &[u64],
+        chunk_bytes: ArrayBytes<'_>,
+        version: ChunkVersion,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let version = match version {
+            ChunkVersion::Unversioned => {
+                return self
+                    .async_store_chunk_opt(chunk_indices, chunk_bytes, options)
+                    .await
+            }
+            ChunkVersion::Missing => None,
+            ChunkVersion::Version(version) => Some(version),
+        };
+
+        let chunk_array_representation = self.chunk_array_representation(chunk_indices)?;
+        chunk_bytes.validate(
+            chunk_array_representation.num_elements(),
+            chunk_array_representation.data_type().size(),
+        )?;
+
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_async_writable_transformer(storage_handle)
+            .await?;
+        let key = self.chunk_key(chunk_indices);
 
-            // Store the updated chunk
-            self.async_store_chunk_opt(chunk_indices, chunk_bytes_new, options)
+        let is_fill_value =
+            !options.store_empty_chunks() && chunk_bytes.is_fill_value(self.fill_value());
+        if is_fill_value {
+            if let Some(version) = version {
+                match storage_transformer.erase_if_version(&key, &version).await {
+                    Ok(()) => {}
+                    Err(StorageError::Unsupported(_)) => storage_transformer.erase(&key).await?,
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        } else {
+            let chunk_encoded = self
+                .codecs()
+                .encode(chunk_bytes, &chunk_array_representation, options)
+                .map_err(ArrayError::CodecError)?;
+            let chunk_encoded = AsyncBytes::from(chunk_encoded.to_vec());
+            match storage_transformer
+                .set_if_version(&key, chunk_encoded.clone(), version.as_ref())
                 .await
+            {
+                Ok(_) => {}
+                Err(StorageError::Unsupported(_)) => {
+                    storage_transformer.set(&key, chunk_encoded).await?;
+                }
+                Err(err) => return Err(err.into()),
+            }
         }
+        Ok(())
     }
 
     /// Async variant of [`store_chunk_subset_elements_opt`](Array::store_chunk_subset_elements_opt).
@@ -346,4 +638,230 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         self.async_store_array_subset_elements_opt(&subset, &subset_array, options)
             .await
     }
+
+    /// Async variant of [`store_orthogonal_selection_opt`](Array::store_orthogonal_selection_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_orthogonal_selection_opt<'a>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>> + Send,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        if !selection.inbounds(self.shape()) {
+            return Err(ArrayError::InvalidOrthogonalSelection(
+                selection.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        if let Some(array_subset) = selection.to_array_subset() {
+            return self
+                .async_store_array_subset_opt(&array_subset, selection_bytes, options)
+                .await;
+        }
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "orthogonal selection of a variable sized data type".to_string(),
+            ));
+        };
+        let selection_bytes = selection_bytes.into();
+        selection_bytes.validate(selection.num_elements(), self.data_type().size())?;
+        let selection_bytes = selection_bytes.into_fixed()?;
+
+        // Find the chunks with selected elements
+        let chunks = self.orthogonal_selection_chunks(selection)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        let selection_shape = selection.shape();
+        let store_chunk =
+            |(chunk_indices, selection_in_chunk): (ArrayIndices, OrthogonalSelectionInSubset)| {
+                let options = options.clone();
+                let selection_bytes = &selection_bytes;
+                let selection_shape = &selection_shape;
+                async move {
+                    self.async_store_chunk_selection_opt(
+                        &chunk_indices,
+                        selection_in_chunk.subset(),
+                        selection_in_chunk.is_dense(),
+                        |chunk_subset_bytes| {
+                            selection_in_chunk.copy_from_selection(
+                                selection_bytes,
+                                chunk_subset_bytes,
+                                selection_shape,
+                                data_type_size,
+                            );
+                        },
+                        data_type_size,
+                        &options,
+                    )
+                    .await
+                }
+            };
+        futures::stream::iter(chunks)
+            .map(Ok)
+            .try_for_each_concurrent(Some(chunk_concurrent_limit), store_chunk)
+            .await?;
+        Ok(())
+    }
+
+    /// Async variant of [`store_orthogonal_selection_elements_opt`](Array::store_orthogonal_selection_elements_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_orthogonal_selection_elements_opt<T: Element + Send + Sync>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_elements: &[T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let selection_bytes = T::into_array_bytes(self.data_type(), selection_elements)?;
+        self.async_store_orthogonal_selection_opt(selection, selection_bytes, options)
+            .await
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Async variant of [`store_orthogonal_selection_ndarray_opt`](Array::store_orthogonal_selection_ndarray_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_orthogonal_selection_ndarray_opt<
+        T: Element + Send + Sync,
+        D: ndarray::Dimension,
+    >(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_array: impl Into<ndarray::Array<T, D>> + Send,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let selection_array: ndarray::Array<T, D> = selection_array.into();
+        let selection_shape: Vec<usize> = selection
+            .shape()
+            .iter()
+            .map(|&d| usize::try_from(d).unwrap())
+            .collect();
+        if selection_array.shape() != selection_shape {
+            return Err(ArrayError::InvalidDataShape(
+                selection_array.shape().to_vec(),
+                selection_shape,
+            ));
+        }
+        let selection_array = super::ndarray_into_vec(selection_array);
+        self.async_store_orthogonal_selection_elements_opt(selection, &selection_array, options)
+            .await
+    }
+
+    /// Async variant of [`store_coordinate_selection_opt`](Array::store_coordinate_selection_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_coordinate_selection_opt<'a>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>> + Send,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "coordinate selection of a variable sized data type".to_string(),
+            ));
+        };
+        let selection_bytes = selection_bytes.into();
+        selection_bytes.validate(selection.num_elements(), self.data_type().size())?;
+        let selection_bytes = selection_bytes.into_fixed()?;
+
+        // Find the chunks with selected elements
+        let chunks = self.coordinate_selection_chunks(selection)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        let store_chunk =
+            |(chunk_indices, selection_in_chunk): (ArrayIndices, CoordinateSelectionInSubset)| {
+                let options = options.clone();
+                let selection_bytes = &selection_bytes;
+                async move {
+                    self.async_store_chunk_selection_opt(
+                        &chunk_indices,
+                        selection_in_chunk.subset(),
+                        selection_in_chunk.is_dense(),
+                        |chunk_subset_bytes| {
+                            selection_in_chunk.copy_from_selection(
+                                selection_bytes,
+                                chunk_subset_bytes,
+                                data_type_size,
+                            );
+                        },
+                        data_type_size,
+                        &options,
+                    )
+                    .await
+                }
+            };
+        futures::stream::iter(chunks)
+            .map(Ok)
+            .try_for_each_concurrent(Some(chunk_concurrent_limit), store_chunk)
+            .await?;
+        Ok(())
+    }
+
+    /// Async variant of [`store_coordinate_selection_elements_opt`](Array::store_coordinate_selection_elements_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_store_coordinate_selection_elements_opt<T: Element + Send + Sync>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_elements: &[T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let selection_bytes = T::into_array_bytes(self.data_type(), selection_elements)?;
+        self.async_store_coordinate_selection_opt(selection, selection_bytes, options)
+            .await
+    }
+
+    /// Async variant of [`partial_encoder`](Array::partial_encoder).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_partial_encoder(
+        &self,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, ArrayError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+
+        // Input
+        let storage_transformer_read = self
+            .storage_transformers()
+            .create_async_readable_transformer(storage_handle.clone())
+            .await?;
+        let input_handle = Arc::new(AsyncStoragePartialDecoder::new(
+            storage_transformer_read,
+            self.chunk_key(chunk_indices),
+        ));
+        let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+
+        // Output
+        let storage_transformer_write = self
+            .storage_transformers()
+            .create_async_writable_transformer(storage_handle)
+            .await?;
+        let output_handle = Arc::new(AsyncStoragePartialEncoder::new(
+            storage_transformer_write,
+            self.chunk_key(chunk_indices),
+        ));
+
+        Ok(self
+            .codecs
+            .clone()
+            .async_partial_encoder(input_handle, output_handle, &chunk_representation, options)
+            .await?)
+    }
 }
@@ -73,6 +73,78 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         }
     }
 
+    /// Async variant of [`store_metadata_if_unchanged`](Array::store_metadata_if_unchanged).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_metadata_if_unchanged(&mut self) -> Result<(), StorageError> {
+        self.async_store_metadata_if_unchanged_opt(&ArrayMetadataOptions::default())
+            .await
+    }
+
+    /// Async variant of [`store_metadata_if_unchanged_opt`](Array::store_metadata_if_unchanged_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_metadata_if_unchanged_opt(
+        &mut self,
+        options: &ArrayMetadataOptions,
+    ) -> Result<(), StorageError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_async_writable_transformer(storage_handle)
+            .await?;
+
+        // Get the metadata with options applied
+        let metadata = self.metadata_opt(options);
+        if std::mem::discriminant(&metadata) != std::mem::discriminant(&self.metadata) {
+            return Err(StorageError::Unsupported(
+                "conditional metadata writes cannot convert the metadata version".to_string(),
+            ));
+        }
+
+        // Store the metadata
+        match metadata {
+            ArrayMetadata::V3(metadata) => {
+                let key = meta_key_v3(self.path());
+                let json = serde_json::to_vec_pretty(&metadata)
+                    .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
+                let version = storage_transformer
+                    .set_if_version(&key, json.into(), self.metadata_versions.get(&key))
+                    .await?;
+                self.metadata_versions.insert(key, version);
+            }
+            ArrayMetadata::V2(mut metadata) => {
+                let attributes = std::mem::take(&mut metadata.attributes);
+
+                // Store .zarray
+                let key = meta_key_v2_array(self.path());
+                let json = serde_json::to_vec_pretty(&metadata)
+                    .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
+                let version = storage_transformer
+                    .set_if_version(&key, json.into(), self.metadata_versions.get(&key))
+                    .await?;
+                self.metadata_versions.insert(key, version);
+
+                let key = meta_key_v2_attributes(self.path());
+                if attributes.is_empty() {
+                    // Erase .zattrs
+                    if let Some(version) = self.metadata_versions.get(&key) {
+                        storage_transformer.erase_if_version(&key, version).await?;
+                        self.metadata_versions.remove(&key);
+                    }
+                } else {
+                    // Store .zattrs
+                    let json = serde_json::to_vec_pretty(&attributes).map_err(|err| {
+                        StorageError::InvalidMetadata(key.clone(), err.to_string())
+                    })?;
+                    let version = storage_transformer
+                        .set_if_version(&key, json.into(), self.metadata_versions.get(&key))
+                        .await?;
+                    self.metadata_versions.insert(key, version);
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Async variant of [`store_chunk`](Array::store_chunk).
     #[allow(clippy::missing_errors_doc)]
     pub async fn async_store_chunk<'a>(
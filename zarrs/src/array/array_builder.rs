@@ -1,7 +1,16 @@
-use std::sync::Arc;
+use std::{collections::HashMap, num::NonZeroU64, sync::Arc};
 
 use crate::{
-    metadata::{v3::AdditionalFields, ChunkKeySeparator},
+    metadata::{
+        v3::{
+            array::chunk_key_encoding::{
+                default::DefaultChunkKeyEncodingConfiguration, v2::V2ChunkKeyEncodingConfiguration,
+            },
+            AdditionalFields, MetadataV3,
+        },
+        v3_to_v2::array_metadata_v3_to_v2,
+        ChunkKeySeparator, ZarrVersion,
+    },
     node::NodePath,
 };
 
@@ -12,8 +21,8 @@ use super::{
         BytesCodec, BytesToBytesCodecTraits,
     },
     data_type::IncompatibleFillValueError,
-    Array, ArrayCreateError, ArrayMetadata, ArrayMetadataV3, ArrayShape, ChunkGrid, CodecChain,
-    DataType, DimensionName, FillValue, StorageTransformerChain,
+    Array, ArrayCreateError, ArrayMetadata, ArrayMetadataV3, ArrayShape, ChunkGrid,
+    ChunkRepresentation, CodecChain, DataType, DimensionName, FillValue, StorageTransformerChain,
 };
 
 /// An [`Array`] builder.
@@ -23,6 +32,7 @@ use super::{
 ///  - The default chunk key encoding is `default` with the `/` chunk key separator.
 ///  - Attributes, storage transformers, and dimension names are empty.
 ///  - Codecs are configured to use multiple threads where possible.
+///  - The array is a Zarr V3 array.
 ///
 /// Use the methods in the array builder to change the configuration away from these defaults, and then build the array at a path of some storage with [`ArrayBuilder::build`].
 /// Note that [`build`](ArrayBuilder::build) does not modify the store; the array metadata has to be explicitly written with [`Array::store_metadata`].
@@ -83,6 +93,8 @@ pub struct ArrayBuilder {
     pub dimension_names: Option<Vec<DimensionName>>,
     /// Additional fields.
     pub additional_fields: AdditionalFields,
+    /// Zarr version.
+    pub zarr_version: ZarrVersion,
 }
 
 impl ArrayBuilder {
@@ -116,6 +128,7 @@ impl ArrayBuilder {
             storage_transformers: StorageTransformerChain::default(),
             dimension_names: None,
             additional_fields: AdditionalFields::default(),
+            zarr_version: ZarrVersion::V3,
         }
     }
 
@@ -136,7 +149,11 @@ impl ArrayBuilder {
             .array_to_array_codecs(array.codecs().array_to_array_codecs().to_vec())
             .array_to_bytes_codec(array.codecs().array_to_bytes_codec().clone())
             .bytes_to_bytes_codecs(array.codecs().bytes_to_bytes_codecs().to_vec())
-            .storage_transformers(array.storage_transformers().clone());
+            .storage_transformers(array.storage_transformers().clone())
+            .zarr_version(match array.metadata() {
+                ArrayMetadata::V3(_) => ZarrVersion::V3,
+                ArrayMetadata::V2(_) => ZarrVersion::V2,
+            });
         builder
     }
 
@@ -271,6 +288,19 @@ impl ArrayBuilder {
         self
     }
 
+    /// Set the Zarr version.
+    ///
+    /// If left unmodified, the array will be a Zarr V3 array.
+    ///
+    /// A Zarr V2 array is stored with `.zarray` and `.zattrs` metadata.
+    /// The array configuration must be compatible with Zarr V2, see [`array_metadata_v3_to_v2`].
+    /// For example, sharding, storage transformers and dimension names are not supported.
+    /// A `default` chunk key encoding is mapped to the `v2` chunk key encoding with the same separator.
+    pub fn zarr_version(&mut self, zarr_version: ZarrVersion) -> &mut Self {
+        self.zarr_version = zarr_version;
+        self
+    }
+
     /// Build into an [`Array`].
     ///
     /// # Errors
@@ -307,27 +337,42 @@ impl ArrayBuilder {
                 .into());
             }
         }
+        if let DataType::Extension(extension) = &self.data_type {
+            // Extension data types can reject a fill value when creating fill value metadata
+            extension.metadata_fill_value(&self.fill_value)?;
+        }
 
         let codec_chain = CodecChain::new(
             self.array_to_array_codecs.clone(),
             self.array_to_bytes_codec.clone(),
             self.bytes_to_bytes_codecs.clone(),
         );
-
-        let array_metadata = ArrayMetadata::V3(
-            ArrayMetadataV3::new(
-                self.shape.clone(),
-                self.chunk_grid.create_metadata(),
-                self.data_type.metadata(),
-                self.data_type.metadata_fill_value(&self.fill_value),
-                codec_chain.create_metadatas(),
-            )
-            .with_attributes(self.attributes.clone())
-            .with_additional_fields(self.additional_fields.clone())
-            .with_chunk_key_encoding(self.chunk_key_encoding.create_metadata())
-            .with_dimension_names(self.dimension_names.clone())
-            .with_storage_transformers(self.storage_transformers.create_metadatas()),
-        );
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::MIN; self.chunk_grid.dimensionality()],
+            self.data_type.clone(),
+            self.fill_value.clone(),
+        )?;
+        codec_chain
+            .validate_data_type(&chunk_representation)
+            .map_err(|err| ArrayCreateError::CodecsCreateError(err.to_string().into()))?;
+
+        let array_metadata_v3 = ArrayMetadataV3::new(
+            self.shape.clone(),
+            self.chunk_grid.create_metadata(),
+            self.data_type.metadata(),
+            self.data_type.metadata_fill_value(&self.fill_value),
+            codec_chain.create_metadatas(),
+        )
+        .with_attributes(self.attributes.clone())
+        .with_additional_fields(self.additional_fields.clone())
+        .with_chunk_key_encoding(self.chunk_key_encoding.create_metadata())
+        .with_dimension_names(self.dimension_names.clone())
+        .with_storage_transformers(self.storage_transformers.create_metadatas());
+
+        if self.zarr_version == ZarrVersion::V2 {
+            return Self::build_v2(storage, &path, array_metadata_v3);
+        }
+        let array_metadata = ArrayMetadata::V3(array_metadata_v3);
 
         Ok(Array {
             storage,
@@ -347,9 +392,36 @@ impl ArrayBuilder {
             dimension_names: self.dimension_names.clone(),
             // additional_fields: self.additional_fields.clone(),
             metadata: array_metadata,
+            metadata_versions: HashMap::new(),
         })
     }
 
+    /// Build a Zarr V2 array from compatible Zarr V3 array metadata.
+    fn build_v2<TStorage: ?Sized>(
+        storage: Arc<TStorage>,
+        path: &NodePath,
+        mut array_metadata_v3: ArrayMetadataV3,
+    ) -> Result<Array<TStorage>, ArrayCreateError> {
+        // Map the default chunk key encoding to the v2 chunk key encoding with the same separator
+        if array_metadata_v3.chunk_key_encoding.name()
+            == crate::metadata::v3::array::chunk_key_encoding::default::IDENTIFIER
+        {
+            let DefaultChunkKeyEncodingConfiguration { separator } = array_metadata_v3
+                .chunk_key_encoding
+                .to_configuration()
+                .map_err(|err| ArrayCreateError::UnsupportedZarrV2Array(err.to_string()))?;
+            array_metadata_v3.chunk_key_encoding = MetadataV3::new_with_serializable_configuration(
+                crate::metadata::v3::array::chunk_key_encoding::v2::IDENTIFIER,
+                &V2ChunkKeyEncodingConfiguration { separator },
+            )
+            .map_err(|err| ArrayCreateError::UnsupportedZarrV2Array(err.to_string()))?;
+        }
+
+        let array_metadata_v2 = array_metadata_v3_to_v2(&array_metadata_v3)
+            .map_err(|err| ArrayCreateError::UnsupportedZarrV2Array(err.to_string()))?;
+        Array::new_with_metadata(storage, path.as_str(), ArrayMetadata::V2(array_metadata_v2))
+    }
+
     /// Build into an [`Arc<Array>`].
     ///
     /// # Errors
@@ -430,6 +502,80 @@ mod tests {
         assert_eq!(builder.additional_fields, builder2.additional_fields);
     }
 
+    #[test]
+    fn array_builder_v2() {
+        let mut builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt16,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(1u16),
+        );
+        builder
+            .chunk_key_encoding_default_separator(ChunkKeySeparator::Dot)
+            .bytes_to_bytes_codecs(vec![
+                #[cfg(feature = "gzip")]
+                Arc::new(crate::array::codec::GzipCodec::new(5).unwrap()),
+            ])
+            .zarr_version(ZarrVersion::V2);
+
+        let storage = Arc::new(MemoryStore::new());
+        let array = builder.build(storage.clone(), "/array").unwrap();
+        let ArrayMetadata::V2(metadata) = array.metadata() else {
+            panic!("expected Zarr V2 array metadata");
+        };
+        assert_eq!(metadata.dimension_separator, ChunkKeySeparator::Dot);
+        assert_eq!(
+            serde_json::to_value(&metadata.fill_value).unwrap(),
+            serde_json::json!(1)
+        );
+        #[cfg(feature = "gzip")]
+        assert_eq!(metadata.compressor.as_ref().unwrap().id(), "gzip");
+        array.store_metadata().unwrap();
+        array
+            .store_chunk_elements::<u16>(&[0, 1], &[2; 16])
+            .unwrap();
+
+        let array = Array::open(storage, "/array").unwrap();
+        assert!(matches!(array.metadata(), ArrayMetadata::V2(_)));
+        assert_eq!(array.builder().zarr_version, ZarrVersion::V2);
+        assert_eq!(array.fill_value(), &FillValue::from(1u16));
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&[0, 1]).unwrap(),
+            vec![2; 16]
+        );
+    }
+
+    #[test]
+    fn array_builder_v2_unsupported() {
+        let storage = Arc::new(MemoryStore::new());
+        let mut builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::Int8,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(0i8),
+        );
+        builder.zarr_version(ZarrVersion::V2);
+        assert!(builder.build(storage.clone(), "/").is_ok());
+
+        builder.dimension_names(["y", "x"].into());
+        assert!(builder.build(storage.clone(), "/").is_err());
+        builder.dimension_names(None::<Vec<DimensionName>>);
+
+        #[cfg(feature = "sharding")]
+        {
+            builder.array_to_bytes_codec(Arc::new(
+                crate::array::codec::array_to_bytes::sharding::ShardingCodecBuilder::new(
+                    vec![2, 2].try_into().unwrap(),
+                )
+                .build(),
+            ));
+            assert_eq!(
+                builder.build(storage, "/").unwrap_err().to_string(),
+                "unsupported Zarr V2 array: codec sharding_indexed is not supported by Zarr V2"
+            );
+        }
+    }
+
     #[test]
     fn array_builder_invalid() {
         let storage = Arc::new(MemoryStore::new());
@@ -1,7 +1,7 @@
 use thiserror::Error;
 
 use crate::{
-    array_subset::{ArraySubset, IncompatibleDimensionalityError},
+    array_subset::{ArraySubset, IncompatibleDimensionalityError, OrthogonalSelection},
     metadata::v3::UnsupportedAdditionalFieldError,
     node::NodePathError,
     plugin::PluginCreateError,
@@ -84,6 +84,12 @@ pub enum ArrayError {
     /// Incompatible array subset.
     #[error("array subset {_0} is not compatible with array shape {_1:?}")]
     InvalidArraySubset(ArraySubset, ArrayShape),
+    /// Incompatible orthogonal selection.
+    #[error("orthogonal selection {_0} is not compatible with array shape {_1:?}")]
+    InvalidOrthogonalSelection(OrthogonalSelection, ArrayShape),
+    /// Incompatible coordinates of a coordinate selection.
+    #[error("coordinates {_0:?} are not compatible with array shape {_1:?}")]
+    InvalidCoordinates(ArrayIndices, ArrayShape),
     /// Incompatible chunk subset.
     #[error("chunk subset {_0} is not compatible with chunk {_1:?} with shape {_2:?}")]
     InvalidChunkSubset(ArraySubset, ArrayIndices, ArrayShape),
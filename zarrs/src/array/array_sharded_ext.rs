@@ -18,13 +18,15 @@ pub trait ArrayShardedExt: private::Sealed {
     /// The effective inner chunk shape is the "read granularity" of the sharded array that accounts for array-to-array codecs preceding the sharding codec.
     /// For example, the transpose codec changes the shape of an array subset that corresponds to a single inner chunk.
     /// The effective inner chunk shape is used when determining the inner chunk grid of a sharded array.
+    ///
+    /// Returns [`None`] for an unsharded array, or if an array-to-array codec cannot map the inner chunk shape to a decoded shape (e.g. `packbits`).
     fn effective_inner_chunk_shape(&self) -> Option<ChunkShape>;
 
     /// Retrieve the inner chunk grid.
     ///
     /// This uses the effective inner shape so that reading an inner chunk reads only one contiguous byte range.
     ///
-    /// Returns the normal chunk grid for an unsharded array.
+    /// Returns the normal chunk grid for an unsharded array, or if the effective inner chunk shape cannot be determined.
     fn inner_chunk_grid(&self) -> ChunkGrid;
 
     /// Return the shape of the inner chunk grid (i.e., the number of inner chunks).
@@ -65,17 +67,11 @@ impl<TStorage: ?Sized> ArrayShardedExt for Array<TStorage> {
     }
 
     fn effective_inner_chunk_shape(&self) -> Option<ChunkShape> {
-        let inner_chunk_shape = self.inner_chunk_shape();
-        if let Some(mut inner_chunk_shape) = inner_chunk_shape {
-            for codec in self.codecs().array_to_array_codecs().iter().rev() {
-                inner_chunk_shape = codec
-                    .compute_decoded_shape(inner_chunk_shape)
-                    .expect("the inner chunk shape is compatible");
-            }
-            Some(inner_chunk_shape)
-        } else {
-            None
+        let mut inner_chunk_shape = self.inner_chunk_shape()?;
+        for codec in self.codecs().array_to_array_codecs().iter().rev() {
+            inner_chunk_shape = codec.compute_decoded_shape(inner_chunk_shape).ok()?;
         }
+        Some(inner_chunk_shape)
     }
 
     fn inner_chunk_grid(&self) -> ChunkGrid {
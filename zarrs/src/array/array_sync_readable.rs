@@ -5,11 +5,16 @@ use rayon_iter_concurrent_limit::iter_concurrent_limit;
 use unsafe_cell_slice::UnsafeCellSlice;
 
 use crate::{
-    array::{ArrayBytes, ArrayMetadataV2},
-    array_subset::ArraySubset,
+    array::{ArrayBytes, ArrayIndices, ArrayMetadataV2},
+    array_subset::{
+        ArraySubset, CoordinateSelection, CoordinateSelectionInSubset, OrthogonalSelection,
+        OrthogonalSelectionInSubset,
+    },
     config::MetadataRetrieveVersion,
     node::{meta_key_v2_array, meta_key_v2_attributes, meta_key_v3, NodePath},
-    storage::{ReadableStorageTraits, StorageError, StorageHandle},
+    storage::{
+        Bytes, ReadableStorageTraits, StorageError, StorageHandle, StoreKey, StoreValueVersion,
+    },
 };
 
 use super::{
@@ -51,29 +56,51 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         if let MetadataRetrieveVersion::Default | MetadataRetrieveVersion::V3 = version {
             // Try V3
             let key_v3 = meta_key_v3(&node_path);
-            if let Some(metadata) = storage.get(&key_v3)? {
-                let metadata: ArrayMetadataV3 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v3, err.to_string()))?;
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata));
+            if let Some((metadata, metadata_version)) = get_versioned_or_get(&*storage, &key_v3)? {
+                let metadata: ArrayMetadataV3 =
+                    serde_json::from_slice(&metadata).map_err(|err| {
+                        StorageError::InvalidMetadata(key_v3.clone(), err.to_string())
+                    })?;
+                let mut array =
+                    Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata))?;
+                if let Some(metadata_version) = metadata_version {
+                    array.metadata_versions.insert(key_v3, metadata_version);
+                }
+                return Ok(array);
             }
         }
 
         if let MetadataRetrieveVersion::Default | MetadataRetrieveVersion::V2 = version {
             // Try V2
             let key_v2 = meta_key_v2_array(&node_path);
-            if let Some(metadata) = storage.get(&key_v2)? {
-                let mut metadata: ArrayMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v2, err.to_string()))?;
+            if let Some((metadata, metadata_version)) = get_versioned_or_get(&*storage, &key_v2)? {
+                let mut metadata: ArrayMetadataV2 =
+                    serde_json::from_slice(&metadata).map_err(|err| {
+                        StorageError::InvalidMetadata(key_v2.clone(), err.to_string())
+                    })?;
 
                 let attributes_key = meta_key_v2_attributes(&node_path);
-                let attributes = storage.get(&attributes_key)?;
-                if let Some(attributes) = attributes {
+                let mut attributes_version = None;
+                if let Some((attributes, version)) =
+                    get_versioned_or_get(&*storage, &attributes_key)?
+                {
                     metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
+                        StorageError::InvalidMetadata(attributes_key.clone(), err.to_string())
                     })?;
+                    attributes_version = version;
                 }
 
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata));
+                let mut array =
+                    Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata))?;
+                if let Some(metadata_version) = metadata_version {
+                    array.metadata_versions.insert(key_v2, metadata_version);
+                }
+                if let Some(attributes_version) = attributes_version {
+                    array
+                        .metadata_versions
+                        .insert(attributes_key, attributes_version);
+                }
+                return Ok(array);
             }
         }
 
@@ -393,6 +420,92 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         self.retrieve_array_subset_ndarray_opt(array_subset, &CodecOptions::default())
     }
 
+    /// Read and decode the elements of an orthogonal `selection` of the array into its bytes.
+    ///
+    /// Only the chunks containing selected elements are retrieved.
+    /// See [`OrthogonalSelection`] for the shape and order of the selected elements.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if:
+    ///  - the `selection` is invalid or out of bounds of the array,
+    ///  - the data type is variable sized and the `selection` is not an array subset,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    ///
+    /// # Panics
+    /// Panics if attempting to reference a byte beyond `usize::MAX`.
+    pub fn retrieve_orthogonal_selection(
+        &self,
+        selection: &OrthogonalSelection,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        self.retrieve_orthogonal_selection_opt(selection, &CodecOptions::default())
+    }
+
+    /// Read and decode the elements of an orthogonal `selection` of the array into a vector of its elements.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if:
+    ///  - the size of `T` does not match the data type size,
+    ///  - the decoded bytes cannot be transmuted, or
+    ///  - a [`retrieve_orthogonal_selection`](Array::retrieve_orthogonal_selection) error condition is met.
+    pub fn retrieve_orthogonal_selection_elements<T: ElementOwned>(
+        &self,
+        selection: &OrthogonalSelection,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.retrieve_orthogonal_selection_elements_opt(selection, &CodecOptions::default())
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Read and decode the elements of an orthogonal `selection` of the array into an [`ndarray::ArrayD`].
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if a [`retrieve_orthogonal_selection_elements`](Array::retrieve_orthogonal_selection_elements) error condition is met.
+    ///
+    /// # Panics
+    /// Will panic if any dimension of the `selection` shape is `usize::MAX` or larger.
+    pub fn retrieve_orthogonal_selection_ndarray<T: ElementOwned>(
+        &self,
+        selection: &OrthogonalSelection,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        self.retrieve_orthogonal_selection_ndarray_opt(selection, &CodecOptions::default())
+    }
+
+    /// Read and decode the elements of a coordinate `selection` of the array into its bytes.
+    ///
+    /// The selected elements are grouped by chunk and each chunk containing selected elements is retrieved once.
+    /// The elements are returned in the order of the coordinates of the `selection`.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if:
+    ///  - the `selection` dimensionality does not match the array dimensionality,
+    ///  - any coordinates of the `selection` are out of bounds of the array,
+    ///  - the data type is variable sized,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    ///
+    /// # Panics
+    /// Panics if attempting to reference a byte beyond `usize::MAX`.
+    pub fn retrieve_coordinate_selection(
+        &self,
+        selection: &CoordinateSelection,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        self.retrieve_coordinate_selection_opt(selection, &CodecOptions::default())
+    }
+
+    /// Read and decode the elements of a coordinate `selection` of the array into a vector of its elements.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if:
+    ///  - the size of `T` does not match the data type size,
+    ///  - the decoded bytes cannot be transmuted, or
+    ///  - a [`retrieve_coordinate_selection`](Array::retrieve_coordinate_selection) error condition is met.
+    pub fn retrieve_coordinate_selection_elements<T: ElementOwned>(
+        &self,
+        selection: &CoordinateSelection,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.retrieve_coordinate_selection_elements_opt(selection, &CodecOptions::default())
+    }
+
     /// Initialises a partial decoder for the chunk at `chunk_indices`.
     ///
     /// # Errors
@@ -785,6 +898,181 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         elements_to_ndarray(array_subset.shape(), elements)
     }
 
+    /// Explicit options version of [`retrieve_orthogonal_selection`](Array::retrieve_orthogonal_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn retrieve_orthogonal_selection_opt(
+        &self,
+        selection: &OrthogonalSelection,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        if !selection.inbounds(self.shape()) {
+            return Err(ArrayError::InvalidOrthogonalSelection(
+                selection.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        if let Some(array_subset) = selection.to_array_subset() {
+            return self.retrieve_array_subset_opt(&array_subset, options);
+        }
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "orthogonal selection of a variable sized data type".to_string(),
+            ));
+        };
+
+        // Allocate the output
+        let size_output = selection.num_elements_usize() * data_type_size;
+        if size_output == 0 {
+            return Ok(ArrayBytes::new_flen(vec![]));
+        }
+        let mut output = Vec::with_capacity(size_output);
+
+        // Find the chunks with selected elements
+        let chunks = self.orthogonal_selection_chunks(selection)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        {
+            let output = UnsafeCellSlice::new_from_vec_with_spare_capacity(&mut output);
+            let selection_shape = selection.shape();
+            let retrieve_chunk = |(chunk_indices, selection_in_chunk): (
+                ArrayIndices,
+                OrthogonalSelectionInSubset,
+            )| {
+                let chunk_subset_bytes = self
+                    .retrieve_chunk_subset_opt(
+                        &chunk_indices,
+                        selection_in_chunk.subset(),
+                        &options,
+                    )?
+                    .into_fixed()?;
+                // SAFETY: each element of the selection is in exactly one chunk
+                unsafe {
+                    selection_in_chunk.copy_to_selection(
+                        &chunk_subset_bytes,
+                        &output,
+                        &selection_shape,
+                        data_type_size,
+                    );
+                }
+                Ok::<_, ArrayError>(())
+            };
+            iter_concurrent_limit!(chunk_concurrent_limit, chunks, try_for_each, retrieve_chunk)?;
+        }
+        unsafe { output.set_len(size_output) };
+        Ok(ArrayBytes::from(output))
+    }
+
+    /// Explicit options version of [`retrieve_orthogonal_selection_elements`](Array::retrieve_orthogonal_selection_elements).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_orthogonal_selection_elements_opt<T: ElementOwned>(
+        &self,
+        selection: &OrthogonalSelection,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        T::from_array_bytes(
+            self.data_type(),
+            self.retrieve_orthogonal_selection_opt(selection, options)?,
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Explicit options version of [`retrieve_orthogonal_selection_ndarray`](Array::retrieve_orthogonal_selection_ndarray).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_orthogonal_selection_ndarray_opt<T: ElementOwned>(
+        &self,
+        selection: &OrthogonalSelection,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        let elements = self.retrieve_orthogonal_selection_elements_opt::<T>(selection, options)?;
+        elements_to_ndarray(&selection.shape(), elements)
+    }
+
+    /// Explicit options version of [`retrieve_coordinate_selection`](Array::retrieve_coordinate_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn retrieve_coordinate_selection_opt(
+        &self,
+        selection: &CoordinateSelection,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "coordinate selection of a variable sized data type".to_string(),
+            ));
+        };
+
+        // Find the chunks with selected elements
+        let chunks = self.coordinate_selection_chunks(selection)?;
+
+        // Allocate the output
+        let size_output = selection.num_elements_usize() * data_type_size;
+        if size_output == 0 {
+            return Ok(ArrayBytes::new_flen(vec![]));
+        }
+        let mut output = Vec::with_capacity(size_output);
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        {
+            let output = UnsafeCellSlice::new_from_vec_with_spare_capacity(&mut output);
+            let retrieve_chunk = |(chunk_indices, selection_in_chunk): (
+                ArrayIndices,
+                CoordinateSelectionInSubset,
+            )| {
+                let chunk_subset_bytes = self
+                    .retrieve_chunk_subset_opt(
+                        &chunk_indices,
+                        selection_in_chunk.subset(),
+                        &options,
+                    )?
+                    .into_fixed()?;
+                // SAFETY: each element of the selection is in exactly one chunk
+                unsafe {
+                    selection_in_chunk.copy_to_selection(
+                        &chunk_subset_bytes,
+                        &output,
+                        data_type_size,
+                    );
+                }
+                Ok::<_, ArrayError>(())
+            };
+            iter_concurrent_limit!(chunk_concurrent_limit, chunks, try_for_each, retrieve_chunk)?;
+        }
+        unsafe { output.set_len(size_output) };
+        Ok(ArrayBytes::from(output))
+    }
+
+    /// Explicit options version of [`retrieve_coordinate_selection_elements`](Array::retrieve_coordinate_selection_elements).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_coordinate_selection_elements_opt<T: ElementOwned>(
+        &self,
+        selection: &CoordinateSelection,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        T::from_array_bytes(
+            self.data_type(),
+            self.retrieve_coordinate_selection_opt(selection, options)?,
+        )
+    }
+
     /// Explicit options version of [`retrieve_chunk_subset`](Array::retrieve_chunk_subset).
     #[allow(clippy::missing_errors_doc)]
     pub fn retrieve_chunk_subset_opt(
@@ -933,3 +1221,15 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
             .partial_decoder(input_handle, &chunk_representation, options)?)
     }
 }
+
+/// Retrieve the value and version of `key`, falling back to an unversioned retrieval if the store does not support versioned values.
+fn get_versioned_or_get<TStorage: ?Sized + ReadableStorageTraits>(
+    storage: &TStorage,
+    key: &StoreKey,
+) -> Result<Option<(Bytes, Option<StoreValueVersion>)>, StorageError> {
+    match storage.get_versioned(key) {
+        Ok(value) => Ok(value.map(|(bytes, version)| (bytes, Some(version)))),
+        Err(StorageError::Unsupported(_)) => Ok(storage.get(key)?.map(|bytes| (bytes, None))),
+        Err(err) => Err(err),
+    }
+}
@@ -1,11 +1,16 @@
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::{
-    array::ArrayBytes,
-    array_subset::ArraySubset,
-    storage::{ReadableWritableStorageTraits, StorageHandle},
+    array::{ArrayBytes, ArrayIndices, DataTypeSize},
+    array_subset::{
+        ArraySubset, CoordinateSelection, CoordinateSelectionInSubset, OrthogonalSelection,
+        OrthogonalSelectionInSubset,
+    },
+    storage::{
+        Bytes, ReadableWritableStorageTraits, StorageError, StorageHandle, StoreValueVersion,
+    },
 };
 
 use super::{
@@ -14,7 +19,7 @@ use super::{
         StoragePartialDecoder, StoragePartialEncoder,
     },
     concurrency::concurrency_chunks_and_codec,
-    update_array_bytes, Array, ArrayError, Element,
+    update_array_bytes, Array, ArrayError, ArraySize, Element,
 };
 
 impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage> {
@@ -151,6 +156,116 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         self.store_array_subset_ndarray_opt(subset_start, subset_array, &CodecOptions::default())
     }
 
+    /// Encode `selection_bytes` and store in the elements of an orthogonal `selection` of the array.
+    ///
+    /// Use [`store_orthogonal_selection_opt`](Array::store_orthogonal_selection_opt) to control codec options.
+    /// Only the chunks containing selected elements are decoded, updated, and encoded.
+    /// See [`OrthogonalSelection`] for the shape and order of the selected elements.
+    /// If an element is selected more than once, the last occurrence in the selection is stored.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the `selection` is invalid or out of bounds of the array,
+    ///  - the data type is variable sized and the `selection` is not an array subset,
+    ///  - the length of `selection_bytes` does not match the expected length governed by the shape of the selection and the data type size,
+    ///  - there is a codec encoding error, or
+    ///  - an underlying store error.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn store_orthogonal_selection<'a>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>>,
+    ) -> Result<(), ArrayError> {
+        self.store_orthogonal_selection_opt(selection, selection_bytes, &CodecOptions::default())
+    }
+
+    /// Encode `selection_elements` and store in the elements of an orthogonal `selection` of the array.
+    ///
+    /// Use [`store_orthogonal_selection_elements_opt`](Array::store_orthogonal_selection_elements_opt) to control codec options.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the size of `T` does not match the data type size, or
+    ///  - a [`store_orthogonal_selection`](Array::store_orthogonal_selection) error condition is met.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn store_orthogonal_selection_elements<T: Element>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_elements: &[T],
+    ) -> Result<(), ArrayError> {
+        self.store_orthogonal_selection_elements_opt(
+            selection,
+            selection_elements,
+            &CodecOptions::default(),
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Encode `selection_array` and store in the elements of an orthogonal `selection` of the array.
+    ///
+    /// Use [`store_orthogonal_selection_ndarray_opt`](Array::store_orthogonal_selection_ndarray_opt) to control codec options.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the shape of `selection_array` does not match the shape of the `selection`, or
+    ///  - a [`store_orthogonal_selection_elements`](Array::store_orthogonal_selection_elements) error condition is met.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn store_orthogonal_selection_ndarray<T: Element, D: ndarray::Dimension>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_array: impl Into<ndarray::Array<T, D>>,
+    ) -> Result<(), ArrayError> {
+        self.store_orthogonal_selection_ndarray_opt(
+            selection,
+            selection_array,
+            &CodecOptions::default(),
+        )
+    }
+
+    /// Encode `selection_bytes` and store in the elements of a coordinate `selection` of the array.
+    ///
+    /// Use [`store_coordinate_selection_opt`](Array::store_coordinate_selection_opt) to control codec options.
+    /// The selected elements are grouped by chunk and each chunk containing selected elements is decoded, updated, and encoded once.
+    /// If an element is selected more than once, the last occurrence in the selection is stored.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the `selection` dimensionality does not match the array dimensionality,
+    ///  - any coordinates of the `selection` are out of bounds of the array,
+    ///  - the data type is variable sized,
+    ///  - the length of `selection_bytes` does not match the expected length governed by the number of selected elements and the data type size,
+    ///  - there is a codec encoding error, or
+    ///  - an underlying store error.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn store_coordinate_selection<'a>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>>,
+    ) -> Result<(), ArrayError> {
+        self.store_coordinate_selection_opt(selection, selection_bytes, &CodecOptions::default())
+    }
+
+    /// Encode `selection_elements` and store in the elements of a coordinate `selection` of the array.
+    ///
+    /// Use [`store_coordinate_selection_elements_opt`](Array::store_coordinate_selection_elements_opt) to control codec options.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the size of `T` does not match the data type size, or
+    ///  - a [`store_coordinate_selection`](Array::store_coordinate_selection) error condition is met.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn store_coordinate_selection_elements<T: Element>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_elements: &[T],
+    ) -> Result<(), ArrayError> {
+        self.store_coordinate_selection_elements_opt(
+            selection,
+            selection_elements,
+            &CodecOptions::default(),
+        )
+    }
+
     /////////////////////////////////////////////////////////////////////////////
     // Advanced methods
     /////////////////////////////////////////////////////////////////////////////
@@ -185,37 +300,224 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
             let chunk_subset_bytes = chunk_subset_bytes.into();
             chunk_subset_bytes.validate(chunk_subset.num_elements(), self.data_type().size())?;
 
-            // Lock the chunk
-            // let key = self.chunk_key(chunk_indices);
-            // let mutex = self.storage.mutex(&key)?;
-            // let _lock = mutex.lock();
-
             if options.experimental_partial_encoding() {
                 let partial_encoder = self.partial_encoder(chunk_indices, options)?;
                 Ok(partial_encoder
                     .partial_encode(&[(chunk_subset, chunk_subset_bytes)], options)?)
             } else {
-                // Decode the entire chunk
-                let chunk_bytes_old = self.retrieve_chunk_opt(chunk_indices, options)?;
-                chunk_bytes_old.validate(chunk_shape.iter().product(), self.data_type().size())?;
-
-                // Update the chunk
-                let chunk_bytes_new = unsafe {
-                    update_array_bytes(
-                        chunk_bytes_old,
-                        &chunk_shape,
-                        chunk_subset,
-                        &chunk_subset_bytes,
-                        self.data_type().size(),
-                    )
-                };
-
-                // Store the updated chunk
-                self.store_chunk_opt(chunk_indices, chunk_bytes_new, options)
+                self.update_chunk_opt(
+                    chunk_indices,
+                    |chunk_bytes_old| {
+                        Ok(unsafe {
+                            update_array_bytes(
+                                chunk_bytes_old,
+                                &chunk_shape,
+                                chunk_subset,
+                                &chunk_subset_bytes,
+                                self.data_type().size(),
+                            )
+                        })
+                    },
+                    options,
+                )
             }
         }
     }
 
+    /// Decode the chunk at `chunk_indices`, `update` it, and store the updated chunk.
+    ///
+    /// If [`CodecOptions::conditional_chunk_updates`] is enabled and the store supports value versions, the chunk is only stored if it was not concurrently modified.
+    /// Otherwise, the update is retried up to [`CONDITIONAL_CHUNK_UPDATE_ATTEMPTS`] times.
+    ///
+    /// # Errors
+    /// Returns [`StorageError::PreconditionFailed`] if the chunk was concurrently modified on every attempt.
+    fn update_chunk_opt(
+        &self,
+        chunk_indices: &[u64],
+        update: impl Fn(ArrayBytes<'_>) -> Result<ArrayBytes<'_>, ArrayError>,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        if !options.conditional_chunk_updates() {
+            let chunk_bytes_old = self.retrieve_chunk_opt(chunk_indices, options)?;
+            let chunk_bytes_new = update(chunk_bytes_old)?;
+            return self.store_chunk_opt(chunk_indices, chunk_bytes_new, options);
+        }
+
+        for _ in 0..CONDITIONAL_CHUNK_UPDATE_ATTEMPTS {
+            // Decode the entire chunk
+            let (chunk_bytes_old, version) =
+                self.retrieve_chunk_versioned_opt(chunk_indices, options)?;
+
+            // Update the chunk
+            let chunk_bytes_new = update(chunk_bytes_old)?;
+
+            // Store the updated chunk, retrying if it was concurrently modified
+            match self.store_chunk_if_version_opt(chunk_indices, chunk_bytes_new, version, options)
+            {
+                Err(ArrayError::StorageError(StorageError::PreconditionFailed(_))) => {}
+                result => return result,
+            }
+        }
+        Err(StorageError::PreconditionFailed(self.chunk_key(chunk_indices)).into())
+    }
+
+    /// Store the elements of a selection in `chunk_subset` of the chunk at `chunk_indices`.
+    ///
+    /// `copy_from_selection` copies the selected elements into the bytes of `chunk_subset`.
+    /// If the selection is not `dense` in `chunk_subset`, elements of `chunk_subset` that are not selected are retained.
+    /// Without partial encoding, the chunk is decoded at most once.
+    fn store_chunk_selection_opt(
+        &self,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        dense: bool,
+        copy_from_selection: impl Fn(&mut [u8]),
+        data_type_size: usize,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        if dense || options.experimental_partial_encoding() {
+            let mut chunk_subset_bytes = if dense {
+                vec![0; chunk_subset.num_elements_usize() * data_type_size]
+            } else {
+                // Elements of the chunk subset that are not selected are retained
+                self.retrieve_chunk_subset_opt(chunk_indices, chunk_subset, options)?
+                    .into_fixed()?
+                    .into_owned()
+            };
+            copy_from_selection(&mut chunk_subset_bytes);
+            self.store_chunk_subset_opt(chunk_indices, chunk_subset, chunk_subset_bytes, options)
+        } else {
+            // Update the selected elements of the decoded chunk, so that it is only decoded once
+            let chunk_shape = self
+                .chunk_grid()
+                .chunk_shape_u64(chunk_indices, self.shape())?
+                .ok_or_else(|| ArrayError::InvalidChunkGridIndicesError(chunk_indices.to_vec()))?;
+            self.update_chunk_opt(
+                chunk_indices,
+                |chunk_bytes_old| {
+                    let mut chunk_subset_bytes = chunk_bytes_old
+                        .extract_array_subset(chunk_subset, &chunk_shape, self.data_type())?
+                        .into_fixed()?
+                        .into_owned();
+                    copy_from_selection(&mut chunk_subset_bytes);
+                    Ok(unsafe {
+                        update_array_bytes(
+                            chunk_bytes_old,
+                            &chunk_shape,
+                            chunk_subset,
+                            &ArrayBytes::new_flen(chunk_subset_bytes),
+                            self.data_type().size(),
+                        )
+                    })
+                },
+                options,
+            )
+        }
+    }
+
+    /// Retrieve and decode the chunk at `chunk_indices` and its [`ChunkVersion`] for a read-modify-write.
+    fn retrieve_chunk_versioned_opt(
+        &self,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<(ArrayBytes<'_>, ChunkVersion), ArrayError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_readable_transformer(storage_handle)?;
+        match storage_transformer.get_versioned(&self.chunk_key(chunk_indices)) {
+            Ok(Some((chunk_encoded, version))) => {
+                let chunk_encoded: Vec<u8> = chunk_encoded.into();
+                let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+                let chunk_bytes = self
+                    .codecs()
+                    .decode(Cow::Owned(chunk_encoded), &chunk_representation, options)
+                    .map_err(ArrayError::CodecError)?;
+                chunk_bytes.validate(
+                    chunk_representation.num_elements(),
+                    chunk_representation.data_type().size(),
+                )?;
+                Ok((chunk_bytes, ChunkVersion::Version(version)))
+            }
+            Ok(None) => {
+                let chunk_shape = self.chunk_shape(chunk_indices)?;
+                let array_size =
+                    ArraySize::new(self.data_type().size(), chunk_shape.num_elements_u64());
+                Ok((
+                    ArrayBytes::new_fill_value(array_size, self.fill_value()),
+                    ChunkVersion::Missing,
+                ))
+            }
+            Err(StorageError::Unsupported(_)) => Ok((
+                self.retrieve_chunk_opt(chunk_indices, options)?,
+                ChunkVersion::Unversioned,
+            )),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Encode `chunk_bytes` and store at `chunk_indices` if the stored chunk matches `version`.
+    ///
+    /// A chunk that is entirely the fill value is erased if the stored chunk matches `version`.
+    /// The chunk is stored or erased unconditionally if the store does not support conditional writes or erasure.
+    ///
+    /// # Errors
+    /// Returns [`StorageError::PreconditionFailed`] if the stored chunk does not match `version`.
+    fn store_chunk_if_version_opt(
+        &self,
+        chunk_indices: &[u64],
+        chunk_bytes: ArrayBytes<'_>,
+        version: ChunkVersion,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let version = match version {
+            ChunkVersion::Unversioned => {
+                return self.store_chunk_opt(chunk_indices, chunk_bytes, options)
+            }
+            ChunkVersion::Missing => None,
+            ChunkVersion::Version(version) => Some(version),
+        };
+
+        let chunk_array_representation = self.chunk_array_representation(chunk_indices)?;
+        chunk_bytes.validate(
+            chunk_array_representation.num_elements(),
+            chunk_array_representation.data_type().size(),
+        )?;
+
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_writable_transformer(storage_handle)?;
+        let key = self.chunk_key(chunk_indices);
+
+        let is_fill_value =
+            !options.store_empty_chunks() && chunk_bytes.is_fill_value(self.fill_value());
+        if is_fill_value {
+            if let Some(version) = version {
+                match storage_transformer.erase_if_version(&key, &version) {
+                    Ok(()) => {}
+                    Err(StorageError::Unsupported(_)) => storage_transformer.erase(&key)?,
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        } else {
+            let chunk_encoded = self
+                .codecs()
+                .encode(chunk_bytes, &chunk_array_representation, options)
+                .map_err(ArrayError::CodecError)?;
+            let chunk_encoded = Bytes::from(chunk_encoded.into_owned());
+            match storage_transformer.set_if_version(&key, chunk_encoded.clone(), version.as_ref())
+            {
+                Ok(_) => {}
+                Err(StorageError::Unsupported(_)) => {
+                    storage_transformer.set(&key, chunk_encoded)?;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(())
+    }
+
     /// Explicit options version of [`store_chunk_subset_elements`](Array::store_chunk_subset_elements).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     pub fn store_chunk_subset_elements_opt<T: Element>(
@@ -369,6 +671,186 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         self.store_array_subset_elements_opt(&subset, &subset_array, options)
     }
 
+    /// Explicit options version of [`store_orthogonal_selection`](Array::store_orthogonal_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn store_orthogonal_selection_opt<'a>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>>,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        if !selection.inbounds(self.shape()) {
+            return Err(ArrayError::InvalidOrthogonalSelection(
+                selection.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        if let Some(array_subset) = selection.to_array_subset() {
+            return self.store_array_subset_opt(&array_subset, selection_bytes, options);
+        }
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "orthogonal selection of a variable sized data type".to_string(),
+            ));
+        };
+        let selection_bytes = selection_bytes.into();
+        selection_bytes.validate(selection.num_elements(), self.data_type().size())?;
+        let selection_bytes = selection_bytes.into_fixed()?;
+
+        // Find the chunks with selected elements
+        let chunks = self.orthogonal_selection_chunks(selection)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        let selection_shape = selection.shape();
+        let store_chunk = |(chunk_indices, selection_in_chunk): (
+            ArrayIndices,
+            OrthogonalSelectionInSubset,
+        )|
+         -> Result<(), ArrayError> {
+            self.store_chunk_selection_opt(
+                &chunk_indices,
+                selection_in_chunk.subset(),
+                selection_in_chunk.is_dense(),
+                |chunk_subset_bytes| {
+                    selection_in_chunk.copy_from_selection(
+                        &selection_bytes,
+                        chunk_subset_bytes,
+                        &selection_shape,
+                        data_type_size,
+                    );
+                },
+                data_type_size,
+                &options,
+            )
+        };
+        rayon_iter_concurrent_limit::iter_concurrent_limit!(
+            chunk_concurrent_limit,
+            chunks,
+            try_for_each,
+            store_chunk
+        )?;
+        Ok(())
+    }
+
+    /// Explicit options version of [`store_orthogonal_selection_elements`](Array::store_orthogonal_selection_elements).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn store_orthogonal_selection_elements_opt<T: Element>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_elements: &[T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let selection_bytes = T::into_array_bytes(self.data_type(), selection_elements)?;
+        self.store_orthogonal_selection_opt(selection, selection_bytes, options)
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Explicit options version of [`store_orthogonal_selection_ndarray`](Array::store_orthogonal_selection_ndarray).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn store_orthogonal_selection_ndarray_opt<T: Element, D: ndarray::Dimension>(
+        &self,
+        selection: &OrthogonalSelection,
+        selection_array: impl Into<ndarray::Array<T, D>>,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let selection_array: ndarray::Array<T, D> = selection_array.into();
+        let selection_shape: Vec<usize> = selection
+            .shape()
+            .iter()
+            .map(|&d| usize::try_from(d).unwrap())
+            .collect();
+        if selection_array.shape() != selection_shape {
+            return Err(ArrayError::InvalidDataShape(
+                selection_array.shape().to_vec(),
+                selection_shape,
+            ));
+        }
+        let selection_array = super::ndarray_into_vec(selection_array);
+        self.store_orthogonal_selection_elements_opt(selection, &selection_array, options)
+    }
+
+    /// Explicit options version of [`store_coordinate_selection`](Array::store_coordinate_selection).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn store_coordinate_selection_opt<'a>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_bytes: impl Into<ArrayBytes<'a>>,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(
+                "coordinate selection of a variable sized data type".to_string(),
+            ));
+        };
+        let selection_bytes = selection_bytes.into();
+        selection_bytes.validate(selection.num_elements(), self.data_type().size())?;
+        let selection_bytes = selection_bytes.into_fixed()?;
+
+        // Find the chunks with selected elements
+        let chunks = self.coordinate_selection_chunks(selection)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        let store_chunk = |(chunk_indices, selection_in_chunk): (
+            ArrayIndices,
+            CoordinateSelectionInSubset,
+        )|
+         -> Result<(), ArrayError> {
+            self.store_chunk_selection_opt(
+                &chunk_indices,
+                selection_in_chunk.subset(),
+                selection_in_chunk.is_dense(),
+                |chunk_subset_bytes| {
+                    selection_in_chunk.copy_from_selection(
+                        &selection_bytes,
+                        chunk_subset_bytes,
+                        data_type_size,
+                    );
+                },
+                data_type_size,
+                &options,
+            )
+        };
+        rayon_iter_concurrent_limit::iter_concurrent_limit!(
+            chunk_concurrent_limit,
+            chunks,
+            try_for_each,
+            store_chunk
+        )?;
+        Ok(())
+    }
+
+    /// Explicit options version of [`store_coordinate_selection_elements`](Array::store_coordinate_selection_elements).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn store_coordinate_selection_elements_opt<T: Element>(
+        &self,
+        selection: &CoordinateSelection,
+        selection_elements: &[T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let selection_bytes = T::into_array_bytes(self.data_type(), selection_elements)?;
+        self.store_coordinate_selection_opt(selection, selection_bytes, options)
+    }
+
     /// Initialises a partial encoder for the chunk at `chunk_indices`.
     ///
     /// Only one partial encoder should be created for a chunk at a time because:
@@ -413,3 +895,16 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         )?)
     }
 }
+
+/// The maximum number of attempts of a conditional chunk update.
+pub(super) const CONDITIONAL_CHUNK_UPDATE_ATTEMPTS: usize = 64;
+
+/// The version of a chunk retrieved for a read-modify-write.
+pub(super) enum ChunkVersion {
+    /// The store does not support value versions.
+    Unversioned,
+    /// The chunk does not exist.
+    Missing,
+    /// The version of the chunk.
+    Version(StoreValueVersion),
+}
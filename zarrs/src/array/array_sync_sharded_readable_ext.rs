@@ -443,7 +443,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayShardedReadableExt
         inner_chunk_indices: &[u64],
         options: &CodecOptions,
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        if let Some(inner_chunk_shape) = self.inner_chunk_shape() {
+        if let Some(inner_chunk_shape) = self.effective_inner_chunk_shape() {
             super::elements_to_ndarray(
                 &inner_chunk_shape.to_array_shape(),
                 self.retrieve_inner_chunk_elements_opt::<T>(cache, inner_chunk_indices, options)?,
@@ -950,4 +950,44 @@ mod tests {
             "invalid inner chunk shape [1, 3, 3], it must evenly divide [4, 8, 3]"
         )
     }
+
+    #[cfg(feature = "packbits")]
+    #[test]
+    fn array_sharded_ext_packbits() -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MemoryStore::default());
+        let mut builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::Bool,
+            vec![4, 4].try_into()?,
+            FillValue::from(false),
+        );
+        builder.array_to_array_codecs(vec![Arc::new(crate::array::codec::PackBitsCodec::new())]);
+        builder.array_to_bytes_codec(Arc::new(
+            ShardingCodecBuilder::new(vec![1].try_into()?).build(),
+        ));
+        let array = builder.build(store, "/array")?;
+
+        let data: Vec<bool> = (0..64).map(|i| i % 3 == 0).collect();
+        array.store_array_subset_elements(&array.subset_all(), &data)?;
+
+        // The packbits codec cannot map the inner chunk shape to a decoded shape, so inner chunks are chunks
+        assert!(array.is_sharded());
+        assert_eq!(array.inner_chunk_shape(), Some(vec![1].try_into()?));
+        assert_eq!(array.effective_inner_chunk_shape(), None);
+        assert_eq!(
+            array.inner_chunk_grid().grid_shape(array.shape())?,
+            Some(vec![2, 2])
+        );
+
+        let cache = ArrayShardedReadableExtCache::new(&array);
+        assert_eq!(
+            array.retrieve_inner_chunk_elements_opt::<bool>(
+                &cache,
+                &[1, 0],
+                &CodecOptions::default()
+            )?,
+            array.retrieve_chunk_elements::<bool>(&[1, 0])?
+        );
+        Ok(())
+    }
 }
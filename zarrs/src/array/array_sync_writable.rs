@@ -75,6 +75,99 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         }
     }
 
+    /// Store metadata with default [`ArrayMetadataOptions`] if the stored metadata is unchanged since the array was opened.
+    ///
+    /// See [`store_metadata_if_unchanged_opt`](Array::store_metadata_if_unchanged_opt).
+    ///
+    /// # Errors
+    /// Returns [`StorageError::PreconditionFailed`] if the stored metadata has changed, or another [`StorageError`] if there is an underlying store error.
+    pub fn store_metadata_if_unchanged(&mut self) -> Result<(), StorageError> {
+        self.store_metadata_if_unchanged_opt(&ArrayMetadataOptions::default())
+    }
+
+    /// Store metadata with non-default [`ArrayMetadataOptions`] if the stored metadata is unchanged since the array was opened.
+    ///
+    /// This enables safe concurrent metadata updates (e.g. attributes) by multiple writers.
+    /// The version of each metadata key is recorded when the array is opened, and a key is only written if its stored version is unchanged.
+    /// A key without a recorded version (e.g. the array was created rather than opened) is only written if it does not already exist.
+    /// On success, the recorded versions are updated so that subsequent conditional writes succeed.
+    ///
+    /// For a Zarr V2 array, `.zarray` and then `.zattrs` are written conditionally.
+    /// If the attributes are empty, `.zattrs` is erased conditionally instead.
+    /// If the write of `.zattrs` fails, the update of `.zarray` is not reverted.
+    ///
+    /// # Errors
+    /// Returns [`StorageError::PreconditionFailed`] if the stored metadata has changed.
+    /// Returns [`StorageError::Unsupported`] if `options` convert the metadata to another Zarr version, or the store does not support conditional writes.
+    /// Returns another [`StorageError`] if there is an underlying store error.
+    pub fn store_metadata_if_unchanged_opt(
+        &mut self,
+        options: &ArrayMetadataOptions,
+    ) -> Result<(), StorageError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_writable_transformer(storage_handle)?;
+
+        // Get the metadata with options applied
+        let metadata = self.metadata_opt(options);
+        if std::mem::discriminant(&metadata) != std::mem::discriminant(&self.metadata) {
+            return Err(StorageError::Unsupported(
+                "conditional metadata writes cannot convert the metadata version".to_string(),
+            ));
+        }
+
+        // Store the metadata
+        match metadata {
+            ArrayMetadata::V3(metadata) => {
+                let key = meta_key_v3(self.path());
+                let json = serde_json::to_vec_pretty(&metadata)
+                    .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
+                let version = storage_transformer.set_if_version(
+                    &key,
+                    json.into(),
+                    self.metadata_versions.get(&key),
+                )?;
+                self.metadata_versions.insert(key, version);
+            }
+            ArrayMetadata::V2(mut metadata) => {
+                let attributes = std::mem::take(&mut metadata.attributes);
+
+                // Store .zarray
+                let key = meta_key_v2_array(self.path());
+                let json = serde_json::to_vec_pretty(&metadata)
+                    .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
+                let version = storage_transformer.set_if_version(
+                    &key,
+                    json.into(),
+                    self.metadata_versions.get(&key),
+                )?;
+                self.metadata_versions.insert(key, version);
+
+                let key = meta_key_v2_attributes(self.path());
+                if attributes.is_empty() {
+                    // Erase .zattrs
+                    if let Some(version) = self.metadata_versions.get(&key) {
+                        storage_transformer.erase_if_version(&key, version)?;
+                        self.metadata_versions.remove(&key);
+                    }
+                } else {
+                    // Store .zattrs
+                    let json = serde_json::to_vec_pretty(&attributes).map_err(|err| {
+                        StorageError::InvalidMetadata(key.clone(), err.to_string())
+                    })?;
+                    let version = storage_transformer.set_if_version(
+                        &key,
+                        json.into(),
+                        self.metadata_versions.get(&key),
+                    )?;
+                    self.metadata_versions.insert(key, version);
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Encode `chunk_bytes` and store at `chunk_indices`.
     ///
     /// Use [`store_chunk_opt`](Array::store_chunk_opt) to control codec options.
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+#[cfg(feature = "async")]
+use crate::storage::AsyncReadableStorageTraits;
 use crate::storage::ReadableStorageTraits;
 
 use super::{codec::CodecOptions, Array, ArrayBytes, ArrayError, RawBytes};
@@ -8,6 +10,11 @@ use super::{codec::CodecOptions, Array, ArrayBytes, ArrayError, RawBytes};
 // pub mod array_chunk_cache_ext_encoded_sync;
 pub(crate) mod array_chunk_cache_ext_sync;
 pub(crate) mod chunk_cache_lru;
+
+#[cfg(feature = "async")]
+pub(crate) mod array_chunk_cache_ext_async;
+#[cfg(feature = "async")]
+pub(crate) mod chunk_cache_lru_async;
 // pub mod chunk_cache_lru_chunk_limit_thread_local;
 // pub mod chunk_cache_lru_size_limit_thread_local;
 
@@ -91,4 +98,36 @@ pub trait ChunkCache<CT: ChunkCacheType>: Send + Sync {
     }
 }
 
-// TODO: AsyncChunkCache
+/// Traits for an async chunk cache.
+///
+/// Implementations should coalesce concurrent retrievals of the same chunk where possible, so that a chunk that is not in the cache is only retrieved from the store once.
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncChunkCache<CT: ChunkCacheType>: Send + Sync {
+    /// Retrieve and decode a chunk.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if the underlying array retrieval method fails.
+    async fn async_retrieve_chunk<TStorage: ?Sized + AsyncReadableStorageTraits + 'static>(
+        &self,
+        array: &Array<TStorage>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Arc<ArrayBytes<'static>>, ArrayError>;
+
+    /// Retrieve a chunk from the cache. Returns [`None`] if the chunk is not present.
+    ///
+    /// The chunk cache implementation may modify the cache (e.g. update LRU cache) on retrieval.
+    async fn get(&self, chunk_indices: &[u64]) -> Option<Arc<CT>>;
+
+    /// Insert a chunk into the cache.
+    async fn insert(&self, chunk_indices: Vec<u64>, chunk: Arc<CT>);
+
+    /// Return the number of chunks in the cache.
+    async fn len(&self) -> usize;
+
+    /// Returns true if the cache is empty.
+    async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
@@ -0,0 +1,542 @@
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
+use unsafe_cell_slice::UnsafeCellSlice;
+
+use crate::{
+    array::{
+        array_bytes::{merge_chunks_vlen, update_bytes_flen},
+        codec::CodecOptions,
+        concurrency::concurrency_chunks_and_codec,
+        Array, ArrayBytes, ArrayError, ArraySize, DataTypeSize, ElementOwned,
+    },
+    array_subset::ArraySubset,
+    storage::AsyncReadableStorageTraits,
+};
+
+use super::{AsyncChunkCache, ChunkCacheType};
+
+/// An async [`Array`] extension trait to support reading with a chunk cache.
+///
+/// Note that these methods never perform partial decoding and always fully decode chunks intersected that are not in the cache.
+#[async_trait::async_trait]
+pub trait AsyncArrayChunkCacheExt<TStorage: ?Sized + AsyncReadableStorageTraits + 'static>:
+    private::Sealed
+{
+    /// Cached variant of [`async_retrieve_chunk_opt`](Array::async_retrieve_chunk_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunk_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Arc<ArrayBytes<'static>>, ArrayError>;
+
+    /// Cached variant of [`async_retrieve_chunk_elements_opt`](Array::async_retrieve_chunk_elements_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunk_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError>;
+
+    #[cfg(feature = "ndarray")]
+    /// Cached variant of [`async_retrieve_chunk_ndarray_opt`](Array::async_retrieve_chunk_ndarray_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunk_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError>;
+
+    /// Cached variant of [`async_retrieve_chunks_opt`](Array::async_retrieve_chunks_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunks_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunks: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'static>, ArrayError>;
+
+    /// Cached variant of [`async_retrieve_chunks_elements_opt`](Array::async_retrieve_chunks_elements_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunks_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunks: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError>;
+
+    #[cfg(feature = "ndarray")]
+    /// Cached variant of [`async_retrieve_chunks_ndarray_opt`](Array::async_retrieve_chunks_ndarray_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunks_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunks: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError>;
+
+    /// Cached variant of [`async_retrieve_chunk_subset_opt`](Array::async_retrieve_chunk_subset_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunk_subset_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'static>, ArrayError>;
+
+    /// Cached variant of [`async_retrieve_chunk_subset_elements_opt`](Array::async_retrieve_chunk_subset_elements_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunk_subset_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError>;
+
+    #[cfg(feature = "ndarray")]
+    /// Cached variant of [`async_retrieve_chunk_subset_ndarray_opt`](Array::async_retrieve_chunk_subset_ndarray_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_chunk_subset_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError>;
+
+    /// Cached variant of [`async_retrieve_array_subset_opt`](Array::async_retrieve_array_subset_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_array_subset_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'static>, ArrayError>;
+
+    /// Cached variant of [`async_retrieve_array_subset_elements_opt`](Array::async_retrieve_array_subset_elements_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_array_subset_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError>;
+
+    #[cfg(feature = "ndarray")]
+    /// Cached variant of [`async_retrieve_array_subset_ndarray_opt`](Array::async_retrieve_array_subset_ndarray_opt).
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_retrieve_array_subset_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError>;
+}
+
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> AsyncArrayChunkCacheExt<TStorage>
+    for Array<TStorage>
+{
+    async fn async_retrieve_chunk_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Arc<ArrayBytes<'static>>, ArrayError> {
+        cache
+            .async_retrieve_chunk(self, chunk_indices, options)
+            .await
+    }
+
+    async fn async_retrieve_chunk_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        T::from_array_bytes(
+            self.data_type(),
+            Arc::unwrap_or_clone(
+                self.async_retrieve_chunk_opt_cached(cache, chunk_indices, options)
+                    .await?,
+            ),
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    async fn async_retrieve_chunk_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        let shape = self
+            .chunk_grid()
+            .chunk_shape_u64(chunk_indices, self.shape())?
+            .ok_or_else(|| ArrayError::InvalidChunkGridIndicesError(chunk_indices.to_vec()))?;
+        crate::array::elements_to_ndarray(
+            &shape,
+            self.async_retrieve_chunk_elements_opt_cached(cache, chunk_indices, options)
+                .await?,
+        )
+    }
+
+    async fn async_retrieve_chunks_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunks: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'static>, ArrayError> {
+        if chunks.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                chunks.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+
+        let array_subset = self.chunks_subset(chunks)?;
+        self.async_retrieve_array_subset_opt_cached(cache, &array_subset, options)
+            .await
+    }
+
+    async fn async_retrieve_chunks_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunks: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        T::from_array_bytes(
+            self.data_type(),
+            self.async_retrieve_chunks_opt_cached(cache, chunks, options)
+                .await?,
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    async fn async_retrieve_chunks_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunks: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        let array_subset = self.chunks_subset(chunks)?;
+        let elements = self
+            .async_retrieve_chunks_elements_opt_cached(cache, chunks, options)
+            .await?;
+        crate::array::elements_to_ndarray(array_subset.shape(), elements)
+    }
+
+    async fn async_retrieve_chunk_subset_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'static>, ArrayError> {
+        let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+        if !chunk_subset.inbounds(&chunk_representation.shape_u64()) {
+            return Err(ArrayError::InvalidArraySubset(
+                chunk_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+
+        let chunk_bytes = self
+            .async_retrieve_chunk_opt_cached(cache, chunk_indices, options)
+            .await?;
+
+        let chunk_subset_bytes = if chunk_subset.start().iter().all(|&o| o == 0)
+            && chunk_subset.shape() == chunk_representation.shape_u64()
+        {
+            // Fast path if `chunk_subset` encompasses the whole chunk
+            Arc::unwrap_or_clone(chunk_bytes)
+        } else {
+            chunk_bytes
+                .extract_array_subset(
+                    chunk_subset,
+                    &chunk_representation.shape_u64(),
+                    self.data_type(),
+                )?
+                .into_owned()
+        };
+        Ok(chunk_subset_bytes)
+    }
+
+    async fn async_retrieve_chunk_subset_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        T::from_array_bytes(
+            self.data_type(),
+            self.async_retrieve_chunk_subset_opt_cached(
+                cache,
+                chunk_indices,
+                chunk_subset,
+                options,
+            )
+            .await?,
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    async fn async_retrieve_chunk_subset_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        chunk_indices: &[u64],
+        chunk_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        let elements = self
+            .async_retrieve_chunk_subset_elements_opt_cached(
+                cache,
+                chunk_indices,
+                chunk_subset,
+                options,
+            )
+            .await?;
+        crate::array::elements_to_ndarray(chunk_subset.shape(), elements)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    async fn async_retrieve_array_subset_opt_cached<CT: ChunkCacheType>(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'static>, ArrayError> {
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+
+        // Find the chunks intersecting this array subset
+        let chunks = self.chunks_in_array_subset(array_subset)?;
+        let Some(chunks) = chunks else {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        };
+
+        let chunk_representation0 =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+
+        let num_chunks = chunks.num_elements_usize();
+        match num_chunks {
+            0 => {
+                let array_size =
+                    ArraySize::new(self.data_type().size(), array_subset.num_elements());
+                Ok(ArrayBytes::new_fill_value(array_size, self.fill_value()))
+            }
+            1 => {
+                let chunk_indices = chunks.start();
+                let chunk_subset = self.chunk_subset(chunk_indices)?;
+                if &chunk_subset == array_subset {
+                    // Single chunk fast path if the array subset domain matches the chunk domain
+                    Ok(Arc::unwrap_or_clone(
+                        self.async_retrieve_chunk_opt_cached(cache, chunk_indices, options)
+                            .await?,
+                    ))
+                } else {
+                    let array_subset_in_chunk_subset =
+                        unsafe { array_subset.relative_to_unchecked(chunk_subset.start()) };
+                    self.async_retrieve_chunk_subset_opt_cached(
+                        cache,
+                        chunk_indices,
+                        &array_subset_in_chunk_subset,
+                        options,
+                    )
+                    .await
+                }
+            }
+            _ => {
+                // Calculate chunk/codec concurrency
+                let codec_concurrency =
+                    self.recommended_codec_concurrency(&chunk_representation0)?;
+                let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+                    options.concurrent_target(),
+                    num_chunks,
+                    options,
+                    &codec_concurrency,
+                );
+
+                // Retrieve chunks
+                let retrieve_chunk = |chunk_indices: Vec<u64>| {
+                    let options = options.clone();
+                    async move {
+                        let chunk_subset = self.chunk_subset(&chunk_indices)?;
+                        self.async_retrieve_chunk_opt_cached(cache, &chunk_indices, &options)
+                            .await
+                            .map(|bytes| (bytes, chunk_subset))
+                    }
+                };
+                let chunk_bytes_and_subsets: Vec<(Arc<ArrayBytes>, ArraySubset)> =
+                    futures::stream::iter(chunks.indices().into_iter().map(retrieve_chunk))
+                        .buffer_unordered(chunk_concurrent_limit)
+                        .try_collect()
+                        .await?;
+
+                // Merge
+                match self.data_type().size() {
+                    DataTypeSize::Variable => {
+                        let chunk_bytes_and_subsets = chunk_bytes_and_subsets
+                            .iter()
+                            .map(|(chunk_bytes, chunk_subset)| {
+                                let chunk_subset_overlap = chunk_subset.overlap(array_subset)?;
+                                Ok::<_, ArrayError>((
+                                    chunk_bytes
+                                        .extract_array_subset(
+                                            &chunk_subset_overlap
+                                                .relative_to(chunk_subset.start())?,
+                                            chunk_subset.shape(),
+                                            self.data_type(),
+                                        )?
+                                        .into_owned(),
+                                    chunk_subset_overlap.relative_to(array_subset.start())?,
+                                ))
+                            })
+                            .collect::<Result<Vec<_>, _>>()?;
+                        Ok(merge_chunks_vlen(
+                            chunk_bytes_and_subsets,
+                            array_subset.shape(),
+                        )?)
+                    }
+                    DataTypeSize::Fixed(data_type_size) => {
+                        // Allocate the output
+                        let size_output = array_subset.num_elements_usize() * data_type_size;
+                        if size_output == 0 {
+                            return Ok(ArrayBytes::new_flen(vec![]));
+                        }
+                        let mut output = Vec::with_capacity(size_output);
+
+                        {
+                            let output =
+                                UnsafeCellSlice::new_from_vec_with_spare_capacity(&mut output);
+                            for (chunk_subset_bytes, chunk_subset) in chunk_bytes_and_subsets {
+                                // Extract the overlapping bytes
+                                let chunk_subset_overlap = chunk_subset.overlap(array_subset)?;
+                                let chunk_subset_bytes = if chunk_subset_overlap == chunk_subset {
+                                    chunk_subset_bytes
+                                } else {
+                                    Arc::new(chunk_subset_bytes.extract_array_subset(
+                                        &chunk_subset_overlap.relative_to(chunk_subset.start())?,
+                                        chunk_subset.shape(),
+                                        self.data_type(),
+                                    )?)
+                                };
+
+                                let fixed = match chunk_subset_bytes.as_ref() {
+                                    ArrayBytes::Fixed(fixed) => fixed,
+                                    ArrayBytes::Variable(_, _) => unreachable!(),
+                                };
+
+                                update_bytes_flen(
+                                    &output,
+                                    array_subset.shape(),
+                                    fixed,
+                                    &chunk_subset_overlap.relative_to(array_subset.start())?,
+                                    data_type_size,
+                                );
+                            }
+                        }
+                        unsafe { output.set_len(size_output) };
+                        Ok(ArrayBytes::from(output))
+                    }
+                }
+            }
+        }
+    }
+
+    async fn async_retrieve_array_subset_elements_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        T::from_array_bytes(
+            self.data_type(),
+            self.async_retrieve_array_subset_opt_cached(cache, array_subset, options)
+                .await?,
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    async fn async_retrieve_array_subset_ndarray_opt_cached<
+        T: ElementOwned + Send + Sync,
+        CT: ChunkCacheType,
+    >(
+        &self,
+        cache: &impl AsyncChunkCache<CT>,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ndarray::ArrayD<T>, ArrayError> {
+        let elements = self
+            .async_retrieve_array_subset_elements_opt_cached(cache, array_subset, options)
+            .await?;
+        crate::array::elements_to_ndarray(array_subset.shape(), elements)
+    }
+}
+
+mod private {
+    use super::{Array, AsyncReadableStorageTraits};
+
+    pub trait Sealed {}
+
+    impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Sealed for Array<TStorage> {}
+}
@@ -0,0 +1,357 @@
+use std::{borrow::Cow, sync::Arc};
+
+use moka::{
+    future::{Cache, CacheBuilder},
+    policy::EvictionPolicy,
+};
+
+use crate::{
+    array::{
+        codec::{ArrayToBytesCodecTraits, CodecOptions},
+        Array, ArrayBytes, ArrayError, ArrayIndices, ArraySize,
+    },
+    storage::{AsyncReadableStorageTraits, StorageError},
+};
+
+use super::{AsyncChunkCache, ChunkCacheType, ChunkCacheTypeDecoded, ChunkCacheTypeEncoded};
+
+type ChunkIndices = ArrayIndices;
+
+/// An async chunk cache with a fixed chunk capacity.
+pub struct AsyncChunkCacheLruChunkLimit<T: ChunkCacheType> {
+    cache: Cache<ChunkIndices, Arc<T>>,
+}
+
+/// An async LRU (least recently used) encoded chunk cache with a fixed chunk capacity.
+pub type AsyncChunkCacheEncodedLruChunkLimit = AsyncChunkCacheLruChunkLimit<ChunkCacheTypeEncoded>;
+
+/// An async LRU (least recently used) decoded chunk cache with a fixed chunk capacity.
+pub type AsyncChunkCacheDecodedLruChunkLimit = AsyncChunkCacheLruChunkLimit<ChunkCacheTypeDecoded>;
+
+/// An async chunk cache with a fixed size capacity.
+pub struct AsyncChunkCacheLruSizeLimit<T: ChunkCacheType> {
+    cache: Cache<ChunkIndices, Arc<T>>,
+}
+
+/// An async LRU (least recently used) encoded chunk cache with a fixed size capacity in bytes.
+pub type AsyncChunkCacheEncodedLruSizeLimit = AsyncChunkCacheLruSizeLimit<ChunkCacheTypeEncoded>;
+
+/// An async LRU (least recently used) decoded chunk cache with a fixed size capacity in bytes.
+pub type AsyncChunkCacheDecodedLruSizeLimit = AsyncChunkCacheLruSizeLimit<ChunkCacheTypeDecoded>;
+
+impl<CT: ChunkCacheType> AsyncChunkCacheLruChunkLimit<CT> {
+    /// Create a new [`AsyncChunkCacheLruChunkLimit`] with a capacity in chunks of `chunk_capacity`.
+    #[must_use]
+    pub fn new(chunk_capacity: u64) -> Self {
+        let cache = CacheBuilder::new(chunk_capacity)
+            .eviction_policy(EvictionPolicy::lru())
+            .build();
+        Self { cache }
+    }
+}
+
+impl<CT: ChunkCacheType> AsyncChunkCacheLruSizeLimit<CT> {
+    /// Create a new [`AsyncChunkCacheLruSizeLimit`] with a capacity in bytes of `capacity`.
+    #[must_use]
+    pub fn new(capacity: u64) -> Self {
+        let cache = CacheBuilder::new(capacity)
+            .eviction_policy(EvictionPolicy::lru())
+            .weigher(|_k, v: &Arc<CT>| u32::try_from(v.size()).unwrap_or(u32::MAX))
+            .build();
+        Self { cache }
+    }
+}
+
+/// moka returns an Arc'd error, unwrap it noting that [`ArrayError`] is not cloneable.
+fn unwrap_arc_error(err: Arc<ArrayError>) -> ArrayError {
+    Arc::try_unwrap(err)
+        .unwrap_or_else(|err| ArrayError::StorageError(StorageError::from(err.to_string())))
+}
+
+async fn async_retrieve_chunk_encoded<TStorage: ?Sized + AsyncReadableStorageTraits + 'static>(
+    cache: &Cache<ChunkIndices, Arc<ChunkCacheTypeEncoded>>,
+    array: &Array<TStorage>,
+    chunk_indices: &[u64],
+    options: &CodecOptions,
+) -> Result<Arc<ArrayBytes<'static>>, ArrayError> {
+    // Concurrent requests for the same chunk are coalesced, only one retrieves the chunk
+    let chunk_encoded = cache
+        .try_get_with(chunk_indices.to_vec(), async {
+            let chunk_encoded = array.async_retrieve_encoded_chunk(chunk_indices).await?;
+            Ok::<_, ArrayError>(Arc::new(
+                chunk_encoded.map(|chunk_encoded| Cow::Owned(chunk_encoded.into())),
+            ))
+        })
+        .await
+        .map_err(unwrap_arc_error)?;
+
+    if let Some(chunk_encoded) = chunk_encoded.as_ref() {
+        let chunk_representation = array.chunk_array_representation(chunk_indices)?;
+        let bytes = array
+            .codecs()
+            .decode(Cow::Borrowed(chunk_encoded), &chunk_representation, options)
+            .map_err(ArrayError::CodecError)?;
+        bytes.validate(
+            chunk_representation.num_elements(),
+            chunk_representation.data_type().size(),
+        )?;
+        Ok(Arc::new(bytes.into_owned()))
+    } else {
+        let chunk_shape = array.chunk_shape(chunk_indices)?;
+        let array_size = ArraySize::new(array.data_type().size(), chunk_shape.num_elements_u64());
+        Ok(Arc::new(ArrayBytes::new_fill_value(
+            array_size,
+            array.fill_value(),
+        )))
+    }
+}
+
+async fn async_retrieve_chunk_decoded<TStorage: ?Sized + AsyncReadableStorageTraits + 'static>(
+    cache: &Cache<ChunkIndices, Arc<ChunkCacheTypeDecoded>>,
+    array: &Array<TStorage>,
+    chunk_indices: &[u64],
+    options: &CodecOptions,
+) -> Result<Arc<ArrayBytes<'static>>, ArrayError> {
+    // Concurrent requests for the same chunk are coalesced, only one retrieves the chunk
+    cache
+        .try_get_with(chunk_indices.to_vec(), async {
+            Ok::<_, ArrayError>(Arc::new(
+                array
+                    .async_retrieve_chunk_opt(chunk_indices, options)
+                    .await?
+                    .into_owned(),
+            ))
+        })
+        .await
+        .map_err(unwrap_arc_error)
+}
+
+macro_rules! impl_AsyncChunkCacheLru {
+    ($cache:ty, $ct:ty, $retrieve:ident) => {
+        #[async_trait::async_trait]
+        impl AsyncChunkCache<$ct> for $cache {
+            async fn async_retrieve_chunk<
+                TStorage: ?Sized + AsyncReadableStorageTraits + 'static,
+            >(
+                &self,
+                array: &Array<TStorage>,
+                chunk_indices: &[u64],
+                options: &CodecOptions,
+            ) -> Result<Arc<ArrayBytes<'static>>, ArrayError> {
+                $retrieve(&self.cache, array, chunk_indices, options).await
+            }
+
+            async fn get(&self, chunk_indices: &[u64]) -> Option<Arc<$ct>> {
+                self.cache.get(&chunk_indices.to_vec()).await
+            }
+
+            async fn insert(&self, chunk_indices: ChunkIndices, chunk: Arc<$ct>) {
+                self.cache.insert(chunk_indices, chunk).await;
+            }
+
+            async fn len(&self) -> usize {
+                self.cache.run_pending_tasks().await;
+                usize::try_from(self.cache.entry_count()).unwrap()
+            }
+        }
+    };
+}
+
+impl_AsyncChunkCacheLru!(
+    AsyncChunkCacheEncodedLruChunkLimit,
+    ChunkCacheTypeEncoded,
+    async_retrieve_chunk_encoded
+);
+impl_AsyncChunkCacheLru!(
+    AsyncChunkCacheDecodedLruChunkLimit,
+    ChunkCacheTypeDecoded,
+    async_retrieve_chunk_decoded
+);
+impl_AsyncChunkCacheLru!(
+    AsyncChunkCacheEncodedLruSizeLimit,
+    ChunkCacheTypeEncoded,
+    async_retrieve_chunk_encoded
+);
+impl_AsyncChunkCacheLru!(
+    AsyncChunkCacheDecodedLruSizeLimit,
+    ChunkCacheTypeDecoded,
+    async_retrieve_chunk_decoded
+);
+
+#[cfg(feature = "ndarray")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{mem::size_of, sync::Arc};
+
+    use object_store::memory::InMemory;
+    use zarrs_object_store::AsyncObjectStore;
+
+    use crate::{
+        array::{Array, ArrayBuilder, AsyncArrayChunkCacheExt, DataType, FillValue},
+        array_subset::ArraySubset,
+        storage::storage_adapter::performance_metrics::PerformanceMetricsStorageAdapter,
+    };
+
+    type TestStore = PerformanceMetricsStorageAdapter<AsyncObjectStore<InMemory>>;
+
+    async fn array_chunk_cache_array() -> (Arc<TestStore>, Array<TestStore>) {
+        let store = Arc::new(AsyncObjectStore::new(InMemory::new()));
+        let store = Arc::new(PerformanceMetricsStorageAdapter::new(store));
+        let builder = ArrayBuilder::new(
+            vec![8, 8], // array shape
+            DataType::UInt8,
+            vec![4, 4].try_into().unwrap(), // regular chunk shape
+            FillValue::from(0u8),
+        );
+        let array = builder.build(store.clone(), "/").unwrap();
+
+        let data: Vec<u8> = (0..array.shape().iter().product())
+            .map(|i| u8::try_from(i).unwrap())
+            .collect();
+        array
+            .async_store_array_subset_elements(&array.subset_all(), &data)
+            .await
+            .unwrap();
+        (store, array)
+    }
+
+    async fn array_chunk_cache_impl<TChunkCache: AsyncChunkCache<CT>, CT: ChunkCacheType>(
+        cache: TChunkCache,
+    ) {
+        let (store, array) = array_chunk_cache_array().await;
+
+        assert_eq!(store.reads(), 0);
+        assert!(cache.is_empty().await);
+        assert_eq!(
+            array
+                .async_retrieve_array_subset_ndarray_opt_cached::<u8, _>(
+                    &cache,
+                    &ArraySubset::new_with_ranges(&[3..5, 0..4]),
+                    &CodecOptions::default()
+                )
+                .await
+                .unwrap(),
+            ndarray::array![[24, 25, 26, 27], [32, 33, 34, 35]].into_dyn()
+        );
+        assert_eq!(store.reads(), 2);
+        assert_eq!(cache.len().await, 2);
+
+        // Retrieve a chunk in cache
+        assert_eq!(
+            array
+                .async_retrieve_chunk_ndarray_opt_cached::<u8, _>(
+                    &cache,
+                    &[0, 0],
+                    &CodecOptions::default()
+                )
+                .await
+                .unwrap(),
+            ndarray::array![
+                [0, 1, 2, 3],
+                [8, 9, 10, 11],
+                [16, 17, 18, 19],
+                [24, 25, 26, 27]
+            ]
+            .into_dyn()
+        );
+        assert_eq!(store.reads(), 2);
+        assert_eq!(cache.len().await, 2);
+        assert!(cache.get(&[0, 0]).await.is_some());
+        assert!(cache.get(&[1, 0]).await.is_some());
+
+        assert_eq!(
+            array
+                .async_retrieve_chunk_subset_ndarray_opt_cached::<u8, _>(
+                    &cache,
+                    &[0, 0],
+                    &ArraySubset::new_with_ranges(&[1..3, 1..3]),
+                    &CodecOptions::default()
+                )
+                .await
+                .unwrap(),
+            ndarray::array![[9, 10], [17, 18],].into_dyn()
+        );
+        assert_eq!(store.reads(), 2);
+        assert_eq!(cache.len().await, 2);
+
+        // Retrieve chunks in the cache
+        assert_eq!(
+            array
+                .async_retrieve_chunks_ndarray_opt_cached::<u8, _>(
+                    &cache,
+                    &ArraySubset::new_with_ranges(&[0..2, 0..1]),
+                    &CodecOptions::default()
+                )
+                .await
+                .unwrap(),
+            ndarray::array![
+                [0, 1, 2, 3],
+                [8, 9, 10, 11],
+                [16, 17, 18, 19],
+                [24, 25, 26, 27],
+                [32, 33, 34, 35],
+                [40, 41, 42, 43],
+                [48, 49, 50, 51],
+                [56, 57, 58, 59]
+            ]
+            .into_dyn()
+        );
+        assert_eq!(store.reads(), 2);
+        assert_eq!(cache.len().await, 2);
+
+        // Retrieve a chunk not in cache
+        assert_eq!(
+            array
+                .async_retrieve_chunk_opt_cached(&cache, &[0, 1], &CodecOptions::default())
+                .await
+                .unwrap(),
+            Arc::new(vec![4, 5, 6, 7, 12, 13, 14, 15, 20, 21, 22, 23, 28, 29, 30, 31].into())
+        );
+        assert_eq!(store.reads(), 3);
+        assert_eq!(cache.len().await, 2);
+        assert!(cache.get(&[0, 1]).await.is_some());
+
+        // Concurrent retrievals of a chunk not in the cache are coalesced
+        let options = CodecOptions::default();
+        let retrievals =
+            (0..4).map(|_| array.async_retrieve_chunk_opt_cached(&cache, &[1, 1], &options));
+        for chunk in futures::future::join_all(retrievals).await {
+            assert_eq!(chunk.unwrap().size(), 16);
+        }
+        assert_eq!(store.reads(), 4);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn async_array_chunk_cache_encoded_chunks() {
+        let cache = AsyncChunkCacheEncodedLruChunkLimit::new(2);
+        array_chunk_cache_impl(cache).await;
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn async_array_chunk_cache_encoded_size() {
+        // Create a cache with a size limit equivalent to 2 chunks
+        let chunk_size = 4 * 4 * size_of::<u8>();
+        let cache = AsyncChunkCacheEncodedLruSizeLimit::new(2 * chunk_size as u64);
+        array_chunk_cache_impl(cache).await;
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn async_array_chunk_cache_decoded_chunks() {
+        let cache = AsyncChunkCacheDecodedLruChunkLimit::new(2);
+        array_chunk_cache_impl(cache).await;
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn async_array_chunk_cache_decoded_size() {
+        // Create a cache with a size limit equivalent to 2 chunks
+        let chunk_size = 4 * 4 * size_of::<u8>();
+        let cache = AsyncChunkCacheDecodedLruSizeLimit::new(2 * chunk_size as u64);
+        array_chunk_cache_impl(cache).await;
+    }
+}
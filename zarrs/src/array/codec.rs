@@ -18,10 +18,32 @@ pub mod options;
 pub use options::{CodecOptions, CodecOptionsBuilder};
 
 // Array to array
+#[cfg(feature = "astype")]
+pub use array_to_array::astype::{
+    AsTypeCodec, AsTypeCodecConfiguration, AsTypeCodecConfigurationV1,
+};
 #[cfg(feature = "bitround")]
 pub use array_to_array::bitround::{
     BitroundCodec, BitroundCodecConfiguration, BitroundCodecConfigurationV1,
 };
+#[cfg(feature = "categorize")]
+pub use array_to_array::categorize::{
+    CategorizeCodec, CategorizeCodecConfiguration, CategorizeCodecConfigurationV1,
+};
+#[cfg(feature = "delta")]
+pub use array_to_array::delta::{DeltaCodec, DeltaCodecConfiguration, DeltaCodecConfigurationV1};
+#[cfg(feature = "fixedscaleoffset")]
+pub use array_to_array::fixedscaleoffset::{
+    FixedScaleOffsetCodec, FixedScaleOffsetCodecConfiguration, FixedScaleOffsetCodecConfigurationV1,
+};
+#[cfg(feature = "packbits")]
+pub use array_to_array::packbits::{
+    PackBitsCodec, PackBitsCodecConfiguration, PackBitsCodecConfigurationV1,
+};
+#[cfg(feature = "quantize")]
+pub use array_to_array::quantize::{
+    QuantizeCodec, QuantizeCodecConfiguration, QuantizeCodecConfigurationV1,
+};
 #[cfg(feature = "transpose")]
 pub use array_to_array::transpose::{
     TransposeCodec, TransposeCodecConfiguration, TransposeCodecConfigurationV1,
@@ -42,6 +64,10 @@ pub use array_to_bytes::sharding::{
 pub use array_to_bytes::zfp::{ZfpCodec, ZfpCodecConfiguration, ZfpCodecConfigurationV1};
 
 // Bytes to bytes
+#[cfg(feature = "bitshuffle")]
+pub use bytes_to_bytes::bitshuffle::{
+    BitshuffleCodec, BitshuffleCodecConfiguration, BitshuffleCodecConfigurationV1,
+};
 #[cfg(feature = "blosc")]
 pub use bytes_to_bytes::blosc::{BloscCodec, BloscCodecConfiguration, BloscCodecConfigurationV1};
 #[cfg(feature = "bz2")]
@@ -50,8 +76,22 @@ pub use bytes_to_bytes::bz2::{Bz2Codec, Bz2CodecConfiguration, Bz2CodecConfigura
 pub use bytes_to_bytes::crc32c::{
     Crc32cCodec, Crc32cCodecConfiguration, Crc32cCodecConfigurationV1,
 };
+#[cfg(feature = "fletcher32")]
+pub use bytes_to_bytes::fletcher32::{
+    Fletcher32Codec, Fletcher32CodecConfiguration, Fletcher32CodecConfigurationV1,
+};
 #[cfg(feature = "gzip")]
 pub use bytes_to_bytes::gzip::{GzipCodec, GzipCodecConfiguration, GzipCodecConfigurationV1};
+#[cfg(feature = "lz4")]
+pub use bytes_to_bytes::lz4::{Lz4Codec, Lz4CodecConfiguration, Lz4CodecConfigurationV1};
+#[cfg(feature = "lzma")]
+pub use bytes_to_bytes::lzma::{LzmaCodec, LzmaCodecConfiguration, LzmaCodecConfigurationV1};
+#[cfg(feature = "shuffle")]
+pub use bytes_to_bytes::shuffle::{
+    ShuffleCodec, ShuffleCodecConfiguration, ShuffleCodecConfigurationV1,
+};
+#[cfg(feature = "zlib")]
+pub use bytes_to_bytes::zlib::{ZlibCodec, ZlibCodecConfiguration, ZlibCodecConfigurationV1};
 #[cfg(feature = "zstd")]
 pub use bytes_to_bytes::zstd::{ZstdCodec, ZstdCodecConfiguration, ZstdCodecConfigurationV1};
 
@@ -72,12 +112,24 @@ use unsafe_cell_slice::UnsafeCellSlice;
 mod array_partial_encoder_default;
 pub use array_partial_encoder_default::ArrayPartialEncoderDefault;
 
+mod array_to_array_partial_decoder_default;
+pub use array_to_array_partial_decoder_default::ArrayToArrayPartialDecoderDefault;
+
 mod array_to_array_partial_encoder_default;
 pub use array_to_array_partial_encoder_default::ArrayToArrayPartialEncoderDefault;
 
 mod bytes_partial_encoder_default;
 pub use bytes_partial_encoder_default::BytesPartialEncoderDefault;
 
+#[cfg(feature = "async")]
+pub use array_partial_encoder_default::AsyncArrayPartialEncoderDefault;
+#[cfg(feature = "async")]
+pub use array_to_array_partial_decoder_default::AsyncArrayToArrayPartialDecoderDefault;
+#[cfg(feature = "async")]
+pub use array_to_array_partial_encoder_default::AsyncArrayToArrayPartialEncoderDefault;
+#[cfg(feature = "async")]
+pub use bytes_partial_encoder_default::AsyncBytesPartialEncoderDefault;
+
 use crate::storage::{StoreKeyOffsetValue, WritableStorage};
 use crate::{
     array_subset::{ArraySubset, IncompatibleArraySubsetAndShapeError},
@@ -88,7 +140,7 @@ use crate::{
 };
 
 #[cfg(feature = "async")]
-use crate::storage::AsyncReadableStorage;
+use crate::storage::{AsyncReadableStorage, AsyncWritableStorage};
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -96,8 +148,8 @@ use std::sync::Arc;
 
 use super::array_bytes::update_bytes_flen;
 use super::{
-    concurrency::RecommendedConcurrency, ArrayMetadataOptions, BytesRepresentation,
-    ChunkRepresentation, ChunkShape, DataType,
+    concurrency::RecommendedConcurrency, data_type::DataTypeExtensionError, ArrayMetadataOptions,
+    BytesRepresentation, ChunkRepresentation, ChunkShape, DataType,
 };
 use super::{ArrayBytes, RawBytes};
 
@@ -131,6 +183,32 @@ impl Codec {
         {
             // Inventory does not work in miri, so manually handle all known codecs
             match metadata.name() {
+                #[cfg(feature = "astype")]
+                array_to_array::astype::IDENTIFIER => {
+                    return array_to_array::astype::create_codec_astype(metadata);
+                }
+                #[cfg(feature = "categorize")]
+                array_to_array::categorize::IDENTIFIER => {
+                    return array_to_array::categorize::create_codec_categorize(metadata);
+                }
+                #[cfg(feature = "delta")]
+                array_to_array::delta::IDENTIFIER => {
+                    return array_to_array::delta::create_codec_delta(metadata);
+                }
+                #[cfg(feature = "fixedscaleoffset")]
+                array_to_array::fixedscaleoffset::IDENTIFIER => {
+                    return array_to_array::fixedscaleoffset::create_codec_fixedscaleoffset(
+                        metadata,
+                    );
+                }
+                #[cfg(feature = "packbits")]
+                array_to_array::packbits::IDENTIFIER => {
+                    return array_to_array::packbits::create_codec_packbits(metadata);
+                }
+                #[cfg(feature = "quantize")]
+                array_to_array::quantize::IDENTIFIER => {
+                    return array_to_array::quantize::create_codec_quantize(metadata);
+                }
                 #[cfg(feature = "transpose")]
                 array_to_array::transpose::IDENTIFIER => {
                     return array_to_array::transpose::create_codec_transpose(metadata);
@@ -160,6 +238,10 @@ impl Codec {
                 array_to_bytes::vlen_v2::IDENTIFIER => {
                     return array_to_bytes::vlen_v2::create_codec_vlen_v2(metadata);
                 }
+                #[cfg(feature = "bitshuffle")]
+                bytes_to_bytes::bitshuffle::IDENTIFIER => {
+                    return bytes_to_bytes::bitshuffle::create_codec_bitshuffle(metadata);
+                }
                 #[cfg(feature = "blosc")]
                 bytes_to_bytes::blosc::IDENTIFIER => {
                     return bytes_to_bytes::blosc::create_codec_blosc(metadata);
@@ -172,6 +254,10 @@ impl Codec {
                 bytes_to_bytes::crc32c::IDENTIFIER => {
                     return bytes_to_bytes::crc32c::create_codec_crc32c(metadata);
                 }
+                #[cfg(feature = "fletcher32")]
+                bytes_to_bytes::fletcher32::IDENTIFIER => {
+                    return bytes_to_bytes::fletcher32::create_codec_fletcher32(metadata);
+                }
                 #[cfg(feature = "gdeflate")]
                 bytes_to_bytes::gdeflate::IDENTIFIER => {
                     return bytes_to_bytes::gdeflate::create_codec_gdeflate(metadata);
@@ -180,6 +266,22 @@ impl Codec {
                 bytes_to_bytes::gzip::IDENTIFIER => {
                     return bytes_to_bytes::gzip::create_codec_gzip(metadata);
                 }
+                #[cfg(feature = "lz4")]
+                bytes_to_bytes::lz4::IDENTIFIER => {
+                    return bytes_to_bytes::lz4::create_codec_lz4(metadata);
+                }
+                #[cfg(feature = "lzma")]
+                bytes_to_bytes::lzma::IDENTIFIER => {
+                    return bytes_to_bytes::lzma::create_codec_lzma(metadata);
+                }
+                #[cfg(feature = "shuffle")]
+                bytes_to_bytes::shuffle::IDENTIFIER => {
+                    return bytes_to_bytes::shuffle::create_codec_shuffle(metadata);
+                }
+                #[cfg(feature = "zlib")]
+                bytes_to_bytes::zlib::IDENTIFIER => {
+                    return bytes_to_bytes::zlib::create_codec_zlib(metadata);
+                }
                 #[cfg(feature = "zstd")]
                 bytes_to_bytes::zstd::IDENTIFIER => {
                     return bytes_to_bytes::zstd::create_codec_zstd(metadata);
@@ -432,6 +534,48 @@ pub trait BytesPartialEncoderTraits: Any + Send + Sync {
     ) -> Result<(), CodecError>;
 }
 
+#[cfg(feature = "async")]
+/// Asynchronous partial array encoder traits.
+#[async_trait::async_trait]
+pub trait AsyncArrayPartialEncoderTraits: Any + Send + Sync {
+    /// Erase the chunk.
+    ///
+    /// # Errors
+    /// Returns an error if there is an underlying store error.
+    async fn erase(&self) -> Result<(), CodecError>;
+
+    /// Partially encode a chunk.
+    ///
+    /// # Errors
+    /// Returns [`CodecError`] if a codec fails or an array subset is invalid.
+    async fn partial_encode(
+        &self,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError>;
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial bytes encoder traits.
+#[async_trait::async_trait]
+pub trait AsyncBytesPartialEncoderTraits: Any + Send + Sync {
+    /// Erase the chunk.
+    ///
+    /// # Errors
+    /// Returns an error if there is an underlying store error.
+    async fn erase(&self) -> Result<(), CodecError>;
+
+    /// Partially encode a chunk.
+    ///
+    /// # Errors
+    /// Returns [`CodecError`] if a codec fails or an array subset is invalid.
+    async fn partial_encode(
+        &self,
+        offsets_and_bytes: &[(ByteOffset, crate::array::RawBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError>;
+}
+
 #[cfg(feature = "async")]
 /// Asynchronous partial array decoder traits.
 #[async_trait::async_trait]
@@ -578,6 +722,41 @@ impl BytesPartialEncoderTraits for StoragePartialEncoder {
     }
 }
 
+#[cfg(feature = "async")]
+/// An [`AsyncWritableStorage`] store value partial encoder.
+pub struct AsyncStoragePartialEncoder {
+    storage: AsyncWritableStorage,
+    key: StoreKey,
+}
+
+#[cfg(feature = "async")]
+impl AsyncStoragePartialEncoder {
+    /// Create a new storage partial encoder.
+    pub fn new(storage: AsyncWritableStorage, key: StoreKey) -> Self {
+        Self { storage, key }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncBytesPartialEncoderTraits for AsyncStoragePartialEncoder {
+    async fn erase(&self) -> Result<(), CodecError> {
+        Ok(self.storage.erase(&self.key).await?)
+    }
+
+    async fn partial_encode(
+        &self,
+        offsets_and_bytes: &[(ByteOffset, crate::array::RawBytes<'_>)],
+        _options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let key_offset_values = offsets_and_bytes
+            .iter()
+            .map(|(offset, bytes)| StoreKeyOffsetValue::new(self.key.clone(), *offset, bytes))
+            .collect::<Vec<_>>();
+        Ok(self.storage.set_partial_values(&key_offset_values).await?)
+    }
+}
+
 /// Traits for array to array codecs.
 #[cfg_attr(feature = "async", async_trait::async_trait)]
 pub trait ArrayToArrayCodecTraits: ArrayCodecTraits + core::fmt::Debug {
@@ -661,7 +840,18 @@ pub trait ArrayToArrayCodecTraits: ArrayCodecTraits + core::fmt::Debug {
         options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError>;
 
-    // TODO: async_partial_encoder
+    #[cfg(feature = "async")]
+    /// Initialise an asynchronous partial encoder.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if initialisation fails.
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError>;
 }
 
 /// Traits for array to bytes codecs.
@@ -781,7 +971,18 @@ pub trait ArrayToBytesCodecTraits: ArrayCodecTraits + core::fmt::Debug {
         options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError>;
 
-    // TODO: Async partial encoder
+    #[cfg(feature = "async")]
+    /// Initialise an asynchronous partial encoder.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if initialisation fails.
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError>;
 }
 
 /// Traits for bytes to bytes codecs.
@@ -805,6 +1006,16 @@ pub trait BytesToBytesCodecTraits: CodecTraits + core::fmt::Debug {
         decoded_representation: &BytesRepresentation,
     ) -> BytesRepresentation;
 
+    /// Check that the codec is compatible with `data_type`, the data type of the array encoded by the preceding array to bytes codec.
+    ///
+    /// Codecs are compatible with any data type by default.
+    ///
+    /// # Errors
+    /// Returns [`CodecError`] if the codec is incompatible with `data_type`.
+    fn validate_data_type(&self, _data_type: &DataType) -> Result<(), CodecError> {
+        Ok(())
+    }
+
     /// Encode chunk bytes.
     ///
     /// # Errors
@@ -861,7 +1072,18 @@ pub trait BytesToBytesCodecTraits: CodecTraits + core::fmt::Debug {
         options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError>;
 
-    // TODO: Async partial encoder
+    #[cfg(feature = "async")]
+    /// Initialise an asynchronous partial encoder.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if initialisation fails.
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError>;
 }
 
 impl BytesPartialDecoderTraits for std::io::Cursor<&'static [u8]> {
@@ -1002,6 +1224,9 @@ pub enum CodecError {
     /// Expected variable length bytes.
     #[error("Expected variable length array bytes")]
     ExpectedVariableLengthBytes,
+    /// A data type extension error.
+    #[error(transparent)]
+    DataTypeExtension(#[from] DataTypeExtensionError),
 }
 
 impl From<&str> for CodecError {
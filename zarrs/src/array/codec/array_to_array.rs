@@ -1,6 +1,158 @@
 //! Array to array codecs.
 
+#[cfg(feature = "astype")]
+pub mod astype;
 #[cfg(feature = "bitround")]
 pub mod bitround;
+#[cfg(feature = "categorize")]
+pub mod categorize;
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "fixedscaleoffset")]
+pub mod fixedscaleoffset;
+#[cfg(feature = "packbits")]
+pub mod packbits;
+#[cfg(feature = "quantize")]
+pub mod quantize;
 #[cfg(feature = "transpose")]
 pub mod transpose;
+
+#[cfg(any(feature = "astype", feature = "delta", feature = "fixedscaleoffset"))]
+use num::traits::AsPrimitive;
+
+#[cfg(any(feature = "astype", feature = "delta", feature = "fixedscaleoffset"))]
+use crate::array::{codec::CodecError, convert_from_bytes_slice, transmute_to_bytes_vec};
+#[cfg(any(
+    feature = "astype",
+    feature = "categorize",
+    feature = "delta",
+    feature = "fixedscaleoffset",
+    feature = "quantize"
+))]
+use crate::{
+    array::DataType,
+    metadata::{
+        v2::array::DataTypeMetadataV2, v2_to_v3::data_type_metadata_v2_to_v3_data_type,
+        v3_to_v2::data_type_metadata_v3_to_v2, Endianness,
+    },
+    plugin::PluginCreateError,
+};
+
+/// Convert a `numcodecs` data type (e.g. `<f8`) to a [`DataType`].
+///
+/// The endianness is ignored, since array to array codecs operate on native endian data.
+#[cfg(any(
+    feature = "astype",
+    feature = "categorize",
+    feature = "delta",
+    feature = "fixedscaleoffset",
+    feature = "quantize"
+))]
+fn numcodecs_data_type_to_data_type(
+    data_type: &DataTypeMetadataV2,
+) -> Result<DataType, PluginCreateError> {
+    data_type_metadata_v2_to_v3_data_type(data_type)
+        .ok()
+        .and_then(|data_type| DataType::from_metadata(&data_type).ok())
+        .ok_or_else(|| PluginCreateError::from(format!("unsupported data type {data_type:?}")))
+}
+
+/// Convert a [`DataType`] to a little endian `numcodecs` data type (e.g. `<f8`).
+///
+/// # Panics
+/// Panics if the data type is not supported by Zarr V2.
+#[cfg(any(
+    feature = "astype",
+    feature = "categorize",
+    feature = "delta",
+    feature = "fixedscaleoffset",
+    feature = "quantize"
+))]
+fn data_type_to_numcodecs_data_type(data_type: &DataType) -> DataTypeMetadataV2 {
+    data_type_metadata_v3_to_v2(&data_type.metadata(), Some(Endianness::Little))
+        .expect("data type is supported by Zarr V2")
+}
+
+/// Returns true if `data_type` is an integer or `float32`/`float64` data type, as supported by [`cast_bytes`].
+#[cfg(any(feature = "astype", feature = "delta", feature = "fixedscaleoffset"))]
+const fn is_numeric_data_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+    )
+}
+
+#[cfg(any(feature = "astype", feature = "delta", feature = "fixedscaleoffset"))]
+fn cast<T, U>(bytes: &[u8]) -> Vec<u8>
+where
+    T: bytemuck::Pod + AsPrimitive<U>,
+    U: bytemuck::Pod,
+{
+    let elements: Vec<U> = convert_from_bytes_slice::<T>(bytes)
+        .into_iter()
+        .map(AsPrimitive::as_)
+        .collect();
+    transmute_to_bytes_vec(elements)
+}
+
+#[cfg(any(feature = "astype", feature = "delta", feature = "fixedscaleoffset"))]
+macro_rules! cast_to {
+    ( $bytes:expr, $t:ty, $data_type_out:expr, $identifier:expr ) => {
+        match $data_type_out {
+            DataType::Int8 => Ok(cast::<$t, i8>($bytes)),
+            DataType::Int16 => Ok(cast::<$t, i16>($bytes)),
+            DataType::Int32 => Ok(cast::<$t, i32>($bytes)),
+            DataType::Int64 => Ok(cast::<$t, i64>($bytes)),
+            DataType::UInt8 => Ok(cast::<$t, u8>($bytes)),
+            DataType::UInt16 => Ok(cast::<$t, u16>($bytes)),
+            DataType::UInt32 => Ok(cast::<$t, u32>($bytes)),
+            DataType::UInt64 => Ok(cast::<$t, u64>($bytes)),
+            DataType::Float32 => Ok(cast::<$t, f32>($bytes)),
+            DataType::Float64 => Ok(cast::<$t, f64>($bytes)),
+            _ => Err(CodecError::UnsupportedDataType(
+                $data_type_out.clone(),
+                $identifier.to_string(),
+            )),
+        }
+    };
+}
+
+/// Cast the elements in `bytes` from `data_type_in` to `data_type_out`.
+///
+/// Floating point values are truncated towards zero and saturated when cast to integers.
+/// Integers are wrapped when cast to smaller integers.
+///
+/// `identifier` is the codec identifier reported if a data type is unsupported.
+#[cfg(any(feature = "astype", feature = "delta", feature = "fixedscaleoffset"))]
+fn cast_bytes(
+    bytes: &[u8],
+    data_type_in: &DataType,
+    data_type_out: &DataType,
+    identifier: &str,
+) -> Result<Vec<u8>, CodecError> {
+    match data_type_in {
+        DataType::Int8 => cast_to!(bytes, i8, data_type_out, identifier),
+        DataType::Int16 => cast_to!(bytes, i16, data_type_out, identifier),
+        DataType::Int32 => cast_to!(bytes, i32, data_type_out, identifier),
+        DataType::Int64 => cast_to!(bytes, i64, data_type_out, identifier),
+        DataType::UInt8 => cast_to!(bytes, u8, data_type_out, identifier),
+        DataType::UInt16 => cast_to!(bytes, u16, data_type_out, identifier),
+        DataType::UInt32 => cast_to!(bytes, u32, data_type_out, identifier),
+        DataType::UInt64 => cast_to!(bytes, u64, data_type_out, identifier),
+        DataType::Float32 => cast_to!(bytes, f32, data_type_out, identifier),
+        DataType::Float64 => cast_to!(bytes, f64, data_type_out, identifier),
+        _ => Err(CodecError::UnsupportedDataType(
+            data_type_in.clone(),
+            identifier.to_string(),
+        )),
+    }
+}
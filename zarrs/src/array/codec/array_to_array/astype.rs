@@ -0,0 +1,242 @@
+//! The `astype` array to array codec.
+//!
+//! Converts the data type of array elements, with the casting semantics of `numpy.ndarray.astype`.
+//! Integer and floating point data types are supported.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `astype` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `astype` codec, and is used when opening Zarr V2 arrays with an `astype` filter.
+//!
+//! See [`AsTypeCodecConfigurationV1`] for example `JSON` metadata.
+
+mod astype_codec;
+mod astype_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::astype::{
+    AsTypeCodecConfiguration, AsTypeCodecConfigurationV1,
+};
+pub use astype_codec::AsTypeCodec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::astype, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use astype::IDENTIFIER;
+
+use super::{cast_bytes, is_numeric_data_type};
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_astype, create_codec_astype)
+}
+
+fn is_name_astype(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_astype(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: AsTypeCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(AsTypeCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::ArrayToArray(codec))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{
+                ArrayToArrayCodecTraits, ArrayToBytesCodecTraits, BytesCodec, CodecOptions,
+                CodecTraits,
+            },
+            transmute_from_bytes_vec, transmute_to_bytes_vec, ArrayBytes, ChunkRepresentation,
+            DataType, FillValue,
+        },
+        array_subset::ArraySubset,
+    };
+
+    use super::*;
+
+    const JSON: &str = r#"{
+        "encode_dtype": "<i2",
+        "decode_dtype": "<f8"
+    }"#;
+
+    #[test]
+    fn codec_astype_configuration() {
+        let configuration: AsTypeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = AsTypeCodec::new_with_configuration(&configuration).unwrap();
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(metadata.name(), "numcodecs.astype");
+        assert_eq!(
+            metadata
+                .to_configuration::<AsTypeCodecConfiguration>()
+                .unwrap(),
+            configuration
+        );
+
+        let configuration: AsTypeCodecConfiguration =
+            serde_json::from_str(r#"{"encode_dtype": "|b1", "decode_dtype": "<f8"}"#).unwrap();
+        assert!(AsTypeCodec::new_with_configuration(&configuration).is_err());
+    }
+
+    #[test]
+    fn codec_astype_cast() {
+        let bytes = transmute_to_bytes_vec(vec![-1.7f64, 0.5, 300.0]);
+        let cast = cast_bytes(&bytes, &DataType::Float64, &DataType::Int8, IDENTIFIER).unwrap();
+        assert_eq!(transmute_from_bytes_vec::<i8>(cast), vec![-1, 0, 127]);
+
+        let bytes = transmute_to_bytes_vec(vec![-1i32, 256, 257]);
+        let cast = cast_bytes(&bytes, &DataType::Int32, &DataType::UInt8, IDENTIFIER).unwrap();
+        assert_eq!(transmute_from_bytes_vec::<u8>(cast), vec![255, 0, 1]);
+
+        assert!(cast_bytes(&bytes, &DataType::Int32, &DataType::Bool, IDENTIFIER).is_err());
+    }
+
+    #[test]
+    fn codec_astype_round_trip() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(6).unwrap()],
+            DataType::Float64,
+            FillValue::from(1.5f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = vec![-2.5, -1.0, 0.0, 1.9, 2.0, 1000.0];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: AsTypeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = AsTypeCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        assert_eq!(encoded_representation.data_type(), &DataType::Int16);
+        assert_eq!(encoded_representation.fill_value(), &FillValue::from(1i16));
+
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            transmute_from_bytes_vec::<i16>(encoded.clone().into_fixed().unwrap().into_owned()),
+            vec![-2, -1, 0, 1, 2, 1000]
+        );
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            transmute_from_bytes_vec::<f64>(decoded.into_fixed().unwrap().into_owned()),
+            vec![-2.0, -1.0, 0.0, 1.0, 2.0, 1000.0]
+        );
+    }
+
+    #[test]
+    fn codec_astype_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(6).unwrap()],
+            DataType::Float64,
+            FillValue::from(1.5f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = (0..6).map(f64::from).collect();
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: AsTypeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(AsTypeCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_regions = [
+            ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap(),
+            ArraySubset::new_with_start_shape(vec![4], vec![2]).unwrap(),
+        ];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<f64>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1.0, 2.0], vec![4.0, 5.0]]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_astype_async_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(6).unwrap()],
+            DataType::Float64,
+            FillValue::from(1.5f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = (0..6).map(f64::from).collect();
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: AsTypeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(AsTypeCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .async_partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_regions = [
+            ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap(),
+            ArraySubset::new_with_start_shape(vec![4], vec![2]).unwrap(),
+        ];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<f64>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1.0, 2.0], vec![4.0, 5.0]]);
+    }
+}
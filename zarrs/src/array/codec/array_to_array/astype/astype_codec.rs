@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            array_to_array::{data_type_to_numcodecs_data_type, numcodecs_data_type_to_data_type},
+            options::CodecOptions,
+            ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderTraits,
+            ArrayToArrayCodecTraits, ArrayToArrayPartialEncoderDefault, CodecError, CodecTraits,
+            RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType, FillValue,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+    AsyncArrayToArrayPartialEncoderDefault,
+};
+
+use super::{
+    astype_partial_decoder, cast_bytes, is_numeric_data_type, AsTypeCodecConfiguration,
+    AsTypeCodecConfigurationV1, IDENTIFIER,
+};
+
+/// An `astype` codec implementation.
+#[derive(Clone, Debug)]
+pub struct AsTypeCodec {
+    encode_data_type: DataType,
+    decode_data_type: DataType,
+}
+
+impl AsTypeCodec {
+    /// Create a new `astype` codec.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not supported.
+    pub fn new(
+        encode_data_type: DataType,
+        decode_data_type: DataType,
+    ) -> Result<Self, PluginCreateError> {
+        for data_type in [&encode_data_type, &decode_data_type] {
+            if !is_numeric_data_type(data_type) {
+                return Err(PluginCreateError::from(format!(
+                    "astype codec does not support data type {data_type}"
+                )));
+            }
+        }
+        Ok(Self {
+            encode_data_type,
+            decode_data_type,
+        })
+    }
+
+    /// Create a new `astype` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not supported.
+    pub fn new_with_configuration(
+        configuration: &AsTypeCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let AsTypeCodecConfiguration::V1(configuration) = configuration;
+        Self::new(
+            numcodecs_data_type_to_data_type(&configuration.encode_dtype)?,
+            numcodecs_data_type_to_data_type(&configuration.decode_dtype)?,
+        )
+    }
+}
+
+impl CodecTraits for AsTypeCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = AsTypeCodecConfigurationV1 {
+            encode_dtype: data_type_to_numcodecs_data_type(&self.encode_data_type),
+            decode_dtype: data_type_to_numcodecs_data_type(&self.decode_data_type),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+impl ArrayCodecTraits for AsTypeCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for AsTypeCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(cast_bytes(
+            &bytes,
+            &self.decode_data_type,
+            &self.encode_data_type,
+            IDENTIFIER,
+        )?))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(cast_bytes(
+            &bytes,
+            &self.encode_data_type,
+            &self.decode_data_type,
+            IDENTIFIER,
+        )?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(astype_partial_decoder::AsTypePartialDecoder::new(
+            input_handle,
+            self.encode_data_type.clone(),
+            self.decode_data_type.clone(),
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(
+            astype_partial_decoder::AsyncAsTypePartialDecoder::new(
+                input_handle,
+                self.encode_data_type.clone(),
+                self.decode_data_type.clone(),
+            ),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type != &self.decode_data_type {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let fill_value = cast_bytes(
+            decoded_representation.fill_value().as_ne_bytes(),
+            &self.decode_data_type,
+            &self.encode_data_type,
+            IDENTIFIER,
+        )?;
+        Ok(ChunkRepresentation::new(
+            decoded_representation.shape().to_vec(),
+            self.encode_data_type.clone(),
+            FillValue::new(fill_value),
+        )
+        .unwrap())
+    }
+
+    fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Ok(encoded_shape)
+    }
+}
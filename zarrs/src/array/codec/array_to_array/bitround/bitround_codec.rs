@@ -14,7 +14,10 @@ use crate::{
 };
 
 #[cfg(feature = "async")]
-use crate::array::codec::AsyncArrayPartialDecoderTraits;
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+    AsyncArrayToArrayPartialEncoderDefault,
+};
 
 use super::{
     bitround_partial_decoder, round_bytes, BitroundCodecConfiguration,
@@ -162,6 +165,22 @@ impl ArrayToArrayCodecTraits for BitroundCodec {
         ))
     }
 
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
     fn compute_encoded_size(
         &self,
         decoded_representation: &ChunkRepresentation,
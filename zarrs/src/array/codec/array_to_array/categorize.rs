@@ -0,0 +1,322 @@
+//! The `categorize` array to array codec.
+//!
+//! Encodes string elements as unsigned integer category indices.
+//! A value matching a label is encoded as the label index plus one, and any other value is encoded as zero.
+//! Zero decodes to an empty string.
+//!
+//! The decoded data type must be `string`, and the encoded data type must be `uint8`, `uint16`, or `uint32`.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `categorize` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `categorize` codec, and is used when opening Zarr V2 arrays with a `categorize` filter.
+//!
+//! See [`CategorizeCodecConfigurationV1`] for example `JSON` metadata.
+
+mod categorize_codec;
+mod categorize_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::categorize::{
+    CategorizeCodecConfiguration, CategorizeCodecConfigurationV1,
+};
+pub use categorize_codec::CategorizeCodec;
+
+use crate::{
+    array::{
+        codec::{ArrayBytes, Codec, CodecError, CodecPlugin},
+        convert_from_bytes_slice, transmute_to_bytes_vec, DataType,
+    },
+    config::global_config,
+    metadata::v3::{array::codec::categorize, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use categorize::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_categorize, create_codec_categorize)
+}
+
+fn is_name_categorize(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_categorize(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: CategorizeCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(CategorizeCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::ArrayToArray(codec))
+}
+
+/// Encode string elements to category indices of `astype`.
+fn encode_categories(
+    bytes: &[u8],
+    offsets: &[usize],
+    labels: &[String],
+    astype: &DataType,
+) -> Result<Vec<u8>, CodecError> {
+    let indices = offsets.windows(2).map(|range| {
+        let element = &bytes[range[0]..range[1]];
+        labels
+            .iter()
+            .position(|label| label.as_bytes() == element)
+            .map_or(0, |index| index + 1)
+    });
+    #[allow(clippy::cast_possible_truncation)]
+    match astype {
+        DataType::UInt8 => Ok(indices.map(|index| index as u8).collect()),
+        DataType::UInt16 => Ok(transmute_to_bytes_vec(
+            indices.map(|index| index as u16).collect::<Vec<_>>(),
+        )),
+        DataType::UInt32 => Ok(transmute_to_bytes_vec(
+            indices.map(|index| index as u32).collect::<Vec<_>>(),
+        )),
+        _ => Err(CodecError::UnsupportedDataType(
+            astype.clone(),
+            IDENTIFIER.to_string(),
+        )),
+    }
+}
+
+/// Decode category indices of `astype` to string elements.
+fn decode_categories(
+    bytes: &[u8],
+    labels: &[String],
+    astype: &DataType,
+) -> Result<ArrayBytes<'static>, CodecError> {
+    let indices: Vec<usize> = match astype {
+        DataType::UInt8 => bytes.iter().map(|index| usize::from(*index)).collect(),
+        DataType::UInt16 => convert_from_bytes_slice::<u16>(bytes)
+            .into_iter()
+            .map(usize::from)
+            .collect(),
+        DataType::UInt32 => convert_from_bytes_slice::<u32>(bytes)
+            .into_iter()
+            .map(|index| usize::try_from(index).unwrap())
+            .collect(),
+        _ => {
+            return Err(CodecError::UnsupportedDataType(
+                astype.clone(),
+                IDENTIFIER.to_string(),
+            ))
+        }
+    };
+
+    let mut decoded = Vec::new();
+    let mut offsets = Vec::with_capacity(indices.len() + 1);
+    offsets.push(0);
+    for index in indices {
+        if index > 0 {
+            let label = labels.get(index - 1).ok_or_else(|| {
+                CodecError::Other(format!(
+                    "categorize codec index {index} exceeds the number of labels {}",
+                    labels.len()
+                ))
+            })?;
+            decoded.extend_from_slice(label.as_bytes());
+        }
+        offsets.push(decoded.len());
+    }
+    Ok(ArrayBytes::new_vlen(decoded, offsets))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{
+                ArrayToArrayCodecTraits, ArrayToBytesCodecTraits, BytesCodec, CodecOptions,
+                CodecTraits,
+            },
+            ChunkRepresentation, FillValue,
+        },
+        array_subset::ArraySubset,
+    };
+
+    use super::*;
+
+    const JSON: &str = r#"{
+        "labels": ["foo", "bar", "baz"],
+        "dtype": "|O",
+        "astype": "<u2"
+    }"#;
+
+    fn strings_to_array_bytes(strings: &[&str]) -> ArrayBytes<'static> {
+        let mut offsets = vec![0];
+        let mut bytes = Vec::new();
+        for string in strings {
+            bytes.extend_from_slice(string.as_bytes());
+            offsets.push(bytes.len());
+        }
+        ArrayBytes::new_vlen(bytes, offsets)
+    }
+
+    #[test]
+    fn codec_categorize_configuration() {
+        let configuration: CategorizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = CategorizeCodec::new_with_configuration(&configuration).unwrap();
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(metadata.name(), "numcodecs.categorize");
+        assert_eq!(
+            metadata
+                .to_configuration::<CategorizeCodecConfiguration>()
+                .unwrap(),
+            configuration
+        );
+
+        let configuration: CategorizeCodecConfiguration =
+            serde_json::from_str(r#"{"labels": ["foo"], "dtype": "|O", "astype": "<i4"}"#).unwrap();
+        assert!(CategorizeCodec::new_with_configuration(&configuration).is_err());
+    }
+
+    #[test]
+    fn codec_categorize_round_trip() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(5).unwrap()],
+            DataType::String,
+            FillValue::from("bar"),
+        )
+        .unwrap();
+        let bytes = strings_to_array_bytes(&["foo", "baz", "qux", "", "bar"]);
+
+        let configuration: CategorizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = CategorizeCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        assert_eq!(encoded_representation.data_type(), &DataType::UInt16);
+        assert_eq!(encoded_representation.fill_value(), &FillValue::from(2u16));
+
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            convert_from_bytes_slice::<u16>(&encoded.clone().into_fixed().unwrap()),
+            vec![1, 3, 0, 0, 2]
+        );
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            decoded,
+            strings_to_array_bytes(&["foo", "baz", "", "", "bar"])
+        );
+    }
+
+    #[test]
+    fn codec_categorize_invalid_index() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(5).unwrap()],
+            DataType::String,
+            FillValue::from("bar"),
+        )
+        .unwrap();
+        let configuration: CategorizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = CategorizeCodec::new_with_configuration(&configuration).unwrap();
+        let encoded = ArrayBytes::from(transmute_to_bytes_vec(vec![0u16, 1, 2, 3, 4]));
+        assert!(codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .is_err());
+    }
+
+    #[test]
+    fn codec_categorize_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(5).unwrap()],
+            DataType::String,
+            FillValue::from("bar"),
+        )
+        .unwrap();
+        let bytes = strings_to_array_bytes(&["foo", "baz", "qux", "", "bar"]);
+
+        let configuration: CategorizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(CategorizeCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap()];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            decoded_partial_chunk,
+            vec![strings_to_array_bytes(&["baz", ""])]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_categorize_async_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(5).unwrap()],
+            DataType::String,
+            FillValue::from("bar"),
+        )
+        .unwrap();
+        let bytes = strings_to_array_bytes(&["foo", "baz", "qux", "", "bar"]);
+
+        let configuration: CategorizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(CategorizeCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .async_partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap()];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            decoded_partial_chunk,
+            vec![strings_to_array_bytes(&["baz", ""])]
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            array_to_array::{data_type_to_numcodecs_data_type, numcodecs_data_type_to_data_type},
+            options::CodecOptions,
+            ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderTraits,
+            ArrayToArrayCodecTraits, ArrayToArrayPartialEncoderDefault, CodecError, CodecTraits,
+            RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType, FillValue,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+    AsyncArrayToArrayPartialEncoderDefault,
+};
+
+use super::{
+    categorize_partial_decoder, decode_categories, encode_categories, CategorizeCodecConfiguration,
+    CategorizeCodecConfigurationV1, IDENTIFIER,
+};
+
+/// A `categorize` codec implementation.
+#[derive(Clone, Debug)]
+pub struct CategorizeCodec {
+    labels: Vec<String>,
+    astype: DataType,
+}
+
+impl CategorizeCodec {
+    /// Create a new `categorize` codec.
+    ///
+    /// `labels` are the category labels and `astype` is the encoded data type.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `astype` is not `uint8`, `uint16`, or `uint32`, or cannot represent every label index.
+    pub fn new(labels: Vec<String>, astype: DataType) -> Result<Self, PluginCreateError> {
+        let max_index = match astype {
+            DataType::UInt8 => u64::from(u8::MAX),
+            DataType::UInt16 => u64::from(u16::MAX),
+            DataType::UInt32 => u64::from(u32::MAX),
+            _ => {
+                return Err(PluginCreateError::from(format!(
+                    "categorize codec does not support data type {astype}"
+                )))
+            }
+        };
+        if labels.len() as u64 > max_index {
+            return Err(PluginCreateError::from(format!(
+                "categorize codec data type {astype} cannot represent {} labels",
+                labels.len()
+            )));
+        }
+        Ok(Self { labels, astype })
+    }
+
+    /// Create a new `categorize` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the decoded data type is not `string` or the encoded data type is unsupported.
+    pub fn new_with_configuration(
+        configuration: &CategorizeCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let CategorizeCodecConfiguration::V1(configuration) = configuration;
+        let data_type = numcodecs_data_type_to_data_type(&configuration.dtype)?;
+        if data_type != DataType::String {
+            return Err(PluginCreateError::from(format!(
+                "categorize codec does not support data type {data_type}"
+            )));
+        }
+        Self::new(
+            configuration.labels.clone(),
+            numcodecs_data_type_to_data_type(&configuration.astype)?,
+        )
+    }
+}
+
+impl CodecTraits for CategorizeCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = CategorizeCodecConfigurationV1 {
+            labels: self.labels.clone(),
+            dtype: data_type_to_numcodecs_data_type(&DataType::String),
+            astype: data_type_to_numcodecs_data_type(&self.astype),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+impl ArrayCodecTraits for CategorizeCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for CategorizeCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let (bytes, offsets) = bytes.into_variable()?;
+        Ok(ArrayBytes::from(encode_categories(
+            &bytes,
+            &offsets,
+            &self.labels,
+            &self.astype,
+        )?))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        decode_categories(&bytes, &self.labels, &self.astype)
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(
+            categorize_partial_decoder::CategorizePartialDecoder::new(
+                input_handle,
+                self.labels.clone(),
+                decoded_representation.data_type().clone(),
+                self.astype.clone(),
+            ),
+        ))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(
+            categorize_partial_decoder::AsyncCategorizePartialDecoder::new(
+                input_handle,
+                self.labels.clone(),
+                decoded_representation.data_type().clone(),
+                self.astype.clone(),
+            ),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type != &DataType::String {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let fill_value = decoded_representation.fill_value().as_ne_bytes();
+        let fill_value = encode_categories(
+            fill_value,
+            &[0, fill_value.len()],
+            &self.labels,
+            &self.astype,
+        )?;
+        Ok(ChunkRepresentation::new(
+            decoded_representation.shape().to_vec(),
+            self.astype.clone(),
+            FillValue::new(fill_value),
+        )
+        .unwrap())
+    }
+
+    fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Ok(encoded_shape)
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{ArrayBytes, ArrayPartialDecoderTraits, CodecError, CodecOptions},
+        DataType,
+    },
+    array_subset::ArraySubset,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncArrayPartialDecoderTraits;
+
+use super::decode_categories;
+
+/// Partial decoder for the `categorize` codec.
+pub(crate) struct CategorizePartialDecoder {
+    input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+    labels: Vec<String>,
+    data_type: DataType,
+    astype: DataType,
+}
+
+impl CategorizePartialDecoder {
+    /// Create a new partial decoder for the `categorize` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        labels: Vec<String>,
+        data_type: DataType,
+        astype: DataType,
+    ) -> Self {
+        Self {
+            input_handle,
+            labels,
+            data_type,
+            astype,
+        }
+    }
+}
+
+impl ArrayPartialDecoderTraits for CategorizePartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded = self.input_handle.partial_decode(array_subsets, options)?;
+        encoded
+            .into_iter()
+            .map(|bytes| {
+                let bytes = bytes.into_fixed()?;
+                decode_categories(&bytes, &self.labels, &self.astype)
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for the `categorize` codec.
+pub(crate) struct AsyncCategorizePartialDecoder {
+    input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+    labels: Vec<String>,
+    data_type: DataType,
+    astype: DataType,
+}
+
+#[cfg(feature = "async")]
+impl AsyncCategorizePartialDecoder {
+    /// Create a new partial decoder for the `categorize` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        labels: Vec<String>,
+        data_type: DataType,
+        astype: DataType,
+    ) -> Self {
+        Self {
+            input_handle,
+            labels,
+            data_type,
+            astype,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialDecoderTraits for AsyncCategorizePartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    async fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded = self
+            .input_handle
+            .partial_decode(array_subsets, options)
+            .await?;
+        encoded
+            .into_iter()
+            .map(|bytes| {
+                let bytes = bytes.into_fixed()?;
+                decode_categories(&bytes, &self.labels, &self.astype)
+            })
+            .collect()
+    }
+}
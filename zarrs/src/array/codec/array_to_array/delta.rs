@@ -0,0 +1,344 @@
+//! The `delta` array to array codec.
+//!
+//! Encodes the differences between consecutive elements (in C order), with the semantics of the `numcodecs` `delta` codec.
+//! The first element is stored as is.
+//! Differences are computed in the decoded data type (integers wrap on overflow) and then cast to the encoded data type.
+//! Integer and floating point data types are supported.
+//!
+//! Partial decoding requires decoding the entire chunk, since each element depends on all preceding elements.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `delta` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `delta` codec, and is used when opening Zarr V2 arrays with a `delta` filter.
+//!
+//! See [`DeltaCodecConfigurationV1`] for example `JSON` metadata.
+
+mod delta_codec;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::delta::{
+    DeltaCodecConfiguration, DeltaCodecConfigurationV1,
+};
+pub use delta_codec::DeltaCodec;
+
+use crate::{
+    array::{
+        codec::{Codec, CodecError, CodecPlugin},
+        convert_from_bytes_slice, transmute_to_bytes_vec, DataType,
+    },
+    config::global_config,
+    metadata::v3::{array::codec::delta, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use delta::IDENTIFIER;
+
+use super::{cast_bytes, is_numeric_data_type};
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_delta, create_codec_delta)
+}
+
+fn is_name_delta(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_delta(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: DeltaCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(DeltaCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::ArrayToArray(codec))
+}
+
+/// Addition and subtraction of elements, wrapping for integers.
+trait DeltaElement: bytemuck::Pod {
+    fn delta_add(self, rhs: Self) -> Self;
+    fn delta_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_delta_element_int {
+    ( $($t:ty),* ) => {
+        $(
+            impl DeltaElement for $t {
+                fn delta_add(self, rhs: Self) -> Self {
+                    self.wrapping_add(rhs)
+                }
+                fn delta_sub(self, rhs: Self) -> Self {
+                    self.wrapping_sub(rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_delta_element_float {
+    ( $($t:ty),* ) => {
+        $(
+            impl DeltaElement for $t {
+                fn delta_add(self, rhs: Self) -> Self {
+                    self + rhs
+                }
+                fn delta_sub(self, rhs: Self) -> Self {
+                    self - rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_delta_element_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_delta_element_float!(f32, f64);
+
+fn delta_encode<T: DeltaElement>(bytes: &[u8]) -> Vec<u8> {
+    let elements = convert_from_bytes_slice::<T>(bytes);
+    let mut previous: Option<T> = None;
+    let encoded: Vec<T> = elements
+        .into_iter()
+        .map(|element| {
+            let difference = previous.map_or(element, |previous| element.delta_sub(previous));
+            previous = Some(element);
+            difference
+        })
+        .collect();
+    transmute_to_bytes_vec(encoded)
+}
+
+fn delta_decode<T: DeltaElement>(bytes: &[u8]) -> Vec<u8> {
+    let elements = convert_from_bytes_slice::<T>(bytes);
+    let mut sum: Option<T> = None;
+    let decoded: Vec<T> = elements
+        .into_iter()
+        .map(|element| {
+            let element = sum.map_or(element, |sum| sum.delta_add(element));
+            sum = Some(element);
+            element
+        })
+        .collect();
+    transmute_to_bytes_vec(decoded)
+}
+
+macro_rules! delta_apply {
+    ( $func:ident, $bytes:expr, $data_type:expr ) => {
+        match $data_type {
+            DataType::Int8 => Ok($func::<i8>($bytes)),
+            DataType::Int16 => Ok($func::<i16>($bytes)),
+            DataType::Int32 => Ok($func::<i32>($bytes)),
+            DataType::Int64 => Ok($func::<i64>($bytes)),
+            DataType::UInt8 => Ok($func::<u8>($bytes)),
+            DataType::UInt16 => Ok($func::<u16>($bytes)),
+            DataType::UInt32 => Ok($func::<u32>($bytes)),
+            DataType::UInt64 => Ok($func::<u64>($bytes)),
+            DataType::Float32 => Ok($func::<f32>($bytes)),
+            DataType::Float64 => Ok($func::<f64>($bytes)),
+            _ => Err(CodecError::UnsupportedDataType(
+                $data_type.clone(),
+                IDENTIFIER.to_string(),
+            )),
+        }
+    };
+}
+
+/// Delta encode elements of `data_type` in `bytes` to elements of `astype`.
+fn delta_encode_bytes(
+    bytes: &[u8],
+    data_type: &DataType,
+    astype: &DataType,
+) -> Result<Vec<u8>, CodecError> {
+    let encoded: Vec<u8> = delta_apply!(delta_encode, bytes, data_type)?;
+    cast_bytes(&encoded, data_type, astype, IDENTIFIER)
+}
+
+/// Delta decode elements of `astype` in `bytes` to elements of `data_type`.
+fn delta_decode_bytes(
+    bytes: &[u8],
+    data_type: &DataType,
+    astype: &DataType,
+) -> Result<Vec<u8>, CodecError> {
+    let bytes = cast_bytes(bytes, astype, data_type, IDENTIFIER)?;
+    delta_apply!(delta_decode, &bytes, data_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{
+                ArrayToArrayCodecTraits, ArrayToBytesCodecTraits, BytesCodec, CodecOptions,
+                CodecTraits,
+            },
+            transmute_from_bytes_vec, ArrayBytes, ChunkRepresentation, FillValue,
+        },
+        array_subset::ArraySubset,
+    };
+
+    use super::*;
+
+    const JSON: &str = r#"{
+        "dtype": "<i4",
+        "astype": "<i2"
+    }"#;
+
+    #[test]
+    fn codec_delta_configuration() {
+        let configuration: DeltaCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = DeltaCodec::new_with_configuration(&configuration).unwrap();
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(metadata.name(), "numcodecs.delta");
+        assert_eq!(
+            metadata
+                .to_configuration::<DeltaCodecConfiguration>()
+                .unwrap(),
+            configuration
+        );
+
+        let configuration: DeltaCodecConfiguration =
+            serde_json::from_str(r#"{"dtype": "|b1"}"#).unwrap();
+        assert!(DeltaCodec::new_with_configuration(&configuration).is_err());
+    }
+
+    #[test]
+    fn codec_delta_wrapping() {
+        let bytes = transmute_to_bytes_vec(vec![100u8, 10, 250]);
+        let encoded = delta_encode_bytes(&bytes, &DataType::UInt8, &DataType::UInt8).unwrap();
+        assert_eq!(encoded, vec![100, 166, 240]);
+        let decoded = delta_decode_bytes(&encoded, &DataType::UInt8, &DataType::UInt8).unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn codec_delta_round_trip() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap(), NonZeroU64::new(3).unwrap()],
+            DataType::Int32,
+            FillValue::from(0i32),
+        )
+        .unwrap();
+        let elements: Vec<i32> = vec![1000, 1002, 1001, 1005, 1005, 990];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements.clone()).into();
+
+        let configuration: DeltaCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = DeltaCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        assert_eq!(encoded_representation.data_type(), &DataType::Int16);
+
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            transmute_from_bytes_vec::<i16>(encoded.clone().into_fixed().unwrap().into_owned()),
+            vec![1000, 2, -1, 4, 0, -15]
+        );
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            transmute_from_bytes_vec::<i32>(decoded.into_fixed().unwrap().into_owned()),
+            elements
+        );
+    }
+
+    #[test]
+    fn codec_delta_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap(), NonZeroU64::new(3).unwrap()],
+            DataType::Int32,
+            FillValue::from(0i32),
+        )
+        .unwrap();
+        let elements: Vec<i32> = vec![1000, 1002, 1001, 1005, 1005, 990];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: DeltaCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(DeltaCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_ranges(&[1..2, 1..3])];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<i32>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1005, 990]]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_delta_async_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap(), NonZeroU64::new(3).unwrap()],
+            DataType::Int32,
+            FillValue::from(0i32),
+        )
+        .unwrap();
+        let elements: Vec<i32> = vec![1000, 1002, 1001, 1005, 1005, 990];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: DeltaCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(DeltaCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .async_partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_ranges(&[1..2, 1..3])];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<i32>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1005, 990]]);
+    }
+}
@@ -0,0 +1,230 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            array_to_array::{data_type_to_numcodecs_data_type, numcodecs_data_type_to_data_type},
+            options::CodecOptions,
+            ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderTraits,
+            ArrayToArrayCodecTraits, ArrayToArrayPartialDecoderDefault,
+            ArrayToArrayPartialEncoderDefault, CodecError, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType, FillValue,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+    AsyncArrayToArrayPartialDecoderDefault, AsyncArrayToArrayPartialEncoderDefault,
+};
+
+use super::{
+    cast_bytes, delta_decode_bytes, delta_encode_bytes, is_numeric_data_type,
+    DeltaCodecConfiguration, DeltaCodecConfigurationV1, IDENTIFIER,
+};
+
+/// A `delta` codec implementation.
+#[derive(Clone, Debug)]
+pub struct DeltaCodec {
+    data_type: DataType,
+    astype: DataType,
+}
+
+impl DeltaCodec {
+    /// Create a new `delta` codec.
+    ///
+    /// `data_type` is the decoded data type, and `astype` is the encoded data type.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not supported.
+    pub fn new(data_type: DataType, astype: DataType) -> Result<Self, PluginCreateError> {
+        for data_type in [&data_type, &astype] {
+            if !is_numeric_data_type(data_type) {
+                return Err(PluginCreateError::from(format!(
+                    "delta codec does not support data type {data_type}"
+                )));
+            }
+        }
+        Ok(Self { data_type, astype })
+    }
+
+    /// Create a new `delta` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not supported.
+    pub fn new_with_configuration(
+        configuration: &DeltaCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let DeltaCodecConfiguration::V1(configuration) = configuration;
+        let data_type = numcodecs_data_type_to_data_type(&configuration.dtype)?;
+        let astype = configuration
+            .astype
+            .as_ref()
+            .map_or_else(|| Ok(data_type.clone()), numcodecs_data_type_to_data_type)?;
+        Self::new(data_type, astype)
+    }
+}
+
+impl CodecTraits for DeltaCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = DeltaCodecConfigurationV1 {
+            dtype: data_type_to_numcodecs_data_type(&self.data_type),
+            astype: Some(data_type_to_numcodecs_data_type(&self.astype)),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+impl ArrayCodecTraits for DeltaCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for DeltaCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(delta_encode_bytes(
+            &bytes,
+            &self.data_type,
+            &self.astype,
+        )?))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(delta_decode_bytes(
+            &bytes,
+            &self.data_type,
+            &self.astype,
+        )?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialDecoderDefault::new(
+            input_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialDecoderDefault::new(
+            input_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type != &self.data_type {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let fill_value = cast_bytes(
+            decoded_representation.fill_value().as_ne_bytes(),
+            &self.data_type,
+            &self.astype,
+            IDENTIFIER,
+        )?;
+        Ok(ChunkRepresentation::new(
+            decoded_representation.shape().to_vec(),
+            self.astype.clone(),
+            FillValue::new(fill_value),
+        )
+        .unwrap())
+    }
+
+    fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Ok(encoded_shape)
+    }
+}
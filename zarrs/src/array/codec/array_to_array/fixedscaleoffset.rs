@@ -0,0 +1,280 @@
+//! The `fixedscaleoffset` array to array codec.
+//!
+//! Encodes elements as `round((x - offset) * scale)` and decodes elements as `x / scale + offset`, with the semantics of the `numcodecs` `fixedscaleoffset` codec.
+//! Rounding is to the nearest integer (ties to even), and computations are performed in double precision.
+//! This is typically used to store floating point data with a fixed precision as integers of a smaller data type.
+//! Integer and floating point data types are supported.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `fixedscaleoffset` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `fixedscaleoffset` codec, and is used when opening Zarr V2 arrays with a `fixedscaleoffset` filter.
+//!
+//! See [`FixedScaleOffsetCodecConfigurationV1`] for example `JSON` metadata.
+
+mod fixedscaleoffset_codec;
+mod fixedscaleoffset_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::fixedscaleoffset::{
+    FixedScaleOffsetCodecConfiguration, FixedScaleOffsetCodecConfigurationV1,
+};
+pub use fixedscaleoffset_codec::FixedScaleOffsetCodec;
+
+use crate::{
+    array::{
+        codec::{Codec, CodecError, CodecPlugin},
+        convert_from_bytes_slice, transmute_to_bytes_vec, DataType,
+    },
+    config::global_config,
+    metadata::v3::{array::codec::fixedscaleoffset, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use fixedscaleoffset::IDENTIFIER;
+
+use super::{cast_bytes, is_numeric_data_type};
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_fixedscaleoffset, create_codec_fixedscaleoffset)
+}
+
+fn is_name_fixedscaleoffset(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_fixedscaleoffset(
+    metadata: &MetadataV3,
+) -> Result<Codec, PluginCreateError> {
+    let configuration: FixedScaleOffsetCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(FixedScaleOffsetCodec::new_with_configuration(
+        &configuration,
+    )?);
+    Ok(Codec::ArrayToArray(codec))
+}
+
+/// Apply `operation` to elements of `data_type_in` in `bytes` (as `f64`), and return elements of `data_type_out`.
+fn map_elements_f64(
+    bytes: &[u8],
+    data_type_in: &DataType,
+    data_type_out: &DataType,
+    operation: impl Fn(f64) -> f64,
+) -> Result<Vec<u8>, CodecError> {
+    let elements = cast_bytes(bytes, data_type_in, &DataType::Float64, IDENTIFIER)?;
+    let elements: Vec<f64> = convert_from_bytes_slice::<f64>(&elements)
+        .into_iter()
+        .map(operation)
+        .collect();
+    cast_bytes(
+        &transmute_to_bytes_vec(elements),
+        &DataType::Float64,
+        data_type_out,
+        IDENTIFIER,
+    )
+}
+
+/// Encode elements of `data_type` in `bytes` to elements of `astype`.
+fn fixedscaleoffset_encode_bytes(
+    bytes: &[u8],
+    offset: f64,
+    scale: f64,
+    data_type: &DataType,
+    astype: &DataType,
+) -> Result<Vec<u8>, CodecError> {
+    map_elements_f64(bytes, data_type, astype, |element| {
+        ((element - offset) * scale).round_ties_even()
+    })
+}
+
+/// Decode elements of `astype` in `bytes` to elements of `data_type`.
+fn fixedscaleoffset_decode_bytes(
+    bytes: &[u8],
+    offset: f64,
+    scale: f64,
+    data_type: &DataType,
+    astype: &DataType,
+) -> Result<Vec<u8>, CodecError> {
+    map_elements_f64(bytes, astype, data_type, |element| element / scale + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{
+                ArrayToArrayCodecTraits, ArrayToBytesCodecTraits, BytesCodec, CodecOptions,
+                CodecTraits,
+            },
+            transmute_from_bytes_vec, ArrayBytes, ChunkRepresentation, FillValue,
+        },
+        array_subset::ArraySubset,
+    };
+
+    use super::*;
+
+    const JSON: &str = r#"{
+        "offset": 1000.0,
+        "scale": 10.0,
+        "dtype": "<f8",
+        "astype": "|u1"
+    }"#;
+
+    #[test]
+    fn codec_fixedscaleoffset_configuration() {
+        let configuration: FixedScaleOffsetCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = FixedScaleOffsetCodec::new_with_configuration(&configuration).unwrap();
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(metadata.name(), "numcodecs.fixedscaleoffset");
+        assert_eq!(
+            metadata
+                .to_configuration::<FixedScaleOffsetCodecConfiguration>()
+                .unwrap(),
+            configuration
+        );
+
+        let configuration: FixedScaleOffsetCodecConfiguration =
+            serde_json::from_str(r#"{"offset": 0, "scale": 1, "dtype": "<f8", "astype": "|b1"}"#)
+                .unwrap();
+        assert!(FixedScaleOffsetCodec::new_with_configuration(&configuration).is_err());
+    }
+
+    #[test]
+    fn codec_fixedscaleoffset_round_trip() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(5).unwrap()],
+            DataType::Float64,
+            FillValue::from(1000.0f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = vec![1000.0, 1000.12, 1000.25, 1003.456, 1025.5];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: FixedScaleOffsetCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = FixedScaleOffsetCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        assert_eq!(encoded_representation.data_type(), &DataType::UInt8);
+        assert_eq!(encoded_representation.fill_value(), &FillValue::from(0u8));
+
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            encoded.clone().into_fixed().unwrap().into_owned(),
+            vec![0, 1, 2, 35, 255]
+        );
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            transmute_from_bytes_vec::<f64>(decoded.into_fixed().unwrap().into_owned()),
+            vec![1000.0, 1000.1, 1000.2, 1003.5, 1025.5]
+        );
+    }
+
+    #[test]
+    fn codec_fixedscaleoffset_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(5).unwrap()],
+            DataType::Float64,
+            FillValue::from(1000.0f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = vec![1000.0, 1000.12, 1000.25, 1003.456, 1025.5];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: FixedScaleOffsetCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec =
+            Arc::new(FixedScaleOffsetCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_start_shape(vec![2], vec![2]).unwrap()];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<f64>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1000.2, 1003.5]]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_fixedscaleoffset_async_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(5).unwrap()],
+            DataType::Float64,
+            FillValue::from(1000.0f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = vec![1000.0, 1000.12, 1000.25, 1003.456, 1025.5];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: FixedScaleOffsetCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec =
+            Arc::new(FixedScaleOffsetCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .async_partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_start_shape(vec![2], vec![2]).unwrap()];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<f64>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1000.2, 1003.5]]);
+    }
+}
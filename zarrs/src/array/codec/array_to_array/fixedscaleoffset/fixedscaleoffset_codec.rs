@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            array_to_array::{data_type_to_numcodecs_data_type, numcodecs_data_type_to_data_type},
+            options::CodecOptions,
+            ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderTraits,
+            ArrayToArrayCodecTraits, ArrayToArrayPartialEncoderDefault, CodecError, CodecTraits,
+            RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType, FillValue,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+    AsyncArrayToArrayPartialEncoderDefault,
+};
+
+use super::{
+    fixedscaleoffset_decode_bytes, fixedscaleoffset_encode_bytes, fixedscaleoffset_partial_decoder,
+    is_numeric_data_type, FixedScaleOffsetCodecConfiguration, FixedScaleOffsetCodecConfigurationV1,
+    IDENTIFIER,
+};
+
+/// A `fixedscaleoffset` codec implementation.
+#[derive(Clone, Debug)]
+pub struct FixedScaleOffsetCodec {
+    offset: f64,
+    scale: f64,
+    data_type: DataType,
+    astype: DataType,
+}
+
+impl FixedScaleOffsetCodec {
+    /// Create a new `fixedscaleoffset` codec.
+    ///
+    /// `data_type` is the decoded data type, and `astype` is the encoded data type.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not supported.
+    pub fn new(
+        offset: f64,
+        scale: f64,
+        data_type: DataType,
+        astype: DataType,
+    ) -> Result<Self, PluginCreateError> {
+        for data_type in [&data_type, &astype] {
+            if !is_numeric_data_type(data_type) {
+                return Err(PluginCreateError::from(format!(
+                    "fixedscaleoffset codec does not support data type {data_type}"
+                )));
+            }
+        }
+        Ok(Self {
+            offset,
+            scale,
+            data_type,
+            astype,
+        })
+    }
+
+    /// Create a new `fixedscaleoffset` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not supported.
+    pub fn new_with_configuration(
+        configuration: &FixedScaleOffsetCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let FixedScaleOffsetCodecConfiguration::V1(configuration) = configuration;
+        let data_type = numcodecs_data_type_to_data_type(&configuration.dtype)?;
+        let astype = configuration
+            .astype
+            .as_ref()
+            .map_or_else(|| Ok(data_type.clone()), numcodecs_data_type_to_data_type)?;
+        Self::new(configuration.offset, configuration.scale, data_type, astype)
+    }
+}
+
+impl CodecTraits for FixedScaleOffsetCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = FixedScaleOffsetCodecConfigurationV1 {
+            offset: self.offset,
+            scale: self.scale,
+            dtype: data_type_to_numcodecs_data_type(&self.data_type),
+            astype: Some(data_type_to_numcodecs_data_type(&self.astype)),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+impl ArrayCodecTraits for FixedScaleOffsetCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for FixedScaleOffsetCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(fixedscaleoffset_encode_bytes(
+            &bytes,
+            self.offset,
+            self.scale,
+            &self.data_type,
+            &self.astype,
+        )?))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(fixedscaleoffset_decode_bytes(
+            &bytes,
+            self.offset,
+            self.scale,
+            &self.data_type,
+            &self.astype,
+        )?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(
+            fixedscaleoffset_partial_decoder::FixedScaleOffsetPartialDecoder::new(
+                input_handle,
+                self.offset,
+                self.scale,
+                self.data_type.clone(),
+                self.astype.clone(),
+            ),
+        ))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(
+            fixedscaleoffset_partial_decoder::AsyncFixedScaleOffsetPartialDecoder::new(
+                input_handle,
+                self.offset,
+                self.scale,
+                self.data_type.clone(),
+                self.astype.clone(),
+            ),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type != &self.data_type {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let fill_value = fixedscaleoffset_encode_bytes(
+            decoded_representation.fill_value().as_ne_bytes(),
+            self.offset,
+            self.scale,
+            &self.data_type,
+            &self.astype,
+        )?;
+        Ok(ChunkRepresentation::new(
+            decoded_representation.shape().to_vec(),
+            self.astype.clone(),
+            FillValue::new(fill_value),
+        )
+        .unwrap())
+    }
+
+    fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Ok(encoded_shape)
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{ArrayBytes, ArrayPartialDecoderTraits, CodecError, CodecOptions},
+        DataType,
+    },
+    array_subset::ArraySubset,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncArrayPartialDecoderTraits;
+
+use super::fixedscaleoffset_decode_bytes;
+
+/// Partial decoder for the `fixedscaleoffset` codec.
+pub(crate) struct FixedScaleOffsetPartialDecoder {
+    input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+    offset: f64,
+    scale: f64,
+    data_type: DataType,
+    astype: DataType,
+}
+
+impl FixedScaleOffsetPartialDecoder {
+    /// Create a new partial decoder for the `fixedscaleoffset` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        offset: f64,
+        scale: f64,
+        data_type: DataType,
+        astype: DataType,
+    ) -> Self {
+        Self {
+            input_handle,
+            offset,
+            scale,
+            data_type,
+            astype,
+        }
+    }
+}
+
+impl ArrayPartialDecoderTraits for FixedScaleOffsetPartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded = self.input_handle.partial_decode(array_subsets, options)?;
+        encoded
+            .into_iter()
+            .map(|bytes| {
+                let bytes = bytes.into_fixed()?;
+                Ok(ArrayBytes::from(fixedscaleoffset_decode_bytes(
+                    &bytes,
+                    self.offset,
+                    self.scale,
+                    &self.data_type,
+                    &self.astype,
+                )?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for the `fixedscaleoffset` codec.
+pub(crate) struct AsyncFixedScaleOffsetPartialDecoder {
+    input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+    offset: f64,
+    scale: f64,
+    data_type: DataType,
+    astype: DataType,
+}
+
+#[cfg(feature = "async")]
+impl AsyncFixedScaleOffsetPartialDecoder {
+    /// Create a new partial decoder for the `fixedscaleoffset` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        offset: f64,
+        scale: f64,
+        data_type: DataType,
+        astype: DataType,
+    ) -> Self {
+        Self {
+            input_handle,
+            offset,
+            scale,
+            data_type,
+            astype,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialDecoderTraits for AsyncFixedScaleOffsetPartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    async fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded = self
+            .input_handle
+            .partial_decode(array_subsets, options)
+            .await?;
+        encoded
+            .into_iter()
+            .map(|bytes| {
+                let bytes = bytes.into_fixed()?;
+                Ok(ArrayBytes::from(fixedscaleoffset_decode_bytes(
+                    &bytes,
+                    self.offset,
+                    self.scale,
+                    &self.data_type,
+                    &self.astype,
+                )?))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,273 @@
+//! The `packbits` array to array codec.
+//!
+//! Packs a `bool` array into a `uint8` array with 8 elements per byte.
+//! The first byte of the encoded chunk is the number of padding bits in the last byte.
+//! Bits are packed in big-endian bit order, as with `numpy.packbits`.
+//!
+//! The encoded chunk is a 1D `uint8` array with `1 + ceil(num_elements / 8)` elements.
+//! The shape of the decoded chunk cannot be determined from the encoded chunk, so this codec cannot precede the `sharding_indexed` codec.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `packbits` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `packbits` codec, and is used when opening Zarr V2 arrays with a `packbits` filter.
+//!
+//! See [`PackBitsCodecConfigurationV1`] for example `JSON` metadata.
+
+mod packbits_codec;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::packbits::{
+    PackBitsCodecConfiguration, PackBitsCodecConfigurationV1,
+};
+pub use packbits_codec::PackBitsCodec;
+
+use crate::{
+    array::codec::{Codec, CodecError, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::packbits, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use packbits::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_packbits, create_codec_packbits)
+}
+
+fn is_name_packbits(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_packbits(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: PackBitsCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(PackBitsCodec::new_with_configuration(&configuration));
+    Ok(Codec::ArrayToArray(codec))
+}
+
+/// The number of encoded bytes for `num_elements` packed bits, including the leading padding byte.
+fn packed_size(num_elements: u64) -> u64 {
+    1 + num_elements.div_ceil(8)
+}
+
+/// Pack `bool` elements into bits with a leading padding byte.
+fn pack_bits(elements: &[u8]) -> Vec<u8> {
+    let padding = (8 - elements.len() % 8) % 8;
+    let mut packed = Vec::with_capacity(1 + elements.len().div_ceil(8));
+    packed.push(u8::try_from(padding).unwrap());
+    packed.extend(elements.chunks(8).map(|bits| {
+        bits.iter().enumerate().fold(0u8, |byte, (i, bit)| {
+            byte | (u8::from(*bit != 0) << (7 - i))
+        })
+    }));
+    packed
+}
+
+/// Unpack `num_elements` `bool` elements from bits with a leading padding byte.
+fn unpack_bits(packed: &[u8], num_elements: usize) -> Result<Vec<u8>, CodecError> {
+    let Some((&padding, packed)) = packed.split_first() else {
+        return Err(CodecError::Other(
+            "packbits encoded value is missing the padding byte".to_string(),
+        ));
+    };
+    if packed.len() != num_elements.div_ceil(8)
+        || packed.len() * 8 - usize::from(padding) != num_elements
+    {
+        return Err(CodecError::Other(format!(
+            "packbits encoded value has {} bytes with {padding} padding bits, expected {num_elements} elements",
+            packed.len()
+        )));
+    }
+    Ok((0..num_elements)
+        .map(|i| (packed[i / 8] >> (7 - i % 8)) & 1)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{
+                ArrayToArrayCodecTraits, ArrayToBytesCodecTraits, BytesCodec, CodecOptions,
+                CodecTraits,
+            },
+            ArrayBytes, ChunkRepresentation, DataType, FillValue,
+        },
+        array_subset::ArraySubset,
+    };
+
+    use super::*;
+
+    #[test]
+    fn codec_packbits_pack_unpack() {
+        let elements = [1, 0, 1, 1, 0, 0, 0, 0, 1, 1];
+        let packed = pack_bits(&elements);
+        assert_eq!(packed, vec![6, 0b1011_0000, 0b1100_0000]);
+        assert_eq!(unpack_bits(&packed, elements.len()).unwrap(), elements);
+        assert!(unpack_bits(&packed, 9).is_err());
+        assert!(unpack_bits(&[], 0).is_err());
+        assert_eq!(pack_bits(&[]), vec![0]);
+        assert_eq!(unpack_bits(&[0], 0).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn codec_packbits_round_trip() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap(), NonZeroU64::new(5).unwrap()],
+            DataType::Bool,
+            FillValue::from(false),
+        )
+        .unwrap();
+        let elements: Vec<bool> = (0..10).map(|i| i % 3 == 0).collect();
+        let bytes: ArrayBytes = crate::array::transmute_to_bytes_vec(elements.clone()).into();
+
+        let configuration: PackBitsCodecConfiguration = serde_json::from_str("{}").unwrap();
+        let codec = PackBitsCodec::new_with_configuration(&configuration);
+        assert_eq!(
+            codec.create_metadata().unwrap().name(),
+            "numcodecs.packbits"
+        );
+
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        assert_eq!(encoded_representation.shape_u64(), vec![3]);
+        assert_eq!(encoded_representation.data_type(), &DataType::UInt8);
+
+        let encoded = codec
+            .encode(
+                bytes.clone(),
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            encoded.clone().into_fixed().unwrap().as_ref(),
+            &[6, 0b1001_0010, 0b0100_0000]
+        );
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded);
+    }
+
+    #[test]
+    fn codec_packbits_unsupported_data_type() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(8).unwrap()],
+            DataType::UInt8,
+            FillValue::from(0u8),
+        )
+        .unwrap();
+        let codec = PackBitsCodec::new();
+        assert!(codec.compute_encoded_size(&chunk_representation).is_err());
+    }
+
+    #[test]
+    fn codec_packbits_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap(), NonZeroU64::new(5).unwrap()],
+            DataType::Bool,
+            FillValue::from(false),
+        )
+        .unwrap();
+        let elements: Vec<bool> = (0..10).map(|i| i % 3 == 0).collect();
+        let bytes: ArrayBytes = crate::array::transmute_to_bytes_vec(elements).into();
+
+        let codec = Arc::new(PackBitsCodec::new());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_ranges(&[1..2, 1..4])];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap();
+        let decoded_partial_chunk: Vec<bool> = decoded_partial_chunk[0]
+            .clone()
+            .into_fixed()
+            .unwrap()
+            .iter()
+            .map(|b| *b != 0)
+            .collect();
+        assert_eq!(decoded_partial_chunk, vec![true, false, false]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_packbits_async_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap(), NonZeroU64::new(5).unwrap()],
+            DataType::Bool,
+            FillValue::from(false),
+        )
+        .unwrap();
+        let elements: Vec<bool> = (0..10).map(|i| i % 3 == 0).collect();
+        let bytes: ArrayBytes = crate::array::transmute_to_bytes_vec(elements).into();
+
+        let codec = Arc::new(PackBitsCodec::new());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .async_partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_ranges(&[1..2, 1..4])];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap();
+        let decoded_partial_chunk: Vec<bool> = decoded_partial_chunk[0]
+            .clone()
+            .into_fixed()
+            .unwrap()
+            .iter()
+            .map(|b| *b != 0)
+            .collect();
+        assert_eq!(decoded_partial_chunk, vec![true, false, false]);
+    }
+}
@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            options::CodecOptions, ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits,
+            ArrayPartialEncoderTraits, ArrayToArrayCodecTraits, ArrayToArrayPartialDecoderDefault,
+            ArrayToArrayPartialEncoderDefault, CodecError, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType, FillValue,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+    AsyncArrayToArrayPartialDecoderDefault, AsyncArrayToArrayPartialEncoderDefault,
+};
+
+use super::{
+    pack_bits, packed_size, unpack_bits, PackBitsCodecConfiguration, PackBitsCodecConfigurationV1,
+    IDENTIFIER,
+};
+
+/// A `packbits` codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct PackBitsCodec;
+
+impl PackBitsCodec {
+    /// Create a new `packbits` codec.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+
+    /// Create a new `packbits` codec from configuration.
+    #[must_use]
+    pub const fn new_with_configuration(_configuration: &PackBitsCodecConfiguration) -> Self {
+        Self {}
+    }
+}
+
+impl CodecTraits for PackBitsCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = PackBitsCodecConfigurationV1 {};
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+impl ArrayCodecTraits for PackBitsCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for PackBitsCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(pack_bits(&bytes)))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        let num_elements = usize::try_from(decoded_representation.num_elements()).unwrap();
+        Ok(ArrayBytes::from(unpack_bits(&bytes, num_elements)?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialDecoderDefault::new(
+            input_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialDecoderDefault::new(
+            input_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type != &DataType::Bool {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let size = packed_size(decoded_representation.num_elements());
+        Ok(ChunkRepresentation::new(
+            vec![size.try_into().unwrap()],
+            DataType::UInt8,
+            FillValue::from(0u8),
+        )
+        .unwrap())
+    }
+
+    fn compute_decoded_shape(&self, _encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Err(CodecError::Other(
+            "the packbits codec cannot compute the decoded shape from the encoded shape"
+                .to_string(),
+        ))
+    }
+}
@@ -0,0 +1,288 @@
+//! The `quantize` array to array codec.
+//!
+//! Quantizes floating point data to a fixed number of decimal digits, with the rounding behaviour of `numcodecs`.
+//! Values are rounded to the nearest multiple of `2^-bits` (ties to even), where `2^bits` is the smallest power of two not less than `10^digits`.
+//! The encoded data type may differ from the decoded data type, both must be `float32` or `float64`.
+//!
+//! This codec is lossy and encode only, the decode operation just converts to the decoded data type.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `quantize` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `quantize` codec, and is used when opening Zarr V2 arrays with a `quantize` filter.
+//!
+//! See [`QuantizeCodecConfigurationV1`] for example `JSON` metadata.
+
+mod quantize_codec;
+mod quantize_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::quantize::{
+    QuantizeCodecConfiguration, QuantizeCodecConfigurationV1,
+};
+pub use quantize_codec::QuantizeCodec;
+
+use crate::{
+    array::{
+        codec::{Codec, CodecError, CodecPlugin},
+        convert_from_bytes_slice, transmute_to_bytes_vec, DataType,
+    },
+    config::global_config,
+    metadata::v3::{array::codec::quantize, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use quantize::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_quantize, create_codec_quantize)
+}
+
+fn is_name_quantize(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_quantize(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: QuantizeCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(QuantizeCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::ArrayToArray(codec))
+}
+
+/// The scale applied before rounding to quantize to `digits` decimal digits.
+fn quantize_scale(digits: i32) -> f64 {
+    let bits = 10f64.powi(digits).log2().ceil();
+    2f64.powf(bits)
+}
+
+/// Convert floating point elements in `bytes` from `data_type_in` to `data_type_out`, optionally quantizing with `scale` first.
+fn quantize_bytes(
+    bytes: &[u8],
+    data_type_in: &DataType,
+    data_type_out: &DataType,
+    scale: Option<f64>,
+) -> Result<Vec<u8>, CodecError> {
+    #[allow(clippy::cast_possible_truncation)]
+    let elements: Vec<f64> = match data_type_in {
+        DataType::Float32 => {
+            let scale = scale.map(|scale| scale as f32);
+            convert_from_bytes_slice::<f32>(bytes)
+                .into_iter()
+                .map(|element| {
+                    scale.map_or(element, |scale| (scale * element).round_ties_even() / scale)
+                })
+                .map(f64::from)
+                .collect()
+        }
+        DataType::Float64 => convert_from_bytes_slice::<f64>(bytes)
+            .into_iter()
+            .map(|element| {
+                scale.map_or(element, |scale| (scale * element).round_ties_even() / scale)
+            })
+            .collect(),
+        _ => {
+            return Err(CodecError::UnsupportedDataType(
+                data_type_in.clone(),
+                IDENTIFIER.to_string(),
+            ))
+        }
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    match data_type_out {
+        DataType::Float32 => Ok(transmute_to_bytes_vec(
+            elements
+                .into_iter()
+                .map(|element| element as f32)
+                .collect::<Vec<_>>(),
+        )),
+        DataType::Float64 => Ok(transmute_to_bytes_vec(elements)),
+        _ => Err(CodecError::UnsupportedDataType(
+            data_type_out.clone(),
+            IDENTIFIER.to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{
+                ArrayToArrayCodecTraits, ArrayToBytesCodecTraits, BytesCodec, CodecOptions,
+                CodecTraits,
+            },
+            transmute_from_bytes_vec, ArrayBytes, ChunkRepresentation, FillValue,
+        },
+        array_subset::ArraySubset,
+    };
+
+    use super::*;
+
+    const JSON: &str = r#"{
+        "digits": 1,
+        "dtype": "<f8",
+        "astype": "<f4"
+    }"#;
+
+    #[test]
+    fn codec_quantize_scale() {
+        assert!((quantize_scale(0) - 1.0).abs() < f64::EPSILON);
+        assert!((quantize_scale(1) - 16.0).abs() < f64::EPSILON);
+        assert!((quantize_scale(2) - 128.0).abs() < f64::EPSILON);
+        assert!((quantize_scale(3) - 1024.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn codec_quantize_configuration() {
+        let configuration: QuantizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = QuantizeCodec::new_with_configuration(&configuration).unwrap();
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(metadata.name(), "numcodecs.quantize");
+        assert_eq!(
+            metadata
+                .to_configuration::<QuantizeCodecConfiguration>()
+                .unwrap(),
+            configuration
+        );
+
+        let configuration: QuantizeCodecConfiguration =
+            serde_json::from_str(r#"{"digits": 1, "dtype": "<i4", "astype": "<f4"}"#).unwrap();
+        assert!(QuantizeCodec::new_with_configuration(&configuration).is_err());
+    }
+
+    #[test]
+    fn codec_quantize_round_trip() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(4).unwrap()],
+            DataType::Float64,
+            FillValue::from(0.0f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = vec![0.0, 1.23456, -2.7, 0.03125];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: QuantizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = QuantizeCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        assert_eq!(encoded_representation.data_type(), &DataType::Float32);
+
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            transmute_from_bytes_vec::<f32>(encoded.clone().into_fixed().unwrap().into_owned()),
+            vec![0.0, 1.25, -2.6875, 0.0]
+        );
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            transmute_from_bytes_vec::<f64>(decoded.into_fixed().unwrap().into_owned()),
+            vec![0.0, 1.25, -2.6875, 0.0]
+        );
+    }
+
+    #[test]
+    fn codec_quantize_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(4).unwrap()],
+            DataType::Float64,
+            FillValue::from(0.0f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = vec![0.0, 1.23456, -2.7, 0.5];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: QuantizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(QuantizeCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap()];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<f64>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1.25, -2.6875]]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_quantize_async_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(4).unwrap()],
+            DataType::Float64,
+            FillValue::from(0.0f64),
+        )
+        .unwrap();
+        let elements: Vec<f64> = vec![0.0, 1.23456, -2.7, 0.5];
+        let bytes: ArrayBytes = transmute_to_bytes_vec(elements).into();
+
+        let configuration: QuantizeCodecConfiguration = serde_json::from_str(JSON).unwrap();
+        let codec = Arc::new(QuantizeCodec::new_with_configuration(&configuration).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.into_fixed().unwrap()));
+        let bytes_codec = Arc::new(BytesCodec::default());
+        let input_handle = bytes_codec
+            .async_partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap()];
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|bytes| transmute_from_bytes_vec::<f64>(bytes.into_fixed().unwrap().into_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_partial_chunk, vec![vec![1.25, -2.6875]]);
+    }
+}
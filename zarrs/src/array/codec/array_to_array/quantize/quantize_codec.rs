@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            array_to_array::{data_type_to_numcodecs_data_type, numcodecs_data_type_to_data_type},
+            options::CodecOptions,
+            ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderTraits,
+            ArrayToArrayCodecTraits, ArrayToArrayPartialEncoderDefault, CodecError, CodecTraits,
+            RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType, FillValue,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+    AsyncArrayToArrayPartialEncoderDefault,
+};
+
+use super::{
+    quantize_bytes, quantize_partial_decoder, quantize_scale, QuantizeCodecConfiguration,
+    QuantizeCodecConfigurationV1, IDENTIFIER,
+};
+
+/// A `quantize` codec implementation.
+#[derive(Clone, Debug)]
+pub struct QuantizeCodec {
+    digits: i32,
+    data_type: DataType,
+    astype: DataType,
+}
+
+impl QuantizeCodec {
+    /// Create a new `quantize` codec.
+    ///
+    /// `digits` is the number of decimal digits to preserve, `data_type` is the decoded data type, and `astype` is the encoded data type.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not `float32` or `float64`.
+    pub fn new(
+        digits: i32,
+        data_type: DataType,
+        astype: DataType,
+    ) -> Result<Self, PluginCreateError> {
+        for data_type in [&data_type, &astype] {
+            if !matches!(data_type, DataType::Float32 | DataType::Float64) {
+                return Err(PluginCreateError::from(format!(
+                    "quantize codec does not support data type {data_type}"
+                )));
+            }
+        }
+        Ok(Self {
+            digits,
+            data_type,
+            astype,
+        })
+    }
+
+    /// Create a new `quantize` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if either data type is not `float32` or `float64`.
+    pub fn new_with_configuration(
+        configuration: &QuantizeCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let QuantizeCodecConfiguration::V1(configuration) = configuration;
+        Self::new(
+            configuration.digits,
+            numcodecs_data_type_to_data_type(&configuration.dtype)?,
+            numcodecs_data_type_to_data_type(&configuration.astype)?,
+        )
+    }
+}
+
+impl CodecTraits for QuantizeCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = QuantizeCodecConfigurationV1 {
+            digits: self.digits,
+            dtype: data_type_to_numcodecs_data_type(&self.data_type),
+            astype: data_type_to_numcodecs_data_type(&self.astype),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+impl ArrayCodecTraits for QuantizeCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for QuantizeCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(quantize_bytes(
+            &bytes,
+            &self.data_type,
+            &self.astype,
+            Some(quantize_scale(self.digits)),
+        )?))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        Ok(ArrayBytes::from(quantize_bytes(
+            &bytes,
+            &self.astype,
+            &self.data_type,
+            None,
+        )?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(
+            quantize_partial_decoder::QuantizePartialDecoder::new(
+                input_handle,
+                self.astype.clone(),
+                self.data_type.clone(),
+            ),
+        ))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        Ok(Arc::new(
+            quantize_partial_decoder::AsyncQuantizePartialDecoder::new(
+                input_handle,
+                self.astype.clone(),
+                self.data_type.clone(),
+            ),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type != &self.data_type {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let fill_value = quantize_bytes(
+            decoded_representation.fill_value().as_ne_bytes(),
+            &self.data_type,
+            &self.astype,
+            Some(quantize_scale(self.digits)),
+        )?;
+        Ok(ChunkRepresentation::new(
+            decoded_representation.shape().to_vec(),
+            self.astype.clone(),
+            FillValue::new(fill_value),
+        )
+        .unwrap())
+    }
+
+    fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Ok(encoded_shape)
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{ArrayBytes, ArrayPartialDecoderTraits, CodecError, CodecOptions},
+        DataType,
+    },
+    array_subset::ArraySubset,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncArrayPartialDecoderTraits;
+
+use super::quantize_bytes;
+
+/// Partial decoder for the `quantize` codec.
+pub(crate) struct QuantizePartialDecoder {
+    input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+    encode_data_type: DataType,
+    decode_data_type: DataType,
+}
+
+impl QuantizePartialDecoder {
+    /// Create a new partial decoder for the `quantize` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        encode_data_type: DataType,
+        decode_data_type: DataType,
+    ) -> Self {
+        Self {
+            input_handle,
+            encode_data_type,
+            decode_data_type,
+        }
+    }
+}
+
+impl ArrayPartialDecoderTraits for QuantizePartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.decode_data_type
+    }
+
+    fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded = self.input_handle.partial_decode(array_subsets, options)?;
+        encoded
+            .into_iter()
+            .map(|bytes| {
+                let bytes = bytes.into_fixed()?;
+                Ok(ArrayBytes::from(quantize_bytes(
+                    &bytes,
+                    &self.encode_data_type,
+                    &self.decode_data_type,
+                    None,
+                )?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for the `quantize` codec.
+pub(crate) struct AsyncQuantizePartialDecoder {
+    input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+    encode_data_type: DataType,
+    decode_data_type: DataType,
+}
+
+#[cfg(feature = "async")]
+impl AsyncQuantizePartialDecoder {
+    /// Create a new partial decoder for the `quantize` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        encode_data_type: DataType,
+        decode_data_type: DataType,
+    ) -> Self {
+        Self {
+            input_handle,
+            encode_data_type,
+            decode_data_type,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialDecoderTraits for AsyncQuantizePartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.decode_data_type
+    }
+
+    async fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded = self
+            .input_handle
+            .partial_decode(array_subsets, options)
+            .await?;
+        encoded
+            .into_iter()
+            .map(|bytes| {
+                let bytes = bytes.into_fixed()?;
+                Ok(ArrayBytes::from(quantize_bytes(
+                    &bytes,
+                    &self.encode_data_type,
+                    &self.decode_data_type,
+                    None,
+                )?))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{ArrayBytes, ChunkRepresentation, DataType},
+    array_subset::ArraySubset,
+};
+
+use super::{ArrayPartialDecoderTraits, ArrayToArrayCodecTraits, CodecError, CodecOptions};
+
+#[cfg(feature = "async")]
+use super::AsyncArrayPartialDecoderTraits;
+
+/// The default array to array partial decoder. Decodes the entire chunk, and then extracts the requested array subsets.
+pub struct ArrayToArrayPartialDecoderDefault {
+    input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    codec: Arc<dyn ArrayToArrayCodecTraits>,
+}
+
+impl ArrayToArrayPartialDecoderDefault {
+    /// Create a new [`ArrayToArrayPartialDecoderDefault`].
+    #[must_use]
+    pub fn new(
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        codec: Arc<dyn ArrayToArrayCodecTraits>,
+    ) -> Self {
+        Self {
+            input_handle,
+            decoded_representation,
+            codec,
+        }
+    }
+}
+
+impl ArrayPartialDecoderTraits for ArrayToArrayPartialDecoderDefault {
+    fn data_type(&self) -> &DataType {
+        self.decoded_representation.data_type()
+    }
+
+    fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        // Read the entire encoded chunk
+        let encoded_representation = self
+            .codec
+            .compute_encoded_size(&self.decoded_representation)?;
+        let encoded_subset_all = ArraySubset::new_with_shape(encoded_representation.shape_u64());
+        let encoded_value = self
+            .input_handle
+            .partial_decode(&[encoded_subset_all], options)?
+            .pop()
+            .unwrap();
+
+        // Decode the entire chunk and extract the subsets
+        partial_decode_extract(
+            &self.codec,
+            &self.decoded_representation,
+            encoded_value,
+            array_subsets,
+            options,
+        )
+    }
+}
+
+/// Decode `encoded_value` and extract `array_subsets`.
+fn partial_decode_extract(
+    codec: &Arc<dyn ArrayToArrayCodecTraits>,
+    decoded_representation: &ChunkRepresentation,
+    encoded_value: ArrayBytes<'_>,
+    array_subsets: &[ArraySubset],
+    options: &CodecOptions,
+) -> Result<Vec<ArrayBytes<'static>>, CodecError> {
+    let chunk_shape = decoded_representation.shape_u64();
+    let decoded_value = codec.decode(encoded_value, decoded_representation, options)?;
+    decoded_value.validate(
+        decoded_representation.num_elements(),
+        decoded_representation.data_type().size(),
+    )?;
+    array_subsets
+        .iter()
+        .map(|array_subset| {
+            Ok(decoded_value
+                .extract_array_subset(
+                    array_subset,
+                    &chunk_shape,
+                    decoded_representation.data_type(),
+                )?
+                .into_owned())
+        })
+        .collect()
+}
+
+#[cfg(feature = "async")]
+/// The default asynchronous array to array partial decoder. Decodes the entire chunk, and then extracts the requested array subsets.
+pub struct AsyncArrayToArrayPartialDecoderDefault {
+    input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    codec: Arc<dyn ArrayToArrayCodecTraits>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncArrayToArrayPartialDecoderDefault {
+    /// Create a new [`AsyncArrayToArrayPartialDecoderDefault`].
+    #[must_use]
+    pub fn new(
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        codec: Arc<dyn ArrayToArrayCodecTraits>,
+    ) -> Self {
+        Self {
+            input_handle,
+            decoded_representation,
+            codec,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialDecoderTraits for AsyncArrayToArrayPartialDecoderDefault {
+    fn data_type(&self) -> &DataType {
+        self.decoded_representation.data_type()
+    }
+
+    async fn partial_decode(
+        &self,
+        array_subsets: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        // Read the entire encoded chunk
+        let encoded_representation = self
+            .codec
+            .compute_encoded_size(&self.decoded_representation)?;
+        let encoded_subset_all = ArraySubset::new_with_shape(encoded_representation.shape_u64());
+        let encoded_value = self
+            .input_handle
+            .partial_decode(&[encoded_subset_all], options)
+            .await?
+            .pop()
+            .unwrap();
+
+        // Decode the entire chunk and extract the subsets
+        partial_decode_extract(
+            &self.codec,
+            &self.decoded_representation,
+            encoded_value,
+            array_subsets,
+            options,
+        )
+    }
+}
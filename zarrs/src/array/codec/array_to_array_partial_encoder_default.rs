@@ -7,8 +7,12 @@ use crate::{
 
 use super::{
     ArrayPartialDecoderTraits, ArrayPartialEncoderTraits, ArrayToArrayCodecTraits, CodecError,
+    CodecOptions,
 };
 
+#[cfg(feature = "async")]
+use super::{AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits};
+
 /// The default array (chunk) partial encoder. Decodes the entire chunk, updates it, and writes the entire chunk.
 pub struct ArrayToArrayPartialEncoderDefault {
     input_handle: Arc<dyn ArrayPartialDecoderTraits>,
@@ -36,78 +40,181 @@ impl ArrayToArrayPartialEncoderDefault {
 }
 
 impl ArrayPartialEncoderTraits for ArrayToArrayPartialEncoderDefault {
-    fn erase(&self) -> Result<(), super::CodecError> {
+    fn erase(&self) -> Result<(), CodecError> {
         self.output_handle.erase()
     }
 
     fn partial_encode(
         &self,
         subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
-        options: &super::CodecOptions,
-    ) -> Result<(), super::CodecError> {
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
         // Read the entire chunk
-        let chunk_shape = self.decoded_representation.shape_u64();
-        let array_subset_all = ArraySubset::new_with_shape(chunk_shape.clone());
+        let encoded_representation = self
+            .codec
+            .compute_encoded_size(&self.decoded_representation)?;
+        let array_subset_all = ArraySubset::new_with_shape(encoded_representation.shape_u64());
         let encoded_value = self
             .input_handle
             .partial_decode(&[array_subset_all.clone()], options)?
             .pop()
             .unwrap();
-        let mut decoded_value =
-            self.codec
-                .decode(encoded_value, &self.decoded_representation, options)?;
-
-        // Validate the bytes
-        decoded_value.validate(
-            self.decoded_representation.num_elements(),
-            self.decoded_representation.data_type().size(),
+
+        // Decode and update the chunk
+        let encoded_value = partial_encode_update(
+            &self.codec,
+            &self.decoded_representation,
+            encoded_value,
+            subsets_and_bytes,
+            options,
         )?;
 
-        // Update the chunk
-        // TODO: More efficient update for multiple chunk subsets?
-        for (chunk_subset, chunk_subset_bytes) in subsets_and_bytes {
-            // Check the subset is within the chunk shape
-            if chunk_subset
-                .end_exc()
-                .iter()
-                .zip(self.decoded_representation.shape())
-                .any(|(a, b)| *a > b.get())
-            {
-                return Err(CodecError::InvalidArraySubsetError(
-                    IncompatibleArraySubsetAndShapeError::new(
-                        (*chunk_subset).clone(),
-                        self.decoded_representation.shape_u64(),
-                    ),
-                ));
-            }
-
-            chunk_subset_bytes.validate(
-                chunk_subset.num_elements(),
-                self.decoded_representation.data_type().size(),
-            )?;
-
-            decoded_value = unsafe {
-                update_array_bytes(
-                    decoded_value,
-                    &chunk_shape,
-                    chunk_subset,
-                    chunk_subset_bytes,
-                    self.decoded_representation.data_type().size(),
-                )
-            };
+        if let Some(encoded_value) = encoded_value {
+            // Store the updated chunk
+            self.output_handle
+                .partial_encode(&[(&array_subset_all, encoded_value)], options)
+        } else {
+            self.output_handle.erase()
         }
+    }
+}
 
-        let is_fill_value = !options.store_empty_chunks()
-            && decoded_value.is_fill_value(self.decoded_representation.fill_value());
-        if is_fill_value {
-            self.output_handle.erase()
-        } else {
+/// Decode `encoded_value`, apply the updates in `subsets_and_bytes`, and encode the result.
+///
+/// Returns [`None`] if the updated chunk is the fill value and empty chunks should not be stored.
+fn partial_encode_update(
+    codec: &Arc<dyn ArrayToArrayCodecTraits>,
+    decoded_representation: &ChunkRepresentation,
+    encoded_value: ArrayBytes<'_>,
+    subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+    options: &CodecOptions,
+) -> Result<Option<ArrayBytes<'static>>, CodecError> {
+    let chunk_shape = decoded_representation.shape_u64();
+    let mut decoded_value = codec
+        .decode(encoded_value, decoded_representation, options)?
+        .into_owned();
+
+    // Validate the bytes
+    decoded_value.validate(
+        decoded_representation.num_elements(),
+        decoded_representation.data_type().size(),
+    )?;
+
+    // Update the chunk
+    // TODO: More efficient update for multiple chunk subsets?
+    for (chunk_subset, chunk_subset_bytes) in subsets_and_bytes {
+        // Check the subset is within the chunk shape
+        if chunk_subset
+            .end_exc()
+            .iter()
+            .zip(decoded_representation.shape())
+            .any(|(a, b)| *a > b.get())
+        {
+            return Err(CodecError::InvalidArraySubsetError(
+                IncompatibleArraySubsetAndShapeError::new(
+                    (*chunk_subset).clone(),
+                    decoded_representation.shape_u64(),
+                ),
+            ));
+        }
+
+        chunk_subset_bytes.validate(
+            chunk_subset.num_elements(),
+            decoded_representation.data_type().size(),
+        )?;
+
+        decoded_value = unsafe {
+            update_array_bytes(
+                decoded_value,
+                &chunk_shape,
+                chunk_subset,
+                chunk_subset_bytes,
+                decoded_representation.data_type().size(),
+            )
+        };
+    }
+
+    let is_fill_value = !options.store_empty_chunks()
+        && decoded_value.is_fill_value(decoded_representation.fill_value());
+    if is_fill_value {
+        Ok(None)
+    } else {
+        Ok(Some(
+            codec
+                .encode(decoded_value, decoded_representation, options)?
+                .into_owned(),
+        ))
+    }
+}
+
+#[cfg(feature = "async")]
+/// The default asynchronous array (chunk) partial encoder. Decodes the entire chunk, updates it, and writes the entire chunk.
+pub struct AsyncArrayToArrayPartialEncoderDefault {
+    input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+    output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    codec: Arc<dyn ArrayToArrayCodecTraits>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncArrayToArrayPartialEncoderDefault {
+    /// Create a new [`AsyncArrayToArrayPartialEncoderDefault`].
+    #[must_use]
+    pub fn new(
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncArrayPartialEncoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        codec: Arc<dyn ArrayToArrayCodecTraits>,
+    ) -> Self {
+        Self {
+            input_handle,
+            output_handle,
+            decoded_representation,
+            codec,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialEncoderTraits for AsyncArrayToArrayPartialEncoderDefault {
+    async fn erase(&self) -> Result<(), CodecError> {
+        self.output_handle.erase().await
+    }
+
+    async fn partial_encode(
+        &self,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        // Read the entire chunk
+        let encoded_representation = self
+            .codec
+            .compute_encoded_size(&self.decoded_representation)?;
+        let array_subset_all = ArraySubset::new_with_shape(encoded_representation.shape_u64());
+        let encoded_value = self
+            .input_handle
+            .partial_decode(&[array_subset_all.clone()], options)
+            .await?
+            .pop()
+            .unwrap();
+
+        // Decode and update the chunk
+        let encoded_value = partial_encode_update(
+            &self.codec,
+            &self.decoded_representation,
+            encoded_value,
+            subsets_and_bytes,
+            options,
+        )?;
+
+        if let Some(encoded_value) = encoded_value {
             // Store the updated chunk
-            let encoded_value =
-                self.codec
-                    .encode(decoded_value, &self.decoded_representation, options)?;
             self.output_handle
                 .partial_encode(&[(&array_subset_all, encoded_value)], options)
+                .await
+        } else {
+            self.output_handle.erase().await
         }
     }
 }
@@ -64,15 +64,32 @@ pub(crate) fn reverse_endianness(v: &mut [u8], data_type: &DataType) {
             };
             v.chunks_exact_mut(4).for_each(swap);
         }
-        DataType::Int64 | DataType::UInt64 | DataType::Float64 | DataType::Complex128 => {
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Complex128
+        | DataType::NumpyDateTime64 { .. }
+        | DataType::NumpyTimeDelta64 { .. } => {
             let swap = |chunk: &mut [u8]| {
                 let bytes = u64::from_ne_bytes(unsafe { chunk.try_into().unwrap_unchecked() });
                 chunk.copy_from_slice(bytes.swap_bytes().to_ne_bytes().as_slice());
             };
             v.chunks_exact_mut(8).for_each(swap);
         }
+        DataType::Structured(structured) => {
+            // Reverse the endianness of each field of each element
+            for element in v.chunks_exact_mut(structured.size()) {
+                for field in structured.fields() {
+                    reverse_endianness(
+                        &mut element[field.offset()..field.offset() + field.size()],
+                        field.data_type(),
+                    );
+                }
+            }
+        }
         // Variable-sized data types are not supported and are rejected outside of this function
-        DataType::String | DataType::Bytes => unreachable!(),
+        // Extension data types are encoded and decoded by their own bytes codec implementation
+        DataType::String | DataType::Bytes | DataType::Extension(_) => unreachable!(),
     }
 }
 
@@ -229,6 +246,29 @@ mod tests {
         assert!(codec_bytes_round_trip_impl(None, DataType::Int32, FillValue::from(0)).is_err());
     }
 
+    #[test]
+    fn codec_bytes_reverse_endianness_structured() {
+        let data_type = DataType::Structured(
+            crate::array::data_type::StructuredDataType::new([
+                ("a".to_string(), DataType::UInt8),
+                ("b".to_string(), DataType::UInt16),
+                ("c".to_string(), DataType::UInt32),
+            ])
+            .unwrap(),
+        );
+        let mut bytes: Vec<u8> = (0..14).collect();
+        reverse_endianness(&mut bytes, &data_type);
+        assert_eq!(bytes, vec![0, 2, 1, 6, 5, 4, 3, 7, 9, 8, 13, 12, 11, 10]);
+
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Big),
+            data_type.clone(),
+            FillValue::new(vec![0; 7]),
+        )
+        .unwrap();
+        assert!(codec_bytes_round_trip_impl(None, data_type, FillValue::new(vec![0; 7])).is_err());
+    }
+
     #[test]
     fn codec_bytes_round_trip_complex64() {
         codec_bytes_round_trip_impl(
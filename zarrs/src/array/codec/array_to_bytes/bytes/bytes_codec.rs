@@ -10,14 +10,17 @@ use crate::{
             BytesPartialEncoderTraits, CodecError, CodecOptions, CodecTraits,
             RecommendedConcurrency,
         },
-        ArrayBytes, ArrayMetadataOptions, BytesRepresentation, ChunkRepresentation, DataTypeSize,
-        RawBytes,
+        ArrayBytes, ArrayMetadataOptions, BytesRepresentation, ChunkRepresentation, DataType,
+        DataTypeSize, RawBytes,
     },
     metadata::v3::MetadataV3,
 };
 
 #[cfg(feature = "async")]
-use crate::array::codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderDefault,
+    AsyncArrayPartialEncoderTraits, AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderTraits,
+};
 
 use super::{
     bytes_partial_decoder, reverse_endianness, BytesCodecConfiguration, BytesCodecConfigurationV1,
@@ -68,6 +71,7 @@ impl BytesCodec {
         &self,
         mut value: RawBytes<'a>,
         decoded_representation: &ChunkRepresentation,
+        encode: bool,
     ) -> Result<RawBytes<'a>, CodecError> {
         match decoded_representation.data_type().size() {
             DataTypeSize::Variable => {
@@ -83,6 +87,13 @@ impl BytesCodec {
                         value.len(),
                         array_size,
                     ));
+                } else if let DataType::Extension(extension) = decoded_representation.data_type() {
+                    let codec_bytes = extension.codec_bytes()?;
+                    return Ok(if encode {
+                        codec_bytes.encode(value, self.endian)?
+                    } else {
+                        codec_bytes.decode(value, self.endian)?
+                    });
                 } else if data_type_size > 1 && self.endian.is_none() {
                     return Err(CodecError::Other(format!(
                         "tried to encode an array with element size {data_type_size} with endianness None"
@@ -158,7 +169,7 @@ impl ArrayToBytesCodecTraits for BytesCodec {
             decoded_representation.data_type().size(),
         )?;
         let bytes = bytes.into_fixed()?;
-        self.do_encode_or_decode(bytes, decoded_representation)
+        self.do_encode_or_decode(bytes, decoded_representation, true)
     }
 
     fn decode<'a>(
@@ -167,9 +178,11 @@ impl ArrayToBytesCodecTraits for BytesCodec {
         decoded_representation: &ChunkRepresentation,
         _options: &CodecOptions,
     ) -> Result<ArrayBytes<'a>, CodecError> {
-        Ok(ArrayBytes::from(
-            self.do_encode_or_decode(bytes, decoded_representation)?,
-        ))
+        Ok(ArrayBytes::from(self.do_encode_or_decode(
+            bytes,
+            decoded_representation,
+            false,
+        )?))
     }
 
     fn partial_decoder(
@@ -216,6 +229,22 @@ impl ArrayToBytesCodecTraits for BytesCodec {
         ))
     }
 
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
     fn compute_encoded_size(
         &self,
         decoded_representation: &ChunkRepresentation,
@@ -6,7 +6,7 @@ use crate::{
             ArrayPartialDecoderTraits, ArraySubset, BytesPartialDecoderTraits, CodecError,
             CodecOptions,
         },
-        ArrayBytes, ArraySize, ChunkRepresentation, DataType, DataTypeSize,
+        ArrayBytes, ArraySize, ChunkRepresentation, DataType, DataTypeSize, RawBytes,
     },
     array_subset::IncompatibleArraySubsetAndShapeError,
 };
@@ -16,6 +16,24 @@ use crate::array::codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecod
 
 use super::{reverse_endianness, Endianness};
 
+/// Decode the bytes of a number of elements encoded with `endian` to native endianness.
+fn decode_endianness<'a>(
+    mut bytes: RawBytes<'a>,
+    data_type: &DataType,
+    endian: Option<Endianness>,
+) -> Result<RawBytes<'a>, CodecError> {
+    if let DataType::Extension(extension) = data_type {
+        Ok(extension.codec_bytes()?.decode(bytes, endian)?)
+    } else {
+        if let Some(endian) = endian {
+            if !endian.is_native() {
+                reverse_endianness(bytes.to_mut(), data_type);
+            }
+        }
+        Ok(bytes)
+    }
+}
+
 /// Partial decoder for the `bytes` codec.
 pub(crate) struct BytesPartialDecoder {
     input_handle: Arc<dyn BytesPartialDecoderTraits>,
@@ -70,32 +88,25 @@ impl ArrayPartialDecoderTraits for BytesPartialDecoder {
                         })?;
 
                     // Decode
-                    let decoded = self
+                    let decoded = if let Some(decoded) = self
                         .input_handle
                         .partial_decode_concat(&byte_ranges, options)?
-                        .map_or_else(
-                            || {
-                                let array_size = ArraySize::new(
-                                    self.decoded_representation.data_type().size(),
-                                    array_subset.num_elements(),
-                                );
-                                ArrayBytes::new_fill_value(
-                                    array_size,
-                                    self.decoded_representation.fill_value(),
-                                )
-                            },
-                            |mut decoded| {
-                                if let Some(endian) = &self.endian {
-                                    if !endian.is_native() {
-                                        reverse_endianness(
-                                            decoded.to_mut(),
-                                            self.decoded_representation.data_type(),
-                                        );
-                                    }
-                                }
-                                ArrayBytes::from(decoded)
-                            },
+                    {
+                        ArrayBytes::from(decode_endianness(
+                            decoded,
+                            self.decoded_representation.data_type(),
+                            self.endian,
+                        )?)
+                    } else {
+                        let array_size = ArraySize::new(
+                            self.decoded_representation.data_type().size(),
+                            array_subset.num_elements(),
                         );
+                        ArrayBytes::new_fill_value(
+                            array_size,
+                            self.decoded_representation.fill_value(),
+                        )
+                    };
 
                     bytes.push(decoded);
                 }
@@ -179,33 +190,23 @@ impl AsyncArrayPartialDecoderTraits for AsyncBytesPartialDecoder {
             };
 
             // Decode
-            let decoded = self
+            let decoded = if let Some(decoded) = self
                 .input_handle
                 .partial_decode_concat(&byte_ranges, options)
                 .await?
-                .map_or_else(
-                    || {
-                        let array_size = ArraySize::new(
-                            self.decoded_representation.data_type().size(),
-                            array_subset.num_elements(),
-                        );
-                        ArrayBytes::new_fill_value(
-                            array_size,
-                            self.decoded_representation.fill_value(),
-                        )
-                    },
-                    |mut decoded| {
-                        if let Some(endian) = &self.endian {
-                            if !endian.is_native() {
-                                reverse_endianness(
-                                    decoded.to_mut(),
-                                    self.decoded_representation.data_type(),
-                                );
-                            }
-                        }
-                        ArrayBytes::from(decoded)
-                    },
+            {
+                ArrayBytes::from(decode_endianness(
+                    decoded,
+                    self.decoded_representation.data_type(),
+                    self.endian,
+                )?)
+            } else {
+                let array_size = ArraySize::new(
+                    self.decoded_representation.data_type().size(),
+                    array_subset.num_elements(),
                 );
+                ArrayBytes::new_fill_value(array_size, self.decoded_representation.fill_value())
+            };
 
             bytes.push(decoded);
         }
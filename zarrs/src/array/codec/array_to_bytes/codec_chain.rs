@@ -23,7 +23,10 @@ use crate::{
 };
 
 #[cfg(feature = "async")]
-use crate::array::codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
+use crate::array::codec::{
+    AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits, AsyncBytesPartialDecoderTraits,
+    AsyncBytesPartialEncoderTraits,
+};
 
 /// A codec chain is a sequence of array to array, a bytes to bytes, and a sequence of array to bytes codecs.
 ///
@@ -181,6 +184,27 @@ impl CodecChain {
         &self.bytes_to_bytes
     }
 
+    /// Check that the bytes to bytes codecs are compatible with the data type encoded by the array to bytes codec.
+    ///
+    /// # Errors
+    /// Returns [`CodecError`] if an array to array codec is incompatible with `decoded_representation` or a bytes to bytes codec is incompatible with the data type.
+    pub fn validate_data_type(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<(), CodecError> {
+        if self.bytes_to_bytes.is_empty() {
+            return Ok(());
+        }
+        let mut decoded_representation = decoded_representation.clone();
+        for codec in &self.array_to_array {
+            decoded_representation = codec.compute_encoded_size(&decoded_representation)?;
+        }
+        for codec in &self.bytes_to_bytes {
+            codec.validate_data_type(decoded_representation.data_type())?;
+        }
+        Ok(())
+    }
+
     fn get_array_representations(
         &self,
         decoded_representation: ChunkRepresentation,
@@ -603,6 +627,82 @@ impl ArrayToBytesCodecTraits for CodecChain {
         Ok(input_handle)
     }
 
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        mut input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        mut output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+        let array_representations =
+            self.get_array_representations(decoded_representation.clone())?;
+        let bytes_representations =
+            self.get_bytes_representations(array_representations.last().unwrap())?;
+
+        for (codec, bytes_representation) in std::iter::zip(
+            self.bytes_to_bytes.iter().rev(),
+            bytes_representations.iter().rev().skip(1),
+        ) {
+            output_handle = Arc::clone(codec)
+                .async_partial_encoder(
+                    input_handle.clone(),
+                    output_handle,
+                    bytes_representation,
+                    options,
+                )
+                .await?;
+            input_handle = Arc::clone(codec)
+                .async_partial_decoder(input_handle, bytes_representation, options)
+                .await?;
+        }
+
+        let mut output_handle = self
+            .array_to_bytes
+            .clone()
+            .async_partial_encoder(
+                input_handle.clone(),
+                output_handle,
+                array_representations.last().unwrap(),
+                options,
+            )
+            .await?;
+
+        if self.array_to_array.is_empty() {
+            return Ok(output_handle);
+        }
+
+        let mut input_handle = self
+            .array_to_bytes
+            .clone()
+            .async_partial_decoder(input_handle, array_representations.last().unwrap(), options)
+            .await?;
+
+        let mut it = std::iter::zip(
+            self.array_to_array.iter().rev(),
+            array_representations.iter().rev().skip(1),
+        )
+        .peekable();
+        while let Some((codec, array_representation)) = it.next() {
+            output_handle = Arc::clone(codec)
+                .async_partial_encoder(
+                    input_handle.clone(),
+                    output_handle,
+                    array_representation,
+                    options,
+                )
+                .await?;
+
+            if it.peek().is_some() {
+                input_handle = Arc::clone(codec)
+                    .async_partial_decoder(input_handle, array_representation, options)
+                    .await?;
+            }
+        }
+
+        Ok(output_handle)
+    }
+
     fn compute_encoded_size(
         &self,
         decoded_representation: &ChunkRepresentation,
@@ -23,6 +23,8 @@ pub use crate::metadata::v3::array::codec::sharding::{
 
 pub use sharding_codec::ShardingCodec;
 pub use sharding_codec_builder::ShardingCodecBuilder;
+#[cfg(feature = "async")]
+pub(crate) use sharding_partial_decoder::AsyncShardingPartialDecoder;
 pub(crate) use sharding_partial_decoder::ShardingPartialDecoder;
 
 use crate::{
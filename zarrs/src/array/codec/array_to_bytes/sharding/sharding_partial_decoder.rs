@@ -68,20 +68,12 @@ impl ShardingPartialDecoder {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<ByteRange>, CodecError> {
-        let shard_index = &self.shard_index;
-        if let Some(shard_index) = shard_index {
-            let chunks_per_shard =
-                calculate_chunks_per_shard(self.decoded_representation.shape(), &self.chunk_shape)?;
-            let chunks_per_shard = chunks_per_shard.to_array_shape();
-
-            let shard_index_idx: usize =
-                usize::try_from(ravel_indices(chunk_indices, &chunks_per_shard) * 2).unwrap();
-            let offset = shard_index[shard_index_idx];
-            let size = shard_index[shard_index_idx + 1];
-            Ok(Some(ByteRange::new(offset..offset + size)))
-        } else {
-            Ok(None)
-        }
+        inner_chunk_byte_range(
+            self.shard_index.as_deref(),
+            &self.decoded_representation,
+            &self.chunk_shape,
+            chunk_indices,
+        )
     }
 
     /// Retrieve the encoded bytes of an inner chunk.
@@ -101,6 +93,30 @@ impl ShardingPartialDecoder {
     }
 }
 
+/// Retrieve the byte range of an encoded inner chunk from a shard index.
+///
+/// Returns [`None`] if the shard index is [`None`] (the shard does not exist).
+fn inner_chunk_byte_range(
+    shard_index: Option<&[u64]>,
+    decoded_representation: &ChunkRepresentation,
+    chunk_shape: &ChunkShape,
+    chunk_indices: &[u64],
+) -> Result<Option<ByteRange>, CodecError> {
+    if let Some(shard_index) = shard_index {
+        let chunks_per_shard =
+            calculate_chunks_per_shard(decoded_representation.shape(), chunk_shape)?;
+        let chunks_per_shard = chunks_per_shard.to_array_shape();
+
+        let shard_index_idx: usize =
+            usize::try_from(ravel_indices(chunk_indices, &chunks_per_shard) * 2).unwrap();
+        let offset = shard_index[shard_index_idx];
+        let size = shard_index[shard_index_idx + 1];
+        Ok(Some(ByteRange::new(offset..offset + size)))
+    } else {
+        Ok(None)
+    }
+}
+
 impl ArrayPartialDecoderTraits for ShardingPartialDecoder {
     fn data_type(&self) -> &DataType {
         self.decoded_representation.data_type()
@@ -370,6 +386,38 @@ impl AsyncShardingPartialDecoder {
             shard_index,
         })
     }
+
+    /// Retrieve the byte range of an encoded inner chunk.
+    ///
+    /// The `chunk_indices` are relative to the start of the shard.
+    pub(crate) fn inner_chunk_byte_range(
+        &self,
+        chunk_indices: &[u64],
+    ) -> Result<Option<ByteRange>, CodecError> {
+        inner_chunk_byte_range(
+            self.shard_index.as_deref(),
+            &self.decoded_representation,
+            &self.chunk_shape,
+            chunk_indices,
+        )
+    }
+
+    /// Retrieve the encoded bytes of an inner chunk.
+    ///
+    /// The `chunk_indices` are relative to the start of the shard.
+    pub(crate) async fn retrieve_inner_chunk_encoded(
+        &self,
+        chunk_indices: &[u64],
+    ) -> Result<Option<RawBytes<'_>>, CodecError> {
+        let byte_range = self.inner_chunk_byte_range(chunk_indices)?;
+        if let Some(byte_range) = byte_range {
+            self.input_handle
+                .partial_decode_concat(&[byte_range], &CodecOptions::default())
+                .await
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "async")]
@@ -18,43 +18,55 @@ use crate::{
             ArrayPartialEncoderTraits, ArrayToBytesCodecTraits, BytesPartialDecoderTraits,
             BytesPartialEncoderTraits, CodecError, CodecOptions,
         },
-        ravel_indices, transmute_to_bytes, ArrayBytes, ArraySize, ChunkRepresentation, ChunkShape,
-        CodecChain, RawBytes,
+        ravel_indices, transmute_to_bytes, ArrayBytes, ArrayShape, ArraySize, ChunkRepresentation,
+        ChunkShape, CodecChain, RawBytes,
     },
     array_subset::{ArraySubset, IncompatibleArraySubsetAndShapeError},
-    byte_range::ByteRange,
+    byte_range::{ByteOffset, ByteRange},
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialEncoderTraits, AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderTraits,
 };
 
 use super::{sharding_index_decoded_representation, ShardingIndexLocation};
 
-pub(crate) struct ShardingPartialEncoder {
-    input_handle: Arc<dyn BytesPartialDecoderTraits>,
-    output_handle: Arc<dyn BytesPartialEncoderTraits>,
+/// The outcome of a sharding partial encode.
+enum ShardingPartialEncoderOutput {
+    /// All inner chunks are empty, so the shard should be erased.
+    Erase,
+    /// Write bytes at the given offsets.
+    ///
+    /// If `erase_existing` is true, the existing shard holds no referenced data and is erased before writing.
+    Write {
+        erase_existing: bool,
+        offsets_and_bytes: Vec<(ByteOffset, Vec<u8>)>,
+    },
+}
+
+/// The storage independent parts of the sharding partial encoder.
+struct ShardingPartialEncoderInner {
     decoded_representation: ChunkRepresentation,
     chunk_grid: RegularChunkGrid,
+    chunks_per_shard: ArrayShape,
     inner_codecs: Arc<CodecChain>,
     index_codecs: Arc<CodecChain>,
     index_location: ShardingIndexLocation,
     index_decoded_representation: ChunkRepresentation,
     inner_chunk_representation: ChunkRepresentation,
-    shard_index: Arc<Mutex<Vec<u64>>>,
 }
 
-impl ShardingPartialEncoder {
-    /// Create a new partial encoder for the sharding codec.
-    #[allow(clippy::too_many_arguments)]
-    pub(crate) fn new(
-        input_handle: Arc<dyn BytesPartialDecoderTraits>,
-        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+impl ShardingPartialEncoderInner {
+    fn new(
         decoded_representation: ChunkRepresentation,
         chunk_shape: ChunkShape,
         inner_codecs: Arc<CodecChain>,
         index_codecs: Arc<CodecChain>,
         index_location: ShardingIndexLocation,
-        options: &CodecOptions,
     ) -> Result<Self, CodecError> {
         let chunks_per_shard =
-            &calculate_chunks_per_shard(decoded_representation.shape(), &chunk_shape)?;
+            calculate_chunks_per_shard(decoded_representation.shape(), &chunk_shape)?;
         let index_decoded_representation =
             sharding_index_decoded_representation(chunks_per_shard.as_slice());
         let inner_chunk_representation = ChunkRepresentation::new(
@@ -64,93 +76,55 @@ impl ShardingPartialEncoder {
         )
         .map_err(|_| CodecError::Other("Fill value and data type are incompatible?".to_string()))?;
 
-        // Decode the index
-        let shard_index = super::decode_shard_index_partial_decoder(
-            &*input_handle,
-            &index_codecs,
-            index_location,
-            inner_chunk_representation.shape(),
-            &decoded_representation,
-            options,
-        )?
-        .unwrap_or_else(|| {
-            let num_chunks =
-                usize::try_from(chunks_per_shard.iter().map(|x| x.get()).product::<u64>()).unwrap();
-            vec![u64::MAX; num_chunks * 2]
-        });
-
         Ok(Self {
-            input_handle,
-            output_handle,
             decoded_representation,
             chunk_grid: RegularChunkGrid::new(chunk_shape),
+            chunks_per_shard: chunks_per_shard.to_array_shape(),
             inner_codecs,
             index_codecs,
             index_location,
             index_decoded_representation,
             inner_chunk_representation,
-            shard_index: Arc::new(Mutex::new(shard_index)),
         })
     }
-}
 
-impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
-    fn erase(&self) -> Result<(), super::CodecError> {
-        self.output_handle.erase()
+    /// Return the shard index of an empty shard.
+    fn empty_shard_index(&self) -> Vec<u64> {
+        let num_chunks = usize::try_from(self.chunks_per_shard.iter().product::<u64>()).unwrap();
+        vec![u64::MAX; num_chunks * 2]
     }
 
-    #[allow(clippy::too_many_lines)]
-    #[allow(clippy::similar_names)]
-    fn partial_encode(
-        &self,
-        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
-        options: &super::CodecOptions,
-    ) -> Result<(), super::CodecError> {
-        let mut shard_index = self.shard_index.lock().unwrap();
-
-        let chunks_per_shard = calculate_chunks_per_shard(
-            self.decoded_representation.shape(),
-            self.inner_chunk_representation.shape(),
-        )?;
-        let chunks_per_shard = chunks_per_shard.to_array_shape();
-
-        // Get the maximum offset of existing encoded chunks
-        let max_data_offset = shard_index
-            .iter()
-            .tuples()
-            .map(|(&offset, &size)| {
-                if offset == u64::MAX && size == u64::MAX {
-                    0
-                } else {
-                    offset + size
-                }
+    /// Return the inner chunks intersecting `chunk_subset`.
+    fn inner_chunks(&self, chunk_subset: &ArraySubset) -> Result<ArraySubset, CodecError> {
+        self.chunk_grid
+            .chunks_in_array_subset(chunk_subset, &self.chunks_per_shard)
+            .map_err(|_| {
+                CodecError::InvalidArraySubsetError(IncompatibleArraySubsetAndShapeError::new(
+                    chunk_subset.clone(),
+                    self.chunks_per_shard.clone(),
+                ))
+            })?
+            .ok_or_else(|| {
+                CodecError::Other("Cannot determine the inner chunk of a chunk subset".to_string())
             })
-            .max()
-            .expect("shards cannot be empty");
+    }
 
-        let get_inner_chunks = |chunk_subset| {
-            self.chunk_grid
-                .chunks_in_array_subset(chunk_subset, &chunks_per_shard)
-                .map_err(|_| {
-                    CodecError::InvalidArraySubsetError(IncompatibleArraySubsetAndShapeError::new(
-                        (*chunk_subset).clone(),
-                        chunks_per_shard.clone(),
-                    ))
-                })?
-                .ok_or_else(|| {
-                    CodecError::Other(
-                        "Cannot determine the inner chunk of a chunk subset".to_string(),
-                    )
-                })
-        };
-        let inner_chunk_fill_value = || {
-            let array_size = ArraySize::new(
-                self.inner_chunk_representation.data_type().size(),
-                self.inner_chunk_representation.num_elements(),
-            );
-            ArrayBytes::new_fill_value(array_size, self.inner_chunk_representation.fill_value())
-        };
+    /// Return the fill value of an inner chunk.
+    fn inner_chunk_fill_value(&self) -> ArrayBytes<'static> {
+        let array_size = ArraySize::new(
+            self.inner_chunk_representation.data_type().size(),
+            self.inner_chunk_representation.num_elements(),
+        );
+        ArrayBytes::new_fill_value(array_size, self.inner_chunk_representation.fill_value())
+    }
 
+    /// Get the inner chunks intersected by `subsets_and_bytes`, and the indices and byte ranges of existing inner chunks that must be read.
+    #[allow(clippy::type_complexity)]
+    fn inner_chunks_to_update(
+        &self,
+        shard_index: &[u64],
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+    ) -> Result<(HashSet<u64>, Vec<u64>, Vec<ByteRange>), CodecError> {
         // Get all the inner chunks that need to be retrieved
         //   This only includes chunks that straddle chunk subsets.
         //   Chunks that are entirely within a chunk subset are entirely replaced and are not read.
@@ -176,15 +150,13 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
             }
 
             // Get the iterator over the inner chunks
-            let inner_chunks = get_inner_chunks(chunk_subset)?;
+            let inner_chunks = self.inner_chunks(chunk_subset)?;
             let inner_chunks = inner_chunks.indices();
 
             // Get all the inner chunks intersected
-            inner_chunks_intersected.extend(
-                inner_chunks.into_iter().map(|inner_chunk_indices| {
-                    ravel_indices(&inner_chunk_indices, &chunks_per_shard)
-                }),
-            );
+            inner_chunks_intersected.extend(inner_chunks.into_iter().map(|inner_chunk_indices| {
+                ravel_indices(&inner_chunk_indices, &self.chunks_per_shard)
+            }));
 
             // Get all the inner chunks that need to be updated
             inner_chunks_indices.extend(inner_chunks.into_iter().filter_map(
@@ -208,7 +180,7 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
                             .any(|(a, b)| *a > b)
                     {
                         let inner_chunk_index =
-                            ravel_indices(&inner_chunk_indices, &chunks_per_shard);
+                            ravel_indices(&inner_chunk_indices, &self.chunks_per_shard);
                         Some(inner_chunk_index)
                     } else {
                         None
@@ -235,12 +207,20 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
             .sorted_by_key(|(_, byte_range)| *byte_range)
             .unzip();
 
-        // Read the straddling inner chunks
-        let inner_chunks_encoded = self
-            .input_handle
-            .partial_decode(&byte_ranges, options)?
-            .map(|bytes| bytes.into_iter().map(Cow::into_owned).collect::<Vec<_>>());
+        Ok((inner_chunks_intersected, inner_chunks_indices, byte_ranges))
+    }
 
+    /// Decode the straddling inner chunks, apply the updates in `subsets_and_bytes`, and encode the updated inner chunks.
+    ///
+    /// Updated inner chunks that are entirely the fill value are returned as [`None`].
+    #[allow(clippy::type_complexity)]
+    fn update_inner_chunks(
+        &self,
+        inner_chunks_indices: Vec<u64>,
+        inner_chunks_encoded: Option<Vec<Vec<u8>>>,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<Vec<(u64, Option<Vec<u8>>)>, CodecError> {
         // Decode the straddling inner chunks
         let inner_chunks_decoded: HashMap<_, _> =
             if let Some(inner_chunks_encoded) = inner_chunks_encoded {
@@ -267,17 +247,18 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
         //   This loop is intentionally not run in parallel so that overapping subset updates are applied incrementally rather than having a non deterministic output.
         let inner_chunks_decoded = Arc::new(Mutex::new(inner_chunks_decoded));
         for (chunk_subset, chunk_subset_bytes) in subsets_and_bytes {
-            let inner_chunks = get_inner_chunks(chunk_subset)?;
+            let inner_chunks = self.inner_chunks(chunk_subset)?;
 
             inner_chunks
                 .indices()
                 .into_par_iter()
                 .try_for_each(|inner_chunk_indices| {
                     // Extract the inner chunk bytes that overlap with the chunk subset
-                    let inner_chunk_index = ravel_indices(&inner_chunk_indices, &chunks_per_shard);
+                    let inner_chunk_index =
+                        ravel_indices(&inner_chunk_indices, &self.chunks_per_shard);
                     let inner_chunk_subset = self
                         .chunk_grid
-                        .subset(&inner_chunk_indices, &chunks_per_shard)
+                        .subset(&inner_chunk_indices, &self.chunks_per_shard)
                         .expect("already validated")
                         .expect("regular grid");
                     let inner_chunk_subset_overlap =
@@ -291,21 +272,21 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
                     )?;
 
                     // Decode the inner chunk
-                    let inner_chunk_decoded = if let Some(inner_chunk_decoded) =
+                    let inner_chunk_current = if let Some(inner_chunk_current) =
                         inner_chunks_decoded
                             .lock()
                             .unwrap()
                             .remove(&inner_chunk_index)
                     {
-                        inner_chunk_decoded.into_owned()
+                        inner_chunk_current.into_owned()
                     } else {
-                        inner_chunk_fill_value()
+                        self.inner_chunk_fill_value()
                     };
 
                     // Update the inner chunk
                     let inner_chunk_updated = unsafe {
                         update_array_bytes(
-                            inner_chunk_decoded,
+                            inner_chunk_current,
                             &self.inner_chunk_representation.shape_u64(),
                             &inner_chunk_subset_overlap
                                 .relative_to(inner_chunk_subset.start())
@@ -328,13 +309,13 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
             .expect("inner_chunks_decoded should not be poisoned");
 
         // Encode the updated inner chunks
-        let updated_inner_chunks = inner_chunks_decoded
+        inner_chunks_decoded
             .into_par_iter()
             .map(|(inner_chunk_index, inner_chunk_decoded)| {
                 if inner_chunk_decoded.is_fill_value(self.inner_chunk_representation.fill_value()) {
                     Ok((inner_chunk_index, None))
                 } else {
-                    let inner_chunk_encoded = self
+                    let inner_chunk_updated = self
                         .inner_codecs
                         .encode(
                             inner_chunk_decoded,
@@ -342,23 +323,42 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
                             options,
                         )?
                         .into_owned();
-                    Ok((inner_chunk_index, Some(inner_chunk_encoded)))
+                    Ok((inner_chunk_index, Some(inner_chunk_updated)))
                 }
             })
-            .collect::<Result<Vec<_>, CodecError>>()?;
+            .collect::<Result<Vec<_>, CodecError>>()
+    }
+
+    /// Update `shard_index` with the updated inner chunks and determine what must be written to the shard.
+    fn update_shard_index(
+        &self,
+        shard_index: &mut [u64],
+        inner_chunks_intersected: &HashSet<u64>,
+        updated_inner_chunks: Vec<(u64, Option<Vec<u8>>)>,
+        options: &CodecOptions,
+    ) -> Result<ShardingPartialEncoderOutput, CodecError> {
+        // Get the maximum offset of existing encoded chunks
+        let max_data_offset = shard_index
+            .iter()
+            .tuples()
+            .map(|(&offset, &size)| {
+                if offset == u64::MAX && size == u64::MAX {
+                    0
+                } else {
+                    offset + size
+                }
+            })
+            .max()
+            .expect("shards cannot be empty");
 
         // Check if the shard can be entirely rewritten instead of appended
         //  This occurs if the shard index is empty if all of the intersected inner chunks are removed
-        for inner_chunk_index in &inner_chunks_intersected {
+        for inner_chunk_index in inner_chunks_intersected {
             shard_index[usize::try_from(inner_chunk_index * 2).unwrap()] = u64::MAX;
             shard_index[usize::try_from(inner_chunk_index * 2 + 1).unwrap()] = u64::MAX;
         }
-        let max_data_offset = if shard_index.par_iter().all(|&x| x == u64::MAX) {
-            self.output_handle.erase()?;
-            0
-        } else {
-            max_data_offset
-        };
+        let erase_existing = shard_index.par_iter().all(|&x| x == u64::MAX);
+        let max_data_offset = if erase_existing { 0 } else { max_data_offset };
 
         // Get the offset for new data
         let index_encoded_size = compute_index_encoded_size(
@@ -388,59 +388,269 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
 
         if shard_index.par_iter().all(|&x| x == u64::MAX) {
             // Erase the shard if all chunks are empty
-            self.output_handle.erase()?;
-        } else {
-            // Encode the updated shard index
-            let shard_index_bytes: RawBytes = transmute_to_bytes(shard_index.as_slice()).into();
-            let encoded_array_index = self
-                .index_codecs
-                .encode(
-                    shard_index_bytes.into(),
-                    &self.index_decoded_representation,
-                    options,
-                )?
-                .into_owned();
-
-            // Get the total size of the encoded inner chunks
-            let encoded_inner_chunks_size = updated_inner_chunks
-                .iter()
-                .filter_map(|(_, inner_chunk_encoded)| inner_chunk_encoded.as_ref().map(Vec::len))
-                .sum::<usize>();
+            return Ok(ShardingPartialEncoderOutput::Erase);
+        }
 
-            // Get the suffix write size
-            let suffix_write_size = match self.index_location {
-                ShardingIndexLocation::Start => encoded_inner_chunks_size,
-                ShardingIndexLocation::End => encoded_inner_chunks_size + encoded_array_index.len(),
-            };
+        // Encode the updated shard index
+        let shard_index_bytes: RawBytes = transmute_to_bytes(&*shard_index).into();
+        let encoded_array_index = self
+            .index_codecs
+            .encode(
+                shard_index_bytes.into(),
+                &self.index_decoded_representation,
+                options,
+            )?
+            .into_owned();
+
+        // Get the total size of the encoded inner chunks
+        let encoded_inner_chunks_size = updated_inner_chunks
+            .iter()
+            .filter_map(|(_, inner_chunk_encoded)| inner_chunk_encoded.as_ref().map(Vec::len))
+            .sum::<usize>();
 
-            // Concatenate the updated inner chunks
-            let mut encoded_output = Vec::with_capacity(suffix_write_size);
-            for (_, inner_chunk_encoded) in updated_inner_chunks {
-                if let Some(inner_chunk_encoded) = inner_chunk_encoded {
-                    encoded_output.extend(inner_chunk_encoded);
-                }
+        // Get the suffix write size
+        let suffix_write_size = match self.index_location {
+            ShardingIndexLocation::Start => encoded_inner_chunks_size,
+            ShardingIndexLocation::End => encoded_inner_chunks_size + encoded_array_index.len(),
+        };
+
+        // Concatenate the updated inner chunks
+        let mut encoded_output = Vec::with_capacity(suffix_write_size);
+        for (_, inner_chunk_encoded) in updated_inner_chunks {
+            if let Some(inner_chunk_encoded) = inner_chunk_encoded {
+                encoded_output.extend(inner_chunk_encoded);
             }
+        }
 
-            // Write the encoded index and updated inner chunks
-            match self.index_location {
-                ShardingIndexLocation::Start => {
-                    self.output_handle.partial_encode(
-                        &[
-                            (0, Cow::Owned(encoded_array_index)),
-                            (offset_new_chunks, Cow::Owned(encoded_output)),
-                        ],
-                        options,
-                    )?;
+        // Get the encoded index and updated inner chunks to write
+        let offsets_and_bytes = match self.index_location {
+            ShardingIndexLocation::Start => vec![
+                (0, encoded_array_index),
+                (offset_new_chunks, encoded_output),
+            ],
+            ShardingIndexLocation::End => {
+                encoded_output.extend(encoded_array_index);
+                vec![(offset_new_chunks, encoded_output)]
+            }
+        };
+        Ok(ShardingPartialEncoderOutput::Write {
+            erase_existing,
+            offsets_and_bytes,
+        })
+    }
+}
+
+pub(crate) struct ShardingPartialEncoder {
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    output_handle: Arc<dyn BytesPartialEncoderTraits>,
+    inner: ShardingPartialEncoderInner,
+    shard_index: Arc<Mutex<Vec<u64>>>,
+}
+
+impl ShardingPartialEncoder {
+    /// Create a new partial encoder for the sharding codec.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        chunk_shape: ChunkShape,
+        inner_codecs: Arc<CodecChain>,
+        index_codecs: Arc<CodecChain>,
+        index_location: ShardingIndexLocation,
+        options: &CodecOptions,
+    ) -> Result<Self, CodecError> {
+        let inner = ShardingPartialEncoderInner::new(
+            decoded_representation,
+            chunk_shape,
+            inner_codecs,
+            index_codecs,
+            index_location,
+        )?;
+
+        // Decode the index
+        let shard_index = super::decode_shard_index_partial_decoder(
+            &*input_handle,
+            &inner.index_codecs,
+            index_location,
+            inner.inner_chunk_representation.shape(),
+            &inner.decoded_representation,
+            options,
+        )?
+        .unwrap_or_else(|| inner.empty_shard_index());
+
+        Ok(Self {
+            input_handle,
+            output_handle,
+            inner,
+            shard_index: Arc::new(Mutex::new(shard_index)),
+        })
+    }
+}
+
+impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
+    fn erase(&self) -> Result<(), CodecError> {
+        self.output_handle.erase()
+    }
+
+    fn partial_encode(
+        &self,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let mut shard_index = self.shard_index.lock().unwrap();
+
+        let (inner_chunks_intersected, inner_chunks_indices, byte_ranges) = self
+            .inner
+            .inner_chunks_to_update(&shard_index, subsets_and_bytes)?;
+
+        // Read the straddling inner chunks
+        let inner_chunks_encoded = self
+            .input_handle
+            .partial_decode(&byte_ranges, options)?
+            .map(|bytes| bytes.into_iter().map(Cow::into_owned).collect::<Vec<_>>());
+
+        // Decode, update, and encode the intersected inner chunks
+        let updated_inner_chunks = self.inner.update_inner_chunks(
+            inner_chunks_indices,
+            inner_chunks_encoded,
+            subsets_and_bytes,
+            options,
+        )?;
+
+        // Update the shard index and write the updated shard
+        match self.inner.update_shard_index(
+            &mut shard_index,
+            &inner_chunks_intersected,
+            updated_inner_chunks,
+            options,
+        )? {
+            ShardingPartialEncoderOutput::Erase => self.output_handle.erase(),
+            ShardingPartialEncoderOutput::Write {
+                erase_existing,
+                offsets_and_bytes,
+            } => {
+                if erase_existing {
+                    self.output_handle.erase()?;
                 }
-                ShardingIndexLocation::End => {
-                    encoded_output.extend(encoded_array_index);
-                    self.output_handle.partial_encode(
-                        &[(offset_new_chunks, Cow::Owned(encoded_output))],
-                        options,
-                    )?;
+                let offsets_and_bytes = offsets_and_bytes
+                    .into_iter()
+                    .map(|(offset, bytes)| (offset, Cow::Owned(bytes)))
+                    .collect::<Vec<_>>();
+                self.output_handle
+                    .partial_encode(&offsets_and_bytes, options)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub(crate) struct AsyncShardingPartialEncoder {
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+    inner: ShardingPartialEncoderInner,
+    shard_index: futures::lock::Mutex<Vec<u64>>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncShardingPartialEncoder {
+    /// Create a new asynchronous partial encoder for the sharding codec.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        chunk_shape: ChunkShape,
+        inner_codecs: Arc<CodecChain>,
+        index_codecs: Arc<CodecChain>,
+        index_location: ShardingIndexLocation,
+        options: &CodecOptions,
+    ) -> Result<Self, CodecError> {
+        let inner = ShardingPartialEncoderInner::new(
+            decoded_representation,
+            chunk_shape,
+            inner_codecs,
+            index_codecs,
+            index_location,
+        )?;
+
+        // Decode the index
+        let shard_index = super::decode_shard_index_async_partial_decoder(
+            &*input_handle,
+            &inner.index_codecs,
+            index_location,
+            inner.inner_chunk_representation.shape(),
+            &inner.decoded_representation,
+            options,
+        )
+        .await?
+        .unwrap_or_else(|| inner.empty_shard_index());
+
+        Ok(Self {
+            input_handle,
+            output_handle,
+            inner,
+            shard_index: futures::lock::Mutex::new(shard_index),
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialEncoderTraits for AsyncShardingPartialEncoder {
+    async fn erase(&self) -> Result<(), CodecError> {
+        self.output_handle.erase().await
+    }
+
+    async fn partial_encode(
+        &self,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let mut shard_index = self.shard_index.lock().await;
+
+        let (inner_chunks_intersected, inner_chunks_indices, byte_ranges) = self
+            .inner
+            .inner_chunks_to_update(&shard_index, subsets_and_bytes)?;
+
+        // Read the straddling inner chunks
+        let inner_chunks_encoded = self
+            .input_handle
+            .partial_decode(&byte_ranges, options)
+            .await?
+            .map(|bytes| bytes.into_iter().map(Cow::into_owned).collect::<Vec<_>>());
+
+        // Decode, update, and encode the intersected inner chunks
+        let updated_inner_chunks = self.inner.update_inner_chunks(
+            inner_chunks_indices,
+            inner_chunks_encoded,
+            subsets_and_bytes,
+            options,
+        )?;
+
+        // Update the shard index and write the updated shard
+        match self.inner.update_shard_index(
+            &mut shard_index,
+            &inner_chunks_intersected,
+            updated_inner_chunks,
+            options,
+        )? {
+            ShardingPartialEncoderOutput::Erase => self.output_handle.erase().await,
+            ShardingPartialEncoderOutput::Write {
+                erase_existing,
+                offsets_and_bytes,
+            } => {
+                if erase_existing {
+                    self.output_handle.erase().await?;
                 }
+                let offsets_and_bytes = offsets_and_bytes
+                    .into_iter()
+                    .map(|(offset, bytes)| (offset, Cow::Owned(bytes)))
+                    .collect::<Vec<_>>();
+                self.output_handle
+                    .partial_encode(&offsets_and_bytes, options)
+                    .await
             }
         }
-        Ok(())
     }
 }
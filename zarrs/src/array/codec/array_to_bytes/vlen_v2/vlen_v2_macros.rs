@@ -52,7 +52,10 @@ macro_rules! vlen_v2_codec {
         };
 
         #[cfg(feature = "async")]
-        use crate::array::codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
+        use crate::array::codec::{
+            AsyncArrayPartialDecoderTraits, AsyncArrayPartialEncoderTraits,
+            AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderTraits,
+        };
 
         /// The `$identifier` codec implementation.
         #[derive(Debug, Clone)]
@@ -162,6 +165,25 @@ macro_rules! vlen_v2_codec {
                     .await
             }
 
+            #[cfg(feature = "async")]
+            async fn async_partial_encoder(
+                self: Arc<Self>,
+                input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+                output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+                decoded_representation: &ChunkRepresentation,
+                options: &CodecOptions,
+            ) -> Result<Arc<dyn AsyncArrayPartialEncoderTraits>, CodecError> {
+                self.inner
+                    .clone()
+                    .async_partial_encoder(
+                        input_handle,
+                        output_handle,
+                        decoded_representation,
+                        options,
+                    )
+                    .await
+            }
+
             fn compute_encoded_size(
                 &self,
                 decoded_representation: &ChunkRepresentation,
@@ -2,9 +2,15 @@ use std::{borrow::Cow, sync::Arc};
 
 use zarrs_storage::byte_range::ByteOffset;
 
-use crate::array::BytesRepresentation;
+use crate::array::{BytesRepresentation, RawBytes};
 
-use super::{BytesPartialDecoderTraits, BytesPartialEncoderTraits, BytesToBytesCodecTraits};
+use super::{
+    BytesPartialDecoderTraits, BytesPartialEncoderTraits, BytesToBytesCodecTraits, CodecError,
+    CodecOptions,
+};
+
+#[cfg(feature = "async")]
+use super::{AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderTraits};
 
 /// The default array (chunk) partial encoder. Decodes the entire chunk, updates it, and writes the entire chunk.
 pub struct BytesPartialEncoderDefault {
@@ -33,48 +39,120 @@ impl BytesPartialEncoderDefault {
 }
 
 impl BytesPartialEncoderTraits for BytesPartialEncoderDefault {
-    fn erase(&self) -> Result<(), super::CodecError> {
+    fn erase(&self) -> Result<(), CodecError> {
         self.output_handle.erase()
     }
 
     fn partial_encode(
         &self,
         offsets_and_bytes: &[(ByteOffset, crate::array::RawBytes<'_>)],
-        options: &super::CodecOptions,
-    ) -> Result<(), super::CodecError> {
-        let encoded_value = self.input_handle.decode(options)?.map(Cow::into_owned);
-
-        let mut decoded_value = if let Some(encoded_value) = encoded_value {
-            self.codec
-                .decode(
-                    Cow::Owned(encoded_value),
-                    &self.decoded_representation,
-                    options,
-                )?
-                .into_owned()
-        } else {
-            vec![]
-        };
-
-        // The decoded value must be resized to the maximum byte range end
-        let decoded_value_len = offsets_and_bytes
-            .iter()
-            .map(|(offset, bytes)| usize::try_from(offset + bytes.len() as u64).unwrap())
-            .max()
-            .unwrap();
-        decoded_value.resize(decoded_value_len, 0);
-
-        for (offset, bytes) in offsets_and_bytes {
-            let start = usize::try_from(*offset).unwrap();
-            decoded_value[start..start + bytes.len()].copy_from_slice(bytes);
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let encoded_value = self.input_handle.decode(options)?;
+
+        // Decode and update the bytes
+        let bytes_encoded = partial_encode_update(
+            &self.codec,
+            &self.decoded_representation,
+            encoded_value,
+            offsets_and_bytes,
+            options,
+        )?;
+
+        self.output_handle
+            .partial_encode(&[(0, Cow::Owned(bytes_encoded))], options)
+    }
+}
+
+/// Decode `encoded_value` (or empty bytes if missing), apply the updates in `offsets_and_bytes`, and encode the result.
+fn partial_encode_update(
+    codec: &Arc<dyn BytesToBytesCodecTraits>,
+    decoded_representation: &BytesRepresentation,
+    encoded_value: Option<RawBytes<'_>>,
+    offsets_and_bytes: &[(ByteOffset, RawBytes<'_>)],
+    options: &CodecOptions,
+) -> Result<Vec<u8>, CodecError> {
+    let encoded_value = encoded_value.map(Cow::into_owned);
+
+    let mut decoded_value = if let Some(encoded_value) = encoded_value {
+        codec
+            .decode(Cow::Owned(encoded_value), decoded_representation, options)?
+            .into_owned()
+    } else {
+        vec![]
+    };
+
+    // The decoded value must be resized to the maximum byte range end
+    let decoded_value_len = offsets_and_bytes
+        .iter()
+        .map(|(offset, bytes)| usize::try_from(offset + bytes.len() as u64).unwrap())
+        .max()
+        .unwrap();
+    decoded_value.resize(decoded_value_len, 0);
+
+    for (offset, bytes) in offsets_and_bytes {
+        let start = usize::try_from(*offset).unwrap();
+        decoded_value[start..start + bytes.len()].copy_from_slice(bytes);
+    }
+
+    Ok(codec
+        .encode(Cow::Owned(decoded_value), options)?
+        .into_owned())
+}
+
+#[cfg(feature = "async")]
+/// The default asynchronous array (chunk) partial encoder. Decodes the entire chunk, updates it, and writes the entire chunk.
+pub struct AsyncBytesPartialEncoderDefault {
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+    decoded_representation: BytesRepresentation,
+    codec: Arc<dyn BytesToBytesCodecTraits>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncBytesPartialEncoderDefault {
+    /// Create a new [`AsyncBytesPartialEncoderDefault`].
+    #[must_use]
+    pub fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: BytesRepresentation,
+        codec: Arc<dyn BytesToBytesCodecTraits>,
+    ) -> Self {
+        Self {
+            input_handle,
+            output_handle,
+            decoded_representation,
+            codec,
         }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncBytesPartialEncoderTraits for AsyncBytesPartialEncoderDefault {
+    async fn erase(&self) -> Result<(), CodecError> {
+        self.output_handle.erase().await
+    }
+
+    async fn partial_encode(
+        &self,
+        offsets_and_bytes: &[(ByteOffset, RawBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let encoded_value = self.input_handle.decode(options).await?;
 
-        let bytes_encoded = self
-            .codec
-            .encode(Cow::Owned(decoded_value), options)?
-            .into_owned();
+        // Decode and update the bytes
+        let bytes_encoded = partial_encode_update(
+            &self.codec,
+            &self.decoded_representation,
+            encoded_value,
+            offsets_and_bytes,
+            options,
+        )?;
 
         self.output_handle
             .partial_encode(&[(0, Cow::Owned(bytes_encoded))], options)
+            .await
     }
 }
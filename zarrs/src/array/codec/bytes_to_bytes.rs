@@ -1,15 +1,27 @@
 //! Bytes to bytes codecs.
 
+#[cfg(feature = "bitshuffle")]
+pub mod bitshuffle;
 #[cfg(feature = "blosc")]
 pub mod blosc;
 #[cfg(feature = "bz2")]
 pub mod bz2;
 #[cfg(feature = "crc32c")]
 pub mod crc32c;
+#[cfg(feature = "fletcher32")]
+pub mod fletcher32;
 #[cfg(feature = "gdeflate")]
 pub mod gdeflate;
 #[cfg(feature = "gzip")]
 pub mod gzip;
+#[cfg(feature = "lz4")]
+pub mod lz4;
+#[cfg(feature = "lzma")]
+pub mod lzma;
+#[cfg(feature = "shuffle")]
+pub mod shuffle;
+#[cfg(feature = "zlib")]
+pub mod zlib;
 #[cfg(feature = "zstd")]
 pub mod zstd;
 
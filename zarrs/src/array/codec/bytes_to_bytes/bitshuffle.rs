@@ -0,0 +1,296 @@
+//! The `bitshuffle` bytes to bytes codec.
+//!
+//! Rearranges bits so that the first bit of every element is stored first, followed by the second bit of every element, etc.
+//! This groups bits of similar significance together, which typically improves the compression ratio of a subsequent compression codec (e.g. `zstd` or `gzip`).
+//!
+//! The bit layout matches the [bitshuffle](https://github.com/kiyo-masui/bitshuffle) library (without compression) with its default block size.
+//! Elements are transposed in blocks, and trailing elements that do not form a multiple of 8 elements are stored unchanged.
+//! Trailing bytes that do not form a complete element are also stored unchanged.
+//!
+//! The element size is set in the codec configuration, and must match the size of the data type encoded by the array to bytes codec.
+//! An array with a mismatched element size cannot be built with an [`ArrayBuilder`](crate::array::ArrayBuilder), but existing arrays with a mismatched element size can still be opened.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `bitshuffle` feature, which is disabled by default.
+//!
+//! See [`BitshuffleCodecConfigurationV1`] for example `JSON` metadata.
+
+mod bitshuffle_codec;
+mod bitshuffle_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::bitshuffle::{
+    BitshuffleCodecConfiguration, BitshuffleCodecConfigurationV1,
+};
+pub use bitshuffle_codec::BitshuffleCodec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::bitshuffle, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use bitshuffle::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_bitshuffle, create_codec_bitshuffle)
+}
+
+fn is_name_bitshuffle(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_bitshuffle(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: BitshuffleCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+/// The number of elements in a block must be a multiple of this.
+const BLOCKED_MULT: usize = 8;
+
+/// The default number of elements in a block, as in the bitshuffle library.
+fn default_block_size(elementsize: usize) -> usize {
+    const TARGET_BLOCK_SIZE_BYTES: usize = 8192;
+    const MIN_BLOCK_SIZE: usize = 128;
+    std::cmp::max(
+        TARGET_BLOCK_SIZE_BYTES / elementsize / BLOCKED_MULT * BLOCKED_MULT,
+        MIN_BLOCK_SIZE,
+    )
+}
+
+/// Apply `transform` to each block of elements in `bytes`, leaving trailing elements and bytes unchanged.
+fn apply_blocks(
+    bytes: &[u8],
+    elementsize: usize,
+    transform: impl Fn(&[u8], &mut [u8], usize),
+) -> Vec<u8> {
+    let elementsize = elementsize.max(1);
+    let num_elements = bytes.len() / elementsize;
+    let block_size = default_block_size(elementsize);
+    let mut out = bytes.to_vec();
+    let mut offset = 0;
+    while offset < num_elements {
+        let remaining = num_elements - offset;
+        let size = if remaining >= block_size {
+            block_size
+        } else {
+            remaining - remaining % BLOCKED_MULT
+        };
+        if size == 0 {
+            break;
+        }
+        let range = offset * elementsize..(offset + size) * elementsize;
+        transform(&bytes[range.clone()], &mut out[range], elementsize);
+        offset += size;
+    }
+    out
+}
+
+/// Transpose the bits of a block of elements, where the number of elements is a multiple of 8.
+fn transpose_bits(input: &[u8], output: &mut [u8], elementsize: usize) {
+    let num_elements = input.len() / elementsize;
+    output.fill(0);
+    for (i, element) in input.chunks_exact(elementsize).enumerate() {
+        for (b, byte) in element.iter().enumerate() {
+            for k in 0..8 {
+                let bit = (byte >> k) & 1;
+                output[((b * 8 + k) * num_elements + i) / 8] |= bit << (i % 8);
+            }
+        }
+    }
+}
+
+/// Reverse [`transpose_bits`].
+fn untranspose_bits(input: &[u8], output: &mut [u8], elementsize: usize) {
+    let num_elements = input.len() / elementsize;
+    for (i, element) in output.chunks_exact_mut(elementsize).enumerate() {
+        for (b, byte) in element.iter_mut().enumerate() {
+            *byte = 0;
+            for k in 0..8 {
+                let bit = (input[((b * 8 + k) * num_elements + i) / 8] >> (i % 8)) & 1;
+                *byte |= bit << k;
+            }
+        }
+    }
+}
+
+/// Bit shuffle elements of `elementsize` bytes.
+fn bitshuffle(bytes: &[u8], elementsize: usize) -> Vec<u8> {
+    apply_blocks(bytes, elementsize, transpose_bits)
+}
+
+/// Reverse [`bitshuffle`].
+fn bitunshuffle(bytes: &[u8], elementsize: usize) -> Vec<u8> {
+    apply_blocks(bytes, elementsize, untranspose_bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions},
+            BytesRepresentation, DataType,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_VALID: &str = r#"{
+        "elementsize": 2
+    }"#;
+
+    #[test]
+    fn codec_bitshuffle_configuration_valid() {
+        assert!(serde_json::from_str::<BitshuffleCodecConfiguration>(JSON_VALID).is_ok());
+    }
+
+    #[test]
+    fn codec_bitshuffle_bytes() {
+        let bytes: Vec<u8> = vec![1, 1, 1, 1, 1, 1, 1, 1, 7, 9];
+        let shuffled = bitshuffle(&bytes, 1);
+        assert_eq!(shuffled, vec![255, 0, 0, 0, 0, 0, 0, 0, 7, 9]);
+        assert_eq!(bitunshuffle(&shuffled, 1), bytes);
+
+        let bytes: Vec<u8> = vec![255, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(bitshuffle(&bytes, 1), vec![1; 8]);
+    }
+
+    #[test]
+    fn codec_bitshuffle_blocks() {
+        // 2 full blocks of 4096 2 byte elements, a partial block of 8 elements, and 3 trailing elements and a trailing byte
+        let bytes: Vec<u8> = (0..=2 * 4096 * 2 + 8 * 2 + 3 * 2)
+            .map(|i: usize| u8::try_from(i * 7 % 251).unwrap())
+            .collect();
+        let shuffled = bitshuffle(&bytes, 2);
+        assert_ne!(shuffled, bytes);
+        assert_eq!(shuffled[shuffled.len() - 7..], bytes[bytes.len() - 7..]);
+        assert_eq!(bitunshuffle(&shuffled, 2), bytes);
+    }
+
+    #[test]
+    fn codec_bitshuffle_data_type() {
+        let codec = BitshuffleCodec::new(2);
+        assert!(codec.validate_data_type(&DataType::Int16).is_ok());
+        assert!(codec.validate_data_type(&DataType::Float64).is_err());
+    }
+
+    #[test]
+    fn codec_bitshuffle_round_trip1() {
+        let elements: Vec<u16> = (0..32).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = BitshuffleCodec::new_with_configuration(&configuration);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            codec.compute_encoded_size(&bytes_representation),
+            bytes_representation
+        );
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_bitshuffle_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_bitshuffle_async_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, DataType, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderDefault, AsyncBytesPartialEncoderTraits,
+};
+
+use super::{
+    bitshuffle, bitshuffle_partial_decoder, bitunshuffle, BitshuffleCodecConfiguration,
+    BitshuffleCodecConfigurationV1, IDENTIFIER,
+};
+
+/// A `bitshuffle` codec implementation.
+#[derive(Clone, Debug)]
+pub struct BitshuffleCodec {
+    elementsize: usize,
+}
+
+impl BitshuffleCodec {
+    /// Create a new `bitshuffle` codec.
+    ///
+    /// `elementsize` is the element size in bytes, and must match the size of the array data type.
+    #[must_use]
+    pub const fn new(elementsize: usize) -> Self {
+        Self { elementsize }
+    }
+
+    /// Create a new `bitshuffle` codec from configuration.
+    #[must_use]
+    pub const fn new_with_configuration(configuration: &BitshuffleCodecConfiguration) -> Self {
+        let BitshuffleCodecConfiguration::V1(configuration) = configuration;
+        Self {
+            elementsize: configuration.elementsize,
+        }
+    }
+}
+
+impl CodecTraits for BitshuffleCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = BitshuffleCodecConfigurationV1 {
+            elementsize: self.elementsize,
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for BitshuffleCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn validate_data_type(&self, data_type: &DataType) -> Result<(), CodecError> {
+        match data_type.fixed_size() {
+            Some(data_type_size) if data_type_size != self.elementsize => {
+                Err(CodecError::Other(format!(
+                    "the {IDENTIFIER} codec element size {} does not match the size {data_type_size} of the {data_type} data type",
+                    self.elementsize
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        Ok(Cow::Owned(bitshuffle(&decoded_value, self.elementsize)))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        Ok(Cow::Owned(bitunshuffle(&encoded_value, self.elementsize)))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        r: Arc<dyn BytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            bitshuffle_partial_decoder::BitshufflePartialDecoder::new(r, self.elementsize),
+        ))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        r: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            bitshuffle_partial_decoder::AsyncBitshufflePartialDecoder::new(r, self.elementsize),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncBytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        *decoded_representation
+    }
+}
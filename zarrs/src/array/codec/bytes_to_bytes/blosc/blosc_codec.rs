@@ -15,7 +15,9 @@ use crate::{
 };
 
 #[cfg(feature = "async")]
-use crate::array::codec::AsyncBytesPartialDecoderTraits;
+use crate::array::codec::{
+    AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderDefault, AsyncBytesPartialEncoderTraits,
+};
 
 use super::{
     blosc_compress_bytes, blosc_decompress_bytes, blosc_partial_decoder, blosc_validate,
@@ -238,6 +240,22 @@ impl BytesToBytesCodecTraits for BloscCodec {
         ))
     }
 
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncBytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
     fn compute_encoded_size(
         &self,
         decoded_representation: &BytesRepresentation,
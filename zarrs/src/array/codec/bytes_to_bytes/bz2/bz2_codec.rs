@@ -17,7 +17,9 @@ use crate::{
 };
 
 #[cfg(feature = "async")]
-use crate::array::codec::AsyncBytesPartialDecoderTraits;
+use crate::array::codec::{
+    AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderDefault, AsyncBytesPartialEncoderTraits,
+};
 
 use super::{
     bz2_partial_decoder, Bz2CodecConfiguration, Bz2CodecConfigurationV1, Bz2CompressionLevel,
@@ -147,6 +149,22 @@ impl BytesToBytesCodecTraits for Bz2Codec {
         )))
     }
 
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncBytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
     fn compute_encoded_size(
         &self,
         decoded_representation: &BytesRepresentation,
@@ -12,7 +12,9 @@ use crate::{
 };
 
 #[cfg(feature = "async")]
-use crate::array::codec::AsyncBytesPartialDecoderTraits;
+use crate::array::codec::{
+    AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderDefault, AsyncBytesPartialEncoderTraits,
+};
 
 use super::{
     crc32c_partial_decoder, Crc32cCodecConfiguration, Crc32cCodecConfigurationV1, CHECKSUM_SIZE,
@@ -138,6 +140,22 @@ impl BytesToBytesCodecTraits for Crc32cCodec {
         ))
     }
 
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncBytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
     fn compute_encoded_size(
         &self,
         decoded_representation: &BytesRepresentation,
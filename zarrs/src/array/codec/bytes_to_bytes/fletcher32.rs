@@ -0,0 +1,253 @@
+//! The `fletcher32` (Fletcher-32 checksum) bytes to bytes codec.
+//!
+//! Appends a Fletcher-32 checksum of the input bytestream, as computed by HDF5.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `fletcher32` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `fletcher32` codec, and is used when opening Zarr V2 arrays with a `fletcher32` filter.
+//!
+//! See [`Fletcher32CodecConfigurationV1`] for example `JSON` metadata.
+
+mod fletcher32_codec;
+mod fletcher32_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::fletcher32::{
+    Fletcher32CodecConfiguration, Fletcher32CodecConfigurationV1,
+};
+pub use fletcher32_codec::Fletcher32Codec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::fletcher32, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use fletcher32::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_fletcher32, create_codec_fletcher32)
+}
+
+fn is_name_fletcher32(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_fletcher32(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(Fletcher32Codec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+const CHECKSUM_SIZE: usize = core::mem::size_of::<u32>();
+
+/// Compute the Fletcher-32 checksum of `data` as in HDF5 (`H5_checksum_fletcher32`).
+///
+/// Bytes are summed in big-endian 16-bit words, and a trailing odd byte is treated as the high byte of a word.
+fn h5_checksum_fletcher32(data: &[u8]) -> u32 {
+    let mut sum1: u32 = 0;
+    let mut sum2: u32 = 0;
+
+    // Sum 16-bit words in blocks of 360 words, which cannot overflow the 32-bit sums
+    let (words, remainder) = data.split_at(data.len() - data.len() % 2);
+    for block in words.chunks(720) {
+        for word in block.chunks_exact(2) {
+            sum1 += u32::from(u16::from_be_bytes([word[0], word[1]]));
+            sum2 += sum1;
+        }
+        sum1 = (sum1 & 0xffff) + (sum1 >> 16);
+        sum2 = (sum2 & 0xffff) + (sum2 >> 16);
+    }
+
+    // Handle an odd number of bytes
+    if let [byte] = remainder {
+        sum1 += u32::from(*byte) << 8;
+        sum2 += sum1;
+        sum1 = (sum1 & 0xffff) + (sum1 >> 16);
+        sum2 = (sum2 & 0xffff) + (sum2 >> 16);
+    }
+
+    // Second reduction step to reduce the sums to 16 bits
+    sum1 = (sum1 & 0xffff) + (sum1 >> 16);
+    sum2 = (sum2 & 0xffff) + (sum2 >> 16);
+
+    (sum2 << 16) | sum1
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions, CodecTraits},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON1: &str = r"{}";
+
+    #[test]
+    fn codec_fletcher32_configuration_none() {
+        let codec_configuration: Fletcher32CodecConfiguration =
+            serde_json::from_str(r"{}").unwrap();
+        let codec = Fletcher32Codec::new_with_configuration(&codec_configuration);
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"name":"numcodecs.fletcher32"}"#
+        );
+    }
+
+    #[test]
+    fn codec_fletcher32() {
+        let elements: Vec<u8> = (0..6).collect();
+        let bytes = elements;
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let codec_configuration: Fletcher32CodecConfiguration =
+            serde_json::from_str(JSON1).unwrap();
+        let codec = Fletcher32Codec::new_with_configuration(&codec_configuration);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded = codec
+            .decode(
+                encoded.clone(),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+
+        // Check that the checksum is correct
+        let checksum: &[u8; 4] = &encoded
+            [encoded.len() - core::mem::size_of::<u32>()..encoded.len()]
+            .try_into()
+            .unwrap();
+        assert_eq!(checksum, &[9, 6, 14, 8]);
+    }
+
+    #[test]
+    fn codec_fletcher32_checksum() {
+        assert_eq!(h5_checksum_fletcher32(&[]), 0);
+        assert_eq!(h5_checksum_fletcher32(&[1]), 0x0100_0100);
+        assert_eq!(h5_checksum_fletcher32(&[0, 1, 2, 3, 4, 5]), 0x080E_0609);
+    }
+
+    #[test]
+    fn codec_fletcher32_invalid_checksum() {
+        let codec = Fletcher32Codec::new();
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let mut encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap()
+            .into_owned();
+        encoded[0] = 1;
+        assert!(codec
+            .decode(
+                Cow::Borrowed(&encoded),
+                &bytes_representation,
+                &CodecOptions::default()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn codec_fletcher32_partial_decode() {
+        let elements: Vec<u8> = (0..32).collect();
+        let bytes = elements;
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let codec_configuration: Fletcher32CodecConfiguration =
+            serde_json::from_str(JSON1).unwrap();
+        let codec = Arc::new(Fletcher32Codec::new_with_configuration(
+            &codec_configuration,
+        ));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [ByteRange::FromStart(3, Some(2))];
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+        let answer: &[Vec<u8>] = &[vec![3, 4]];
+        assert_eq!(
+            answer,
+            decoded_partial_chunk
+                .into_iter()
+                .map(|v| v.to_vec())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_fletcher32_async_partial_decode() {
+        let elements: Vec<u8> = (0..32).collect();
+        let bytes = elements;
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let codec_configuration: Fletcher32CodecConfiguration =
+            serde_json::from_str(JSON1).unwrap();
+        let codec = Arc::new(Fletcher32Codec::new_with_configuration(
+            &codec_configuration,
+        ));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [ByteRange::FromStart(3, Some(2))];
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+        let answer: &[Vec<u8>] = &[vec![3, 4]];
+        assert_eq!(
+            answer,
+            decoded_partial_chunk
+                .into_iter()
+                .map(|v| v.to_vec())
+                .collect::<Vec<_>>()
+        );
+    }
+}
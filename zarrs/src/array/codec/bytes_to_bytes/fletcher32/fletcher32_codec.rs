@@ -0,0 +1,183 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderDefault, AsyncBytesPartialEncoderTraits,
+};
+
+use super::{
+    fletcher32_partial_decoder, h5_checksum_fletcher32, Fletcher32CodecConfiguration,
+    Fletcher32CodecConfigurationV1, CHECKSUM_SIZE, IDENTIFIER,
+};
+
+/// A `fletcher32` (Fletcher-32 checksum) codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct Fletcher32Codec;
+
+impl Fletcher32Codec {
+    /// Create a new `fletcher32` codec.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+
+    /// Create a new `fletcher32` codec from configuration.
+    #[must_use]
+    pub const fn new_with_configuration(_configuration: &Fletcher32CodecConfiguration) -> Self {
+        Self {}
+    }
+}
+
+impl CodecTraits for Fletcher32Codec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = Fletcher32CodecConfigurationV1 {};
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for Fletcher32Codec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let checksum = h5_checksum_fletcher32(&decoded_value).to_le_bytes();
+        let mut encoded_value: Vec<u8> = Vec::with_capacity(decoded_value.len() + checksum.len());
+        encoded_value.extend_from_slice(&decoded_value);
+        encoded_value.extend_from_slice(&checksum);
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        if encoded_value.len() >= CHECKSUM_SIZE {
+            if options.validate_checksums() {
+                let decoded_value = &encoded_value[..encoded_value.len() - CHECKSUM_SIZE];
+                let checksum = h5_checksum_fletcher32(decoded_value).to_le_bytes();
+                if checksum != encoded_value[encoded_value.len() - CHECKSUM_SIZE..] {
+                    return Err(CodecError::InvalidChecksum);
+                }
+            }
+            let decoded_value = encoded_value[..encoded_value.len() - CHECKSUM_SIZE].to_vec();
+            Ok(Cow::Owned(decoded_value))
+        } else {
+            Err(CodecError::Other(
+                "fletcher32 decoder expects a 32 bit input".to_string(),
+            ))
+        }
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            fletcher32_partial_decoder::Fletcher32PartialDecoder::new(input_handle),
+        ))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            fletcher32_partial_decoder::AsyncFletcher32PartialDecoder::new(input_handle),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncBytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => {
+                BytesRepresentation::FixedSize(size + core::mem::size_of::<u32>() as u64)
+            }
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + core::mem::size_of::<u32>() as u64)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! The `lz4` bytes to bytes codec.
+//!
+//! Applies [LZ4](https://github.com/lz4/lz4) block compression, prefixed by the little-endian 32-bit decoded size.
+//! LZ4 favours compression and decompression speed over compression ratio.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `lz4` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `lz4` codec, and is used when opening Zarr V2 arrays with an `lz4` compressor.
+//!
+//! See [`Lz4CodecConfigurationV1`] for example `JSON` metadata.
+
+mod lz4_codec;
+mod lz4_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::lz4::{Lz4CodecConfiguration, Lz4CodecConfigurationV1};
+pub use lz4_codec::Lz4Codec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use crate::metadata::v3::array::codec::lz4::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_lz4, create_codec_lz4)
+}
+
+fn is_name_lz4(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_lz4(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: Lz4CodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(Lz4Codec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_VALID: &str = r#"{
+        "acceleration": 1
+    }"#;
+
+    #[test]
+    fn codec_lz4_configuration_valid() {
+        assert!(serde_json::from_str::<Lz4CodecConfiguration>(JSON_VALID).is_ok());
+    }
+
+    #[test]
+    fn codec_lz4_round_trip() {
+        let elements: Vec<u16> = (0..256).map(|i| i % 16).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: Lz4CodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Lz4Codec::new_with_configuration(&configuration);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        // The decoded size prefix
+        assert_eq!(&encoded[..4], &512u32.to_le_bytes());
+        assert!(encoded.len() < bytes.len());
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_lz4_numcodecs_name() {
+        let metadata: MetadataV3 = serde_json::from_str(
+            r#"{"name": "numcodecs.lz4", "configuration": {"acceleration": 2}}"#,
+        )
+        .unwrap();
+        let Codec::BytesToBytes(codec) = Codec::from_metadata(&metadata).unwrap() else {
+            panic!()
+        };
+        assert_eq!(codec.create_metadata(), Some(metadata));
+    }
+
+    #[test]
+    fn codec_lz4_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: Lz4CodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(Lz4Codec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_lz4_async_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: Lz4CodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(Lz4Codec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+}
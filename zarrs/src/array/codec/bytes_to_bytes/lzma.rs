@@ -0,0 +1,225 @@
+//! The `lzma` bytes to bytes codec.
+//!
+//! Applies [LZMA](https://tukaani.org/xz/) compression in the `.xz` or legacy `.lzma` container format.
+//! LZMA favours compression ratio over compression and decompression speed.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `lzma` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `lzma` codec, and is used when opening Zarr V2 arrays with an `lzma` compressor.
+//! The raw format and custom filter chains are not supported.
+//!
+//! See [`LzmaCodecConfigurationV1`] for example `JSON` metadata.
+
+mod lzma_codec;
+mod lzma_partial_decoder;
+
+use std::{
+    io::{Cursor, Read},
+    sync::Arc,
+};
+
+pub use crate::metadata::v3::array::codec::lzma::{
+    LzmaCheck, LzmaCodecConfiguration, LzmaCodecConfigurationV1, LzmaFormat, LZMA_PRESET_EXTREME,
+};
+pub use lzma_codec::LzmaCodec;
+
+use crate::{
+    array::codec::{Codec, CodecError, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::lzma, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use lzma::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_lzma, create_codec_lzma)
+}
+
+fn is_name_lzma(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_lzma(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: LzmaCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(LzmaCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::BytesToBytes(codec))
+}
+
+/// Decompress `.xz` or `.lzma` encoded bytes.
+fn lzma_decompress(encoded_value: &[u8]) -> Result<Vec<u8>, CodecError> {
+    let stream = xz2::stream::Stream::new_auto_decoder(u64::MAX, 0)
+        .map_err(|err| CodecError::Other(err.to_string()))?;
+    let mut decoder = xz2::read::XzDecoder::new_stream(Cursor::new(encoded_value), stream);
+    let mut decompressed = Vec::new();
+    decoder.read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_VALID: &str = r#"{
+        "format": 1,
+        "check": -1,
+        "preset": 1,
+        "filters": null
+    }"#;
+
+    #[test]
+    fn codec_lzma_configuration_valid() {
+        let configuration: LzmaCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        assert!(LzmaCodec::new_with_configuration(&configuration).is_ok());
+    }
+
+    #[test]
+    fn codec_lzma_configuration_invalid() {
+        for json in [
+            r#"{"preset": 10}"#,
+            r#"{"format": 3}"#,
+            r#"{"format": 2, "check": 4}"#,
+            r#"{"filters": [{"id": 33, "preset": 1}]}"#,
+        ] {
+            let configuration: LzmaCodecConfiguration = serde_json::from_str(json).unwrap();
+            assert!(LzmaCodec::new_with_configuration(&configuration).is_err());
+        }
+    }
+
+    #[test]
+    fn codec_lzma_round_trip() {
+        let elements: Vec<u16> = (0..256).map(|i| i % 16).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for (format, magic) in [
+            (LzmaFormat::Xz, &[0xFD, b'7', b'z', b'X', b'Z', 0x00][..]),
+            (LzmaFormat::Alone, &[0x5D][..]),
+        ] {
+            let codec = LzmaCodec::new(format, LzmaCheck::Default, Some(9)).unwrap();
+            let encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            assert_eq!(&encoded[..magic.len()], magic);
+            let decoded = codec
+                .decode(encoded, &bytes_representation, &CodecOptions::default())
+                .unwrap();
+            assert_eq!(bytes, decoded.to_vec());
+        }
+    }
+
+    #[test]
+    fn codec_lzma_numcodecs_name() {
+        let metadata: MetadataV3 = serde_json::from_str(
+            r#"{"name": "numcodecs.lzma", "configuration": {"format": 1, "check": -1, "preset": null, "filters": null}}"#,
+        )
+        .unwrap();
+        let Codec::BytesToBytes(codec) = Codec::from_metadata(&metadata).unwrap() else {
+            panic!()
+        };
+        assert_eq!(codec.create_metadata(), Some(metadata));
+    }
+
+    #[test]
+    fn codec_lzma_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: LzmaCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(LzmaCodec::new_with_configuration(&configuration).unwrap());
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_lzma_async_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: LzmaCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(LzmaCodec::new_with_configuration(&configuration).unwrap());
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+}
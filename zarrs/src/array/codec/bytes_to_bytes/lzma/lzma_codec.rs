@@ -0,0 +1,256 @@
+use std::{
+    borrow::Cow,
+    io::{Cursor, Read},
+    sync::Arc,
+};
+
+use xz2::stream::{Check, LzmaOptions, Stream};
+
+use crate::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderDefault, AsyncBytesPartialEncoderTraits,
+};
+
+use super::{
+    lzma_decompress, lzma_partial_decoder, LzmaCheck, LzmaCodecConfiguration,
+    LzmaCodecConfigurationV1, LzmaFormat, IDENTIFIER, LZMA_PRESET_EXTREME,
+};
+
+/// The preset used if a preset is not specified.
+const LZMA_PRESET_DEFAULT: u32 = 6;
+
+/// An `lzma` codec implementation.
+#[derive(Clone, Debug)]
+pub struct LzmaCodec {
+    format: LzmaFormat,
+    check: LzmaCheck,
+    preset: Option<u32>,
+}
+
+impl LzmaCodec {
+    /// Create a new `lzma` codec.
+    ///
+    /// `preset` is an integer from 0 to 9, optionally combined with [`LZMA_PRESET_EXTREME`].
+    /// It defaults to 6 if [`None`].
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if
+    ///  - `format` is [`LzmaFormat::Raw`],
+    ///  - `check` is not supported by `format`, or
+    ///  - `preset` is not valid.
+    pub fn new(
+        format: LzmaFormat,
+        check: LzmaCheck,
+        preset: Option<u32>,
+    ) -> Result<Self, PluginCreateError> {
+        if format == LzmaFormat::Raw {
+            return Err(PluginCreateError::from(
+                "lzma codec does not support the raw format",
+            ));
+        }
+        if format == LzmaFormat::Alone && !matches!(check, LzmaCheck::Default | LzmaCheck::None) {
+            return Err(PluginCreateError::from(
+                "lzma codec does not support an integrity check with the alone format",
+            ));
+        }
+        if let Some(preset) = preset {
+            if preset & !LZMA_PRESET_EXTREME > 9 {
+                return Err(PluginCreateError::from(format!(
+                    "lzma codec preset {preset} is invalid, must be 0-9 optionally combined with LZMA_PRESET_EXTREME"
+                )));
+            }
+        }
+        Ok(Self {
+            format,
+            check,
+            preset,
+        })
+    }
+
+    /// Create a new `lzma` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &LzmaCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let LzmaCodecConfiguration::V1(configuration) = configuration;
+        if configuration.filters.is_some() {
+            return Err(PluginCreateError::from(
+                "lzma codec does not support custom filters",
+            ));
+        }
+        Self::new(
+            configuration.format,
+            configuration.check,
+            configuration.preset,
+        )
+    }
+
+    fn encoder_stream(&self) -> Result<Stream, xz2::stream::Error> {
+        let preset = self.preset.unwrap_or(LZMA_PRESET_DEFAULT);
+        match self.format {
+            LzmaFormat::Xz => {
+                let check = match self.check {
+                    LzmaCheck::Default | LzmaCheck::Crc64 => Check::Crc64,
+                    LzmaCheck::None => Check::None,
+                    LzmaCheck::Crc32 => Check::Crc32,
+                    LzmaCheck::Sha256 => Check::Sha256,
+                };
+                Stream::new_easy_encoder(preset, check)
+            }
+            LzmaFormat::Alone | LzmaFormat::Raw => {
+                Stream::new_lzma_encoder(&LzmaOptions::new_preset(preset)?)
+            }
+        }
+    }
+}
+
+impl CodecTraits for LzmaCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = LzmaCodecConfigurationV1 {
+            format: self.format,
+            check: self.check,
+            preset: self.preset,
+            filters: None,
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for LzmaCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let stream = self
+            .encoder_stream()
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        let mut encoder = xz2::read::XzEncoder::new_stream(Cursor::new(decoded_value), stream);
+        let mut out: Vec<u8> = Vec::new();
+        encoder.read_to_end(&mut out)?;
+        Ok(Cow::Owned(out))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        Ok(Cow::Owned(lzma_decompress(&encoded_value)?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        r: Arc<dyn BytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(lzma_partial_decoder::LzmaPartialDecoder::new(r)))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        r: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            lzma_partial_decoder::AsyncLzmaPartialDecoder::new(r),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncBytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match (self.format, decoded_representation.size()) {
+            (LzmaFormat::Xz, Some(size)) => {
+                // https://tukaani.org/xz/xz-file-format.txt
+                // Incompressible data is stored in uncompressed LZMA2 chunks, see lzma_stream_buffer_bound
+                const HEADER_TRAILER_OVERHEAD: u64 = 12 + 12 + 1024 + 64 + 24;
+                const CHUNK_SIZE: u64 = 65536;
+                const CHUNK_OVERHEAD: u64 = 3;
+                let chunks_overhead = CHUNK_OVERHEAD * size.div_ceil(CHUNK_SIZE);
+                BytesRepresentation::BoundedSize(size + HEADER_TRAILER_OVERHEAD + chunks_overhead)
+            }
+            _ => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! The `shuffle` bytes to bytes codec.
+//!
+//! Rearranges bytes so that the first byte of every element is stored first, followed by the second byte of every element, etc.
+//! This groups bytes of similar significance together, which typically improves the compression ratio of a subsequent compression codec (e.g. `zstd` or `gzip`).
+//! Trailing bytes that do not form a complete element are stored unchanged.
+//!
+//! The element size is set in the codec configuration, and must match the size of the data type encoded by the array to bytes codec.
+//! An array with a mismatched element size cannot be built with an [`ArrayBuilder`](crate::array::ArrayBuilder), but existing arrays with a mismatched element size can still be opened.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `shuffle` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `shuffle` codec, and is used when opening Zarr V2 arrays with a `shuffle` filter.
+//!
+//! See [`ShuffleCodecConfigurationV1`] for example `JSON` metadata.
+
+mod shuffle_codec;
+mod shuffle_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::shuffle::{
+    ShuffleCodecConfiguration, ShuffleCodecConfigurationV1,
+};
+pub use shuffle_codec::ShuffleCodec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::shuffle, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use shuffle::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_shuffle, create_codec_shuffle)
+}
+
+fn is_name_shuffle(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_shuffle(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: ShuffleCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(ShuffleCodec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+/// Shuffle the bytes of elements of `elementsize` bytes.
+fn shuffle(bytes: &[u8], elementsize: usize) -> Vec<u8> {
+    if elementsize <= 1 {
+        return bytes.to_vec();
+    }
+    let count = bytes.len() / elementsize;
+    let mut shuffled = vec![0; bytes.len()];
+    for (i, element) in bytes.chunks_exact(elementsize).enumerate() {
+        for (byte_index, byte) in element.iter().enumerate() {
+            shuffled[byte_index * count + i] = *byte;
+        }
+    }
+    let trailing = count * elementsize;
+    shuffled[trailing..].copy_from_slice(&bytes[trailing..]);
+    shuffled
+}
+
+/// Reverse [`shuffle`].
+fn unshuffle(bytes: &[u8], elementsize: usize) -> Vec<u8> {
+    if elementsize <= 1 {
+        return bytes.to_vec();
+    }
+    let count = bytes.len() / elementsize;
+    let mut unshuffled = vec![0; bytes.len()];
+    for (i, element) in unshuffled.chunks_exact_mut(elementsize).enumerate() {
+        for (byte_index, byte) in element.iter_mut().enumerate() {
+            *byte = bytes[byte_index * count + i];
+        }
+    }
+    let trailing = count * elementsize;
+    unshuffled[trailing..].copy_from_slice(&bytes[trailing..]);
+    unshuffled
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions},
+            Array, ArrayBuilder, ArrayMetadata, BytesRepresentation, DataType, FillValue,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_VALID: &str = r#"{
+        "elementsize": 2
+    }"#;
+
+    #[test]
+    fn codec_shuffle_configuration_valid() {
+        assert!(serde_json::from_str::<ShuffleCodecConfiguration>(JSON_VALID).is_ok());
+    }
+
+    #[test]
+    fn codec_shuffle_bytes() {
+        let bytes: Vec<u8> = (0..11).collect();
+        let shuffled = shuffle(&bytes, 4);
+        assert_eq!(shuffled, vec![0, 4, 1, 5, 2, 6, 3, 7, 8, 9, 10]);
+        assert_eq!(unshuffle(&shuffled, 4), bytes);
+        assert_eq!(shuffle(&bytes, 1), bytes);
+    }
+
+    #[test]
+    fn codec_shuffle_data_type() {
+        let codec = ShuffleCodec::new(2);
+        assert!(codec.validate_data_type(&DataType::UInt16).is_ok());
+        assert!(codec.validate_data_type(&DataType::UInt32).is_err());
+        assert!(codec.validate_data_type(&DataType::String).is_ok());
+
+        let store = Arc::new(crate::storage::store::MemoryStore::new());
+        let mut builder = ArrayBuilder::new(
+            vec![8],
+            DataType::UInt32,
+            vec![4].try_into().unwrap(),
+            FillValue::from(0u32),
+        );
+        builder.bytes_to_bytes_codecs(vec![Arc::new(codec)]);
+        assert!(builder.build(store.clone(), "/").is_err());
+        builder
+            .data_type(DataType::UInt16)
+            .fill_value(FillValue::from(0u16));
+        assert!(builder.build(store.clone(), "/").is_ok());
+
+        // Existing metadata with a mismatched element size can still be opened
+        let metadata: ArrayMetadata = serde_json::from_str(
+            r#"{
+                "zarr_format": 3,
+                "node_type": "array",
+                "shape": [8],
+                "data_type": "uint32",
+                "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [4]}},
+                "chunk_key_encoding": {"name": "default"},
+                "fill_value": 0,
+                "codecs": [
+                    {"name": "bytes", "configuration": {"endian": "little"}},
+                    {"name": "numcodecs.shuffle", "configuration": {"elementsize": 2}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(Array::new_with_metadata(store, "/", metadata).is_ok());
+    }
+
+    #[test]
+    fn codec_shuffle_round_trip1() {
+        let elements: Vec<u16> = (0..32).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: ShuffleCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = ShuffleCodec::new_with_configuration(&configuration);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            codec.compute_encoded_size(&bytes_representation),
+            bytes_representation
+        );
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_shuffle_numcodecs_name() {
+        let metadata: MetadataV3 = serde_json::from_str(
+            r#"{"name": "numcodecs.shuffle", "configuration": {"elementsize": 4}}"#,
+        )
+        .unwrap();
+        let Codec::BytesToBytes(codec) = Codec::from_metadata(&metadata).unwrap() else {
+            panic!()
+        };
+        assert_eq!(codec.create_metadata(), Some(metadata));
+    }
+
+    #[test]
+    fn codec_shuffle_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: ShuffleCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(ShuffleCodec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_shuffle_async_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: ShuffleCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(ShuffleCodec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+}
@@ -0,0 +1,102 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{BytesPartialDecoderTraits, CodecError, CodecOptions},
+        RawBytes,
+    },
+    byte_range::{extract_byte_ranges, ByteRange},
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+use super::unshuffle;
+
+/// Partial decoder for the `shuffle` codec.
+pub(crate) struct ShufflePartialDecoder {
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    elementsize: usize,
+}
+
+impl ShufflePartialDecoder {
+    /// Create a new partial decoder for the `shuffle` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        elementsize: usize,
+    ) -> Self {
+        Self {
+            input_handle,
+            elementsize,
+        }
+    }
+}
+
+impl BytesPartialDecoderTraits for ShufflePartialDecoder {
+    fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        let encoded_value = self.input_handle.decode(options)?;
+        let Some(encoded_value) = encoded_value else {
+            return Ok(None);
+        };
+
+        let decoded_value = unshuffle(&encoded_value, self.elementsize);
+
+        Ok(Some(
+            extract_byte_ranges(&decoded_value, decoded_regions)
+                .map_err(CodecError::InvalidByteRangeError)?
+                .into_iter()
+                .map(Cow::Owned)
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for the `shuffle` codec.
+pub(crate) struct AsyncShufflePartialDecoder {
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    elementsize: usize,
+}
+
+#[cfg(feature = "async")]
+impl AsyncShufflePartialDecoder {
+    /// Create a new partial decoder for the `shuffle` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        elementsize: usize,
+    ) -> Self {
+        Self {
+            input_handle,
+            elementsize,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncBytesPartialDecoderTraits for AsyncShufflePartialDecoder {
+    async fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        let encoded_value = self.input_handle.decode(options).await?;
+        let Some(encoded_value) = encoded_value else {
+            return Ok(None);
+        };
+
+        let decoded_value = unshuffle(&encoded_value, self.elementsize);
+
+        Ok(Some(
+            extract_byte_ranges(&decoded_value, decoded_regions)
+                .map_err(CodecError::InvalidByteRangeError)?
+                .into_iter()
+                .map(Cow::Owned)
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,207 @@
+//! The `zlib` bytes to bytes codec.
+//!
+//! Applies [zlib](https://datatracker.ietf.org/doc/html/rfc1950) compression.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `zlib` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `zlib` codec, and is used when opening Zarr V2 arrays with a `zlib` compressor.
+//!
+//! See [`ZlibCodecConfigurationV1`] for example `JSON` metadata.
+
+mod zlib_codec;
+mod zlib_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::zlib::{
+    ZlibCodecConfiguration, ZlibCodecConfigurationV1, ZlibCompressionLevel,
+    ZlibCompressionLevelError,
+};
+pub use zlib_codec::ZlibCodec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::zlib, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use zlib::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_zlib, create_codec_zlib)
+}
+
+fn is_name_zlib(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_zlib(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: ZlibCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(ZlibCodec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_VALID: &str = r#"{
+        "level": 1
+    }"#;
+
+    #[test]
+    fn codec_zlib_configuration_valid() {
+        assert!(serde_json::from_str::<ZlibCodecConfiguration>(JSON_VALID).is_ok());
+    }
+
+    #[test]
+    fn codec_zlib_configuration_invalid1() {
+        const JSON_INVALID1: &str = r#"{
+        "level": -1
+    }"#;
+        assert!(serde_json::from_str::<ZlibCodecConfiguration>(JSON_INVALID1).is_err());
+    }
+
+    #[test]
+    fn codec_zlib_configuration_invalid2() {
+        const JSON_INVALID2: &str = r#"{
+        "level": 10
+    }"#;
+        assert!(serde_json::from_str::<ZlibCodecConfiguration>(JSON_INVALID2).is_err());
+    }
+
+    #[test]
+    fn codec_zlib_round_trip1() {
+        let elements: Vec<u16> = (0..32).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: ZlibCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = ZlibCodec::new_with_configuration(&configuration);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        // zlib header with the fastest compression level
+        assert_eq!(&encoded[..2], &[0x78, 0x01]);
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_zlib_numcodecs_name() {
+        let metadata: MetadataV3 =
+            serde_json::from_str(r#"{"name": "numcodecs.zlib", "configuration": {"level": 1}}"#)
+                .unwrap();
+        let Codec::BytesToBytes(codec) = Codec::from_metadata(&metadata).unwrap() else {
+            panic!()
+        };
+        assert_eq!(codec.create_metadata(), Some(metadata));
+    }
+
+    #[test]
+    fn codec_zlib_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: ZlibCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(ZlibCodec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_zlib_async_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: ZlibCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(ZlibCodec::new_with_configuration(&configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .to_vec()
+            .chunks_exact(std::mem::size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    borrow::Cow,
+    io::{Cursor, Read},
+    sync::Arc,
+};
+
+use flate2::bufread::{ZlibDecoder, ZlibEncoder};
+
+use crate::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderDefault, AsyncBytesPartialEncoderTraits,
+};
+
+use super::{
+    zlib_partial_decoder, ZlibCodecConfiguration, ZlibCodecConfigurationV1, ZlibCompressionLevel,
+    ZlibCompressionLevelError, IDENTIFIER,
+};
+
+/// A `zlib` codec implementation.
+#[derive(Clone, Debug)]
+pub struct ZlibCodec {
+    compression_level: ZlibCompressionLevel,
+}
+
+impl ZlibCodec {
+    /// Create a new `zlib` codec.
+    ///
+    /// # Errors
+    /// Returns [`ZlibCompressionLevelError`] if `compression_level` is not valid.
+    pub fn new(compression_level: u32) -> Result<Self, ZlibCompressionLevelError> {
+        let compression_level: ZlibCompressionLevel = compression_level.try_into()?;
+        Ok(Self { compression_level })
+    }
+
+    /// Create a new `zlib` codec from configuration.
+    #[must_use]
+    pub const fn new_with_configuration(configuration: &ZlibCodecConfiguration) -> Self {
+        let ZlibCodecConfiguration::V1(configuration) = configuration;
+        Self {
+            compression_level: configuration.level,
+        }
+    }
+}
+
+impl CodecTraits for ZlibCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = ZlibCodecConfigurationV1 {
+            level: self.compression_level,
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for ZlibCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let mut encoder = ZlibEncoder::new(
+            Cursor::new(decoded_value),
+            flate2::Compression::new(self.compression_level.as_u32()),
+        );
+        let mut out: Vec<u8> = Vec::new();
+        encoder.read_to_end(&mut out)?;
+        Ok(Cow::Owned(out))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let mut decoder = ZlibDecoder::new(Cursor::new(encoded_value));
+        let mut out: Vec<u8> = Vec::new();
+        decoder.read_to_end(&mut out)?;
+        Ok(Cow::Owned(out))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        r: Arc<dyn BytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(zlib_partial_decoder::ZlibPartialDecoder::new(r)))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        r: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            zlib_partial_decoder::AsyncZlibPartialDecoder::new(r),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        output_handle: Arc<dyn AsyncBytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncBytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        decoded_representation
+            .size()
+            .map_or(BytesRepresentation::UnboundedSize, |size| {
+                // https://datatracker.ietf.org/doc/html/rfc1950
+                const HEADER_TRAILER_OVERHEAD: u64 = 2 + 4;
+                const BLOCK_SIZE: u64 = 32768;
+                const BLOCK_OVERHEAD: u64 = 5;
+                let blocks_overhead = BLOCK_OVERHEAD * size.div_ceil(BLOCK_SIZE);
+                BytesRepresentation::BoundedSize(size + HEADER_TRAILER_OVERHEAD + blocks_overhead)
+            })
+    }
+}
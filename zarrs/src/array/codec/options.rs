@@ -11,6 +11,7 @@ pub struct CodecOptions {
     store_empty_chunks: bool,
     concurrent_target: usize,
     experimental_partial_encoding: bool,
+    conditional_chunk_updates: bool,
 }
 
 impl Default for CodecOptions {
@@ -20,6 +21,7 @@ impl Default for CodecOptions {
             store_empty_chunks: global_config().store_empty_chunks(),
             concurrent_target: global_config().codec_concurrent_target(),
             experimental_partial_encoding: global_config().experimental_partial_encoding(),
+            conditional_chunk_updates: global_config().conditional_chunk_updates(),
         }
     }
 }
@@ -39,6 +41,7 @@ impl CodecOptions {
             store_empty_chunks: self.store_empty_chunks,
             concurrent_target: self.concurrent_target,
             experimental_partial_encoding: self.experimental_partial_encoding,
+            conditional_chunk_updates: self.conditional_chunk_updates,
         }
     }
 
@@ -92,6 +95,18 @@ impl CodecOptions {
         self.experimental_partial_encoding = experimental_partial_encoding;
         self
     }
+
+    /// Return the conditional chunk updates setting.
+    #[must_use]
+    pub fn conditional_chunk_updates(&self) -> bool {
+        self.conditional_chunk_updates
+    }
+
+    /// Set whether or not to use conditional writes for chunk read-modify-write updates.
+    pub fn set_conditional_chunk_updates(&mut self, conditional_chunk_updates: bool) -> &mut Self {
+        self.conditional_chunk_updates = conditional_chunk_updates;
+        self
+    }
 }
 
 /// Builder for [`CodecOptions`].
@@ -103,6 +118,7 @@ pub struct CodecOptionsBuilder {
     store_empty_chunks: bool,
     concurrent_target: usize,
     experimental_partial_encoding: bool,
+    conditional_chunk_updates: bool,
 }
 
 impl Default for CodecOptionsBuilder {
@@ -120,6 +136,7 @@ impl CodecOptionsBuilder {
             store_empty_chunks: global_config().store_empty_chunks(),
             concurrent_target: global_config().codec_concurrent_target(),
             experimental_partial_encoding: global_config().experimental_partial_encoding(),
+            conditional_chunk_updates: global_config().conditional_chunk_updates(),
         }
     }
 
@@ -131,6 +148,7 @@ impl CodecOptionsBuilder {
             store_empty_chunks: self.store_empty_chunks,
             concurrent_target: self.concurrent_target,
             experimental_partial_encoding: self.experimental_partial_encoding,
+            conditional_chunk_updates: self.conditional_chunk_updates,
         }
     }
 
@@ -161,4 +179,11 @@ impl CodecOptionsBuilder {
         self.experimental_partial_encoding = experimental_partial_encoding;
         self
     }
+
+    /// Set whether or not to use conditional writes for chunk read-modify-write updates.
+    #[must_use]
+    pub fn conditional_chunk_updates(mut self, conditional_chunk_updates: bool) -> Self {
+        self.conditional_chunk_updates = conditional_chunk_updates;
+        self
+    }
 }
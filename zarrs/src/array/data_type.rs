@@ -1,21 +1,44 @@
 //! Zarr data types.
 //!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#data-types>.
+//!
+//! Data types are Zarr extension points.
+//! Data types not natively supported by `zarrs` can be registered through [`inventory`] as a [`DataTypePlugin`] which creates a [`DataTypeExtension`].
+
+mod data_type_extension;
+mod structured_data_type;
+
+pub use data_type_extension::{
+    DataTypeExtension, DataTypeExtensionBytesCodec, DataTypeExtensionError,
+};
+pub use structured_data_type::{
+    StructuredDataType, StructuredDataTypeError, StructuredDataTypeField,
+};
+
+use std::{num::NonZeroU32, sync::Arc};
 
 use derive_more::From;
 use half::{bf16, f16};
 use thiserror::Error;
 
-use crate::metadata::v3::array::{
-    data_type::{DataTypeMetadataV3, DataTypeSize},
-    fill_value::{
-        bfloat16_to_fill_value, float16_to_fill_value, float32_to_fill_value,
-        float64_to_fill_value, FillValueFloat, FillValueMetadataV3,
+use crate::{
+    metadata::v3::{
+        array::{
+            data_type::{DataTypeMetadataV3, DataTypeSize},
+            fill_value::{
+                bfloat16_to_fill_value, float16_to_fill_value, float32_to_fill_value,
+                float64_to_fill_value, FillValueFloat, FillValueMetadataV3,
+            },
+        },
+        MetadataV3,
     },
+    plugin::Plugin,
 };
 
 use super::FillValue;
 
+pub use crate::metadata::v3::array::data_type::NumpyTimeUnit;
+
 /// A data type.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -57,8 +80,30 @@ pub enum DataType {
     String,
     /// Variable-sized binary data.
     Bytes,
+    /// `structured` a fixed-size compound data type with named fields.
+    Structured(StructuredDataType),
+    /// `numpy.datetime64` a 64-bit signed integer count of `scale_factor` `unit`s since the Unix epoch. The minimum value is `NaT` (not-a-time).
+    NumpyDateTime64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The time unit scale factor.
+        scale_factor: NonZeroU32,
+    },
+    /// `numpy.timedelta64` a 64-bit signed integer count of `scale_factor` `unit`s. The minimum value is `NaT` (not-a-time).
+    NumpyTimeDelta64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The time unit scale factor.
+        scale_factor: NonZeroU32,
+    },
+    /// An extension data type.
+    Extension(Arc<dyn DataTypeExtension>),
 }
 
+/// A data type plugin.
+pub type DataTypePlugin = Plugin<Arc<dyn DataTypeExtension>>;
+inventory::collect!(DataTypePlugin);
+
 /// An unsupported data type error.
 #[derive(Debug, Error, From)]
 #[error("unsupported data type {_0}")]
@@ -69,6 +114,14 @@ pub struct UnsupportedDataTypeError(String);
 #[error("incompatible fill value {1} for data type {0}")]
 pub struct IncompatibleFillValueMetadataError(String, FillValueMetadataV3);
 
+impl IncompatibleFillValueMetadataError {
+    /// Create a new incompatible fill value metadata error.
+    #[must_use]
+    pub const fn new(data_type_name: String, fill_value_metadata: FillValueMetadataV3) -> Self {
+        Self(data_type_name, fill_value_metadata)
+    }
+}
+
 /// A fill value incompatibility error.
 #[derive(Debug, Error)]
 #[error("incompatible fill value {1} for data type {0}")]
@@ -85,7 +138,7 @@ impl IncompatibleFillValueError {
 impl DataType {
     /// Returns the identifier.
     #[must_use]
-    pub const fn identifier(&self) -> &'static str {
+    pub fn identifier(&self) -> &'static str {
         match self {
             Self::Bool => "bool",
             Self::Int8 => "int8",
@@ -105,7 +158,10 @@ impl DataType {
             Self::RawBits(_usize) => "r*",
             Self::String => "string",
             Self::Bytes => "bytes",
-            // Self::Extension(extension) => extension.identifier(),
+            Self::Structured(_) => "structured",
+            Self::NumpyDateTime64 { .. } => "numpy.datetime64",
+            Self::NumpyTimeDelta64 { .. } => "numpy.timedelta64",
+            Self::Extension(extension) => extension.identifier(),
         }
     }
 
@@ -114,7 +170,7 @@ impl DataType {
     pub fn name(&self) -> String {
         match self {
             Self::RawBits(size) => format!("r{}", size * 8),
-            // Self::Extension(extension) => extension.name(),
+            Self::Extension(extension) => extension.name(),
             _ => self.identifier().to_string(),
         }
     }
@@ -141,27 +197,51 @@ impl DataType {
             Self::RawBits(size) => DataTypeMetadataV3::RawBits(*size),
             Self::String => DataTypeMetadataV3::String,
             Self::Bytes => DataTypeMetadataV3::Bytes,
+            Self::Structured(structured) => DataTypeMetadataV3::Structured(structured.metadata()),
+            Self::NumpyDateTime64 { unit, scale_factor } => DataTypeMetadataV3::NumpyDateTime64 {
+                unit: *unit,
+                scale_factor: *scale_factor,
+            },
+            Self::NumpyTimeDelta64 { unit, scale_factor } => DataTypeMetadataV3::NumpyTimeDelta64 {
+                unit: *unit,
+                scale_factor: *scale_factor,
+            },
+            Self::Extension(extension) => {
+                let metadata = extension.configuration().map_or_else(
+                    || MetadataV3::new(&extension.name()),
+                    |configuration| {
+                        MetadataV3::new_with_configuration(&extension.name(), configuration)
+                    },
+                );
+                DataTypeMetadataV3::Unknown(metadata)
+            }
         }
     }
 
     /// Returns the [`DataTypeSize`].
     #[must_use]
-    pub const fn size(&self) -> DataTypeSize {
+    pub fn size(&self) -> DataTypeSize {
         match self {
             Self::Bool | Self::Int8 | Self::UInt8 => DataTypeSize::Fixed(1),
             Self::Int16 | Self::UInt16 | Self::Float16 | Self::BFloat16 => DataTypeSize::Fixed(2),
             Self::Int32 | Self::UInt32 | Self::Float32 => DataTypeSize::Fixed(4),
-            Self::Int64 | Self::UInt64 | Self::Float64 | Self::Complex64 => DataTypeSize::Fixed(8),
+            Self::Int64
+            | Self::UInt64
+            | Self::Float64
+            | Self::Complex64
+            | Self::NumpyDateTime64 { .. }
+            | Self::NumpyTimeDelta64 { .. } => DataTypeSize::Fixed(8),
             Self::Complex128 => DataTypeSize::Fixed(16),
             Self::RawBits(size) => DataTypeSize::Fixed(*size),
             Self::String | Self::Bytes => DataTypeSize::Variable,
-            // Self::Extension(extension) => extension.size(),
+            Self::Structured(structured) => DataTypeSize::Fixed(structured.size()),
+            Self::Extension(extension) => extension.size(),
         }
     }
 
     /// Returns the size in bytes of a fixed-size data type, otherwise returns [`None`].
     #[must_use]
-    pub const fn fixed_size(&self) -> Option<usize> {
+    pub fn fixed_size(&self) -> Option<usize> {
         match self.size() {
             DataTypeSize::Fixed(size) => Some(size),
             DataTypeSize::Variable => None,
@@ -193,7 +273,30 @@ impl DataType {
             DataTypeMetadataV3::RawBits(size) => Ok(Self::RawBits(*size)),
             DataTypeMetadataV3::String => Ok(Self::String),
             DataTypeMetadataV3::Bytes => Ok(Self::Bytes),
+            DataTypeMetadataV3::Structured(fields) => {
+                Ok(Self::Structured(StructuredDataType::from_metadata(fields)?))
+            }
+            DataTypeMetadataV3::NumpyDateTime64 { unit, scale_factor } => {
+                Ok(Self::NumpyDateTime64 {
+                    unit: *unit,
+                    scale_factor: *scale_factor,
+                })
+            }
+            DataTypeMetadataV3::NumpyTimeDelta64 { unit, scale_factor } => {
+                Ok(Self::NumpyTimeDelta64 {
+                    unit: *unit,
+                    scale_factor: *scale_factor,
+                })
+            }
             DataTypeMetadataV3::Unknown(metadata) => {
+                for plugin in inventory::iter::<DataTypePlugin> {
+                    if plugin.match_name(metadata.name()) {
+                        return plugin
+                            .create(metadata)
+                            .map(Self::Extension)
+                            .map_err(|err| UnsupportedDataTypeError(err.to_string()));
+                    }
+                }
                 Err(UnsupportedDataTypeError(metadata.to_string()))
             }
             _ => Err(UnsupportedDataTypeError(metadata.to_string())),
@@ -241,6 +344,18 @@ impl DataType {
                 }
                 Err(err())
             }
+            Self::Structured(structured) => {
+                if let FillValueMetadataV3::ByteArray(bytes) = fill_value {
+                    if bytes.len() == structured.size() {
+                        return Ok(FillValue::new(bytes.clone()));
+                    }
+                }
+                Err(err())
+            }
+            Self::NumpyDateTime64 { .. } | Self::NumpyTimeDelta64 { .. } => match fill_value {
+                FillValueMetadataV3::String(string) if string == "NaT" => Ok(FV::from(i64::MIN)),
+                _ => Ok(FV::from(fill_value.try_as_int::<i64>().ok_or_else(err)?)),
+            },
             Self::Bytes => {
                 if let FillValueMetadataV3::ByteArray(bytes) = fill_value {
                     Ok(FillValue::new(bytes.clone()))
@@ -248,7 +363,7 @@ impl DataType {
                     Err(err())
                 }
             }
-            // Self::Extension(extension) => extension.fill_value_from_metadata(fill_value),
+            Self::Extension(extension) => extension.fill_value(fill_value),
             Self::String => match fill_value {
                 FillValueMetadataV3::String(string) => {
                     Ok(FillValue::new(string.as_bytes().to_vec()))
@@ -269,7 +384,8 @@ impl DataType {
     /// # Panics
     ///
     /// Panics if the metadata cannot be created from the fill value.
-    /// This would indicate an implementation error with a data type.
+    /// This would indicate an implementation error with a data type, or an [`Extension`](DataType::Extension) data type with an incompatible fill value.
+    /// The fill value of an extension data type is validated by [`ArrayBuilder::build`](crate::array::ArrayBuilder::build).
     #[must_use]
     pub fn metadata_fill_value(&self, fill_value: &FillValue) -> FillValueMetadataV3 {
         let bytes = fill_value.as_ne_bytes();
@@ -325,7 +441,19 @@ impl DataType {
                 debug_assert_eq!(fill_value.as_ne_bytes().len(), *size);
                 FillValueMetadataV3::ByteArray(fill_value.as_ne_bytes().to_vec())
             }
-            // DataType::Extension(extension) => extension.metadata_fill_value(fill_value),
+            Self::Structured(structured) => {
+                debug_assert_eq!(fill_value.as_ne_bytes().len(), structured.size());
+                FillValueMetadataV3::ByteArray(fill_value.as_ne_bytes().to_vec())
+            }
+            Self::NumpyDateTime64 { .. } | Self::NumpyTimeDelta64 { .. } => {
+                let value = i64::from_ne_bytes(bytes.try_into().unwrap());
+                if value == i64::MIN {
+                    FillValueMetadataV3::String("NaT".to_string())
+                } else {
+                    FillValueMetadataV3::Int(value)
+                }
+            }
+            Self::Extension(extension) => extension.metadata_fill_value(fill_value).unwrap(),
             Self::String => FillValueMetadataV3::String(
                 String::from_utf8(fill_value.as_ne_bytes().to_vec()).unwrap(),
             ),
@@ -368,6 +496,261 @@ mod tests {
         assert!(DataType::try_from(metadata).is_err());
     }
 
+    #[derive(Debug)]
+    struct FixedPointDataType {
+        scale: u32,
+    }
+
+    const FIXED_POINT_IDENTIFIER: &str = "zarrs.test.fixed_point";
+
+    inventory::submit! {
+        DataTypePlugin::new(FIXED_POINT_IDENTIFIER, is_name_fixed_point, create_fixed_point)
+    }
+
+    fn is_name_fixed_point(name: &str) -> bool {
+        name.eq(FIXED_POINT_IDENTIFIER)
+    }
+
+    fn create_fixed_point(
+        metadata: &MetadataV3,
+    ) -> Result<Arc<dyn DataTypeExtension>, crate::plugin::PluginCreateError> {
+        #[derive(serde::Deserialize)]
+        struct FixedPointConfiguration {
+            scale: u32,
+        }
+        let configuration: FixedPointConfiguration = metadata.to_configuration().map_err(|_| {
+            crate::plugin::PluginMetadataInvalidError::new(
+                FIXED_POINT_IDENTIFIER,
+                "data type",
+                metadata.clone(),
+            )
+        })?;
+        Ok(Arc::new(FixedPointDataType {
+            scale: configuration.scale,
+        }))
+    }
+
+    impl DataTypeExtension for FixedPointDataType {
+        fn identifier(&self) -> &'static str {
+            FIXED_POINT_IDENTIFIER
+        }
+
+        fn configuration(&self) -> Option<crate::metadata::v3::MetadataConfiguration> {
+            let mut configuration = crate::metadata::v3::MetadataConfiguration::new();
+            configuration.insert("scale".to_string(), self.scale.into());
+            Some(configuration)
+        }
+
+        fn size(&self) -> DataTypeSize {
+            DataTypeSize::Fixed(4)
+        }
+
+        fn fill_value(
+            &self,
+            fill_value_metadata: &FillValueMetadataV3,
+        ) -> Result<FillValue, IncompatibleFillValueMetadataError> {
+            let fill_value = fill_value_metadata.try_as_int::<i32>().ok_or_else(|| {
+                IncompatibleFillValueMetadataError::new(self.name(), fill_value_metadata.clone())
+            })?;
+            Ok(FillValue::from(fill_value))
+        }
+
+        fn metadata_fill_value(
+            &self,
+            fill_value: &FillValue,
+        ) -> Result<FillValueMetadataV3, IncompatibleFillValueError> {
+            let bytes: [u8; 4] = fill_value
+                .as_ne_bytes()
+                .try_into()
+                .map_err(|_| IncompatibleFillValueError::new(self.name(), fill_value.clone()))?;
+            Ok(FillValueMetadataV3::Int(i64::from(i32::from_ne_bytes(
+                bytes,
+            ))))
+        }
+
+        fn codec_bytes(&self) -> Result<&dyn DataTypeExtensionBytesCodec, DataTypeExtensionError> {
+            Ok(self)
+        }
+    }
+
+    impl DataTypeExtensionBytesCodec for FixedPointDataType {
+        fn encode<'a>(
+            &self,
+            mut bytes: crate::array::RawBytes<'a>,
+            endianness: Option<crate::metadata::Endianness>,
+        ) -> Result<crate::array::RawBytes<'a>, DataTypeExtensionError> {
+            let endianness = endianness
+                .ok_or_else(|| DataTypeExtensionError::EndiannessNotSpecified(self.name()))?;
+            if !endianness.is_native() {
+                for element in bytes.to_mut().chunks_exact_mut(4) {
+                    element.reverse();
+                }
+            }
+            Ok(bytes)
+        }
+
+        fn decode<'a>(
+            &self,
+            bytes: crate::array::RawBytes<'a>,
+            endianness: Option<crate::metadata::Endianness>,
+        ) -> Result<crate::array::RawBytes<'a>, DataTypeExtensionError> {
+            self.encode(bytes, endianness)
+        }
+    }
+
+    #[test]
+    fn data_type_extension() {
+        let json = r#"{"name":"zarrs.test.fixed_point","configuration":{"scale":2}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type.identifier(), FIXED_POINT_IDENTIFIER);
+        assert_eq!(data_type.name().as_str(), FIXED_POINT_IDENTIFIER);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(4));
+        assert_eq!(data_type, DataType::from_metadata(&metadata).unwrap());
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("-1234").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), (-1234i32).to_ne_bytes());
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("1.5").unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+
+        let builder = crate::array::ArrayBuilder::new(
+            vec![2],
+            data_type.clone(),
+            vec![2].try_into().unwrap(),
+            FillValue::from(0i16),
+        );
+        assert!(builder
+            .build(Arc::new(crate::storage::store::MemoryStore::new()), "/")
+            .is_err());
+
+        let json = r#"{"name":"zarrs.test.fixed_point","configuration":{"scale":3}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert_ne!(data_type, DataType::from_metadata(&metadata).unwrap());
+
+        let json = r#"{"name":"zarrs.test.fixed_point","configuration":{"scale":"2"}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(DataType::from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn data_type_extension_codec_bytes() {
+        use crate::array::{
+            codec::{ArrayToBytesCodecTraits, BytesCodec, CodecOptions},
+            ArrayBytes, ChunkRepresentation,
+        };
+
+        let data_type = DataType::Extension(Arc::new(FixedPointDataType { scale: 2 }));
+        let chunk_representation = ChunkRepresentation::new(
+            vec![std::num::NonZeroU64::new(2).unwrap()],
+            data_type,
+            FillValue::from(0i32),
+        )
+        .unwrap();
+        let elements: Vec<u8> = [1i32, -2i32]
+            .iter()
+            .flat_map(|element| element.to_ne_bytes())
+            .collect();
+
+        let codec = BytesCodec::big();
+        let encoded = codec
+            .encode(
+                ArrayBytes::from(elements.clone()),
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(encoded.as_ref(), [0, 0, 0, 1, 255, 255, 255, 254]);
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(decoded.into_fixed().unwrap().as_ref(), elements);
+
+        let codec = BytesCodec::new(None);
+        assert!(codec
+            .encode(
+                ArrayBytes::from(elements),
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn data_type_structured() {
+        let json =
+            r#"{"name":"structured","configuration":{"fields":[["x","float32"],["id","uint64"]]}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type.name(), "structured");
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(12));
+        let DataType::Structured(structured) = &data_type else {
+            panic!()
+        };
+        assert_eq!(structured.fields()[1].name(), "id");
+        assert_eq!(structured.fields()[1].data_type(), &DataType::UInt64);
+
+        let metadata =
+            serde_json::from_str::<FillValueMetadataV3>("[0,0,128,63,1,0,0,0,0,0,0,0]").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.size(), 12);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+        assert!(data_type
+            .fill_value_from_metadata(&serde_json::from_str::<FillValueMetadataV3>("[0]").unwrap())
+            .is_err());
+
+        let json =
+            r#"{"name":"structured","configuration":{"fields":[["x","float32"],["s","string"]]}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(DataType::from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn data_type_numpy_datetime64() {
+        let json = r#"{"name":"numpy.datetime64","configuration":{"unit":"ns","scale_factor":1}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type.name(), "numpy.datetime64");
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(8));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""NaT""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), i64::MIN.to_ne_bytes());
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("-7").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), (-7i64).to_ne_bytes());
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        assert!(data_type
+            .fill_value_from_metadata(&serde_json::from_str::<FillValueMetadataV3>("1.5").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn data_type_numpy_timedelta64() {
+        let json = r#"{"name":"numpy.timedelta64","configuration":{"unit":"s","scale_factor":10}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(
+            data_type,
+            DataType::NumpyTimeDelta64 {
+                unit: NumpyTimeUnit::Second,
+                scale_factor: NonZeroU32::new(10).unwrap()
+            }
+        );
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""NaT""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), i64::MIN.to_ne_bytes());
+    }
+
     #[test]
     fn data_type_bool() {
         let json = r#""bool""#;
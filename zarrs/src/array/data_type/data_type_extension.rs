@@ -0,0 +1,129 @@
+use thiserror::Error;
+
+use crate::{
+    array::{FillValue, RawBytes},
+    metadata::{
+        v3::{array::fill_value::FillValueMetadataV3, MetadataConfiguration},
+        Endianness,
+    },
+};
+
+use super::{DataTypeSize, IncompatibleFillValueError, IncompatibleFillValueMetadataError};
+
+/// Traits for a data type extension.
+///
+/// A data type extension is created from metadata by a registered [`DataTypePlugin`](super::DataTypePlugin).
+///
+/// The in-memory representation of an element of an extension data type is defined by the extension.
+/// [`Element`](crate::array::Element) and [`ElementOwned`](crate::array::ElementOwned) can be implemented for types compatible with the extension by matching on [`DataType::Extension`](super::DataType::Extension).
+pub trait DataTypeExtension: core::fmt::Debug + Send + Sync {
+    /// Returns the identifier.
+    fn identifier(&self) -> &'static str;
+
+    /// Returns the name.
+    ///
+    /// Defaults to the identifier.
+    fn name(&self) -> String {
+        self.identifier().to_string()
+    }
+
+    /// Returns the configuration, if any.
+    fn configuration(&self) -> Option<MetadataConfiguration>;
+
+    /// Returns the [`DataTypeSize`].
+    fn size(&self) -> DataTypeSize;
+
+    /// Create a fill value from metadata.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleFillValueMetadataError`] if the fill value is incompatible with the data type.
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadataV3,
+    ) -> Result<FillValue, IncompatibleFillValueMetadataError>;
+
+    /// Create fill value metadata.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleFillValueError`] if the fill value is incompatible with the data type.
+    fn metadata_fill_value(
+        &self,
+        fill_value: &FillValue,
+    ) -> Result<FillValueMetadataV3, IncompatibleFillValueError>;
+
+    /// Return the `bytes` codec implementation for the data type, if supported.
+    ///
+    /// The `bytes` codec does not support the data type by default.
+    ///
+    /// # Errors
+    /// Returns [`DataTypeExtensionError::CodecUnsupported`] if the `bytes` codec is not supported.
+    fn codec_bytes(&self) -> Result<&dyn DataTypeExtensionBytesCodec, DataTypeExtensionError> {
+        Err(DataTypeExtensionError::CodecUnsupported {
+            data_type: self.name(),
+            codec: "bytes".to_string(),
+        })
+    }
+}
+
+impl PartialEq for dyn DataTypeExtension {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.configuration() == other.configuration()
+    }
+}
+
+impl Eq for dyn DataTypeExtension {}
+
+/// Traits for a data type extension supporting the `bytes` codec.
+pub trait DataTypeExtensionBytesCodec {
+    /// Encode the bytes of a number of elements with the given endianness.
+    ///
+    /// # Errors
+    /// Returns a [`DataTypeExtensionError`] if `bytes` cannot be encoded or `endianness` is unsupported.
+    fn encode<'a>(
+        &self,
+        bytes: RawBytes<'a>,
+        endianness: Option<Endianness>,
+    ) -> Result<RawBytes<'a>, DataTypeExtensionError>;
+
+    /// Decode the bytes of a number of elements with the given endianness.
+    ///
+    /// # Errors
+    /// Returns a [`DataTypeExtensionError`] if `bytes` cannot be decoded or `endianness` is unsupported.
+    fn decode<'a>(
+        &self,
+        bytes: RawBytes<'a>,
+        endianness: Option<Endianness>,
+    ) -> Result<RawBytes<'a>, DataTypeExtensionError>;
+}
+
+/// A data type extension error.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DataTypeExtensionError {
+    /// The codec is not supported by the data type.
+    #[error("codec {codec} is not supported by data type {data_type}")]
+    CodecUnsupported {
+        /// The data type name.
+        data_type: String,
+        /// The codec name.
+        codec: String,
+    },
+    /// The endianness must be specified.
+    #[error("endianness must be specified for data type {_0}")]
+    EndiannessNotSpecified(String),
+    /// Other
+    #[error("{_0}")]
+    Other(String),
+}
+
+impl From<&str> for DataTypeExtensionError {
+    fn from(err: &str) -> Self {
+        Self::Other(err.to_string())
+    }
+}
+
+impl From<String> for DataTypeExtensionError {
+    fn from(err: String) -> Self {
+        Self::Other(err)
+    }
+}
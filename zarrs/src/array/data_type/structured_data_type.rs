@@ -0,0 +1,190 @@
+use thiserror::Error;
+
+use crate::metadata::v3::array::data_type::{DataTypeMetadataV3StructuredField, DataTypeSize};
+
+use super::{DataType, UnsupportedDataTypeError};
+
+/// A field of a [`StructuredDataType`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructuredDataTypeField {
+    name: String,
+    data_type: DataType,
+    offset: usize,
+}
+
+impl StructuredDataTypeField {
+    /// Returns the field name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the field data type.
+    #[must_use]
+    pub const fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the byte offset of the field within an element.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the size of the field in bytes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        // Variable-sized fields are rejected by StructuredDataType::new
+        self.data_type.fixed_size().unwrap_or_default()
+    }
+}
+
+/// A structured (compound) data type composed of named fixed-size fields.
+///
+/// Fields are packed without padding in the order they are defined, matching the layout of an unaligned `NumPy` structured data type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructuredDataType {
+    fields: Vec<StructuredDataTypeField>,
+    size: usize,
+}
+
+/// An invalid structured data type error.
+#[derive(Debug, Error)]
+pub enum StructuredDataTypeError {
+    /// No fields.
+    #[error("a structured data type must have at least one field")]
+    NoFields,
+    /// A duplicate field name.
+    #[error("duplicate structured data type field name {_0}")]
+    DuplicateFieldName(String),
+    /// A variable-sized field.
+    #[error("structured data type field {_0} has variable-sized data type {_1}")]
+    VariableSizedField(String, DataType),
+    /// An extension data type field.
+    #[error(
+        "structured data type field {_0} has extension data type {_1}, which is not supported"
+    )]
+    ExtensionField(String, DataType),
+}
+
+impl StructuredDataType {
+    /// Create a new structured data type from (name, data type) pairs.
+    ///
+    /// # Errors
+    /// Returns a [`StructuredDataTypeError`] if there are no fields, field names are not unique, or a field has a variable-sized or extension data type.
+    pub fn new(
+        fields: impl IntoIterator<Item = (String, DataType)>,
+    ) -> Result<Self, StructuredDataTypeError> {
+        let mut size = 0;
+        let mut structured_fields: Vec<StructuredDataTypeField> = Vec::new();
+        for (name, data_type) in fields {
+            if structured_fields.iter().any(|field| field.name == name) {
+                return Err(StructuredDataTypeError::DuplicateFieldName(name));
+            }
+            if let DataType::Extension(_) = data_type {
+                return Err(StructuredDataTypeError::ExtensionField(name, data_type));
+            }
+            let DataTypeSize::Fixed(field_size) = data_type.size() else {
+                return Err(StructuredDataTypeError::VariableSizedField(name, data_type));
+            };
+            structured_fields.push(StructuredDataTypeField {
+                name,
+                data_type,
+                offset: size,
+            });
+            size += field_size;
+        }
+        if structured_fields.is_empty() {
+            return Err(StructuredDataTypeError::NoFields);
+        }
+        Ok(Self {
+            fields: structured_fields,
+            size,
+        })
+    }
+
+    /// Create a structured data type from metadata.
+    ///
+    /// # Errors
+    /// Returns [`UnsupportedDataTypeError`] if any field data type is unsupported or the fields are invalid.
+    pub fn from_metadata(
+        fields: &[DataTypeMetadataV3StructuredField],
+    ) -> Result<Self, UnsupportedDataTypeError> {
+        let fields = fields
+            .iter()
+            .map(|field| {
+                Ok((
+                    field.name.clone(),
+                    DataType::from_metadata(&field.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, UnsupportedDataTypeError>>()?;
+        Self::new(fields).map_err(|err| UnsupportedDataTypeError::from(err.to_string()))
+    }
+
+    /// Returns the fields metadata.
+    #[must_use]
+    pub fn metadata(&self) -> Vec<DataTypeMetadataV3StructuredField> {
+        self.fields
+            .iter()
+            .map(|field| {
+                DataTypeMetadataV3StructuredField::new(
+                    field.name.clone(),
+                    field.data_type.metadata(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the fields.
+    #[must_use]
+    pub fn fields(&self) -> &[StructuredDataTypeField] {
+        &self.fields
+    }
+
+    /// Returns the field with `name`, if it exists.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&StructuredDataTypeField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the size of an element in bytes.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structured_data_type() {
+        let data_type = StructuredDataType::new([
+            ("x".to_string(), DataType::Float32),
+            ("id".to_string(), DataType::UInt64),
+        ])
+        .unwrap();
+        assert_eq!(data_type.size(), 12);
+        assert_eq!(data_type.field("id").unwrap().offset(), 4);
+        assert_eq!(data_type.field("id").unwrap().size(), 8);
+        assert!(data_type.field("y").is_none());
+
+        assert!(matches!(
+            StructuredDataType::new([]),
+            Err(StructuredDataTypeError::NoFields)
+        ));
+        assert!(matches!(
+            StructuredDataType::new([
+                ("x".to_string(), DataType::Float32),
+                ("x".to_string(), DataType::UInt64),
+            ]),
+            Err(StructuredDataTypeError::DuplicateFieldName(_))
+        ));
+        assert!(matches!(
+            StructuredDataType::new([("x".to_string(), DataType::String)]),
+            Err(StructuredDataTypeError::VariableSizedField(_, _))
+        ));
+    }
+}
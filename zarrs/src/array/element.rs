@@ -5,6 +5,9 @@ use ArrayError::IncompatibleElementType as IET;
 
 use super::{convert_from_bytes_slice, transmute_to_bytes, ArrayBytes, ArrayError, DataType};
 
+#[cfg(feature = "chrono")]
+mod element_chrono;
+
 /// A trait representing an array element type.
 pub trait Element: Sized + Clone {
     /// Validate the data type.
@@ -91,10 +94,10 @@ impl ElementOwned for bool {
 }
 
 macro_rules! impl_element_pod {
-    ($raw_type:ty, $data_type:expr) => {
+    ($raw_type:ty, $data_type:pat) => {
         impl Element for $raw_type {
             fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
-                (data_type == &$data_type).then_some(()).ok_or(IET)
+                matches!(data_type, $data_type).then_some(()).ok_or(IET)
             }
 
             fn into_array_bytes<'a>(
@@ -122,7 +125,10 @@ macro_rules! impl_element_pod {
 impl_element_pod!(i8, DataType::Int8);
 impl_element_pod!(i16, DataType::Int16);
 impl_element_pod!(i32, DataType::Int32);
-impl_element_pod!(i64, DataType::Int64);
+impl_element_pod!(
+    i64,
+    DataType::Int64 | DataType::NumpyDateTime64 { .. } | DataType::NumpyTimeDelta64 { .. }
+);
 impl_element_pod!(u8, DataType::UInt8);
 impl_element_pod!(u16, DataType::UInt16);
 impl_element_pod!(u32, DataType::UInt32);
@@ -265,3 +271,103 @@ impl ElementOwned for Vec<u8> {
         Ok(elements)
     }
 }
+
+/// The name, byte offset, and data type validation function of a field of a structured element type.
+type StructuredElementField<'a> = (&'a str, usize, fn(&DataType) -> Result<(), ArrayError>);
+
+/// Validate that a structured element type matches a [`DataType::Structured`] data type.
+///
+/// `fields` holds the name, byte offset, and data type validation function of each field of the element type.
+/// This is used by [`impl_element_structured`](crate::impl_element_structured).
+///
+/// # Errors
+/// Returns [`ArrayError::IncompatibleElementType`] if the element type is incompatible with the data type.
+#[doc(hidden)]
+pub fn validate_structured_element(
+    data_type: &DataType,
+    size: usize,
+    fields: &[StructuredElementField],
+) -> Result<(), ArrayError> {
+    let DataType::Structured(structured) = data_type else {
+        return Err(IET);
+    };
+    if structured.size() != size || structured.fields().len() != fields.len() {
+        return Err(IET);
+    }
+    for (field, (name, offset, validate_data_type)) in std::iter::zip(structured.fields(), fields) {
+        if field.name() != *name || field.offset() != *offset {
+            return Err(IET);
+        }
+        validate_data_type(field.data_type())?;
+    }
+    Ok(())
+}
+
+/// Implement [`Element`], [`ElementOwned`], and [`ElementFixedLength`] for a struct matching a [`structured`](crate::array::data_type::StructuredDataType) data type.
+///
+/// The struct must be `#[repr(C)]` (or `#[repr(C, packed)]`) and implement [`bytemuck::Pod`], so it cannot contain padding.
+/// Its fields must be listed in declaration order with their types, and match the names, byte offsets, and data types of the structured data type fields.
+///
+/// ```
+/// # use zarrs::array::{data_type::StructuredDataType, DataType, Element};
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct Point {
+///     id: u64,
+///     x: f32,
+///     y: f32,
+/// }
+/// unsafe impl bytemuck::Zeroable for Point {}
+/// unsafe impl bytemuck::Pod for Point {}
+/// zarrs::impl_element_structured!(Point, id: u64, x: f32, y: f32);
+///
+/// let data_type = DataType::Structured(StructuredDataType::new([
+///     ("id".to_string(), DataType::UInt64),
+///     ("x".to_string(), DataType::Float32),
+///     ("y".to_string(), DataType::Float32),
+/// ])?);
+/// assert!(Point::validate_data_type(&data_type).is_ok());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[macro_export]
+macro_rules! impl_element_structured {
+    ($struct:ty, $($field:ident: $field_type:ty),+ $(,)?) => {
+        impl $crate::array::Element for $struct {
+            fn validate_data_type(
+                data_type: &$crate::array::DataType,
+            ) -> Result<(), $crate::array::ArrayError> {
+                $crate::array::validate_structured_element(
+                    data_type,
+                    ::core::mem::size_of::<$struct>(),
+                    &[$((
+                        ::core::stringify!($field),
+                        ::core::mem::offset_of!($struct, $field),
+                        <$field_type as $crate::array::Element>::validate_data_type
+                            as fn(&$crate::array::DataType) -> Result<(), $crate::array::ArrayError>,
+                    )),+],
+                )
+            }
+
+            fn into_array_bytes<'a>(
+                data_type: &$crate::array::DataType,
+                elements: &'a [Self],
+            ) -> Result<$crate::array::ArrayBytes<'a>, $crate::array::ArrayError> {
+                <Self as $crate::array::Element>::validate_data_type(data_type)?;
+                Ok($crate::array::transmute_to_bytes(elements).into())
+            }
+        }
+
+        impl $crate::array::ElementOwned for $struct {
+            fn from_array_bytes(
+                data_type: &$crate::array::DataType,
+                bytes: $crate::array::ArrayBytes<'_>,
+            ) -> Result<Vec<Self>, $crate::array::ArrayError> {
+                <Self as $crate::array::Element>::validate_data_type(data_type)?;
+                let bytes = bytes.into_fixed()?;
+                Ok($crate::array::convert_from_bytes_slice::<Self>(&bytes))
+            }
+        }
+
+        impl $crate::array::ElementFixedLength for $struct {}
+    };
+}
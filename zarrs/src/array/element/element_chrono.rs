@@ -0,0 +1,316 @@
+//! [`Element`] implementations for [`chrono`] date and time types.
+//!
+//! [`chrono::DateTime<Utc>`] is compatible with the `numpy.datetime64` data type and [`chrono::TimeDelta`] is compatible with the `numpy.timedelta64` data type.
+//! Values are converted to and from the units of the data type, rounding towards negative infinity where required.
+//! `NaT` (not a time) elements cannot be represented and are decoded as an [`ArrayError::InvalidElementValue`] error.
+
+use std::num::NonZeroU32;
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Utc};
+
+use super::{Element, ElementFixedLength, ElementOwned, IET};
+use crate::array::{
+    convert_from_bytes_slice, data_type::NumpyTimeUnit, transmute_to_bytes_vec, ArrayBytes,
+    ArrayError, DataType,
+};
+
+const NAT: i64 = i64::MIN;
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Return the length of a time unit in nanoseconds as a `(numerator, denominator)` pair.
+///
+/// Returns [`None`] for units without a fixed length.
+const fn unit_nanoseconds(unit: NumpyTimeUnit) -> Option<(i128, i128)> {
+    match unit {
+        NumpyTimeUnit::Week => Some((604_800 * NANOSECONDS_PER_SECOND, 1)),
+        NumpyTimeUnit::Day => Some((86_400 * NANOSECONDS_PER_SECOND, 1)),
+        NumpyTimeUnit::Hour => Some((3_600 * NANOSECONDS_PER_SECOND, 1)),
+        NumpyTimeUnit::Minute => Some((60 * NANOSECONDS_PER_SECOND, 1)),
+        NumpyTimeUnit::Second => Some((NANOSECONDS_PER_SECOND, 1)),
+        NumpyTimeUnit::Millisecond => Some((1_000_000, 1)),
+        NumpyTimeUnit::Microsecond => Some((1_000, 1)),
+        NumpyTimeUnit::Nanosecond => Some((1, 1)),
+        NumpyTimeUnit::Picosecond => Some((1, 1_000)),
+        NumpyTimeUnit::Femtosecond => Some((1, 1_000_000)),
+        NumpyTimeUnit::Attosecond => Some((1, 1_000_000_000)),
+        NumpyTimeUnit::Generic | NumpyTimeUnit::Year | NumpyTimeUnit::Month => None,
+    }
+}
+
+/// Convert a count of `unit * scale_factor` to nanoseconds.
+fn value_to_nanoseconds(value: i64, unit: NumpyTimeUnit, scale_factor: NonZeroU32) -> Option<i128> {
+    let (num, den) = unit_nanoseconds(unit)?;
+    i128::from(value)
+        .checked_mul(i128::from(scale_factor.get()))?
+        .checked_mul(num)
+        .map(|ns| ns.div_euclid(den))
+}
+
+/// Convert nanoseconds to a count of `unit * scale_factor`.
+fn nanoseconds_to_value(ns: i128, unit: NumpyTimeUnit, scale_factor: NonZeroU32) -> Option<i64> {
+    let (num, den) = unit_nanoseconds(unit)?;
+    let value = ns
+        .checked_mul(den)?
+        .div_euclid(num.checked_mul(i128::from(scale_factor.get()))?);
+    i64::try_from(value).ok().filter(|value| *value != NAT)
+}
+
+fn datetime_parameters(data_type: &DataType) -> Result<(NumpyTimeUnit, NonZeroU32), ArrayError> {
+    match data_type {
+        DataType::NumpyDateTime64 { unit, scale_factor } if *unit != NumpyTimeUnit::Generic => {
+            Ok((*unit, *scale_factor))
+        }
+        _ => Err(IET),
+    }
+}
+
+fn timedelta_parameters(data_type: &DataType) -> Result<(NumpyTimeUnit, NonZeroU32), ArrayError> {
+    match data_type {
+        DataType::NumpyTimeDelta64 { unit, scale_factor } if unit_nanoseconds(*unit).is_some() => {
+            Ok((*unit, *scale_factor))
+        }
+        _ => Err(IET),
+    }
+}
+
+fn datetime_to_value(
+    datetime: &DateTime<Utc>,
+    unit: NumpyTimeUnit,
+    scale_factor: NonZeroU32,
+) -> Option<i64> {
+    let scale_factor_i64 = i64::from(scale_factor.get());
+    match unit {
+        NumpyTimeUnit::Year => {
+            let years = i64::from(datetime.year()) - 1970;
+            Some(years.div_euclid(scale_factor_i64))
+        }
+        NumpyTimeUnit::Month => {
+            let months = (i64::from(datetime.year()) - 1970) * 12 + i64::from(datetime.month0());
+            Some(months.div_euclid(scale_factor_i64))
+        }
+        _ => {
+            let ns = i128::from(datetime.timestamp()) * NANOSECONDS_PER_SECOND
+                + i128::from(datetime.timestamp_subsec_nanos());
+            nanoseconds_to_value(ns, unit, scale_factor)
+        }
+    }
+}
+
+fn value_to_datetime(
+    value: i64,
+    unit: NumpyTimeUnit,
+    scale_factor: NonZeroU32,
+) -> Option<DateTime<Utc>> {
+    if value == NAT {
+        return None;
+    }
+    let scale_factor_i64 = i64::from(scale_factor.get());
+    match unit {
+        NumpyTimeUnit::Year => {
+            let year = value.checked_mul(scale_factor_i64)?.checked_add(1970)?;
+            let date = NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, 1, 1)?;
+            Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+        }
+        NumpyTimeUnit::Month => {
+            let months = value.checked_mul(scale_factor_i64)?;
+            let year = months.div_euclid(12).checked_add(1970)?;
+            let month = u32::try_from(months.rem_euclid(12)).ok()? + 1;
+            let date = NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, 1)?;
+            Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+        }
+        _ => {
+            let ns = value_to_nanoseconds(value, unit, scale_factor)?;
+            let secs = i64::try_from(ns.div_euclid(NANOSECONDS_PER_SECOND)).ok()?;
+            let nsecs = u32::try_from(ns.rem_euclid(NANOSECONDS_PER_SECOND)).ok()?;
+            DateTime::from_timestamp(secs, nsecs)
+        }
+    }
+}
+
+fn timedelta_to_value(
+    timedelta: &TimeDelta,
+    unit: NumpyTimeUnit,
+    scale_factor: NonZeroU32,
+) -> Option<i64> {
+    let ns = i128::from(timedelta.num_seconds()) * NANOSECONDS_PER_SECOND
+        + i128::from(timedelta.subsec_nanos());
+    nanoseconds_to_value(ns, unit, scale_factor)
+}
+
+fn value_to_timedelta(
+    value: i64,
+    unit: NumpyTimeUnit,
+    scale_factor: NonZeroU32,
+) -> Option<TimeDelta> {
+    if value == NAT {
+        return None;
+    }
+    let ns = value_to_nanoseconds(value, unit, scale_factor)?;
+    let secs = i64::try_from(ns.div_euclid(NANOSECONDS_PER_SECOND)).ok()?;
+    let nsecs = u32::try_from(ns.rem_euclid(NANOSECONDS_PER_SECOND)).ok()?;
+    TimeDelta::new(secs, nsecs)
+}
+
+impl ElementFixedLength for DateTime<Utc> {}
+impl ElementFixedLength for TimeDelta {}
+
+impl Element for DateTime<Utc> {
+    fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
+        datetime_parameters(data_type).map(|_| ())
+    }
+
+    fn into_array_bytes<'a>(
+        data_type: &DataType,
+        elements: &'a [Self],
+    ) -> Result<ArrayBytes<'a>, ArrayError> {
+        let (unit, scale_factor) = datetime_parameters(data_type)?;
+        let values = elements
+            .iter()
+            .map(|datetime| datetime_to_value(datetime, unit, scale_factor))
+            .collect::<Option<Vec<i64>>>()
+            .ok_or(ArrayError::InvalidElementValue)?;
+        Ok(transmute_to_bytes_vec(values).into())
+    }
+}
+
+impl ElementOwned for DateTime<Utc> {
+    fn from_array_bytes(
+        data_type: &DataType,
+        bytes: ArrayBytes<'_>,
+    ) -> Result<Vec<Self>, ArrayError> {
+        let (unit, scale_factor) = datetime_parameters(data_type)?;
+        let bytes = bytes.into_fixed()?;
+        convert_from_bytes_slice::<i64>(&bytes)
+            .into_iter()
+            .map(|value| value_to_datetime(value, unit, scale_factor))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ArrayError::InvalidElementValue)
+    }
+}
+
+impl Element for TimeDelta {
+    fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
+        timedelta_parameters(data_type).map(|_| ())
+    }
+
+    fn into_array_bytes<'a>(
+        data_type: &DataType,
+        elements: &'a [Self],
+    ) -> Result<ArrayBytes<'a>, ArrayError> {
+        let (unit, scale_factor) = timedelta_parameters(data_type)?;
+        let values = elements
+            .iter()
+            .map(|timedelta| timedelta_to_value(timedelta, unit, scale_factor))
+            .collect::<Option<Vec<i64>>>()
+            .ok_or(ArrayError::InvalidElementValue)?;
+        Ok(transmute_to_bytes_vec(values).into())
+    }
+}
+
+impl ElementOwned for TimeDelta {
+    fn from_array_bytes(
+        data_type: &DataType,
+        bytes: ArrayBytes<'_>,
+    ) -> Result<Vec<Self>, ArrayError> {
+        let (unit, scale_factor) = timedelta_parameters(data_type)?;
+        let bytes = bytes.into_fixed()?;
+        convert_from_bytes_slice::<i64>(&bytes)
+            .into_iter()
+            .map(|value| value_to_timedelta(value, unit, scale_factor))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ArrayError::InvalidElementValue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime64(unit: NumpyTimeUnit, scale_factor: u32) -> DataType {
+        DataType::NumpyDateTime64 {
+            unit,
+            scale_factor: NonZeroU32::new(scale_factor).unwrap(),
+        }
+    }
+
+    fn timedelta64(unit: NumpyTimeUnit, scale_factor: u32) -> DataType {
+        DataType::NumpyTimeDelta64 {
+            unit,
+            scale_factor: NonZeroU32::new(scale_factor).unwrap(),
+        }
+    }
+
+    #[test]
+    fn element_chrono_datetime() {
+        let datetimes = vec![
+            DateTime::from_timestamp(0, 0).unwrap(),
+            DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap(),
+            DateTime::from_timestamp(-1, 500_000_000).unwrap(),
+        ];
+
+        let data_type = datetime64(NumpyTimeUnit::Nanosecond, 1);
+        let bytes = DateTime::<Utc>::into_array_bytes(&data_type, &datetimes).unwrap();
+        let values = i64::from_array_bytes(&data_type, bytes.clone()).unwrap();
+        assert_eq!(values, vec![0, 1_700_000_000_123_456_789, -500_000_000]);
+        assert_eq!(
+            DateTime::<Utc>::from_array_bytes(&data_type, bytes).unwrap(),
+            datetimes
+        );
+
+        let data_type = datetime64(NumpyTimeUnit::Second, 10);
+        let bytes = DateTime::<Utc>::into_array_bytes(&data_type, &datetimes).unwrap();
+        let values = i64::from_array_bytes(&data_type, bytes).unwrap();
+        assert_eq!(values, vec![0, 170_000_000, -1]);
+
+        let data_type = datetime64(NumpyTimeUnit::Month, 1);
+        let bytes = DateTime::<Utc>::into_array_bytes(&data_type, &datetimes).unwrap();
+        let values = i64::from_array_bytes(&data_type, bytes.clone()).unwrap();
+        assert_eq!(values, vec![0, 646, -1]);
+        assert_eq!(
+            DateTime::<Utc>::from_array_bytes(&data_type, bytes).unwrap()[1],
+            NaiveDate::from_ymd_opt(2023, 11, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
+        );
+
+        let nat: ArrayBytes = transmute_to_bytes_vec(vec![NAT]).into();
+        assert!(DateTime::<Utc>::from_array_bytes(&data_type, nat).is_err());
+        assert!(DateTime::<Utc>::validate_data_type(&DataType::Int64).is_err());
+        assert!(
+            DateTime::<Utc>::validate_data_type(&datetime64(NumpyTimeUnit::Generic, 1)).is_err()
+        );
+    }
+
+    #[test]
+    fn element_chrono_timedelta() {
+        let timedeltas = vec![
+            TimeDelta::zero(),
+            TimeDelta::milliseconds(1_500),
+            TimeDelta::milliseconds(-1_500),
+        ];
+
+        let data_type = timedelta64(NumpyTimeUnit::Millisecond, 1);
+        let bytes = TimeDelta::into_array_bytes(&data_type, &timedeltas).unwrap();
+        let values = i64::from_array_bytes(&data_type, bytes.clone()).unwrap();
+        assert_eq!(values, vec![0, 1_500, -1_500]);
+        assert_eq!(
+            TimeDelta::from_array_bytes(&data_type, bytes).unwrap(),
+            timedeltas
+        );
+
+        let data_type = timedelta64(NumpyTimeUnit::Second, 1);
+        let bytes = TimeDelta::into_array_bytes(&data_type, &timedeltas).unwrap();
+        let values = i64::from_array_bytes(&data_type, bytes).unwrap();
+        assert_eq!(values, vec![0, 1, -2]);
+
+        let data_type = timedelta64(NumpyTimeUnit::Picosecond, 1);
+        let bytes = TimeDelta::into_array_bytes(&data_type, &timedeltas).unwrap();
+        let values = i64::from_array_bytes(&data_type, bytes).unwrap();
+        assert_eq!(values, vec![0, 1_500_000_000_000, -1_500_000_000_000]);
+
+        assert!(TimeDelta::validate_data_type(&timedelta64(NumpyTimeUnit::Year, 1)).is_err());
+        assert!(TimeDelta::validate_data_type(&datetime64(NumpyTimeUnit::Second, 1)).is_err());
+    }
+}
@@ -4,6 +4,9 @@
 //! A [`StorageTransformerChain`] represents a sequence of storage transformers.
 //! A storage transformer chain and individual storage transformers all have the same interface as a [store](crate::storage::store).
 //!
+//! The following storage transformers are supported:
+//!  - [`chunk_manifest`]: redirects chunk keys to byte ranges within other values in the store.
+//!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#id23>.
 
 mod storage_transformer_chain;
@@ -12,6 +15,9 @@ pub use storage_transformer_chain::StorageTransformerChain;
 mod storage_transformer_plugin;
 pub use storage_transformer_plugin::StorageTransformerPlugin;
 
+pub mod chunk_manifest;
+pub use chunk_manifest::ChunkManifestStorageTransformer;
+
 use std::sync::Arc;
 
 use crate::{
@@ -0,0 +1,636 @@
+//! The `chunk_manifest` storage transformer.
+//!
+//! Redirects chunk keys of an array to byte ranges within other values in the store (virtual references).
+//! This enables chunks in existing files (e.g. uncompressed or independently compressed chunks of NetCDF/HDF5 files) to be exposed as a Zarr array without copying data, similar to [kerchunk](https://github.com/fsspec/kerchunk) and [VirtualiZarr](https://github.com/zarr-developers/VirtualiZarr).
+//!
+//! The manifest maps chunk keys (relative to the array) to a `path` (a key relative to the root of the store), an `offset`, and a `length`.
+//! Keys that are not in the manifest are passed through to the underlying store, and manifest entries are read only.
+//!
+//! ### Storage Transformer `configuration` Example - [`ChunkManifestStorageTransformerConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "manifest": {
+//!         "c/0/0": {"path": "data.nc", "offset": 8192, "length": 4000},
+//!         "c/0/1": {"path": "data.nc", "offset": 12192, "length": 4000}
+//!     }
+//! }
+//! # "#;
+//! # use zarrs_metadata::v3::array::storage_transformer::chunk_manifest::ChunkManifestStorageTransformerConfiguration;
+//! # let configuration: ChunkManifestStorageTransformerConfiguration = serde_json::from_str(JSON).unwrap();
+//! ```
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use itertools::Itertools;
+
+pub use crate::metadata::v3::array::storage_transformer::chunk_manifest::{
+    ChunkManifestEntry, ChunkManifestStorageTransformerConfiguration,
+    ChunkManifestStorageTransformerConfigurationV1, IDENTIFIER,
+};
+
+use crate::{
+    byte_range::{ByteRange, InvalidByteRangeError},
+    metadata::v3::MetadataV3,
+    node::{data_key, NodePath},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+    storage::{
+        Bytes, ListableStorage, ListableStorageTraits, ReadableStorage, ReadableStorageTraits,
+        StorageError, StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
+        StoreValueVersion, WritableStorage, WritableStorageTraits,
+    },
+};
+
+#[cfg(feature = "async")]
+use crate::storage::{
+    AsyncBytes, AsyncListableStorage, AsyncListableStorageTraits, AsyncReadableStorage,
+    AsyncReadableStorageTraits, AsyncWritableStorage, AsyncWritableStorageTraits,
+};
+
+use super::{StorageTransformer, StorageTransformerExtension, StorageTransformerPlugin};
+
+// Register the storage transformer.
+inventory::submit! {
+    StorageTransformerPlugin::new(IDENTIFIER, is_name_chunk_manifest, create_chunk_manifest)
+}
+
+fn is_name_chunk_manifest(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+}
+
+fn create_chunk_manifest(
+    metadata: &MetadataV3,
+    path: &NodePath,
+) -> Result<StorageTransformer, PluginCreateError> {
+    let configuration: ChunkManifestStorageTransformerConfiguration =
+        metadata.to_configuration().map_err(|_| {
+            PluginMetadataInvalidError::new(IDENTIFIER, "storage transformer", metadata.clone())
+        })?;
+    let storage_transformer =
+        ChunkManifestStorageTransformer::new_with_configuration(&configuration, path).map_err(
+            |_| {
+                PluginMetadataInvalidError::new(IDENTIFIER, "storage transformer", metadata.clone())
+            },
+        )?;
+    Ok(Arc::new(storage_transformer))
+}
+
+/// A resolved chunk manifest entry.
+#[derive(Debug, Clone)]
+struct ChunkReference {
+    key: StoreKey,
+    offset: u64,
+    length: u64,
+}
+
+impl ChunkReference {
+    /// Map a byte range of a chunk to a byte range of the referenced value.
+    fn byte_range(&self, byte_range: &ByteRange) -> Result<ByteRange, StorageError> {
+        match *byte_range {
+            ByteRange::FromStart(offset, None) if offset <= self.length => Ok(
+                ByteRange::FromStart(self.offset + offset, Some(self.length - offset)),
+            ),
+            ByteRange::FromStart(offset, Some(length))
+                if offset
+                    .checked_add(length)
+                    .is_some_and(|end| end <= self.length) =>
+            {
+                Ok(ByteRange::FromStart(self.offset + offset, Some(length)))
+            }
+            ByteRange::Suffix(length) if length <= self.length => Ok(ByteRange::FromStart(
+                self.offset + self.length - length,
+                Some(length),
+            )),
+            _ => Err(InvalidByteRangeError::new(*byte_range, self.length).into()),
+        }
+    }
+
+    fn byte_ranges(&self, byte_ranges: &[ByteRange]) -> Result<Vec<ByteRange>, StorageError> {
+        byte_ranges
+            .iter()
+            .map(|byte_range| self.byte_range(byte_range))
+            .collect()
+    }
+
+    fn missing(&self) -> StorageError {
+        StorageError::Other(format!(
+            "the chunk manifest references a missing key {}",
+            self.key
+        ))
+    }
+}
+
+type ChunkManifest = BTreeMap<StoreKey, ChunkReference>;
+
+/// The `chunk_manifest` storage transformer.
+#[derive(Debug)]
+pub struct ChunkManifestStorageTransformer {
+    configuration: ChunkManifestStorageTransformerConfiguration,
+    manifest: Arc<ChunkManifest>,
+}
+
+impl ChunkManifestStorageTransformer {
+    /// Create a new `chunk_manifest` storage transformer for the array at `path`.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if a chunk key or referenced key in the manifest is invalid.
+    pub fn new(
+        manifest: BTreeMap<String, ChunkManifestEntry>,
+        path: &NodePath,
+    ) -> Result<Self, StorageError> {
+        let configuration = ChunkManifestStorageTransformerConfigurationV1::new(manifest);
+        Self::new_with_configuration(&configuration.into(), path)
+    }
+
+    /// Create a new `chunk_manifest` storage transformer for the array at `path` from configuration.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if a chunk key or referenced key in the manifest is invalid.
+    pub fn new_with_configuration(
+        configuration: &ChunkManifestStorageTransformerConfiguration,
+        path: &NodePath,
+    ) -> Result<Self, StorageError> {
+        let ChunkManifestStorageTransformerConfiguration::V1(configuration_v1) = configuration;
+        let manifest = configuration_v1
+            .manifest
+            .iter()
+            .map(|(chunk_key, entry)| {
+                let key = data_key(path, &StoreKey::new(chunk_key.as_str())?);
+                let reference = ChunkReference {
+                    key: StoreKey::new(entry.path.as_str())?,
+                    offset: entry.offset,
+                    length: entry.length,
+                };
+                Ok((key, reference))
+            })
+            .collect::<Result<ChunkManifest, StorageError>>()?;
+        Ok(Self {
+            configuration: configuration.clone(),
+            manifest: Arc::new(manifest),
+        })
+    }
+
+    fn create_transformer<TStorage: ?Sized>(
+        &self,
+        storage: Arc<TStorage>,
+    ) -> Arc<ChunkManifestStorage<TStorage>> {
+        Arc::new(ChunkManifestStorage {
+            storage,
+            manifest: self.manifest.clone(),
+        })
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl StorageTransformerExtension for ChunkManifestStorageTransformer {
+    fn create_metadata(&self) -> MetadataV3 {
+        MetadataV3::new_with_serializable_configuration(IDENTIFIER, &self.configuration).unwrap()
+    }
+
+    fn create_readable_transformer(
+        self: Arc<Self>,
+        storage: ReadableStorage,
+    ) -> Result<ReadableStorage, StorageError> {
+        Ok(self.create_transformer(storage))
+    }
+
+    fn create_writable_transformer(
+        self: Arc<Self>,
+        storage: WritableStorage,
+    ) -> Result<WritableStorage, StorageError> {
+        Ok(self.create_transformer(storage))
+    }
+
+    fn create_listable_transformer(
+        self: Arc<Self>,
+        storage: ListableStorage,
+    ) -> Result<ListableStorage, StorageError> {
+        Ok(self.create_transformer(storage))
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_readable_transformer(
+        self: Arc<Self>,
+        storage: AsyncReadableStorage,
+    ) -> Result<AsyncReadableStorage, StorageError> {
+        Ok(self.create_transformer(storage))
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_writable_transformer(
+        self: Arc<Self>,
+        storage: AsyncWritableStorage,
+    ) -> Result<AsyncWritableStorage, StorageError> {
+        Ok(self.create_transformer(storage))
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_listable_transformer(
+        self: Arc<Self>,
+        storage: AsyncListableStorage,
+    ) -> Result<AsyncListableStorage, StorageError> {
+        Ok(self.create_transformer(storage))
+    }
+}
+
+/// Storage with chunk keys redirected by a chunk manifest.
+struct ChunkManifestStorage<TStorage: ?Sized> {
+    storage: Arc<TStorage>,
+    manifest: Arc<ChunkManifest>,
+}
+
+impl<TStorage: ?Sized> ChunkManifestStorage<TStorage> {
+    fn manifest_keys_prefix<'a>(
+        &'a self,
+        prefix: &'a StorePrefix,
+    ) -> impl Iterator<Item = (&'a StoreKey, &'a ChunkReference)> + 'a {
+        self.manifest
+            .iter()
+            .filter(move |(key, _)| key.has_prefix(prefix))
+    }
+
+    fn merge_keys(&self, keys: StoreKeys, prefix: &StorePrefix) -> StoreKeys {
+        keys.into_iter()
+            .chain(
+                self.manifest_keys_prefix(prefix)
+                    .map(|(key, _)| key.clone()),
+            )
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    fn merge_keys_prefixes(
+        &self,
+        keys_prefixes: &StoreKeysPrefixes,
+        prefix: &StorePrefix,
+    ) -> StoreKeysPrefixes {
+        let mut keys = keys_prefixes.keys().clone();
+        let mut prefixes = keys_prefixes.prefixes().clone();
+        for (key, _) in self.manifest_keys_prefix(prefix) {
+            let child = &key.as_str()[prefix.as_str().len()..];
+            if let Some((child_prefix, _)) = child.split_once('/') {
+                let child_prefix = format!("{}{child_prefix}/", prefix.as_str());
+                prefixes.push(unsafe { StorePrefix::new_unchecked(child_prefix) });
+            } else {
+                keys.push(key.clone());
+            }
+        }
+        StoreKeysPrefixes::new(
+            keys.into_iter().sorted().dedup().collect(),
+            prefixes.into_iter().sorted().dedup().collect(),
+        )
+    }
+
+    fn manifest_size_prefix(&self, prefix: &StorePrefix) -> u64 {
+        self.manifest_keys_prefix(prefix)
+            .map(|(_, reference)| reference.length)
+            .sum()
+    }
+
+    fn check_writable(&self, key: &StoreKey) -> Result<(), StorageError> {
+        if self.manifest.contains_key(key) {
+            Err(StorageError::ReadOnly)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for ChunkManifestStorage<TStorage>
+{
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        if let Some(reference) = self.manifest.get(key) {
+            let byte_ranges = reference.byte_ranges(byte_ranges)?;
+            self.storage
+                .get_partial_values_key(&reference.key, &byte_ranges)?
+                .map_or_else(|| Err(reference.missing()), |values| Ok(Some(values)))
+        } else {
+            self.storage.get_partial_values_key(key, byte_ranges)
+        }
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        if let Some(reference) = self.manifest.get(key) {
+            Ok(Some(reference.length))
+        } else {
+            self.storage.size_key(key)
+        }
+    }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        if self.manifest.contains_key(key) {
+            Err(StorageError::Unsupported(
+                "manifest entries are not versioned".to_string(),
+            ))
+        } else {
+            self.storage.get_versioned(key)
+        }
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        if self.manifest.contains_key(key) {
+            Err(StorageError::Unsupported(
+                "manifest entries are not versioned".to_string(),
+            ))
+        } else {
+            self.storage.version_key(key)
+        }
+    }
+}
+
+impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
+    for ChunkManifestStorage<TStorage>
+{
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        Ok(self.merge_keys(self.storage.list()?, &StorePrefix::root()))
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        Ok(self.merge_keys(self.storage.list_prefix(prefix)?, prefix))
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        Ok(self.merge_keys_prefixes(&self.storage.list_dir(prefix)?, prefix))
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        Ok(self.storage.size_prefix(prefix)? + self.manifest_size_prefix(prefix))
+    }
+}
+
+impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
+    for ChunkManifestStorage<TStorage>
+{
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        self.check_writable(key)?;
+        self.storage.set(key, value)
+    }
+
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.check_writable(key)?;
+        self.storage.set_if_version(key, value, version)
+    }
+
+    fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        for key_offset_value in key_offset_values {
+            self.check_writable(key_offset_value.key())?;
+        }
+        self.storage.set_partial_values(key_offset_values)
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.check_writable(key)?;
+        self.storage.erase(key)
+    }
+
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.check_writable(key)?;
+        self.storage.erase_if_version(key, version)
+    }
+
+    fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.storage.erase_prefix(prefix)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
+    for ChunkManifestStorage<TStorage>
+{
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        if let Some(reference) = self.manifest.get(key) {
+            let byte_ranges = reference.byte_ranges(byte_ranges)?;
+            self.storage
+                .get_partial_values_key(&reference.key, &byte_ranges)
+                .await?
+                .map_or_else(|| Err(reference.missing()), |values| Ok(Some(values)))
+        } else {
+            self.storage.get_partial_values_key(key, byte_ranges).await
+        }
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        if let Some(reference) = self.manifest.get(key) {
+            Ok(Some(reference.length))
+        } else {
+            self.storage.size_key(key).await
+        }
+    }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        if self.manifest.contains_key(key) {
+            Err(StorageError::Unsupported(
+                "manifest entries are not versioned".to_string(),
+            ))
+        } else {
+            self.storage.get_versioned(key).await
+        }
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        if self.manifest.contains_key(key) {
+            Err(StorageError::Unsupported(
+                "manifest entries are not versioned".to_string(),
+            ))
+        } else {
+            self.storage.version_key(key).await
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncListableStorageTraits> AsyncListableStorageTraits
+    for ChunkManifestStorage<TStorage>
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        Ok(self.merge_keys(self.storage.list().await?, &StorePrefix::root()))
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        Ok(self.merge_keys(self.storage.list_prefix(prefix).await?, prefix))
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        Ok(self.merge_keys_prefixes(&self.storage.list_dir(prefix).await?, prefix))
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        Ok(self.storage.size_prefix(prefix).await? + self.manifest_size_prefix(prefix))
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
+    for ChunkManifestStorage<TStorage>
+{
+    async fn set(&self, key: &StoreKey, value: AsyncBytes) -> Result<(), StorageError> {
+        self.check_writable(key)?;
+        self.storage.set(key, value).await
+    }
+
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.check_writable(key)?;
+        self.storage.set_if_version(key, value, version).await
+    }
+
+    async fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        for key_offset_value in key_offset_values {
+            self.check_writable(key_offset_value.key())?;
+        }
+        self.storage.set_partial_values(key_offset_values).await
+    }
+
+    async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.check_writable(key)?;
+        self.storage.erase(key).await
+    }
+
+    async fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.check_writable(key)?;
+        self.storage.erase_if_version(key, version).await
+    }
+
+    async fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.storage.erase_prefix(prefix).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::{ArrayBuilder, DataType, FillValue},
+        array_subset::ArraySubset,
+        storage::store::MemoryStore,
+    };
+
+    use super::*;
+
+    #[test]
+    fn storage_transformer_chunk_manifest() -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MemoryStore::new());
+
+        // A "file" with a 4 byte header followed by two chunks of 2x2 u8 elements
+        store.set(
+            &StoreKey::new("data.bin")?,
+            vec![255, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 8].into(),
+        )?;
+
+        let path = NodePath::new("/array")?;
+        let manifest = BTreeMap::from([
+            (
+                "c/0/0".to_string(),
+                ChunkManifestEntry::new("data.bin", 4, 4),
+            ),
+            (
+                "c/1/1".to_string(),
+                ChunkManifestEntry::new("data.bin", 8, 4),
+            ),
+        ]);
+        let storage_transformer = ChunkManifestStorageTransformer::new(manifest, &path)?;
+        let array = ArrayBuilder::new(
+            vec![4, 4],
+            DataType::UInt8,
+            vec![2, 2].try_into()?,
+            FillValue::from(0u8),
+        )
+        .storage_transformers(vec![Arc::new(storage_transformer) as StorageTransformer].into())
+        .build(store.clone(), path.as_str())?;
+        array.store_metadata()?;
+
+        // Reopen the array from metadata
+        let array = crate::array::Array::open(store.clone(), path.as_str())?;
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&array.subset_all())?,
+            vec![1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 5, 6, 0, 0, 7, 8]
+        );
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[
+                1..3,
+                1..3
+            ]))?,
+            vec![4, 0, 0, 5]
+        );
+
+        // Manifest entries are read only
+        assert!(array.store_chunk_elements::<u8>(&[0, 0], &[0; 4]).is_err());
+        array.store_chunk_elements::<u8>(&[0, 1], &[9; 4])?;
+        assert_eq!(array.retrieve_chunk_elements::<u8>(&[0, 1])?, vec![9; 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn storage_transformer_chunk_manifest_byte_range() {
+        let reference = ChunkReference {
+            key: StoreKey::new("data.bin").unwrap(),
+            offset: 10,
+            length: 5,
+        };
+        assert_eq!(
+            reference
+                .byte_range(&ByteRange::FromStart(0, None))
+                .unwrap(),
+            ByteRange::FromStart(10, Some(5))
+        );
+        assert_eq!(
+            reference
+                .byte_range(&ByteRange::FromStart(1, Some(2)))
+                .unwrap(),
+            ByteRange::FromStart(11, Some(2))
+        );
+        assert_eq!(
+            reference.byte_range(&ByteRange::Suffix(2)).unwrap(),
+            ByteRange::FromStart(13, Some(2))
+        );
+        assert!(reference
+            .byte_range(&ByteRange::FromStart(4, Some(2)))
+            .is_err());
+        assert!(reference
+            .byte_range(&ByteRange::FromStart(6, None))
+            .is_err());
+        assert!(reference.byte_range(&ByteRange::Suffix(6)).is_err());
+    }
+}
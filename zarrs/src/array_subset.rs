@@ -5,10 +5,21 @@
 //! Many [`Array`](crate::array::Array) store and retrieve methods have an [`ArraySubset`] parameter.
 //! [`iterators`] includes various types of [`ArraySubset`] iterators.
 //!
+//! An [`OrthogonalSelection`] generalises an [`ArraySubset`] with strided slices and lists of indices in each dimension.
+//! A [`CoordinateSelection`] selects individual elements by their coordinates or with a boolean mask.
+//!
 //! This module also provides convenience functions for:
 //!  - computing the byte ranges of array subsets within an array with a fixed element size.
 
+mod coordinate_selection;
 pub mod iterators;
+mod orthogonal_selection;
+
+pub(crate) use coordinate_selection::CoordinateSelectionInSubset;
+pub use coordinate_selection::{CoordinateSelection, IncompatibleMaskShapeError};
+
+pub use orthogonal_selection::{DimensionSelection, OrthogonalSelection};
+pub(crate) use orthogonal_selection::{OrthogonalSelectionInSubset, OrthogonalSelectionIndexer};
 
 use std::{fmt::Debug, num::NonZeroU64, ops::Range};
 
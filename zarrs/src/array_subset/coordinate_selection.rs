@@ -0,0 +1,308 @@
+use derive_more::From;
+use thiserror::Error;
+use unsafe_cell_slice::UnsafeCellSlice;
+
+use crate::array::{ArrayIndices, ArrayShape};
+
+use super::{ArraySubset, IncompatibleDimensionalityError};
+
+/// A coordinate (point) selection.
+///
+/// A coordinate selection is a list of the [`ArrayIndices`] of individual elements, like coordinate indexing in `zarr-python`.
+/// The selection is one dimensional, with elements ordered by the order of the coordinates.
+/// Coordinates can be in any order and may be repeated.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct CoordinateSelection {
+    dimensionality: usize,
+    coordinates: Vec<ArrayIndices>,
+}
+
+impl CoordinateSelection {
+    /// Create a new coordinate selection of elements of an array with `dimensionality`.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleDimensionalityError`] if the length of any of the `coordinates` is not `dimensionality`.
+    pub fn new(
+        dimensionality: usize,
+        coordinates: Vec<ArrayIndices>,
+    ) -> Result<Self, IncompatibleDimensionalityError> {
+        if let Some(indices) = coordinates
+            .iter()
+            .find(|indices| indices.len() != dimensionality)
+        {
+            Err(IncompatibleDimensionalityError::new(
+                indices.len(),
+                dimensionality,
+            ))
+        } else {
+            Ok(Self {
+                dimensionality,
+                coordinates,
+            })
+        }
+    }
+
+    /// Create a new coordinate selection of the elements where a boolean `mask` with shape `mask_shape` is true.
+    ///
+    /// The `mask` is in C (row-major) order and the selected elements are in the same order.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleMaskShapeError`] if the length of `mask` does not match the number of elements of `mask_shape`.
+    pub fn new_from_mask(
+        mask: &[bool],
+        mask_shape: &[u64],
+    ) -> Result<Self, IncompatibleMaskShapeError> {
+        let num_elements: u64 = mask_shape.iter().product();
+        if mask.len() as u64 != num_elements {
+            return Err(IncompatibleMaskShapeError(mask.len(), mask_shape.to_vec()));
+        }
+        let coordinates = ArraySubset::new_with_shape(mask_shape.to_vec())
+            .indices()
+            .iter()
+            .zip(mask)
+            .filter_map(|(indices, selected)| selected.then_some(indices))
+            .collect();
+        Ok(Self {
+            dimensionality: mask_shape.len(),
+            coordinates,
+        })
+    }
+
+    /// Return the coordinates of the selected elements.
+    #[must_use]
+    pub fn coordinates(&self) -> &[ArrayIndices] {
+        &self.coordinates
+    }
+
+    /// Return the dimensionality of the selection.
+    #[must_use]
+    pub fn dimensionality(&self) -> usize {
+        self.dimensionality
+    }
+
+    /// Return the number of elements of the selection.
+    #[must_use]
+    pub fn num_elements(&self) -> u64 {
+        self.coordinates.len() as u64
+    }
+
+    /// Return the number of elements of the selection as a `usize`.
+    #[must_use]
+    pub fn num_elements_usize(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    /// Returns true if the selection has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.coordinates.is_empty()
+    }
+
+    /// Returns true if the selection is within the bounds of `array_shape`.
+    #[must_use]
+    pub fn inbounds(&self, array_shape: &[u64]) -> bool {
+        self.dimensionality == array_shape.len()
+            && self.coordinates.iter().all(|indices| {
+                std::iter::zip(indices, array_shape).all(|(index, shape)| index < shape)
+            })
+    }
+
+    /// Return the smallest array subset containing the selection.
+    ///
+    /// The array subset is empty if the selection is empty.
+    #[must_use]
+    pub fn bounding_subset(&self) -> ArraySubset {
+        bounding_subset(self.dimensionality, self.coordinates.iter())
+    }
+}
+
+impl TryFrom<Vec<ArrayIndices>> for CoordinateSelection {
+    type Error = IncompatibleDimensionalityError;
+
+    fn try_from(coordinates: Vec<ArrayIndices>) -> Result<Self, Self::Error> {
+        let dimensionality = coordinates.first().map_or(0, Vec::len);
+        Self::new(dimensionality, coordinates)
+    }
+}
+
+/// An incompatible mask and mask shape error.
+#[derive(Clone, Debug, Error, From)]
+#[error("mask with length {0} is incompatible with shape {1:?}")]
+pub struct IncompatibleMaskShapeError(usize, ArrayShape);
+
+/// Return the smallest array subset containing `coordinates`.
+fn bounding_subset<'a>(
+    dimensionality: usize,
+    coordinates: impl Iterator<Item = &'a ArrayIndices>,
+) -> ArraySubset {
+    let mut start = vec![u64::MAX; dimensionality];
+    let mut end = vec![0; dimensionality];
+    let mut is_empty = true;
+    for indices in coordinates {
+        is_empty = false;
+        for (start, end, &index) in itertools::izip!(&mut start, &mut end, indices) {
+            *start = (*start).min(index);
+            *end = (*end).max(index + 1);
+        }
+    }
+    if is_empty {
+        ArraySubset::new_with_shape(vec![0; dimensionality])
+    } else {
+        let ranges: Vec<_> = std::iter::zip(start, end)
+            .map(|(start, end)| start..end)
+            .collect();
+        ArraySubset::new_with_ranges(&ranges)
+    }
+}
+
+/// The elements of a [`CoordinateSelection`] within an array subset (e.g. a chunk).
+pub(crate) struct CoordinateSelectionInSubset {
+    /// The bounding subset of the selected elements, relative to the start of the array subset.
+    subset: ArraySubset,
+    /// The position in the selection and the linearised index in `subset` of the selected elements, ordered by position.
+    elements: Vec<(usize, usize)>,
+}
+
+impl CoordinateSelectionInSubset {
+    /// Create from the positions in the selection and the indices relative to the start of the array subset of the selected elements.
+    ///
+    /// # Panics
+    /// Panics if a linearised index exceeds [`usize::MAX`].
+    pub(crate) fn new(mut elements: Vec<(usize, ArrayIndices)>) -> Self {
+        elements.sort_unstable_by_key(|(position, _)| *position);
+        let dimensionality = elements.first().map_or(0, |(_, indices)| indices.len());
+        let subset = bounding_subset(dimensionality, elements.iter().map(|(_, indices)| indices));
+        let elements = elements
+            .into_iter()
+            .map(|(position, indices)| {
+                let index = itertools::izip!(&indices, subset.start(), subset.shape())
+                    .fold(0, |linear, (index, start, shape)| {
+                        linear * shape + index - start
+                    });
+                (position, usize::try_from(index).unwrap())
+            })
+            .collect();
+        Self { subset, elements }
+    }
+
+    /// Return the bounding subset of the selected elements, relative to the start of the array subset.
+    pub(crate) fn subset(&self) -> &ArraySubset {
+        &self.subset
+    }
+
+    /// Returns true if every element of [`subset`](Self::subset) is selected.
+    pub(crate) fn is_dense(&self) -> bool {
+        let mut selected = vec![false; self.subset.num_elements_usize()];
+        for (_, index) in &self.elements {
+            selected[*index] = true;
+        }
+        selected.into_iter().all(|selected| selected)
+    }
+
+    /// Copy the selected elements from the bytes of [`subset`](Self::subset) to the bytes of the selection.
+    ///
+    /// # Safety
+    /// The selected elements must not be concurrently written by another thread.
+    pub(crate) unsafe fn copy_to_selection(
+        &self,
+        subset_bytes: &[u8],
+        selection_bytes: &UnsafeCellSlice<u8>,
+        data_type_size: usize,
+    ) {
+        for (position, index) in &self.elements {
+            let selection_offset = position * data_type_size;
+            let subset_offset = index * data_type_size;
+            unsafe {
+                selection_bytes
+                    .index_mut(selection_offset..selection_offset + data_type_size)
+                    .copy_from_slice(&subset_bytes[subset_offset..subset_offset + data_type_size]);
+            }
+        }
+    }
+
+    /// Copy the selected elements from the bytes of the selection to the bytes of [`subset`](Self::subset).
+    ///
+    /// If an element is selected more than once, the last occurrence in the selection is copied.
+    pub(crate) fn copy_from_selection(
+        &self,
+        selection_bytes: &[u8],
+        subset_bytes: &mut [u8],
+        data_type_size: usize,
+    ) {
+        for (position, index) in &self.elements {
+            let selection_offset = position * data_type_size;
+            let subset_offset = index * data_type_size;
+            subset_bytes[subset_offset..subset_offset + data_type_size].copy_from_slice(
+                &selection_bytes[selection_offset..selection_offset + data_type_size],
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coordinate_selection() {
+        assert!(CoordinateSelection::new(2, vec![vec![0, 1], vec![2]]).is_err());
+        let selection: CoordinateSelection =
+            vec![vec![3, 1], vec![0, 5], vec![3, 1]].try_into().unwrap();
+        assert_eq!(selection.dimensionality(), 2);
+        assert_eq!(selection.num_elements(), 3);
+        assert!(selection.inbounds(&[4, 6]));
+        assert!(!selection.inbounds(&[4, 5]));
+        assert!(!selection.inbounds(&[4, 6, 1]));
+        assert_eq!(
+            selection.bounding_subset(),
+            ArraySubset::new_with_ranges(&[0..4, 1..6])
+        );
+        assert_eq!(
+            CoordinateSelection::new(2, vec![])
+                .unwrap()
+                .bounding_subset(),
+            ArraySubset::new_with_shape(vec![0, 0])
+        );
+    }
+
+    #[test]
+    fn coordinate_selection_from_mask() {
+        assert!(CoordinateSelection::new_from_mask(&[true, false], &[3]).is_err());
+        let selection =
+            CoordinateSelection::new_from_mask(&[false, true, false, true, false, true], &[2, 3])
+                .unwrap();
+        assert_eq!(
+            selection.coordinates(),
+            &[vec![0, 1], vec![1, 0], vec![1, 2]]
+        );
+    }
+
+    #[test]
+    fn coordinate_selection_in_subset() {
+        let in_subset = CoordinateSelectionInSubset::new(vec![
+            (2, vec![1, 3]),
+            (0, vec![2, 2]),
+            (3, vec![1, 3]),
+        ]);
+        assert_eq!(
+            in_subset.subset(),
+            &ArraySubset::new_with_ranges(&[1..3, 2..4])
+        );
+        assert!(!in_subset.is_dense());
+
+        let subset_bytes: Vec<u8> = (0..4).collect();
+        let mut selection_bytes = vec![u8::MAX; 4];
+        unsafe {
+            in_subset.copy_to_selection(
+                &subset_bytes,
+                &UnsafeCellSlice::new(&mut selection_bytes),
+                1,
+            );
+        }
+        assert_eq!(selection_bytes, vec![2, 255, 1, 1]);
+
+        let mut subset_bytes = vec![u8::MAX; 4];
+        in_subset.copy_from_selection(&[10, 11, 12, 13], &mut subset_bytes, 1);
+        assert_eq!(subset_bytes, vec![255, 13, 10, 255]);
+    }
+}
@@ -0,0 +1,536 @@
+use std::{fmt::Display, num::NonZeroU64, ops::Range};
+
+use itertools::izip;
+use unsafe_cell_slice::UnsafeCellSlice;
+
+use crate::array::ArrayShape;
+
+use super::ArraySubset;
+
+/// A selection of indices along a single dimension of an array.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DimensionSelection {
+    /// Every `step`th index in `start..end`.
+    Slice {
+        /// The start index (inclusive).
+        start: u64,
+        /// The end index (exclusive).
+        end: u64,
+        /// The step between indices.
+        step: NonZeroU64,
+    },
+    /// A list of indices.
+    ///
+    /// Indices can be in any order and may be repeated.
+    Indices(Vec<u64>),
+}
+
+impl DimensionSelection {
+    /// Create a selection of every `step`th index in `range`.
+    #[must_use]
+    pub fn new_strided(range: Range<u64>, step: NonZeroU64) -> Self {
+        Self::Slice {
+            start: range.start,
+            end: range.end,
+            step,
+        }
+    }
+
+    /// Create a selection of a list of `indices`.
+    #[must_use]
+    pub fn new_indices(indices: Vec<u64>) -> Self {
+        Self::Indices(indices)
+    }
+
+    /// Return the number of selected indices.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        match self {
+            Self::Slice { start, end, step } => end.saturating_sub(*start).div_ceil(step.get()),
+            Self::Indices(indices) => indices.len() as u64,
+        }
+    }
+
+    /// Returns true if no indices are selected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the selected indices.
+    ///
+    /// # Panics
+    /// Panics if the step of a slice exceeds [`usize::MAX`].
+    #[must_use]
+    pub fn to_indices(&self) -> Vec<u64> {
+        match self {
+            Self::Slice { start, end, step } => (*start..*end)
+                .step_by(usize::try_from(step.get()).unwrap())
+                .collect(),
+            Self::Indices(indices) => indices.clone(),
+        }
+    }
+
+    /// Return the range spanning the selected indices.
+    ///
+    /// Returns [`None`] if no indices are selected, or if the end of the range would exceed [`u64::MAX`].
+    #[must_use]
+    pub fn bounding_range(&self) -> Option<Range<u64>> {
+        match self {
+            Self::Slice { start, step, .. } => {
+                let len = self.len();
+                (len > 0).then(|| *start..start + (len - 1) * step.get() + 1)
+            }
+            Self::Indices(indices) => {
+                let min = indices.iter().min()?;
+                let max = indices.iter().max()?;
+                Some(*min..max.checked_add(1)?)
+            }
+        }
+    }
+
+    /// Return the selection as a range if it selects contiguous increasing indices.
+    #[must_use]
+    pub fn as_range(&self) -> Option<Range<u64>> {
+        match self {
+            Self::Slice { start, step, .. } => {
+                if step.get() == 1 || self.len() <= 1 {
+                    self.bounding_range().or(Some(*start..*start))
+                } else {
+                    None
+                }
+            }
+            Self::Indices(indices) => {
+                if indices.is_empty() {
+                    Some(0..0)
+                } else if indices
+                    .windows(2)
+                    .all(|w| w[0].checked_add(1) == Some(w[1]))
+                {
+                    self.bounding_range()
+                } else {
+                    None
+                }
+            }
+        }
+        .map(|range| range.start..range.end.max(range.start))
+    }
+}
+
+impl From<Range<u64>> for DimensionSelection {
+    fn from(range: Range<u64>) -> Self {
+        Self::new_strided(range, NonZeroU64::MIN)
+    }
+}
+
+impl From<Vec<u64>> for DimensionSelection {
+    fn from(indices: Vec<u64>) -> Self {
+        Self::Indices(indices)
+    }
+}
+
+impl Display for DimensionSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Slice { start, end, step } => write!(f, "{start}..{end};{step}"),
+            Self::Indices(indices) => write!(f, "{indices:?}"),
+        }
+    }
+}
+
+/// An orthogonal selection.
+///
+/// An orthogonal selection is composed of an independent [`DimensionSelection`] for each dimension of an array.
+/// It selects the outer product of the indices selected in each dimension, like orthogonal indexing in `zarr-python`.
+///
+/// The shape of the selection is the number of indices selected in each dimension.
+/// Selected elements are ordered by the order of the indices in each dimension.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct OrthogonalSelection(Vec<DimensionSelection>);
+
+impl OrthogonalSelection {
+    /// Create a new orthogonal selection from a [`DimensionSelection`] for each dimension.
+    #[must_use]
+    pub fn new(dimensions: Vec<DimensionSelection>) -> Self {
+        Self(dimensions)
+    }
+
+    /// Return the selection of each dimension.
+    #[must_use]
+    pub fn dimensions(&self) -> &[DimensionSelection] {
+        &self.0
+    }
+
+    /// Return the dimensionality of the selection.
+    #[must_use]
+    pub fn dimensionality(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return the shape of the selection.
+    #[must_use]
+    pub fn shape(&self) -> ArrayShape {
+        self.0.iter().map(DimensionSelection::len).collect()
+    }
+
+    /// Return the number of elements of the selection.
+    #[must_use]
+    pub fn num_elements(&self) -> u64 {
+        self.0.iter().map(DimensionSelection::len).product()
+    }
+
+    /// Return the number of elements of the selection as a `usize`.
+    ///
+    /// # Panics
+    /// Panics if [`num_elements()`](Self::num_elements()) is greater than [`usize::MAX`].
+    #[must_use]
+    pub fn num_elements_usize(&self) -> usize {
+        usize::try_from(self.num_elements()).unwrap()
+    }
+
+    /// Returns true if the selection has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(DimensionSelection::is_empty)
+    }
+
+    /// Returns true if the selection is within the bounds of `array_shape`.
+    #[must_use]
+    pub fn inbounds(&self, array_shape: &[u64]) -> bool {
+        self.dimensionality() == array_shape.len()
+            && std::iter::zip(&self.0, array_shape).all(|(dimension, shape)| {
+                dimension.is_empty()
+                    || dimension
+                        .bounding_range()
+                        .is_some_and(|range| range.end <= *shape)
+            })
+    }
+
+    /// Return the smallest array subset containing the selection.
+    ///
+    /// The array subset is empty if the selection is empty.
+    #[must_use]
+    pub fn bounding_subset(&self) -> ArraySubset {
+        let ranges: Vec<_> = self
+            .0
+            .iter()
+            .map(|dimension| dimension.bounding_range().unwrap_or(0..0))
+            .collect();
+        ArraySubset::new_with_ranges(&ranges)
+    }
+
+    /// Return the selection as an [`ArraySubset`] if it selects contiguous increasing indices in every dimension.
+    #[must_use]
+    pub fn to_array_subset(&self) -> Option<ArraySubset> {
+        let ranges = self
+            .0
+            .iter()
+            .map(DimensionSelection::as_range)
+            .collect::<Option<Vec<_>>>()?;
+        Some(ArraySubset::new_with_ranges(&ranges))
+    }
+}
+
+impl From<Vec<DimensionSelection>> for OrthogonalSelection {
+    fn from(dimensions: Vec<DimensionSelection>) -> Self {
+        Self::new(dimensions)
+    }
+}
+
+impl From<&ArraySubset> for OrthogonalSelection {
+    fn from(array_subset: &ArraySubset) -> Self {
+        Self(
+            array_subset
+                .to_ranges()
+                .into_iter()
+                .map(DimensionSelection::from)
+                .collect(),
+        )
+    }
+}
+
+impl From<ArraySubset> for OrthogonalSelection {
+    fn from(array_subset: ArraySubset) -> Self {
+        Self::from(&array_subset)
+    }
+}
+
+impl Display for OrthogonalSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, dimension) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{dimension}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Maps the elements of an [`OrthogonalSelection`] to array subsets (e.g. chunks).
+pub(crate) struct OrthogonalSelectionIndexer {
+    /// The selected indices and their positions in the selection, sorted by index, for each dimension.
+    sorted_indices: Vec<Vec<(u64, u64)>>,
+}
+
+impl OrthogonalSelectionIndexer {
+    pub(crate) fn new(selection: &OrthogonalSelection) -> Self {
+        let sorted_indices = selection
+            .dimensions()
+            .iter()
+            .map(|dimension| {
+                let mut indices: Vec<(u64, u64)> =
+                    dimension.to_indices().into_iter().zip(0..).collect();
+                indices.sort_unstable();
+                indices
+            })
+            .collect();
+        Self { sorted_indices }
+    }
+
+    /// Return the selected indices and their positions in the selection, sorted by index, for each dimension.
+    pub(crate) fn sorted_indices(&self) -> &[Vec<(u64, u64)>] {
+        &self.sorted_indices
+    }
+
+    /// Return the selected elements within `array_subset`, or [`None`] if there are none.
+    pub(crate) fn in_subset(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Option<OrthogonalSelectionInSubset> {
+        let dimensionality = self.sorted_indices.len();
+        let mut ranges = Vec::with_capacity(dimensionality);
+        let mut positions = Vec::with_capacity(dimensionality);
+        for (sorted_indices, &start, &shape) in izip!(
+            &self.sorted_indices,
+            array_subset.start(),
+            array_subset.shape()
+        ) {
+            let end = start + shape;
+            let lower = sorted_indices.partition_point(|(index, _)| *index < start);
+            let upper = sorted_indices.partition_point(|(index, _)| *index < end);
+            let sorted_indices = &sorted_indices[lower..upper];
+            let first = sorted_indices.first()?.0;
+            let last = sorted_indices.last()?.0;
+            ranges.push(first - start..last - start + 1);
+            positions.push(
+                sorted_indices
+                    .iter()
+                    .map(|(index, position)| (*position, index - first))
+                    .collect(),
+            );
+        }
+        Some(OrthogonalSelectionInSubset {
+            subset: ArraySubset::new_with_ranges(&ranges),
+            positions,
+        })
+    }
+}
+
+/// The elements of an [`OrthogonalSelection`] within an array subset.
+pub(crate) struct OrthogonalSelectionInSubset {
+    /// The bounding subset of the selected elements, relative to the start of the array subset.
+    subset: ArraySubset,
+    /// The position in the selection and the index relative to `subset` of the selected elements, for each dimension.
+    positions: Vec<Vec<(u64, u64)>>,
+}
+
+impl OrthogonalSelectionInSubset {
+    /// Return the bounding subset of the selected elements, relative to the start of the array subset.
+    pub(crate) fn subset(&self) -> &ArraySubset {
+        &self.subset
+    }
+
+    /// Returns true if every element of [`subset`](Self::subset) is selected exactly once.
+    pub(crate) fn is_dense(&self) -> bool {
+        std::iter::zip(&self.positions, self.subset.shape()).all(|(positions, &shape)| {
+            positions.len() as u64 == shape && positions.windows(2).all(|w| w[1].1 == w[0].1 + 1)
+        })
+    }
+
+    /// Call `f` with the linearised index in the selection and in [`subset`](Self::subset) of each selected element.
+    fn for_each_element(&self, selection_shape: &[u64], mut f: impl FnMut(usize, usize)) {
+        let strides = |shape: &[u64]| {
+            let mut strides = vec![1; shape.len()];
+            for i in (0..shape.len().saturating_sub(1)).rev() {
+                strides[i] = strides[i + 1] * shape[i + 1];
+            }
+            strides
+        };
+        let selection_strides = strides(selection_shape);
+        let subset_strides = strides(self.subset.shape());
+
+        let dimensionality = self.positions.len();
+        let mut indices = vec![0; dimensionality];
+        loop {
+            let (mut selection_index, mut subset_index) = (0, 0);
+            for (positions, &index, selection_stride, subset_stride) in izip!(
+                &self.positions,
+                &indices,
+                &selection_strides,
+                &subset_strides
+            ) {
+                let (position, index) = positions[index];
+                selection_index += position * selection_stride;
+                subset_index += index * subset_stride;
+            }
+            f(
+                usize::try_from(selection_index).unwrap(),
+                usize::try_from(subset_index).unwrap(),
+            );
+
+            // Increment the indices
+            let mut dimension = dimensionality;
+            loop {
+                if dimension == 0 {
+                    return;
+                }
+                dimension -= 1;
+                indices[dimension] += 1;
+                if indices[dimension] < self.positions[dimension].len() {
+                    break;
+                }
+                indices[dimension] = 0;
+            }
+        }
+    }
+
+    /// Copy the selected elements from the bytes of [`subset`](Self::subset) to the bytes of the selection.
+    ///
+    /// # Safety
+    /// The selected elements must not be concurrently written by another thread.
+    pub(crate) unsafe fn copy_to_selection(
+        &self,
+        subset_bytes: &[u8],
+        selection_bytes: &UnsafeCellSlice<u8>,
+        selection_shape: &[u64],
+        data_type_size: usize,
+    ) {
+        self.for_each_element(selection_shape, |selection_index, subset_index| {
+            let selection_offset = selection_index * data_type_size;
+            let subset_offset = subset_index * data_type_size;
+            unsafe {
+                selection_bytes
+                    .index_mut(selection_offset..selection_offset + data_type_size)
+                    .copy_from_slice(&subset_bytes[subset_offset..subset_offset + data_type_size]);
+            }
+        });
+    }
+
+    /// Copy the selected elements from the bytes of the selection to the bytes of [`subset`](Self::subset).
+    pub(crate) fn copy_from_selection(
+        &self,
+        selection_bytes: &[u8],
+        subset_bytes: &mut [u8],
+        selection_shape: &[u64],
+        data_type_size: usize,
+    ) {
+        self.for_each_element(selection_shape, |selection_index, subset_index| {
+            let selection_offset = selection_index * data_type_size;
+            let subset_offset = subset_index * data_type_size;
+            subset_bytes[subset_offset..subset_offset + data_type_size].copy_from_slice(
+                &selection_bytes[selection_offset..selection_offset + data_type_size],
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dimension_selection() {
+        let slice = DimensionSelection::new_strided(1..8, NonZeroU64::new(3).unwrap());
+        assert_eq!(slice.len(), 3);
+        assert_eq!(slice.to_indices(), vec![1, 4, 7]);
+        assert_eq!(slice.bounding_range(), Some(1..8));
+        assert_eq!(slice.as_range(), None);
+        assert_eq!(slice.to_string(), "1..8;3");
+
+        let slice = DimensionSelection::new_strided(2..2, NonZeroU64::new(3).unwrap());
+        assert!(slice.is_empty());
+        assert_eq!(slice.bounding_range(), None);
+        assert_eq!(slice.as_range(), Some(2..2));
+
+        let indices = DimensionSelection::from(vec![5, 1, 5]);
+        assert_eq!(indices.len(), 3);
+        assert_eq!(indices.bounding_range(), Some(1..6));
+        assert_eq!(indices.as_range(), None);
+        assert_eq!(
+            DimensionSelection::from(vec![3, 4, 5]).as_range(),
+            Some(3..6)
+        );
+
+        let indices = DimensionSelection::from(vec![u64::MAX - 1, u64::MAX]);
+        assert_eq!(indices.bounding_range(), None);
+        assert_eq!(indices.as_range(), None);
+        assert!(!OrthogonalSelection::new(vec![indices]).inbounds(&[u64::MAX]));
+    }
+
+    #[test]
+    fn orthogonal_selection() {
+        let selection = OrthogonalSelection::new(vec![
+            DimensionSelection::new_strided(0..8, NonZeroU64::new(2).unwrap()),
+            vec![6, 1].into(),
+        ]);
+        assert_eq!(selection.shape(), vec![4, 2]);
+        assert_eq!(selection.num_elements(), 8);
+        assert!(selection.inbounds(&[8, 7]));
+        assert!(!selection.inbounds(&[8, 6]));
+        assert_eq!(
+            selection.bounding_subset(),
+            ArraySubset::new_with_ranges(&[0..7, 1..7])
+        );
+        assert_eq!(selection.to_array_subset(), None);
+        assert_eq!(selection.to_string(), "[0..8;2, [6, 1]]");
+
+        let array_subset = ArraySubset::new_with_ranges(&[1..3, 4..6]);
+        assert_eq!(
+            OrthogonalSelection::from(&array_subset).to_array_subset(),
+            Some(array_subset)
+        );
+    }
+
+    #[test]
+    fn orthogonal_selection_indexer() {
+        let selection = OrthogonalSelection::new(vec![
+            DimensionSelection::new_strided(0..8, NonZeroU64::new(2).unwrap()),
+            vec![6, 1, 2].into(),
+        ]);
+        let indexer = OrthogonalSelectionIndexer::new(&selection);
+        assert!(indexer
+            .in_subset(&ArraySubset::new_with_ranges(&[0..4, 3..6]))
+            .is_none());
+
+        let in_subset = indexer
+            .in_subset(&ArraySubset::new_with_ranges(&[0..4, 0..4]))
+            .unwrap();
+        assert_eq!(
+            in_subset.subset(),
+            &ArraySubset::new_with_ranges(&[0..3, 1..3])
+        );
+        assert!(!in_subset.is_dense());
+
+        // Rows 0 and 2 and columns 1 and 2 of the array subset are selected at positions 0 and 1 and 1 and 2
+        let subset_bytes: Vec<u8> = (0..6).collect();
+        let mut selection_bytes = vec![u8::MAX; 12];
+        unsafe {
+            in_subset.copy_to_selection(
+                &subset_bytes,
+                &UnsafeCellSlice::new(&mut selection_bytes),
+                &selection.shape(),
+                1,
+            );
+        }
+        assert_eq!(
+            selection_bytes,
+            vec![255, 0, 1, 255, 4, 5, 255, 255, 255, 255, 255, 255]
+        );
+
+        let mut subset_bytes = vec![u8::MAX; 6];
+        in_subset.copy_from_selection(&selection_bytes, &mut subset_bytes, &selection.shape(), 1);
+        assert_eq!(subset_bytes, vec![0, 1, 255, 255, 4, 5]);
+    }
+}
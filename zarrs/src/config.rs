@@ -54,6 +54,14 @@ use crate::array::{codec::CodecOptions, ArrayMetadataOptions};
 /// This option sets the preferred minimum chunk concurrency.
 /// The concurrency of internal codecs is adjusted to accomodate for the chunk concurrency in accordance with the concurrent target set in the [`CodecOptions`] parameter of an encode or decode method.
 ///
+/// ### Conditional Chunk Updates
+/// > default: [`false`]
+///
+/// [`CodecOptions::conditional_chunk_updates()`] defaults to [`Config::conditional_chunk_updates()`].
+///
+/// If `true`, read-modify-write chunk updates (e.g. [`Array::store_chunk_subset`](crate::array::Array::store_chunk_subset)) only store a chunk if it was not concurrently modified, and are otherwise retried a limited number of times.
+/// This requires a store that supports conditional writes (e.g. a `FilesystemStore` with file locking enabled for writers in multiple processes).
+///
 /// ## Metadata Options
 ///
 /// ### Experimental Codec Store Metadata If Encode Only
@@ -96,6 +104,7 @@ use crate::array::{codec::CodecOptions, ArrayMetadataOptions};
 ///
 /// Sets the names used when serialising and deserialising the names of experimental codecs.
 /// Deserialisation also accepts the standard `IDENTIFIER` of the codec.
+/// Codecs compatible with `numcodecs` are named `numcodecs.<id>` by default (e.g. `numcodecs.zlib`).
 ///
 /// ### Experimental Partial Encoding
 /// > default: [`false`]
@@ -117,6 +126,7 @@ pub struct Config {
     include_zarrs_metadata: bool,
     experimental_codec_names: HashMap<String, String>,
     experimental_partial_encoding: bool,
+    conditional_chunk_updates: bool,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -125,8 +135,20 @@ impl Default for Config {
         #[rustfmt::skip]
         let experimental_codec_names = HashMap::from([
             // Array to array
+            #[cfg(feature = "astype")]
+            (codec::astype::IDENTIFIER.to_string(), "numcodecs.astype".to_string()),
             #[cfg(feature = "bitround")]
             (codec::bitround::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_array/bitround".to_string()),
+            #[cfg(feature = "categorize")]
+            (codec::categorize::IDENTIFIER.to_string(), "numcodecs.categorize".to_string()),
+            #[cfg(feature = "delta")]
+            (codec::delta::IDENTIFIER.to_string(), "numcodecs.delta".to_string()),
+            #[cfg(feature = "fixedscaleoffset")]
+            (codec::fixedscaleoffset::IDENTIFIER.to_string(), "numcodecs.fixedscaleoffset".to_string()),
+            #[cfg(feature = "packbits")]
+            (codec::packbits::IDENTIFIER.to_string(), "numcodecs.packbits".to_string()),
+            #[cfg(feature = "quantize")]
+            (codec::quantize::IDENTIFIER.to_string(), "numcodecs.quantize".to_string()),
             // Array to bytes
             #[cfg(feature = "zfp")]
             (codec::zfp::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_bytes/zfp".to_string()),
@@ -134,8 +156,20 @@ impl Default for Config {
             (codec::pcodec::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_bytes/pcodec".to_string()),
             (codec::vlen::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_bytes/vlen".to_string()),
             // Bytes to bytes
+            #[cfg(feature = "bitshuffle")]
+            (codec::bitshuffle::IDENTIFIER.to_string(), "https://codec.zarrs.dev/bytes_to_bytes/bitshuffle".to_string()),
             #[cfg(feature = "bz2")]
             (codec::bz2::IDENTIFIER.to_string(), "https://codec.zarrs.dev/bytes_to_bytes/bz2".to_string()),
+            #[cfg(feature = "fletcher32")]
+            (codec::fletcher32::IDENTIFIER.to_string(), "numcodecs.fletcher32".to_string()),
+            #[cfg(feature = "lz4")]
+            (codec::lz4::IDENTIFIER.to_string(), "numcodecs.lz4".to_string()),
+            #[cfg(feature = "lzma")]
+            (codec::lzma::IDENTIFIER.to_string(), "numcodecs.lzma".to_string()),
+            #[cfg(feature = "shuffle")]
+            (codec::shuffle::IDENTIFIER.to_string(), "numcodecs.shuffle".to_string()),
+            #[cfg(feature = "zlib")]
+            (codec::zlib::IDENTIFIER.to_string(), "numcodecs.zlib".to_string()),
         ]);
 
         Self {
@@ -149,6 +183,7 @@ impl Default for Config {
             include_zarrs_metadata: true,
             experimental_codec_names,
             experimental_partial_encoding: false,
+            conditional_chunk_updates: false,
         }
     }
 }
@@ -278,6 +313,18 @@ impl Config {
         self.experimental_partial_encoding = experimental_partial_encoding;
         self
     }
+
+    /// Get the [conditional chunk updates](#conditional-chunk-updates) configuration.
+    #[must_use]
+    pub fn conditional_chunk_updates(&self) -> bool {
+        self.conditional_chunk_updates
+    }
+
+    /// Set the [conditional chunk updates](#conditional-chunk-updates) configuration.
+    pub fn set_conditional_chunk_updates(&mut self, conditional_chunk_updates: bool) -> &mut Self {
+        self.conditional_chunk_updates = conditional_chunk_updates;
+        self
+    }
 }
 
 static CONFIG: OnceLock<RwLock<Config>> = OnceLock::new();
@@ -39,13 +39,14 @@ use crate::{
         global_config, MetadataConvertVersion, MetadataEraseVersion, MetadataRetrieveVersion,
     },
     metadata::{
-        v2::GroupMetadataV2,
+        v2::{ConsolidatedMetadataV2, GroupMetadataV2},
         v2_to_v3::group_metadata_v2_to_v3,
-        v3::{AdditionalFields, UnsupportedAdditionalFieldError},
+        v3::{AdditionalFields, ConsolidatedMetadata, UnsupportedAdditionalFieldError},
     },
     node::{
-        _get_child_nodes, meta_key_v2_attributes, meta_key_v2_group, meta_key_v3, Node, NodePath,
-        NodePathError,
+        _get_child_nodes, consolidated_child_nodes, consolidated_metadata_from_nodes,
+        consolidated_metadata_from_v2, flatten_nodes, meta_key_v2_attributes,
+        meta_key_v2_consolidated, meta_key_v2_group, meta_key_v3, Node, NodePath, NodePathError,
     },
     storage::{ReadableStorageTraits, StorageError, StorageHandle, WritableStorageTraits},
 };
@@ -139,6 +140,62 @@ impl<TStorage: ?Sized> Group<TStorage> {
             GroupMetadata::V2(metadata) => &mut metadata.additional_fields,
         }
     }
+
+    /// Get the consolidated metadata of the hierarchy below the group, if present.
+    #[must_use]
+    pub const fn consolidated_metadata(&self) -> Option<&ConsolidatedMetadata> {
+        self.metadata.consolidated_metadata()
+    }
+
+    /// Set the consolidated metadata of the hierarchy below the group.
+    ///
+    /// Consolidated metadata can be created with [`consolidate_metadata`](Group::consolidate_metadata).
+    /// Use [`store_metadata`](Group::store_metadata) to write it to the store.
+    pub fn set_consolidated_metadata(
+        &mut self,
+        consolidated_metadata: Option<ConsolidatedMetadata>,
+    ) -> &mut Self {
+        self.metadata
+            .set_consolidated_metadata(consolidated_metadata);
+        self
+    }
+
+    /// Return the children of the group from its consolidated metadata without accessing the store.
+    ///
+    /// Returns [`None`] if the group does not have consolidated metadata.
+    ///
+    /// # Errors
+    /// Returns a [`NodePathError`] if the consolidated metadata includes an invalid path.
+    pub fn consolidated_children(
+        &self,
+        recursive: bool,
+    ) -> Result<Option<Vec<Node>>, NodePathError> {
+        self.consolidated_metadata()
+            .map(|consolidated_metadata| {
+                consolidated_child_nodes(&self.path, consolidated_metadata, recursive)
+            })
+            .transpose()
+    }
+
+    /// Return the paths of the children of the group from its consolidated metadata without accessing the store.
+    ///
+    /// If `recursive` is true, the paths of all descendants are included.
+    /// Returns [`None`] if the group does not have consolidated metadata.
+    ///
+    /// # Errors
+    /// Returns a [`NodePathError`] if the consolidated metadata includes an invalid path.
+    pub fn consolidated_child_paths(
+        &self,
+        recursive: bool,
+    ) -> Result<Option<Vec<NodePath>>, NodePathError> {
+        Ok(self.consolidated_children(recursive)?.map(|children| {
+            flatten_nodes(children)
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        }))
+    }
+
     /// Return the underlying group metadata.
     #[must_use]
     pub fn metadata(&self) -> &GroupMetadata {
@@ -224,6 +281,13 @@ impl<TStorage: ?Sized + ReadableStorageTraits> Group<TStorage> {
                         StorageError::InvalidMetadata(attributes_key, err.to_string())
                     })?;
                 }
+                let consolidated_key = meta_key_v2_consolidated(&node_path);
+                if let Some(consolidated_metadata) = storage.get(&consolidated_key)? {
+                    metadata.consolidated_metadata = Some(consolidated_metadata_from_v2(
+                        consolidated_key,
+                        &consolidated_metadata,
+                    )?);
+                }
                 return Self::new_with_metadata(storage, path, GroupMetadata::V2(metadata));
             }
         }
@@ -236,11 +300,33 @@ impl<TStorage: ?Sized + ReadableStorageTraits> Group<TStorage> {
 impl<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits> Group<TStorage> {
     /// Return the children of the group
     ///
+    /// If the group has consolidated metadata, the children are retrieved from it rather than listing the store.
+    ///
     /// # Errors
     /// Returns [`StorageError`] if there is an underlying error with the store.
     pub fn children(&self, recursive: bool) -> Result<Vec<Node>, StorageError> {
+        if let Some(children) = self
+            .consolidated_children(recursive)
+            .map_err(|err| StorageError::Other(err.to_string()))?
+        {
+            Ok(children)
+        } else {
+            #[allow(clippy::used_underscore_items)]
+            _get_child_nodes(&self.storage, &self.path, recursive)
+        }
+    }
+
+    /// Create consolidated metadata of the hierarchy below the group by listing the store.
+    ///
+    /// Any existing consolidated metadata of the group is ignored.
+    /// Use [`set_consolidated_metadata`](Group::set_consolidated_metadata) and [`store_metadata`](Group::store_metadata) to persist the result.
+    ///
+    /// # Errors
+    /// Returns [`StorageError`] if there is an underlying error with the store.
+    pub fn consolidate_metadata(&self) -> Result<ConsolidatedMetadata, StorageError> {
         #[allow(clippy::used_underscore_items)]
-        _get_child_nodes(&self.storage, &self.path, recursive)
+        let nodes = _get_child_nodes(&self.storage, &self.path, true)?;
+        Ok(consolidated_metadata_from_nodes(&self.path, &nodes))
     }
 
     /// Return the children of the group that are [`Group`]s
@@ -289,11 +375,12 @@ impl<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits> Group<TSt
 
     /// Return the paths of the groups children
     ///
+    /// If `recursive` is true, the paths of all descendants are included.
+    ///
     /// # Errors
     /// Returns [`StorageError`] if there is an underlying error with the store.
     pub fn child_paths(&self, recursive: bool) -> Result<Vec<NodePath>, StorageError> {
-        let paths = self
-            .children(recursive)?
+        let paths = flatten_nodes(self.children(recursive)?)
             .into_iter()
             .map(Into::into)
             .collect();
@@ -302,11 +389,12 @@ impl<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits> Group<TSt
 
     /// Return the paths of the groups children if the child is a group
     ///
+    /// If `recursive` is true, the paths of all descendant groups are included.
+    ///
     /// # Errors
     /// Returns [`StorageError`] if there is an underlying error with the store.
     pub fn child_group_paths(&self, recursive: bool) -> Result<Vec<NodePath>, StorageError> {
-        let paths = self
-            .children(recursive)?
+        let paths = flatten_nodes(self.children(recursive)?)
             .into_iter()
             .filter_map(|node| match node.metadata() {
                 NodeMetadata::Group(_) => Some(node.into()),
@@ -318,11 +406,12 @@ impl<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits> Group<TSt
 
     /// Return the paths of the groups children if the child is an array
     ///
+    /// If `recursive` is true, the paths of all descendant arrays are included.
+    ///
     /// # Errors
     /// Returns [`StorageError`] if there is an underlying error with the store.
     pub fn child_array_paths(&self, recursive: bool) -> Result<Vec<NodePath>, StorageError> {
-        let paths = self
-            .children(recursive)?
+        let paths = flatten_nodes(self.children(recursive)?)
             .into_iter()
             .filter_map(|node| match node.metadata() {
                 NodeMetadata::Array(_) => Some(node.into()),
@@ -373,6 +462,13 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> Group<TStorage> {
                         StorageError::InvalidMetadata(attributes_key, err.to_string())
                     })?;
                 }
+                let consolidated_key = meta_key_v2_consolidated(&node_path);
+                if let Some(consolidated_metadata) = storage.get(&consolidated_key).await? {
+                    metadata.consolidated_metadata = Some(consolidated_metadata_from_v2(
+                        consolidated_key,
+                        &consolidated_metadata,
+                    )?);
+                }
                 return Self::new_with_metadata(storage, path, GroupMetadata::V2(metadata));
             }
         }
@@ -386,11 +482,28 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> Group<TStorage> {
 impl<TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits> Group<TStorage> {
     /// Return the children of the group
     ///
+    /// If the group has consolidated metadata, the children are retrieved from it rather than listing the store.
+    ///
     /// # Errors
     /// Returns [`StorageError`] if there is an underlying error with the store.
     pub async fn async_children(&self, recursive: bool) -> Result<Vec<Node>, StorageError> {
+        if let Some(children) = self
+            .consolidated_children(recursive)
+            .map_err(|err| StorageError::Other(err.to_string()))?
+        {
+            Ok(children)
+        } else {
+            #[allow(clippy::used_underscore_items)]
+            _async_get_child_nodes(&self.storage, &self.path, recursive).await
+        }
+    }
+
+    /// Async variant of [`consolidate_metadata`](Group::consolidate_metadata).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_consolidate_metadata(&self) -> Result<ConsolidatedMetadata, StorageError> {
         #[allow(clippy::used_underscore_items)]
-        _async_get_child_nodes(&self.storage, &self.path, recursive).await
+        let nodes = _async_get_child_nodes(&self.storage, &self.path, true).await?;
+        Ok(consolidated_metadata_from_nodes(&self.path, &nodes))
     }
 
     /// Return the children of the group that are [`Group`]s
@@ -493,6 +606,17 @@ impl<TStorage: ?Sized + WritableStorageTraits> Group<TStorage> {
             GroupMetadata::V2(metadata) => {
                 let mut metadata = metadata.clone();
 
+                if metadata.consolidated_metadata.is_some() {
+                    // Store .zmetadata
+                    let key = meta_key_v2_consolidated(path);
+                    let json = ConsolidatedMetadataV2::from_group_metadata(&metadata)
+                        .and_then(|metadata| serde_json::to_vec_pretty(&metadata))
+                        .map_err(|err| {
+                            StorageError::InvalidMetadata(key.clone(), err.to_string())
+                        })?;
+                    storage_handle.set(&key, json.into())?;
+                }
+
                 if !metadata.attributes.is_empty() {
                     // Store .zgroup
                     let key = meta_key_v2_attributes(path);
@@ -538,18 +662,21 @@ impl<TStorage: ?Sized + WritableStorageTraits> Group<TStorage> {
                 GroupMetadata::V3(_) => storage_handle.erase(&meta_key_v3(self.path())),
                 GroupMetadata::V2(_) => {
                     storage_handle.erase(&meta_key_v2_group(self.path()))?;
-                    storage_handle.erase(&meta_key_v2_attributes(self.path()))
+                    storage_handle.erase(&meta_key_v2_attributes(self.path()))?;
+                    storage_handle.erase(&meta_key_v2_consolidated(self.path()))
                 }
             },
             MetadataEraseVersion::All => {
                 storage_handle.erase(&meta_key_v3(self.path()))?;
                 storage_handle.erase(&meta_key_v2_group(self.path()))?;
-                storage_handle.erase(&meta_key_v2_attributes(self.path()))
+                storage_handle.erase(&meta_key_v2_attributes(self.path()))?;
+                storage_handle.erase(&meta_key_v2_consolidated(self.path()))
             }
             MetadataEraseVersion::V3 => storage_handle.erase(&meta_key_v3(self.path())),
             MetadataEraseVersion::V2 => {
                 storage_handle.erase(&meta_key_v2_group(self.path()))?;
-                storage_handle.erase(&meta_key_v2_attributes(self.path()))
+                storage_handle.erase(&meta_key_v2_attributes(self.path()))?;
+                storage_handle.erase(&meta_key_v2_consolidated(self.path()))
             }
         }
     }
@@ -587,6 +714,17 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
             GroupMetadata::V2(metadata) => {
                 let mut metadata = metadata.clone();
 
+                if metadata.consolidated_metadata.is_some() {
+                    // Store .zmetadata
+                    let key = meta_key_v2_consolidated(path);
+                    let json = ConsolidatedMetadataV2::from_group_metadata(&metadata)
+                        .and_then(|metadata| serde_json::to_vec_pretty(&metadata))
+                        .map_err(|err| {
+                            StorageError::InvalidMetadata(key.clone(), err.to_string())
+                        })?;
+                    storage_handle.set(&key, json.into()).await?;
+                }
+
                 if !metadata.attributes.is_empty() {
                     // Store .zgroup
                     let key = meta_key_v2_attributes(path);
@@ -631,6 +769,9 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
                         .await?;
                     storage_handle
                         .erase(&meta_key_v2_attributes(self.path()))
+                        .await?;
+                    storage_handle
+                        .erase(&meta_key_v2_consolidated(self.path()))
                         .await
                 }
             },
@@ -641,6 +782,9 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
                     .await?;
                 storage_handle
                     .erase(&meta_key_v2_attributes(self.path()))
+                    .await?;
+                storage_handle
+                    .erase(&meta_key_v2_consolidated(self.path()))
                     .await
             }
             MetadataEraseVersion::V3 => storage_handle.erase(&meta_key_v3(self.path())).await,
@@ -650,6 +794,9 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
                     .await?;
                 storage_handle
                     .erase(&meta_key_v2_attributes(self.path()))
+                    .await?;
+                storage_handle
+                    .erase(&meta_key_v2_consolidated(self.path()))
                     .await
             }
         }
@@ -658,7 +805,10 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
 
 #[cfg(test)]
 mod tests {
-    use crate::storage::{store::MemoryStore, StoreKey};
+    use crate::{
+        array::ArrayMetadata,
+        storage::{store::MemoryStore, StoreKey},
+    };
 
     use super::*;
 
@@ -816,6 +966,112 @@ mod tests {
         assert_eq!(group_copy.metadata(), group.metadata());
     }
 
+    #[test]
+    fn group_consolidated_metadata_v3() {
+        use crate::array::{ArrayBuilder, DataType, FillValue};
+
+        let store = std::sync::Arc::new(MemoryStore::new());
+        let mut group = GroupBuilder::new().build(store.clone(), "/").unwrap();
+        group.store_metadata().unwrap();
+        GroupBuilder::new()
+            .build(store.clone(), "/a")
+            .unwrap()
+            .store_metadata()
+            .unwrap();
+        ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt8,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .build(store.clone(), "/a/b")
+        .unwrap()
+        .store_metadata()
+        .unwrap();
+
+        assert!(group.consolidated_children(true).unwrap().is_none());
+        let consolidated_metadata = group.consolidate_metadata().unwrap();
+        assert_eq!(
+            consolidated_metadata.metadata.keys().collect::<Vec<_>>(),
+            vec!["a", "a/b"]
+        );
+        group.set_consolidated_metadata(Some(consolidated_metadata));
+        group.store_metadata().unwrap();
+
+        // Remove the array metadata, the children should still be retrieved from the consolidated metadata
+        store
+            .erase(&StoreKey::new("a/b/zarr.json").unwrap())
+            .unwrap();
+        let group = Group::open(store.clone(), "/").unwrap();
+        assert!(group.consolidated_metadata().is_some());
+        assert_eq!(
+            group.child_paths(true).unwrap(),
+            vec![NodePath::new("/a").unwrap(), NodePath::new("/a/b").unwrap()]
+        );
+        assert_eq!(
+            group.consolidated_child_paths(false).unwrap(),
+            Some(vec![NodePath::new("/a").unwrap()])
+        );
+        assert_eq!(
+            group.child_array_paths(true).unwrap(),
+            vec![NodePath::new("/a/b").unwrap()]
+        );
+        assert_eq!(group.child_array_paths(false).unwrap(), vec![]);
+        let node = Node::open(&store, "/").unwrap();
+        assert_eq!(node.children().len(), 1);
+        assert_eq!(node.children()[0].children().len(), 1);
+        let node = Node::open_consolidated(&store, "/").unwrap();
+        assert_eq!(node.children()[0].children().len(), 1);
+        assert!(Node::open_consolidated(&store, "/a").is_err());
+    }
+
+    #[test]
+    fn group_consolidated_metadata_v2() {
+        let store = std::sync::Arc::new(MemoryStore::new());
+        store
+            .set(
+                &StoreKey::new(".zgroup").unwrap(),
+                br#"{"zarr_format":2}"#.to_vec().into(),
+            )
+            .unwrap();
+        store
+            .set(
+                &StoreKey::new("b/.zarray").unwrap(),
+                br#"{"chunks":[2],"compressor":null,"dtype":"|u1","fill_value":0,"filters":null,"order":"C","shape":[4],"zarr_format":2}"#.to_vec().into(),
+            )
+            .unwrap();
+        store
+            .set(
+                &StoreKey::new("b/.zattrs").unwrap(),
+                br#"{"key":"value"}"#.to_vec().into(),
+            )
+            .unwrap();
+
+        let mut group = Group::open(store.clone(), "/").unwrap();
+        let consolidated_metadata = group.consolidate_metadata().unwrap();
+        group.set_consolidated_metadata(Some(consolidated_metadata));
+        group.store_metadata().unwrap();
+        assert!(store
+            .get(&StoreKey::new(".zmetadata").unwrap())
+            .unwrap()
+            .is_some());
+
+        let group_copy = Group::open(store.clone(), "/").unwrap();
+        assert_eq!(group_copy.metadata(), group.metadata());
+        let children = group_copy.consolidated_children(true).unwrap().unwrap();
+        assert_eq!(children.len(), 1);
+        let NodeMetadata::Array(ArrayMetadata::V2(array_metadata)) = children[0].metadata() else {
+            panic!()
+        };
+        assert_eq!(array_metadata.attributes["key"], "value");
+
+        group_copy.erase_metadata().unwrap();
+        assert!(store
+            .get(&StoreKey::new(".zmetadata").unwrap())
+            .unwrap()
+            .is_none());
+    }
+
     /// Implicit group support is removed since implicit groups were removed from the Zarr V3 spec
     #[test]
     fn group_implicit() {
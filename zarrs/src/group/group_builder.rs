@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
 use crate::metadata::{
+    v2_to_v3::group_metadata_v2_to_v3,
     v3::{AdditionalFields, GroupMetadataV3},
-    GroupMetadata,
+    v3_to_v2::group_metadata_v3_to_v2,
+    GroupMetadata, ZarrVersion,
 };
 
 use super::{Group, GroupCreateError};
@@ -54,6 +56,23 @@ impl GroupBuilder {
         self
     }
 
+    /// Set the Zarr version.
+    ///
+    /// If left unmodified, the group will be a Zarr V3 group.
+    /// A Zarr V2 group is stored with `.zgroup` and `.zattrs` metadata.
+    pub fn zarr_version(&mut self, zarr_version: ZarrVersion) -> &mut Self {
+        self.metadata = match (&self.metadata, zarr_version) {
+            (GroupMetadata::V3(metadata), ZarrVersion::V2) => {
+                group_metadata_v3_to_v2(metadata).into()
+            }
+            (GroupMetadata::V2(metadata), ZarrVersion::V3) => {
+                group_metadata_v2_to_v3(metadata).into()
+            }
+            (metadata, _) => metadata.clone(),
+        };
+        self
+    }
+
     /// Build into a [`Group`].
     ///
     /// # Errors
@@ -96,4 +115,28 @@ mod tests {
         assert_eq!(group.attributes_mut(), &attributes);
         assert_eq!(group.additional_fields_mut(), &additional_fields);
     }
+
+    #[test]
+    fn group_builder_v2() {
+        let mut builder = GroupBuilder::new();
+        let mut attributes = serde_json::Map::new();
+        attributes.insert("key".to_string(), "value".into());
+        builder.attributes(attributes.clone());
+        builder.zarr_version(ZarrVersion::V2);
+
+        let storage = Arc::new(MemoryStore::new());
+        let group = builder.build(storage.clone(), "/group").unwrap();
+        assert!(matches!(group.metadata(), GroupMetadata::V2(_)));
+        assert_eq!(group.attributes(), &attributes);
+        group.store_metadata().unwrap();
+
+        let group = Group::open(storage, "/group").unwrap();
+        assert!(matches!(group.metadata(), GroupMetadata::V2(_)));
+        assert_eq!(group.attributes(), &attributes);
+
+        builder.zarr_version(ZarrVersion::V3);
+        let group = builder.build(Arc::new(MemoryStore::new()), "/").unwrap();
+        assert!(matches!(group.metadata(), GroupMetadata::V3(_)));
+        assert_eq!(group.attributes(), &attributes);
+    }
 }
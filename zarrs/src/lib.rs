@@ -25,7 +25,7 @@
 //!
 //! An existing V2 or V3 array can be opened with [`Array::open`](crate::array::Array::open).
 //! A new array can be created from V2 or V3 metadata with [`Array::new_with_metadata`](crate::array::Array::new_with_metadata).
-//! The [`ArrayBuilder`](crate::array::ArrayBuilder) only supports V3 array creation.
+//! The [`ArrayBuilder`](crate::array::ArrayBuilder) creates V3 arrays by default, and V2 arrays with a compatible subset of V3 metadata (see [`ArrayBuilder::zarr_version`](crate::array::ArrayBuilder::zarr_version)).
 //!
 //! `zarrs` supports forward conversion of Zarr V2 data to V3.
 //! See ["Metadata Convert Version"](crate::config::Config#metadata-convert-version) and ["Metadata Erase Version"](crate::config::Config#metadata-erase-version) for information about manipulating the version of array/group metadata.
@@ -83,6 +83,7 @@
 //! [`zarrs_opendal`]: https://docs.rs/zarrs_opendal/latest/zarrs_opendal/
 //!
 //! The [`AsyncToSyncStorageAdapter`](crate::storage::storage_adapter::async_to_sync::AsyncToSyncStorageAdapter) enables some async stores to be used in a sync context.
+//! The [`SyncToAsyncStorageAdapter`](crate::storage::storage_adapter::sync_to_async::SyncToAsyncStorageAdapter) enables sync stores to be used in an async context.
 //!
 //! ## Examples
 #![cfg_attr(feature = "ndarray", doc = "```rust")]
@@ -171,7 +172,8 @@
 //!  - `async`: an **experimental** asynchronous API for [`stores`](storage), [`Array`](crate::array::Array), and [`Group`](group::Group).
 //!    - The async API is runtime-agnostic. This has some limitations that are detailed in the [`Array`](crate::array::Array) docs.
 //!    - The async API is not as performant as the sync API.
-//!  - Codecs: `bitround`, `bz2`, `pcodec`, `zfp`, `zstd`.
+//!  - `chrono`: [`Element`](crate::array::Element) implementations for [`chrono`] date and time types.
+//!  - Codecs: `astype`, `bitround`, `bitshuffle`, `bz2`, `categorize`, `delta`, `fixedscaleoffset`, `fletcher32`, `lz4`, `lzma`, `packbits`, `pcodec`, `quantize`, `shuffle`, `zfp`, `zlib`, `zstd`.
 //!
 //! ## `zarrs` Ecosystem
 #![doc = include_str!("../doc/ecosystem.md")]
@@ -18,7 +18,8 @@ pub use node_sync::{get_child_nodes, node_exists, node_exists_listable};
 
 mod key;
 pub use key::{
-    data_key, meta_key, meta_key_v2_array, meta_key_v2_attributes, meta_key_v2_group, meta_key_v3,
+    data_key, meta_key, meta_key_v2_array, meta_key_v2_attributes, meta_key_v2_consolidated,
+    meta_key_v2_group, meta_key_v3,
 };
 
 #[cfg(feature = "async")]
@@ -37,10 +38,11 @@ use crate::{
     array::ArrayMetadata,
     config::MetadataRetrieveVersion,
     metadata::{
-        v2::{ArrayMetadataV2, GroupMetadataV2},
+        v2::{ArrayMetadataV2, ConsolidatedMetadataV2, GroupMetadataV2},
+        v3::{ConsolidatedMetadata, ConsolidatedMetadataMetadata},
         GroupMetadata,
     },
-    storage::{ListableStorageTraits, ReadableStorageTraits, StorageError},
+    storage::{ListableStorageTraits, ReadableStorageTraits, StorageError, StoreKey},
 };
 
 #[cfg(feature = "async")]
@@ -107,7 +109,7 @@ impl From<NodeCreateError> for StorageError {
 }
 
 impl Node {
-    fn get_metadata<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits>(
+    fn get_metadata<TStorage: ?Sized + ReadableStorageTraits>(
         storage: &Arc<TStorage>,
         path: &NodePath,
         version: &MetadataRetrieveVersion,
@@ -156,6 +158,13 @@ impl Node {
                         StorageError::InvalidMetadata(attributes_key, err.to_string())
                     })?;
                 }
+                let consolidated_key = meta_key_v2_consolidated(path);
+                if let Some(consolidated_metadata) = storage.get(&consolidated_key)? {
+                    metadata.consolidated_metadata = Some(consolidated_metadata_from_v2(
+                        consolidated_key,
+                        &consolidated_metadata,
+                    )?);
+                }
                 return Ok(NodeMetadata::Group(GroupMetadata::V2(metadata)));
             }
         }
@@ -167,9 +176,7 @@ impl Node {
     #[cfg(feature = "async")]
     // Identical to get_metadata.. with awaits
     // "maybe async" one day?
-    async fn async_get_metadata<
-        TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits,
-    >(
+    async fn async_get_metadata<TStorage: ?Sized + AsyncReadableStorageTraits>(
         storage: &Arc<TStorage>,
         path: &NodePath,
         version: &MetadataRetrieveVersion,
@@ -218,6 +225,13 @@ impl Node {
                         StorageError::InvalidMetadata(attributes_key, err.to_string())
                     })?;
                 }
+                let consolidated_key = meta_key_v2_consolidated(path);
+                if let Some(consolidated_metadata) = storage.get(&consolidated_key).await? {
+                    metadata.consolidated_metadata = Some(consolidated_metadata_from_v2(
+                        consolidated_key,
+                        &consolidated_metadata,
+                    )?);
+                }
                 return Ok(NodeMetadata::Group(GroupMetadata::V2(metadata)));
             }
         }
@@ -241,6 +255,8 @@ impl Node {
     ///
     /// # Errors
     /// Returns [`NodeCreateError`] if metadata is invalid or there is a failure to list child nodes.
+    ///
+    /// If the group at `path` has consolidated metadata, its children are retrieved from the consolidated metadata rather than listing `storage`.
     pub fn open_opt<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits>(
         storage: &Arc<TStorage>,
         path: &str,
@@ -248,9 +264,11 @@ impl Node {
     ) -> Result<Self, NodeCreateError> {
         let path: NodePath = path.try_into()?;
         let metadata = Self::get_metadata(storage, &path, version)?;
-        let children = match metadata {
-            NodeMetadata::Array(_) => Vec::default(),
-            NodeMetadata::Group(_) => get_child_nodes(storage, &path)?,
+        let children = if let Some(children) = consolidated_children_of_metadata(&path, &metadata)?
+        {
+            children
+        } else {
+            get_child_nodes(storage, &path)?
         };
         let node = Self {
             path,
@@ -260,6 +278,41 @@ impl Node {
         Ok(node)
     }
 
+    /// Open a node at `path` and read metadata from `storage` with default [`MetadataRetrieveVersion`], retrieving the children of a group from its consolidated metadata.
+    ///
+    /// Unlike [`open`](Node::open), `storage` does not need to be listable.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if metadata is invalid or the node is a group without consolidated metadata.
+    pub fn open_consolidated<TStorage: ?Sized + ReadableStorageTraits>(
+        storage: &Arc<TStorage>,
+        path: &str,
+    ) -> Result<Self, NodeCreateError> {
+        Self::open_consolidated_opt(storage, path, &MetadataRetrieveVersion::Default)
+    }
+
+    /// Open a node at `path` and read metadata from `storage` with non-default [`MetadataRetrieveVersion`], retrieving the children of a group from its consolidated metadata.
+    ///
+    /// Unlike [`open_opt`](Node::open_opt), `storage` does not need to be listable.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if metadata is invalid or the node is a group without consolidated metadata.
+    pub fn open_consolidated_opt<TStorage: ?Sized + ReadableStorageTraits>(
+        storage: &Arc<TStorage>,
+        path: &str,
+        version: &MetadataRetrieveVersion,
+    ) -> Result<Self, NodeCreateError> {
+        let path: NodePath = path.try_into()?;
+        let metadata = Self::get_metadata(storage, &path, version)?;
+        let children = consolidated_children_of_metadata(&path, &metadata)?
+            .ok_or(NodeCreateError::MissingMetadata)?;
+        Ok(Self {
+            path,
+            metadata,
+            children,
+        })
+    }
+
     #[cfg(feature = "async")]
     /// Asynchronously open a node at `path` and read metadata and children from `storage` with default [`MetadataRetrieveVersion`].
     ///
@@ -288,9 +341,11 @@ impl Node {
     ) -> Result<Self, NodeCreateError> {
         let path: NodePath = path.try_into()?;
         let metadata = Self::async_get_metadata(&storage, &path, version).await?;
-        let children = match metadata {
-            NodeMetadata::Array(_) => Vec::default(),
-            NodeMetadata::Group(_) => async_get_child_nodes(&storage, &path).await?,
+        let children = if let Some(children) = consolidated_children_of_metadata(&path, &metadata)?
+        {
+            children
+        } else {
+            async_get_child_nodes(&storage, &path).await?
         };
         let node = Self {
             path,
@@ -300,6 +355,43 @@ impl Node {
         Ok(node)
     }
 
+    #[cfg(feature = "async")]
+    /// Asynchronously open a node at `path` and read metadata from `storage` with default [`MetadataRetrieveVersion`], retrieving the children of a group from its consolidated metadata.
+    ///
+    /// Unlike [`async_open`](Node::async_open), `storage` does not need to be listable.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if metadata is invalid or the node is a group without consolidated metadata.
+    pub async fn async_open_consolidated<TStorage: ?Sized + AsyncReadableStorageTraits>(
+        storage: Arc<TStorage>,
+        path: &str,
+    ) -> Result<Self, NodeCreateError> {
+        Self::async_open_consolidated_opt(storage, path, &MetadataRetrieveVersion::Default).await
+    }
+
+    #[cfg(feature = "async")]
+    /// Asynchronously open a node at `path` and read metadata from `storage` with non-default [`MetadataRetrieveVersion`], retrieving the children of a group from its consolidated metadata.
+    ///
+    /// Unlike [`async_open_opt`](Node::async_open_opt), `storage` does not need to be listable.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if metadata is invalid or the node is a group without consolidated metadata.
+    pub async fn async_open_consolidated_opt<TStorage: ?Sized + AsyncReadableStorageTraits>(
+        storage: Arc<TStorage>,
+        path: &str,
+        version: &MetadataRetrieveVersion,
+    ) -> Result<Self, NodeCreateError> {
+        let path: NodePath = path.try_into()?;
+        let metadata = Self::async_get_metadata(&storage, &path, version).await?;
+        let children = consolidated_children_of_metadata(&path, &metadata)?
+            .ok_or(NodeCreateError::MissingMetadata)?;
+        Ok(Self {
+            path,
+            metadata,
+            children,
+        })
+    }
+
     /// Create a new node at `path` with `metadata` and `children`.
     #[must_use]
     pub fn new_with_metadata(path: NodePath, metadata: NodeMetadata, children: Vec<Self>) -> Self {
@@ -395,6 +487,122 @@ impl Node {
     }
 }
 
+/// Parse Zarr V2 consolidated metadata (`.zmetadata`) stored at `key`.
+pub(crate) fn consolidated_metadata_from_v2(
+    key: StoreKey,
+    consolidated_metadata: &[u8],
+) -> Result<ConsolidatedMetadata, StorageError> {
+    serde_json::from_slice::<ConsolidatedMetadataV2>(consolidated_metadata)
+        .and_then(|consolidated_metadata| consolidated_metadata.to_consolidated_metadata())
+        .map_err(|err| StorageError::InvalidMetadata(key, err.to_string()))
+}
+
+/// Return the child nodes of the node at `path` from its `consolidated_metadata`.
+pub(crate) fn consolidated_child_nodes(
+    path: &NodePath,
+    consolidated_metadata: &ConsolidatedMetadata,
+    recursive: bool,
+) -> Result<Vec<Node>, NodePathError> {
+    fn child_nodes(
+        path: &NodePath,
+        metadata: &ConsolidatedMetadataMetadata,
+        prefix: &str,
+        recursive: bool,
+    ) -> Result<Vec<Node>, NodePathError> {
+        let mut nodes = Vec::new();
+        for (relative_path, node_metadata) in metadata {
+            let Some(name) = relative_path.strip_prefix(prefix) else {
+                continue;
+            };
+            if name.is_empty() || name.contains('/') {
+                continue;
+            }
+            let children = match node_metadata {
+                NodeMetadata::Group(_) if recursive => {
+                    child_nodes(path, metadata, &format!("{relative_path}/"), recursive)?
+                }
+                _ => vec![],
+            };
+            let child_path = if path.as_str() == "/" {
+                format!("/{relative_path}")
+            } else {
+                format!("{}/{relative_path}", path.as_str())
+            };
+            nodes.push(Node::new_with_metadata(
+                NodePath::new(&child_path)?,
+                node_metadata.clone(),
+                children,
+            ));
+        }
+        Ok(nodes)
+    }
+
+    child_nodes(path, &consolidated_metadata.metadata, "", recursive)
+}
+
+/// Return the child nodes of the node at `path` with `metadata` if they can be determined without listing the store.
+///
+/// The children of an array are empty, and the children of a group are retrieved from its consolidated metadata.
+/// Returns [`None`] if the node is a group without consolidated metadata.
+fn consolidated_children_of_metadata(
+    path: &NodePath,
+    metadata: &NodeMetadata,
+) -> Result<Option<Vec<Node>>, NodePathError> {
+    match metadata {
+        NodeMetadata::Array(_) => Ok(Some(Vec::default())),
+        NodeMetadata::Group(group_metadata) => group_metadata
+            .consolidated_metadata()
+            .map(|consolidated_metadata| {
+                consolidated_child_nodes(path, consolidated_metadata, true)
+            })
+            .transpose(),
+    }
+}
+
+/// Flatten `nodes` and their descendants into a depth-first list of nodes without children.
+pub(crate) fn flatten_nodes(nodes: Vec<Node>) -> Vec<Node> {
+    let mut flattened = Vec::with_capacity(nodes.len());
+    for mut node in nodes {
+        let children = std::mem::take(&mut node.children);
+        flattened.push(node);
+        flattened.extend(flatten_nodes(children));
+    }
+    flattened
+}
+
+/// Create consolidated metadata for the node at `path` from its (recursive) child `nodes`.
+///
+/// Any consolidated metadata of child groups is excluded.
+pub(crate) fn consolidated_metadata_from_nodes(
+    path: &NodePath,
+    nodes: &[Node],
+) -> ConsolidatedMetadata {
+    fn insert_nodes(prefix: &str, nodes: &[Node], metadata: &mut ConsolidatedMetadataMetadata) {
+        for node in nodes {
+            let relative_path = node
+                .path()
+                .as_str()
+                .strip_prefix(prefix)
+                .unwrap_or(node.path().as_str());
+            let mut node_metadata = node.metadata().clone();
+            if let NodeMetadata::Group(group_metadata) = &mut node_metadata {
+                group_metadata.set_consolidated_metadata(None);
+            }
+            metadata.insert(relative_path.to_string(), node_metadata);
+            insert_nodes(prefix, node.children(), metadata);
+        }
+    }
+
+    let prefix = if path.as_str() == "/" {
+        "/".to_string()
+    } else {
+        format!("{}/", path.as_str())
+    };
+    let mut metadata = ConsolidatedMetadataMetadata::new();
+    insert_nodes(&prefix, nodes, &mut metadata);
+    ConsolidatedMetadata::new(metadata)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -1,12 +1,14 @@
 #![allow(missing_docs)]
 #![cfg(all(feature = "async", feature = "ndarray"))]
 
-use std::sync::Arc;
+use std::{num::NonZeroU64, sync::Arc};
 
 use zarrs::array::codec::array_to_bytes::vlen::VlenCodec;
 use zarrs::array::codec::{CodecOptions, TransposeCodec};
 use zarrs::array::{Array, ArrayBuilder, DataType, FillValue};
-use zarrs::array_subset::ArraySubset;
+use zarrs::array_subset::{
+    ArraySubset, CoordinateSelection, DimensionSelection, OrthogonalSelection,
+};
 
 use object_store::memory::InMemory;
 
@@ -139,6 +141,68 @@ async fn array_async_read_shard_compress() -> Result<(), Box<dyn std::error::Err
     array_async_read(true).await
 }
 
+#[rustfmt::skip]
+#[tokio::test]
+async fn array_async_orthogonal_selection() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(InMemory::new()));
+    let array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store, "/array")?;
+
+    // 1  2 | 3  4
+    // 5  6 | 7  8
+    // -----|-----
+    // 9 10 | 0  0
+    // 0  0 | 0  0
+    array.async_store_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[0..3, 0..4]), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0]).await?;
+
+    let selection = OrthogonalSelection::new(vec![DimensionSelection::new_strided(0..4, NonZeroU64::new(2).unwrap()), vec![3, 0].into()]);
+    assert!(array.async_retrieve_orthogonal_selection(&OrthogonalSelection::new(vec![(0..4).into()])).await.is_err());
+    assert!(array.async_retrieve_orthogonal_selection(&OrthogonalSelection::new(vec![vec![4].into(), (0..4).into()])).await.is_err()); // OOB
+    assert_eq!(array.async_retrieve_orthogonal_selection(&selection).await?, vec![4, 1, 0, 9].into());
+    assert_eq!(array.async_retrieve_orthogonal_selection(&OrthogonalSelection::from(ArraySubset::new_with_ranges(&[1..3, 1..3]))).await?, vec![6, 7, 10, 0].into());
+    assert_eq!(array.async_retrieve_orthogonal_selection_ndarray::<u8>(&selection).await?, ndarray::array![[4, 1], [0, 9]].into_dyn());
+    assert_eq!(array.async_retrieve_orthogonal_selection(&OrthogonalSelection::new(vec![vec![3, 0].into(), vec![0, 3].into()])).await?, vec![0, 0, 1, 4].into()); // corner chunks only
+
+    array.async_store_orthogonal_selection(&OrthogonalSelection::new(vec![vec![3, 1].into(), (1..3).into()]), vec![13u8, 14, 11, 12]).await?;
+    assert_eq!(array.async_retrieve_array_subset_ndarray::<u8>(&ArraySubset::new_with_ranges(&[0..4, 0..4])).await?, ndarray::array![[1, 2, 3, 4], [5, 11, 12, 8], [9, 10, 0, 0], [0, 13, 14, 0]].into_dyn());
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[tokio::test]
+async fn array_async_coordinate_selection() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(InMemory::new()));
+    let array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store, "/array")?;
+
+    // 1  2 | 3  4
+    // 5  6 | 7  8
+    // -----|-----
+    // 9 10 | 0  0
+    // 0  0 | 0  0
+    array.async_store_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[0..3, 0..4]), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0]).await?;
+
+    assert!(array.async_retrieve_coordinate_selection(&CoordinateSelection::try_from(vec![vec![0]])?).await.is_err());
+    assert!(array.async_retrieve_coordinate_selection(&CoordinateSelection::try_from(vec![vec![4, 0]])?).await.is_err()); // OOB
+    assert_eq!(array.async_retrieve_coordinate_selection(&CoordinateSelection::new(2, vec![])?).await?, vec![].into());
+    assert_eq!(array.async_retrieve_coordinate_selection(&CoordinateSelection::try_from(vec![vec![2, 1], vec![0, 3], vec![2, 1], vec![1, 0]])?).await?, vec![10, 4, 10, 5].into());
+    assert_eq!(array.async_retrieve_coordinate_selection_elements::<u8>(&CoordinateSelection::new_from_mask(&[true, false, false, false, false, true, false, false, false, false, true, false, false, false, false, true], &[4, 4])?).await?, vec![1, 6, 0, 0]);
+
+    array.async_store_coordinate_selection_elements::<u8>(&CoordinateSelection::try_from(vec![vec![0, 0], vec![3, 3], vec![0, 0]])?, &[20, 21, 22]).await?;
+    assert_eq!(array.async_retrieve_coordinate_selection_elements::<u8>(&CoordinateSelection::try_from(vec![vec![0, 0], vec![0, 1], vec![3, 3]])?).await?, vec![22, 2, 21]);
+    Ok(())
+}
+
 async fn array_str_impl(
     array: Array<zarrs_object_store::AsyncObjectStore<InMemory>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,125 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::array::{codec::CodecOptions, Array, ArrayBuilder, DataType, FillValue};
+use zarrs::array_subset::ArraySubset;
+use zarrs_filesystem::{FilesystemStore, FilesystemStoreOptions};
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn array_store_chunk_subset_concurrent() -> Result<(), Box<dyn std::error::Error>> {
+    const ARRAY_PATH: &str = "/array";
+    let path = tempfile::TempDir::new()?;
+    let mut options = FilesystemStoreOptions::default();
+    options.file_locking(true);
+
+    let store = Arc::new(FilesystemStore::new_with_options(
+        path.path(),
+        options.clone(),
+    )?);
+    let array = ArrayBuilder::new(
+        vec![64],
+        DataType::UInt8,
+        vec![64].try_into()?,
+        FillValue::from(0u8),
+    )
+    .build(store, ARRAY_PATH)?;
+    array.store_metadata()?;
+    let codec_options = CodecOptions::builder()
+        .conditional_chunk_updates(true)
+        .build();
+    let codec_options = &codec_options;
+
+    // Each writer has its own store, like writers in separate processes
+    std::thread::scope(|scope| {
+        for writer in 0..8u64 {
+            let store =
+                Arc::new(FilesystemStore::new_with_options(path.path(), options.clone()).unwrap());
+            scope.spawn(move || {
+                let array = Array::open(store, ARRAY_PATH).unwrap();
+                for element in writer * 8..(writer + 1) * 8 {
+                    let value = u8::try_from(element + 1).unwrap();
+                    array
+                        .store_chunk_subset_elements_opt::<u8>(
+                            &[0],
+                            &ArraySubset::new_with_start_shape(vec![element], vec![1]).unwrap(),
+                            &[value],
+                            codec_options,
+                        )
+                        .unwrap();
+                }
+            });
+        }
+    });
+
+    let elements = array.retrieve_chunk_elements::<u8>(&[0])?;
+    assert_eq!(elements, (1..=64).collect::<Vec<u8>>());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn array_store_chunk_subset_fill_value_concurrent() -> Result<(), Box<dyn std::error::Error>> {
+    const ARRAY_PATH: &str = "/array";
+    let path = tempfile::TempDir::new()?;
+    let mut options = FilesystemStoreOptions::default();
+    options.file_locking(true);
+
+    let store = Arc::new(FilesystemStore::new_with_options(
+        path.path(),
+        options.clone(),
+    )?);
+    let array = ArrayBuilder::new(
+        vec![8],
+        DataType::UInt8,
+        vec![8].try_into()?,
+        FillValue::from(0u8),
+    )
+    .build(store, ARRAY_PATH)?;
+    array.store_metadata()?;
+    let codec_options = CodecOptions::builder()
+        .conditional_chunk_updates(true)
+        .build();
+    let codec_options = &codec_options;
+
+    // Writers resetting an element to the fill value must not erase the elements of other writers
+    std::thread::scope(|scope| {
+        for element in 0..8u64 {
+            let store =
+                Arc::new(FilesystemStore::new_with_options(path.path(), options.clone()).unwrap());
+            scope.spawn(move || {
+                let array = Array::open(store, ARRAY_PATH).unwrap();
+                let subset = ArraySubset::new_with_start_shape(vec![element], vec![1]).unwrap();
+                for _ in 0..8 {
+                    array
+                        .store_chunk_subset_elements_opt::<u8>(&[0], &subset, &[1], codec_options)
+                        .unwrap();
+                    if element % 2 == 0 {
+                        array
+                            .store_chunk_subset_elements_opt::<u8>(
+                                &[0],
+                                &subset,
+                                &[0],
+                                codec_options,
+                            )
+                            .unwrap();
+                    }
+                }
+            });
+        }
+    });
+
+    let elements = array.retrieve_chunk_elements::<u8>(&[0])?;
+    assert_eq!(elements, vec![0, 1, 0, 1, 0, 1, 0, 1]);
+
+    // A chunk updated to the fill value is erased
+    array.store_chunk_subset_elements_opt::<u8>(
+        &[0],
+        &ArraySubset::new_with_shape(vec![8]),
+        &[0; 8],
+        codec_options,
+    )?;
+    assert!(array.retrieve_encoded_chunk(&[0])?.is_none());
+    Ok(())
+}
@@ -257,3 +257,116 @@ fn array_partial_encode_sharding_index_compressed() {
         .unwrap();
     }
 }
+
+#[cfg(feature = "async")]
+async fn array_partial_encode_sharding_async(
+    sharding_index_location: ShardingIndexLocation,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs_storage::AsyncReadableStorageTraits;
+
+    let opt = CodecOptionsBuilder::new()
+        .experimental_partial_encoding(true)
+        .build();
+
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+
+    let array_path = "/";
+    let mut builder = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::UInt16,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u16),
+    );
+    builder
+        .array_to_bytes_codec(Arc::new(
+            ShardingCodecBuilder::new(vec![1, 1].try_into().unwrap())
+                .index_bytes_to_bytes_codecs(vec![])
+                .index_location(sharding_index_location)
+                .build(),
+        ))
+        .bytes_to_bytes_codecs(vec![]);
+
+    let array = builder.build(store.clone(), array_path).unwrap();
+    let key_0_0 = array.chunk_key_encoding().encode(&[0, 0]);
+
+    let chunks_per_shard = 2 * 2;
+    let shard_index_size = size_of::<u64>() * 2 * chunks_per_shard;
+    assert!(store.get(&key_0_0).await?.is_none());
+
+    // [1, 0]
+    // [0, 0]
+    array
+        .async_store_array_subset_elements_opt::<u16>(
+            &ArraySubset::new_with_ranges(&[0..1, 0..1]),
+            &[1],
+            &opt,
+        )
+        .await?;
+    assert_eq!(
+        store.get(&key_0_0).await?.unwrap().len(),
+        shard_index_size + size_of::<u16>() * 1
+    );
+
+    // [0, 0]
+    // [0, 0]
+    array
+        .async_store_array_subset_elements_opt::<u16>(
+            &ArraySubset::new_with_ranges(&[0..1, 0..1]),
+            &[0],
+            &opt,
+        )
+        .await?;
+    assert!(store.get(&key_0_0).await?.is_none());
+
+    // [1, 2]
+    // [0, 0]
+    array
+        .async_store_array_subset_elements_opt::<u16>(
+            &ArraySubset::new_with_ranges(&[0..1, 0..2]),
+            &[1, 2],
+            &opt,
+        )
+        .await?;
+    assert_eq!(
+        array.async_retrieve_chunk_elements::<u16>(&[0, 0]).await?,
+        vec![1, 2, 0, 0]
+    );
+
+    // [99, 2]
+    // [5, 0]
+    array
+        .async_store_array_subset_elements_opt::<u16>(
+            &ArraySubset::new_with_ranges(&[0..2, 0..1]),
+            &[99, 5],
+            &opt,
+        )
+        .await?;
+    assert_eq!(
+        store.get(&key_0_0).await?.unwrap().len(),
+        shard_index_size + size_of::<u16>() * 4 // 1 stale inner chunk + 3 inner chunks
+    );
+    assert_eq!(
+        array.async_retrieve_chunk_elements::<u16>(&[0, 0]).await?,
+        vec![99, 2, 5, 0]
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_partial_encode_sharding_async_index_start() {
+    array_partial_encode_sharding_async(ShardingIndexLocation::Start)
+        .await
+        .unwrap();
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_partial_encode_sharding_async_index_end() {
+    array_partial_encode_sharding_async(ShardingIndexLocation::End)
+        .await
+        .unwrap();
+}
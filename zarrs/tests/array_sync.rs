@@ -1,11 +1,13 @@
 #![allow(missing_docs)]
 #![cfg(feature = "ndarray")]
 
-use std::sync::Arc;
+use std::{num::NonZeroU64, sync::Arc};
 
 use zarrs::array::codec::CodecOptions;
 use zarrs::array::{Array, ArrayBuilder, ArrayCodecTraits, DataType, FillValue};
-use zarrs::array_subset::ArraySubset;
+use zarrs::array_subset::{
+    ArraySubset, CoordinateSelection, DimensionSelection, OrthogonalSelection,
+};
 use zarrs::storage::store::MemoryStore;
 
 #[rustfmt::skip]
@@ -165,6 +167,68 @@ fn array_sync_read_shard_compress() -> Result<(), Box<dyn std::error::Error>> {
     array_sync_read(array)
 }
 
+#[rustfmt::skip]
+#[test]
+fn array_sync_orthogonal_selection() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store, "/array")?;
+
+    // 1  2 | 3  4
+    // 5  6 | 7  8
+    // -----|-----
+    // 9 10 | 0  0
+    // 0  0 | 0  0
+    array.store_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[0..3, 0..4]), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0])?;
+
+    let selection = OrthogonalSelection::new(vec![DimensionSelection::new_strided(0..4, NonZeroU64::new(2).unwrap()), vec![3, 0].into()]);
+    assert!(array.retrieve_orthogonal_selection(&OrthogonalSelection::new(vec![(0..4).into()])).is_err());
+    assert!(array.retrieve_orthogonal_selection(&OrthogonalSelection::new(vec![vec![4].into(), (0..4).into()])).is_err()); // OOB
+    assert_eq!(array.retrieve_orthogonal_selection(&selection)?, vec![4, 1, 0, 9].into());
+    assert_eq!(array.retrieve_orthogonal_selection(&OrthogonalSelection::from(ArraySubset::new_with_ranges(&[1..3, 1..3])))?, vec![6, 7, 10, 0].into());
+    assert_eq!(array.retrieve_orthogonal_selection_ndarray::<u8>(&selection)?, ndarray::array![[4, 1], [0, 9]].into_dyn());
+    assert_eq!(array.retrieve_orthogonal_selection(&OrthogonalSelection::new(vec![vec![3, 0].into(), vec![0, 3].into()]))?, vec![0, 0, 1, 4].into()); // corner chunks only
+
+    array.store_orthogonal_selection(&OrthogonalSelection::new(vec![vec![3, 1].into(), (1..3).into()]), vec![13u8, 14, 11, 12])?;
+    assert_eq!(array.retrieve_array_subset_ndarray::<u8>(&ArraySubset::new_with_ranges(&[0..4, 0..4]))?, ndarray::array![[1, 2, 3, 4], [5, 11, 12, 8], [9, 10, 0, 0], [0, 13, 14, 0]].into_dyn());
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[test]
+fn array_sync_coordinate_selection() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store, "/array")?;
+
+    // 1  2 | 3  4
+    // 5  6 | 7  8
+    // -----|-----
+    // 9 10 | 0  0
+    // 0  0 | 0  0
+    array.store_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[0..3, 0..4]), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0])?;
+
+    assert!(array.retrieve_coordinate_selection(&CoordinateSelection::try_from(vec![vec![0]])?).is_err());
+    assert!(array.retrieve_coordinate_selection(&CoordinateSelection::try_from(vec![vec![4, 0]])?).is_err()); // OOB
+    assert_eq!(array.retrieve_coordinate_selection(&CoordinateSelection::new(2, vec![])?)?, vec![].into());
+    assert_eq!(array.retrieve_coordinate_selection(&CoordinateSelection::try_from(vec![vec![2, 1], vec![0, 3], vec![2, 1], vec![1, 0]])?)?, vec![10, 4, 10, 5].into());
+    assert_eq!(array.retrieve_coordinate_selection_elements::<u8>(&CoordinateSelection::new_from_mask(&[true, false, false, false, false, true, false, false, false, false, true, false, false, false, false, true], &[4, 4])?)?, vec![1, 6, 0, 0]);
+
+    array.store_coordinate_selection_elements::<u8>(&CoordinateSelection::try_from(vec![vec![0, 0], vec![3, 3], vec![0, 0]])?, &[20, 21, 22])?;
+    assert_eq!(array.retrieve_coordinate_selection_elements::<u8>(&CoordinateSelection::try_from(vec![vec![0, 0], vec![0, 1], vec![3, 3]])?)?, vec![22, 2, 21]);
+    Ok(())
+}
+
 fn array_str_impl(array: Array<MemoryStore>) -> Result<(), Box<dyn std::error::Error>> {
     // Store a single chunk
     array.store_chunk_elements(&[0, 0], &["a", "bb", "ccc", "dddd"])?;
@@ -0,0 +1,75 @@
+#![allow(missing_docs)]
+#![cfg(all(feature = "async", feature = "ndarray"))]
+
+use serde_json::json;
+use std::sync::Arc;
+use zarrs::storage::{
+    storage_adapter::sync_to_async::{SyncToAsyncSpawnBlocking, SyncToAsyncStorageAdapter},
+    AsyncReadableWritableListableStorage,
+};
+use zarrs::{
+    array::{Array, DataType, FillValue, ZARR_NAN_F32},
+    array_subset::ArraySubset,
+};
+
+pub struct TokioSpawnBlocking;
+
+impl SyncToAsyncSpawnBlocking for TokioSpawnBlocking {
+    fn spawn_blocking<F, R>(&self, f: F) -> impl core::future::Future<Output = R> + Send
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let handle = tokio::task::spawn_blocking(f);
+        async move { handle.await.unwrap() }
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn array_read_and_write_sync_storage_adapter() -> Result<(), Box<dyn std::error::Error>> {
+    const GROUP_PATH: &str = "/group";
+    const ARRAY_PATH: &str = "/group/array";
+    let path = tempfile::TempDir::new()?;
+    let store = Arc::new(zarrs_filesystem::FilesystemStore::new(path.path())?);
+    let store: AsyncReadableWritableListableStorage =
+        Arc::new(SyncToAsyncStorageAdapter::new(store, TokioSpawnBlocking));
+
+    // Create a group
+    let mut group = zarrs::group::GroupBuilder::new().build(store.clone(), GROUP_PATH)?;
+    group
+        .attributes_mut()
+        .insert("foo".into(), serde_json::Value::String("bar".into()));
+    group.async_store_metadata().await?;
+    let group = zarrs::group::Group::async_open(store.clone(), GROUP_PATH).await?;
+    assert_eq!(group.attributes().get("foo"), Some(&json!("bar")));
+
+    // Create an array
+    let array = zarrs::array::ArrayBuilder::new(
+        vec![8, 8],
+        DataType::Float32,
+        vec![4, 4].try_into()?,
+        FillValue::from(ZARR_NAN_F32),
+    )
+    .dimension_names(["y", "x"].into())
+    .build(store.clone(), ARRAY_PATH)?;
+    array.async_store_metadata().await?;
+
+    array
+        .async_store_chunk_elements::<f32>(
+            &[0, 0],
+            &[
+                0.0, 0.1, 0.2, 0.3, 1.0, 1.1, 1.2, 1.3, 2.0, 2.1, 2.2, 2.3, 3.0, 3.1, 3.2, 3.3,
+            ],
+        )
+        .await?;
+
+    let array = Array::async_open(store.clone(), ARRAY_PATH).await?;
+    assert_eq!(array.shape(), &[8, 8]);
+    let subset = ArraySubset::new_with_ranges(&[2..4, 2..4]);
+    let data = array
+        .async_retrieve_array_subset_ndarray::<f32>(&subset)
+        .await?;
+    assert_eq!(data, ndarray::array![[2.2, 2.3], [3.2, 3.3]].into_dyn());
+    Ok(())
+}
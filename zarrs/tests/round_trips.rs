@@ -120,3 +120,43 @@ fn chunk_round_trip_filesystem_key_encoding_v2_slash() -> Result<(), Box<dyn Err
     assert!(path_expect.is_file());
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn array_round_trip_zip_writer() -> Result<(), Box<dyn Error>> {
+    use std::sync::Arc;
+    use zarrs::{
+        array::{ArrayBuilder, DataType, FillValue},
+        array_subset::ArraySubset,
+        storage::StoreKey,
+    };
+    use zarrs_zip::{ZipStorageAdapter, ZipStorageWriter};
+
+    let path = tempfile::TempDir::new()?;
+    let file = std::fs::File::create(path.path().join("array.zip"))?;
+    let writer = Arc::new(ZipStorageWriter::new(file));
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(writer.clone(), "/array")?;
+    array.store_metadata()?;
+    for chunk_indices in [[0, 0], [0, 1], [1, 1]] {
+        array.store_chunk_elements::<u16>(&chunk_indices, &[1, 2, 3, 4])?;
+    }
+    writer.finish()?;
+
+    let store = Arc::new(FilesystemStore::new(path.path())?);
+    let store = Arc::new(ZipStorageAdapter::new(store, StoreKey::new("array.zip")?)?);
+    let array_read = zarrs::array::Array::open(store, "/array")?;
+    assert_eq!(array_read.shape(), array.shape());
+    assert_eq!(array_read.data_type(), array.data_type());
+    assert_eq!(
+        array_read
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_shape(vec![4, 4]))?,
+        vec![1, 2, 1, 2, 3, 4, 3, 4, 0, 0, 1, 2, 0, 0, 3, 4]
+    );
+    Ok(())
+}
@@ -0,0 +1,269 @@
+//! An asynchronous filesystem store.
+
+use zarrs_storage::{
+    byte_range::ByteRange,
+    storage_adapter::sync_to_async::{SyncToAsyncSpawnBlocking, SyncToAsyncStorageAdapter},
+    AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits,
+    MaybeAsyncBytes, StorageError, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys,
+    StoreKeysPrefixes, StorePrefix, StoreValueVersion,
+};
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{FilesystemStore, FilesystemStoreCreateError, FilesystemStoreOptions};
+
+/// An asynchronous file system store.
+///
+/// See <https://zarr-specs.readthedocs.io/en/latest/v3/stores/filesystem/v1.0.html>.
+///
+/// File operations are performed by a [`FilesystemStore`] on the blocking thread pool of an asynchronous runtime through a [`SyncToAsyncSpawnBlocking`] implementation.
+/// Keys are mapped to paths identically, per-file locks are held for the duration of each operation, and direct I/O is supported through [`FilesystemStoreOptions`].
+///
+/// ### Example
+/// ```rust,ignore
+/// # use std::sync::Arc;
+/// # use zarrs_filesystem::AsyncFilesystemStore;
+/// # use zarrs_storage::storage_adapter::sync_to_async::SyncToAsyncSpawnBlocking;
+/// struct TokioSpawnBlocking;
+///
+/// impl SyncToAsyncSpawnBlocking for TokioSpawnBlocking {
+///     fn spawn_blocking<F, R>(&self, f: F) -> impl core::future::Future<Output = R> + Send
+///     where
+///         F: FnOnce() -> R + Send + 'static,
+///         R: Send + 'static,
+///     {
+///         let handle = tokio::task::spawn_blocking(f);
+///         async move { handle.await.unwrap() }
+///     }
+/// }
+///
+/// let store = Arc::new(AsyncFilesystemStore::new("/path/to/hierarchy.zarr", TokioSpawnBlocking)?);
+/// ```
+pub struct AsyncFilesystemStore<TSpawnBlocking: SyncToAsyncSpawnBlocking> {
+    store: Arc<FilesystemStore>,
+    adapter: SyncToAsyncStorageAdapter<FilesystemStore, TSpawnBlocking>,
+}
+
+impl<TSpawnBlocking: SyncToAsyncSpawnBlocking> std::fmt::Debug
+    for AsyncFilesystemStore<TSpawnBlocking>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFilesystemStore")
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<TSpawnBlocking: SyncToAsyncSpawnBlocking> AsyncFilesystemStore<TSpawnBlocking> {
+    /// Create a new asynchronous file system store at a given `base_path`.
+    ///
+    /// # Errors
+    /// Returns a [`FilesystemStoreCreateError`] if `base_directory`:
+    ///   - is not valid, or
+    ///   - it points to an existing file rather than a directory.
+    pub fn new<P: AsRef<Path>>(
+        base_path: P,
+        spawn_blocking: TSpawnBlocking,
+    ) -> Result<Self, FilesystemStoreCreateError> {
+        Self::new_with_options(base_path, FilesystemStoreOptions::default(), spawn_blocking)
+    }
+
+    /// Create a new asynchronous file system store at a given `base_path` and `options`.
+    ///
+    /// # Errors
+    /// Returns a [`FilesystemStoreCreateError`] if `base_directory`:
+    ///   - is not valid, or
+    ///   - it points to an existing file rather than a directory.
+    pub fn new_with_options<P: AsRef<Path>>(
+        base_path: P,
+        options: FilesystemStoreOptions,
+        spawn_blocking: TSpawnBlocking,
+    ) -> Result<Self, FilesystemStoreCreateError> {
+        Ok(Self::new_with_store(
+            FilesystemStore::new_with_options(base_path, options)?,
+            spawn_blocking,
+        ))
+    }
+
+    /// Create a new asynchronous file system store from a synchronous [`FilesystemStore`].
+    ///
+    /// This can be used to create a store that sorts directories/files when walking with [`FilesystemStore::sorted`].
+    #[must_use]
+    pub fn new_with_store(store: FilesystemStore, spawn_blocking: TSpawnBlocking) -> Self {
+        let store = Arc::new(store);
+        let adapter = SyncToAsyncStorageAdapter::new(store.clone(), spawn_blocking);
+        Self { store, adapter }
+    }
+
+    /// Maps a [`StoreKey`] to a filesystem [`PathBuf`].
+    #[must_use]
+    pub fn key_to_fspath(&self, key: &StoreKey) -> PathBuf {
+        self.store.key_to_fspath(key)
+    }
+
+    /// Maps a store [`StorePrefix`] to a filesystem [`PathBuf`].
+    #[must_use]
+    pub fn prefix_to_fs_path(&self, prefix: &StorePrefix) -> PathBuf {
+        self.store.prefix_to_fs_path(prefix)
+    }
+}
+
+#[async_trait::async_trait]
+impl<TSpawnBlocking: SyncToAsyncSpawnBlocking> AsyncReadableStorageTraits
+    for AsyncFilesystemStore<TSpawnBlocking>
+{
+    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        self.adapter.get(key).await
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.adapter.get_partial_values_key(key, byte_ranges).await
+    }
+
+    async fn get_partial_values(
+        &self,
+        key_ranges: &[StoreKeyRange],
+    ) -> Result<Vec<MaybeAsyncBytes>, StorageError> {
+        self.adapter.get_partial_values(key_ranges).await
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.adapter.size_key(key).await
+    }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        self.adapter.get_versioned(key).await
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.adapter.version_key(key).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<TSpawnBlocking: SyncToAsyncSpawnBlocking> AsyncWritableStorageTraits
+    for AsyncFilesystemStore<TSpawnBlocking>
+{
+    async fn set(&self, key: &StoreKey, value: AsyncBytes) -> Result<(), StorageError> {
+        self.adapter.set(key, value).await
+    }
+
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.adapter.set_if_version(key, value, version).await
+    }
+
+    async fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        self.adapter.set_partial_values(key_offset_values).await
+    }
+
+    async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.adapter.erase(key).await
+    }
+
+    async fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.adapter.erase_if_version(key, version).await
+    }
+
+    async fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        self.adapter.erase_values(keys).await
+    }
+
+    async fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.adapter.erase_prefix(prefix).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<TSpawnBlocking: SyncToAsyncSpawnBlocking> AsyncListableStorageTraits
+    for AsyncFilesystemStore<TSpawnBlocking>
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.adapter.list().await
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.adapter.list_prefix(prefix).await
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.adapter.list_dir(prefix).await
+    }
+
+    async fn size(&self) -> Result<u64, StorageError> {
+        self.adapter.size().await
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.adapter.size_prefix(prefix).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+    use zarrs_storage::store_test::ThreadSpawnBlocking;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn async_filesystem() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let store = AsyncFilesystemStore::new_with_store(
+            FilesystemStore::new(path.path())?.sorted(),
+            ThreadSpawnBlocking,
+        );
+        assert_eq!(
+            store.key_to_fspath(&StoreKey::new("a/b")?),
+            path.path().join("a/b")
+        );
+        futures::executor::block_on(async {
+            zarrs_storage::store_test::async_store_write(&store).await?;
+            zarrs_storage::store_test::async_store_read(&store).await?;
+            zarrs_storage::store_test::async_store_list(&store).await?;
+            zarrs_storage::store_test::async_store_set_if_version(&store).await?;
+            zarrs_storage::store_test::async_store_erase_if_version(&store).await?;
+            Ok(())
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn async_direct_io() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.direct_io(true);
+
+        let store = AsyncFilesystemStore::new_with_store(
+            FilesystemStore::new_with_options(path.path(), opts)?.sorted(),
+            ThreadSpawnBlocking,
+        );
+        futures::executor::block_on(async {
+            zarrs_storage::store_test::async_store_write(&store).await?;
+            zarrs_storage::store_test::async_store_read(&store).await?;
+            zarrs_storage::store_test::async_store_list(&store).await?;
+            Ok(())
+        })
+    }
+}
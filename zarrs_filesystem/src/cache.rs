@@ -0,0 +1,758 @@
+//! A read-through filesystem cache storage adapter.
+
+use zarrs_storage::{
+    byte_range::{ByteRange, InvalidByteRangeError},
+    Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError, StoreKey, StoreKeys,
+    StoreKeysPrefixes, StorePrefix, StoreValueVersion, WritableStorageTraits,
+};
+
+#[cfg(feature = "async")]
+use zarrs_storage::{AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits};
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    ops::Range,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use crate::{FilesystemStore, FilesystemStoreCreateError};
+
+/// How [`FilesystemCacheStorageAdapter`] caches partial reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilesystemCachePartialReads {
+    /// Retrieve and cache the whole value, then read byte ranges from the cache.
+    WholeValue,
+    /// Retrieve and cache each byte range separately.
+    #[default]
+    ByteRanges,
+}
+
+/// How [`FilesystemCacheStorageAdapter`] validates the freshness of cached values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilesystemCacheValidation {
+    /// Cached values are always fresh.
+    #[default]
+    None,
+    /// Cached values are stale if the size of the value in the underlying store has changed.
+    Size,
+    /// Cached values are stale if the version (e.g. `ETag`) of the value in the underlying store has changed.
+    ///
+    /// The underlying store must support [`ReadableStorageTraits::version_key`].
+    Version,
+}
+
+/// Options for use with [`FilesystemCacheStorageAdapter`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct FilesystemCacheOptions {
+    max_size: Option<u64>,
+    partial_reads: FilesystemCachePartialReads,
+    validation: FilesystemCacheValidation,
+}
+
+impl FilesystemCacheOptions {
+    /// Set the maximum size of the cache in bytes. The cache size is unbounded by default.
+    ///
+    /// The least recently used entries are evicted when the cache exceeds `max_size`.
+    pub fn max_size(&mut self, max_size: u64) -> &mut Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Set how partial reads are cached. Defaults to [`FilesystemCachePartialReads::ByteRanges`].
+    pub fn partial_reads(&mut self, partial_reads: FilesystemCachePartialReads) -> &mut Self {
+        self.partial_reads = partial_reads;
+        self
+    }
+
+    /// Set how the freshness of cached values is validated. Defaults to [`FilesystemCacheValidation::None`].
+    pub fn validation(&mut self, validation: FilesystemCacheValidation) -> &mut Self {
+        self.validation = validation;
+        self
+    }
+}
+
+/// A read-through filesystem cache storage adapter.
+///
+/// Values read from the underlying store are cached in a [`FilesystemStore`], so they persist across store instances and processes.
+/// Whole values are cached under `values/` and byte ranges of values are cached under `ranges/` in the cache directory.
+/// Byte ranges are read from a cached whole value if available.
+///
+/// If the cache exceeds its maximum size, the least recently used entries are evicted.
+/// The size of the cache is tracked per adapter instance, so the maximum size is not enforced if multiple adapters share a cache directory.
+///
+/// If freshness validation is enabled, the size or version of a value in the underlying store is retrieved the first time the value is read by the adapter.
+/// Cached entries of the value are discarded if it does not match the size or version the entries were cached with.
+///
+/// Listing is forwarded to the underlying store.
+/// The cache is accessed synchronously, including by the asynchronous storage traits.
+///
+/// ### Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use zarrs_storage::store::MemoryStore;
+/// use zarrs_filesystem::{
+///     FilesystemCacheOptions, FilesystemCacheStorageAdapter, FilesystemCacheValidation,
+/// };
+///
+/// # let cache_dir = tempfile::TempDir::new()?;
+/// # let cache_path = cache_dir.path();
+/// let store = Arc::new(MemoryStore::new()); // e.g. a HTTPStore
+/// let mut options = FilesystemCacheOptions::default();
+/// options
+///     .max_size(1024 * 1024 * 1024)
+///     .validation(FilesystemCacheValidation::Size);
+/// let store = Arc::new(FilesystemCacheStorageAdapter::new_with_options(
+///     store, cache_path, options,
+/// )?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct FilesystemCacheStorageAdapter<TStorage: ?Sized> {
+    storage: Arc<TStorage>,
+    cache: FilesystemStore,
+    options: FilesystemCacheOptions,
+    index: Mutex<CacheIndex>,
+    validators: Mutex<HashMap<StoreKey, String>>,
+}
+
+/// The sizes and recency of cache entries.
+#[derive(Debug, Default)]
+struct CacheIndex {
+    /// The size and last use of each entry.
+    entries: HashMap<StoreKey, (u64, u64)>,
+    /// The entries ordered by last use.
+    lru: BTreeMap<u64, StoreKey>,
+    /// The total size of the entries.
+    size: u64,
+    /// The last use.
+    tick: u64,
+}
+
+impl CacheIndex {
+    /// Mark an entry with `size` as most recently used.
+    fn touch(&mut self, key: &StoreKey, size: u64) {
+        self.remove(key);
+        self.tick += 1;
+        self.lru.insert(self.tick, key.clone());
+        self.entries.insert(key.clone(), (size, self.tick));
+        self.size += size;
+    }
+
+    /// Remove an entry.
+    fn remove(&mut self, key: &StoreKey) {
+        if let Some((size, tick)) = self.entries.remove(key) {
+            self.lru.remove(&tick);
+            self.size -= size;
+        }
+    }
+
+    /// Remove the least recently used entries until the total size does not exceed `max_size`.
+    fn evict(&mut self, max_size: u64) -> Vec<StoreKey> {
+        let mut evicted = Vec::new();
+        while self.size > max_size {
+            let Some((_, key)) = self.lru.pop_first() else {
+                break;
+            };
+            if let Some((size, _)) = self.entries.remove(&key) {
+                self.size -= size;
+            }
+            evicted.push(key);
+        }
+        evicted
+    }
+}
+
+impl<TStorage: ?Sized> FilesystemCacheStorageAdapter<TStorage> {
+    /// Create a new filesystem cache storage adapter with the cache at `cache_path`.
+    ///
+    /// # Errors
+    /// Returns a [`FilesystemStoreCreateError`] if the cache store cannot be created or the existing cache cannot be read.
+    pub fn new<P: AsRef<Path>>(
+        storage: Arc<TStorage>,
+        cache_path: P,
+    ) -> Result<Self, FilesystemStoreCreateError> {
+        Self::new_with_options(storage, cache_path, FilesystemCacheOptions::default())
+    }
+
+    /// Create a new filesystem cache storage adapter with the cache at `cache_path` and `options`.
+    ///
+    /// Existing entries in the cache are reused, with recency determined by their modification time.
+    ///
+    /// # Errors
+    /// Returns a [`FilesystemStoreCreateError`] if the cache store cannot be created or the existing cache cannot be read.
+    pub fn new_with_options<P: AsRef<Path>>(
+        storage: Arc<TStorage>,
+        cache_path: P,
+        options: FilesystemCacheOptions,
+    ) -> Result<Self, FilesystemStoreCreateError> {
+        let cache = FilesystemStore::new(cache_path)?;
+
+        let mut entries = Vec::new();
+        for key in cache.list().map_err(std::io::Error::other)? {
+            let metadata = std::fs::metadata(cache.key_to_fspath(&key))?;
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            entries.push((modified, key, metadata.len()));
+        }
+        entries.sort_by_key(|(modified, _, _)| *modified);
+        let mut index = CacheIndex::default();
+        for (_, key, size) in entries {
+            index.touch(&key, size);
+        }
+
+        let adapter = Self {
+            storage,
+            cache,
+            options,
+            index: Mutex::new(index),
+            validators: Mutex::default(),
+        };
+        adapter.evict().map_err(std::io::Error::other)?;
+        Ok(adapter)
+    }
+
+    /// Evict the least recently used entries until the cache does not exceed its maximum size.
+    fn evict(&self) -> Result<(), StorageError> {
+        if let Some(max_size) = self.options.max_size {
+            let evicted = self.index.lock().unwrap().evict(max_size);
+            for key in evicted {
+                self.cache.erase(&key)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Return the memoised validator of the value at `key`, or [`None`] if it has not been retrieved.
+    fn cached_validator(&self, key: &StoreKey) -> Option<String> {
+        match self.options.validation {
+            FilesystemCacheValidation::None => Some(String::new()),
+            FilesystemCacheValidation::Size | FilesystemCacheValidation::Version => {
+                self.validators.lock().unwrap().get(key).cloned()
+            }
+        }
+    }
+
+    /// Memoise the `validator` of the value at `key`.
+    fn set_validator(&self, key: &StoreKey, validator: &str) {
+        self.validators
+            .lock()
+            .unwrap()
+            .insert(key.clone(), validator.to_string());
+    }
+
+    /// Read `byte_ranges` of the cache entry at `cache_key` if it is cached with `validator`.
+    ///
+    /// Returns the size of the cached value and the bytes of the byte ranges, or [`None`] if the entry is not cached or is stale.
+    fn read_entry(
+        &self,
+        cache_key: &StoreKey,
+        validator: &str,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<(u64, Vec<Bytes>)>, StorageError> {
+        let Some(entry_size) = self.cache.size_key(cache_key)? else {
+            return Ok(None);
+        };
+        let header = entry_header(validator);
+        let header_size = header.len() as u64;
+        if entry_size < header_size {
+            self.remove_entry(cache_key)?;
+            return Ok(None);
+        }
+        let size = entry_size - header_size;
+
+        let mut entry_byte_ranges = Vec::with_capacity(byte_ranges.len() + 1);
+        entry_byte_ranges.push(ByteRange::FromStart(0, Some(header_size)));
+        for byte_range in byte_ranges {
+            let range = byte_range_in_bounds(byte_range, size)?;
+            entry_byte_ranges.push(ByteRange::FromStart(
+                header_size + range.start,
+                Some(range.end - range.start),
+            ));
+        }
+        let Some(mut bytes) = self
+            .cache
+            .get_partial_values_key(cache_key, &entry_byte_ranges)?
+        else {
+            return Ok(None);
+        };
+        if bytes.remove(0) != header {
+            self.remove_entry(cache_key)?;
+            return Ok(None);
+        }
+
+        self.index.lock().unwrap().touch(cache_key, entry_size);
+        // Persist the recency for other adapters, this is not critical
+        let _ = File::options()
+            .write(true)
+            .open(self.cache.key_to_fspath(cache_key))
+            .and_then(|file| file.set_modified(SystemTime::now()));
+        Ok(Some((size, bytes)))
+    }
+
+    /// Cache `bytes` at `cache_key` with `validator`.
+    ///
+    /// The bytes are not cached if they exceed the maximum size of the cache.
+    fn write_entry(
+        &self,
+        cache_key: &StoreKey,
+        validator: &str,
+        bytes: &[u8],
+    ) -> Result<(), StorageError> {
+        let mut entry = entry_header(validator);
+        let entry_size = (entry.len() + bytes.len()) as u64;
+        if self
+            .options
+            .max_size
+            .is_some_and(|max_size| entry_size > max_size)
+        {
+            return Ok(());
+        }
+        entry.extend_from_slice(bytes);
+        self.cache.set(cache_key, entry.into())?;
+        self.index.lock().unwrap().touch(cache_key, entry_size);
+        self.evict()
+    }
+
+    /// Remove the cache entry at `cache_key`.
+    fn remove_entry(&self, cache_key: &StoreKey) -> Result<(), StorageError> {
+        self.index.lock().unwrap().remove(cache_key);
+        self.cache.erase(cache_key)
+    }
+
+    /// Read `byte_ranges` of the value at `key` from the cache.
+    ///
+    /// Returns the cached byte ranges and the byte ranges that are not cached with their cache keys.
+    #[allow(clippy::type_complexity)]
+    fn read_byte_ranges(
+        &self,
+        key: &StoreKey,
+        validator: &str,
+        byte_ranges: &[ByteRange],
+    ) -> Result<(Vec<Option<Bytes>>, Vec<(usize, StoreKey)>), StorageError> {
+        let mut values = Vec::with_capacity(byte_ranges.len());
+        let mut missing = Vec::new();
+        for (i, byte_range) in byte_ranges.iter().enumerate() {
+            let cache_key = byte_range_cache_key(key, byte_range)?;
+            if let Some((_, mut bytes)) =
+                self.read_entry(&cache_key, validator, &[ByteRange::FromStart(0, None)])?
+            {
+                values.push(Some(bytes.remove(0)));
+            } else {
+                values.push(None);
+                missing.push((i, cache_key));
+            }
+        }
+        Ok((values, missing))
+    }
+
+    /// Cache the `missing` byte ranges retrieved from the underlying store and insert them into `values`.
+    fn write_byte_ranges(
+        &self,
+        validator: &str,
+        mut values: Vec<Option<Bytes>>,
+        missing: Vec<(usize, StoreKey)>,
+        retrieved: Vec<Bytes>,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        for ((i, cache_key), bytes) in std::iter::zip(missing, retrieved) {
+            self.write_entry(&cache_key, validator, &bytes)?;
+            values[i] = Some(bytes);
+        }
+        Ok(values.into_iter().collect())
+    }
+
+    /// Returns true if the whole value should be retrieved to satisfy `byte_ranges`.
+    fn retrieve_whole_value(&self, byte_ranges: &[ByteRange]) -> bool {
+        self.options.partial_reads == FilesystemCachePartialReads::WholeValue
+            || matches!(byte_ranges, [ByteRange::FromStart(0, None)])
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> FilesystemCacheStorageAdapter<TStorage> {
+    /// Return the validator of the value at `key`, or [`None`] if the value does not exist in the underlying store.
+    fn validator(&self, key: &StoreKey) -> Result<Option<String>, StorageError> {
+        if let Some(validator) = self.cached_validator(key) {
+            return Ok(Some(validator));
+        }
+        let validator = match self.options.validation {
+            FilesystemCacheValidation::None => Some(String::new()),
+            FilesystemCacheValidation::Size => {
+                self.storage.size_key(key)?.map(|size| size.to_string())
+            }
+            FilesystemCacheValidation::Version => self
+                .storage
+                .version_key(key)?
+                .map(|version| version.to_string()),
+        };
+        if let Some(validator) = &validator {
+            self.set_validator(key, validator);
+        }
+        Ok(validator)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits> FilesystemCacheStorageAdapter<TStorage> {
+    /// Return the validator of the value at `key`, or [`None`] if the value does not exist in the underlying store.
+    async fn async_validator(&self, key: &StoreKey) -> Result<Option<String>, StorageError> {
+        if let Some(validator) = self.cached_validator(key) {
+            return Ok(Some(validator));
+        }
+        let validator = match self.options.validation {
+            FilesystemCacheValidation::None => Some(String::new()),
+            FilesystemCacheValidation::Size => self
+                .storage
+                .size_key(key)
+                .await?
+                .map(|size| size.to_string()),
+            FilesystemCacheValidation::Version => self
+                .storage
+                .version_key(key)
+                .await?
+                .map(|version| version.to_string()),
+        };
+        if let Some(validator) = &validator {
+            self.set_validator(key, validator);
+        }
+        Ok(validator)
+    }
+}
+
+/// The header of a cache entry, the length of the validator as a little endian `u64` followed by the validator.
+fn entry_header(validator: &str) -> Vec<u8> {
+    let mut header = Vec::with_capacity(8 + validator.len());
+    header.extend_from_slice(&(validator.len() as u64).to_le_bytes());
+    header.extend_from_slice(validator.as_bytes());
+    header
+}
+
+/// The cache key of the whole value at `key`.
+fn value_cache_key(key: &StoreKey) -> Result<StoreKey, StorageError> {
+    Ok(StoreKey::new(format!("values/{key}"))?)
+}
+
+/// The cache key of a `byte_range` of the value at `key`.
+fn byte_range_cache_key(key: &StoreKey, byte_range: &ByteRange) -> Result<StoreKey, StorageError> {
+    let byte_range = match byte_range {
+        ByteRange::FromStart(offset, Some(length)) => format!("{offset}-{}", offset + length),
+        ByteRange::FromStart(offset, None) => format!("{offset}-"),
+        ByteRange::Suffix(length) => format!("-{length}"),
+    };
+    Ok(StoreKey::new(format!("ranges/{key}/bytes={byte_range}"))?)
+}
+
+/// Return the range of a `byte_range` of bytes with `size`.
+///
+/// # Errors
+/// Returns an [`InvalidByteRangeError`] if the byte range extends beyond `size`.
+fn byte_range_in_bounds(
+    byte_range: &ByteRange,
+    size: u64,
+) -> Result<Range<u64>, InvalidByteRangeError> {
+    let valid = match byte_range {
+        ByteRange::FromStart(offset, length) => offset + length.unwrap_or(0) <= size,
+        ByteRange::Suffix(length) => *length <= size,
+    };
+    if valid {
+        Ok(byte_range.to_range(size))
+    } else {
+        Err(InvalidByteRangeError::new(*byte_range, size))
+    }
+}
+
+/// Slice `byte_ranges` of `value`.
+fn slice_byte_ranges(value: &Bytes, byte_ranges: &[ByteRange]) -> Result<Vec<Bytes>, StorageError> {
+    byte_ranges
+        .iter()
+        .map(|byte_range| {
+            let range = byte_range_in_bounds(byte_range, value.len() as u64)?;
+            Ok(value
+                .slice(usize::try_from(range.start).unwrap()..usize::try_from(range.end).unwrap()))
+        })
+        .collect()
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for FilesystemCacheStorageAdapter<TStorage>
+{
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        let Some(validator) = self.validator(key)? else {
+            return Ok(None);
+        };
+        let value_key = value_cache_key(key)?;
+        if let Some((_, bytes)) = self.read_entry(&value_key, &validator, byte_ranges)? {
+            return Ok(Some(bytes));
+        }
+
+        if self.retrieve_whole_value(byte_ranges) {
+            let Some(value) = self.storage.get(key)? else {
+                return Ok(None);
+            };
+            self.write_entry(&value_key, &validator, &value)?;
+            slice_byte_ranges(&value, byte_ranges).map(Some)
+        } else {
+            let (values, missing) = self.read_byte_ranges(key, &validator, byte_ranges)?;
+            if missing.is_empty() {
+                return Ok(values.into_iter().collect());
+            }
+            let missing_byte_ranges: Vec<ByteRange> =
+                missing.iter().map(|(i, _)| byte_ranges[*i]).collect();
+            let Some(retrieved) = self
+                .storage
+                .get_partial_values_key(key, &missing_byte_ranges)?
+            else {
+                return Ok(None);
+            };
+            self.write_byte_ranges(&validator, values, missing, retrieved)
+        }
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let Some(validator) = self.validator(key)? else {
+            return Ok(None);
+        };
+        if let Some((size, _)) = self.read_entry(&value_cache_key(key)?, &validator, &[])? {
+            Ok(Some(size))
+        } else {
+            self.storage.size_key(key)
+        }
+    }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        self.storage.get_versioned(key)
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.storage.version_key(key)
+    }
+}
+
+impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
+    for FilesystemCacheStorageAdapter<TStorage>
+{
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.storage.list()
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.storage.list_prefix(prefix)
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.storage.list_dir(prefix)
+    }
+
+    fn size(&self) -> Result<u64, StorageError> {
+        self.storage.size()
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.storage.size_prefix(prefix)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
+    for FilesystemCacheStorageAdapter<TStorage>
+{
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        let Some(validator) = self.async_validator(key).await? else {
+            return Ok(None);
+        };
+        let value_key = value_cache_key(key)?;
+        if let Some((_, bytes)) = self.read_entry(&value_key, &validator, byte_ranges)? {
+            return Ok(Some(bytes));
+        }
+
+        if self.retrieve_whole_value(byte_ranges) {
+            let Some(value) = self.storage.get(key).await? else {
+                return Ok(None);
+            };
+            self.write_entry(&value_key, &validator, &value)?;
+            slice_byte_ranges(&value, byte_ranges).map(Some)
+        } else {
+            let (values, missing) = self.read_byte_ranges(key, &validator, byte_ranges)?;
+            if missing.is_empty() {
+                return Ok(values.into_iter().collect());
+            }
+            let missing_byte_ranges: Vec<ByteRange> =
+                missing.iter().map(|(i, _)| byte_ranges[*i]).collect();
+            let Some(retrieved) = self
+                .storage
+                .get_partial_values_key(key, &missing_byte_ranges)
+                .await?
+            else {
+                return Ok(None);
+            };
+            self.write_byte_ranges(&validator, values, missing, retrieved)
+        }
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let Some(validator) = self.async_validator(key).await? else {
+            return Ok(None);
+        };
+        if let Some((size, _)) = self.read_entry(&value_cache_key(key)?, &validator, &[])? {
+            Ok(Some(size))
+        } else {
+            self.storage.size_key(key).await
+        }
+    }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        self.storage.get_versioned(key).await
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.storage.version_key(key).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncListableStorageTraits> AsyncListableStorageTraits
+    for FilesystemCacheStorageAdapter<TStorage>
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.storage.list().await
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.storage.list_prefix(prefix).await
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.storage.list_dir(prefix).await
+    }
+
+    async fn size(&self) -> Result<u64, StorageError> {
+        self.storage.size().await
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.storage.size_prefix(prefix).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+    use zarrs_storage::{
+        storage_adapter::performance_metrics::PerformanceMetricsStorageAdapter, store::MemoryStore,
+    };
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn filesystem_cache() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let memory = Arc::new(MemoryStore::new());
+        zarrs_storage::store_test::store_write(&*memory)?;
+        let store = Arc::new(PerformanceMetricsStorageAdapter::new(memory));
+
+        for partial_reads in [
+            FilesystemCachePartialReads::ByteRanges,
+            FilesystemCachePartialReads::WholeValue,
+        ] {
+            let mut options = FilesystemCacheOptions::default();
+            options.partial_reads(partial_reads);
+            let cache = FilesystemCacheStorageAdapter::new_with_options(
+                store.clone(),
+                path.path().join(format!("{partial_reads:?}")),
+                options.clone(),
+            )?;
+            zarrs_storage::store_test::store_read(&cache)?;
+            zarrs_storage::store_test::store_list(&cache)?;
+
+            // Subsequent reads, including by a new adapter, are cached
+            store.reset();
+            zarrs_storage::store_test::store_read(&cache)?;
+            let cache = FilesystemCacheStorageAdapter::new_with_options(
+                store.clone(),
+                path.path().join(format!("{partial_reads:?}")),
+                options,
+            )?;
+            zarrs_storage::store_test::store_read(&cache)?;
+            assert_eq!(store.reads(), 2); // "notfound" is not cached
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn filesystem_cache_eviction() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let memory = Arc::new(MemoryStore::new());
+        for key in ["a", "b", "c"] {
+            memory.set(&key.try_into()?, vec![0; 100].into())?;
+        }
+        let store = Arc::new(PerformanceMetricsStorageAdapter::new(memory));
+
+        // Each entry is 100 bytes with an 8 byte header
+        let mut options = FilesystemCacheOptions::default();
+        options.max_size(250);
+        let cache =
+            FilesystemCacheStorageAdapter::new_with_options(store.clone(), path.path(), options)?;
+        cache.get(&"a".try_into()?)?;
+        cache.get(&"b".try_into()?)?;
+        cache.get(&"a".try_into()?)?;
+        cache.get(&"c".try_into()?)?; // evicts "b"
+        assert_eq!(store.reads(), 3);
+        cache.get(&"a".try_into()?)?;
+        cache.get(&"c".try_into()?)?;
+        assert_eq!(store.reads(), 3);
+        cache.get(&"b".try_into()?)?;
+        assert_eq!(store.reads(), 4);
+        assert_eq!(FilesystemStore::new(path.path())?.size()?, 216);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn filesystem_cache_validation() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let memory = Arc::new(MemoryStore::new());
+        let key: StoreKey = "a".try_into()?;
+        memory.set(&key, vec![0, 1, 2].into())?;
+
+        let mut options = FilesystemCacheOptions::default();
+        options.validation(FilesystemCacheValidation::Size);
+        let cache = FilesystemCacheStorageAdapter::new_with_options(
+            memory.clone(),
+            path.path(),
+            options.clone(),
+        )?;
+        assert_eq!(cache.get(&key)?, Some(vec![0, 1, 2].into()));
+
+        memory.set(&key, vec![3, 4].into())?;
+        assert_eq!(cache.get(&key)?, Some(vec![0, 1, 2].into()));
+        let cache =
+            FilesystemCacheStorageAdapter::new_with_options(memory.clone(), path.path(), options)?;
+        assert_eq!(cache.get(&key)?, Some(vec![3, 4].into()));
+        assert_eq!(cache.size_key(&key)?, Some(2));
+
+        memory.erase(&key)?;
+        let mut options = FilesystemCacheOptions::default();
+        options.validation(FilesystemCacheValidation::Version);
+        let cache = FilesystemCacheStorageAdapter::new_with_options(memory, path.path(), options)?;
+        assert_eq!(cache.get(&key)?, None);
+        Ok(())
+    }
+}
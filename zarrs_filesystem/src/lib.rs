@@ -2,29 +2,51 @@
 //!
 //! This implementation is conformant with the filesystem store defined in the Zarr V3 specification: <https://zarr-specs.readthedocs.io/en/latest/v3/stores/filesystem/v1.0.html>.
 //!
+//! This crate also includes [`FilesystemCacheStorageAdapter`], a read-through cache for any readable store backed by a [`FilesystemStore`].
+//!
+//! ## Crate Features
+//!  - `async`: enable the asynchronous `AsyncFilesystemStore` and implement the asynchronous storage traits for [`FilesystemCacheStorageAdapter`].
+//!
 //! ## Licence
 //! `zarrs_filesystem` is licensed under either of
 //! - the Apache License, Version 2.0 [LICENSE-APACHE](https://docs.rs/crate/zarrs_filesystem/latest/source/LICENCE-APACHE) or <http://www.apache.org/licenses/LICENSE-2.0> or
 //! - the MIT license [LICENSE-MIT](https://docs.rs/crate/zarrs_filesystem/latest/source/LICENCE-MIT) or <http://opensource.org/licenses/MIT>, at your option.
 
+#[cfg(feature = "async")]
+mod async_store;
+mod cache;
+
+#[cfg(feature = "async")]
+pub use async_store::AsyncFilesystemStore;
+
+pub use cache::{
+    FilesystemCacheOptions, FilesystemCachePartialReads, FilesystemCacheStorageAdapter,
+    FilesystemCacheValidation,
+};
+
 use zarrs_storage::{
     byte_range::{ByteOffset, ByteRange},
-    store_set_partial_values, Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError,
-    StoreKey, StoreKeyError, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
-    StorePrefixes, WritableStorageTraits,
+    Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError, StoreKey, StoreKeyError,
+    StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
+    StoreValueVersion, WritableStorageTraits,
 };
 
 use bytes::BytesMut;
+use itertools::Itertools;
 use parking_lot::RwLock;
 use thiserror::Error;
 use walkdir::WalkDir;
 
 use std::{
+    borrow::Cow,
     collections::HashMap,
     fs::{File, OpenOptions},
     io::{Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
 };
 
 #[cfg(target_os = "linux")]
@@ -62,11 +84,117 @@ fn bytes_aligned(size: usize) -> BytesMut {
     bytes.split_off(offset)
 }
 
+/// The suffix of the temporary files written by [`FilesystemStore`] with atomic writes.
+const TEMPORARY_FILE_SUFFIX: &str = ".zarrs-tmp";
+
+/// Returns a unique temporary path in the same directory as `path`.
+fn temporary_fspath(path: &Path) -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(
+        ".{file_name}.{}.{}{TEMPORARY_FILE_SUFFIX}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// The suffix of the lock files written by [`FilesystemStore`] with file locking.
+const LOCK_FILE_SUFFIX: &str = ".zarrs-lock";
+
+/// Returns the path of the lock file of the file at `path`.
+fn lock_fspath(path: &Path) -> PathBuf {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{file_name}{LOCK_FILE_SUFFIX}"))
+}
+
+/// Returns true if `path` is a temporary file or lock file written by [`FilesystemStore`].
+fn is_internal_fspath(path: &Path) -> bool {
+    path.file_name().is_some_and(|file_name| {
+        let file_name = file_name.to_string_lossy();
+        file_name.ends_with(TEMPORARY_FILE_SUFFIX) || file_name.ends_with(LOCK_FILE_SUFFIX)
+    })
+}
+
+/// An advisory lock of a lock file, released when dropped.
+#[cfg_attr(not(unix), allow(dead_code))]
+struct FileLock {
+    _file: File,
+}
+
+impl FileLock {
+    /// Acquire a shared or exclusive advisory lock of the lock file at `path`, blocking until it is available.
+    ///
+    /// The lock file is created if it does not exist and the lock is exclusive.
+    ///
+    /// # Errors
+    /// Returns an error with kind [`std::io::ErrorKind::NotFound`] if the lock is shared and the lock file does not exist.
+    #[cfg(unix)]
+    fn new(path: &Path, exclusive: bool) -> std::io::Result<Self> {
+        use std::os::fd::AsRawFd;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(exclusive)
+            .truncate(false)
+            .open(path)?;
+
+        let operation = if exclusive {
+            libc::LOCK_EX
+        } else {
+            libc::LOCK_SH
+        };
+        // SAFETY: the file descriptor is valid for the lifetime of `file`
+        while unsafe { libc::flock(file.as_raw_fd(), operation) } != 0 {
+            let err = std::io::Error::last_os_error();
+            if err.kind() != std::io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+
+        // The lock is released when the file is closed
+        Ok(Self { _file: file })
+    }
+
+    /// Acquire a shared or exclusive advisory lock of the lock file at `path`.
+    ///
+    /// Advisory file locking is not implemented on this platform.
+    ///
+    /// # Errors
+    /// Always returns an error with kind [`std::io::ErrorKind::Unsupported`].
+    #[cfg(not(unix))]
+    fn new(_path: &Path, _exclusive: bool) -> std::io::Result<Self> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "advisory file locking is only supported on unix",
+        ))
+    }
+}
+
+/// Flush the entries of the directory at `path` to disk.
+#[cfg(unix)]
+fn sync_dir(path: &Path) -> std::io::Result<()> {
+    File::open(path)?.sync_all()
+}
+
+/// Flush the entries of the directory at `path` to disk.
+///
+/// Directories cannot be opened as files on this platform, so this is a no-op.
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+fn sync_dir(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
 /// Options for use with [`FilesystemStore`]
 #[non_exhaustive]
 #[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct FilesystemStoreOptions {
     direct_io: bool,
+    atomic_writes: bool,
+    fsync: bool,
+    file_locking: bool,
 }
 
 impl FilesystemStoreOptions {
@@ -76,11 +204,51 @@ impl FilesystemStoreOptions {
         self.direct_io = direct_io;
         self
     }
+
+    /// Set whether or not to write values atomically. Disabled by default.
+    ///
+    /// If enabled, values are written to a temporary file in the same directory which then replaces the destination file.
+    /// Readers never observe a partially written value, even if a write is interrupted by a crash.
+    /// Partial writes are copy-on-write: the existing value is read and rewritten in full with the partial values applied.
+    pub fn atomic_writes(&mut self, atomic_writes: bool) -> &mut Self {
+        self.atomic_writes = atomic_writes;
+        self
+    }
+
+    /// Set whether or not to flush written files and their directories to disk. Disabled by default.
+    ///
+    /// If enabled, a write does not return until the file and any directories with new entries have been synced, so written values survive power loss.
+    pub fn fsync(&mut self, fsync: bool) -> &mut Self {
+        self.fsync = fsync;
+        self
+    }
+
+    /// Set whether or not to enable advisory file locking. Disabled by default. Needs support from the
+    /// operating system (currently only Unix).
+    ///
+    /// File locking is not implemented on other platforms.
+    /// Rather than silently writing without a lock, operations that would acquire a lock return [`StorageError::Unsupported`].
+    ///
+    /// If enabled, operations on a value acquire an advisory lock (`flock`) of a lock file next to the value.
+    /// Reads take a shared lock, and writes, conditional writes, partial writes, and erasures take an exclusive lock.
+    /// This makes conditional writes and partial writes (read-modify-write) safe with concurrent writers in other processes, provided they also enable file locking.
+    /// Lock files are created by writes, hidden from listings, and not removed when a value is erased.
+    pub fn file_locking(&mut self, file_locking: bool) -> &mut Self {
+        self.file_locking = file_locking;
+        self
+    }
 }
 
 /// A synchronous file system store.
 ///
 /// See <https://zarr-specs.readthedocs.io/en/latest/v3/stores/filesystem/v1.0.html>.
+///
+/// Value versions for conditional writes are derived from the content of files.
+/// Conditional writes and partial writes are atomic with respect to other operations on the same store instance.
+/// They are only atomic across store instances or processes if [`FilesystemStoreOptions::file_locking`] is enabled.
+///
+/// By default, values are written directly into their destination file.
+/// See [`FilesystemStoreOptions::atomic_writes`] and [`FilesystemStoreOptions::fsync`] for crash consistency and durability.
 #[derive(Debug)]
 pub struct FilesystemStore {
     base_path: PathBuf,
@@ -187,6 +355,58 @@ impl FilesystemStore {
         file
     }
 
+    /// Create the parent directories of `key` if they do not exist.
+    ///
+    /// If fsync is enabled, the directories gaining new entries are synced to disk.
+    fn create_parent_dirs(&self, key: &StoreKey) -> Result<(), StorageError> {
+        let key_path = self.key_to_fspath(key);
+        let Some(parent) = key_path.parent() else {
+            return Ok(());
+        };
+        if parent.exists() {
+            return Ok(());
+        }
+
+        // The created directories (except the parent) and the first existing ancestor gain new entries
+        let mut sync_dirs = Vec::new();
+        if self.options.fsync {
+            for dir in parent.ancestors().skip(1) {
+                sync_dirs.push(dir.to_path_buf());
+                if dir.exists() {
+                    break;
+                }
+            }
+        }
+        std::fs::create_dir_all(parent)?;
+        for dir in sync_dirs {
+            sync_dir(&dir)?;
+        }
+        Ok(())
+    }
+
+    /// Acquire an advisory lock of the lock file of `key` if file locking is enabled.
+    ///
+    /// Returns [`None`] if file locking is disabled, the store is read only, or `key` cannot exist because its parent directory does not exist.
+    /// A shared lock is also [`None`] if the lock file does not exist, since the value has never been written with file locking.
+    /// Writers should create the parent directories with [`create_parent_dirs`](FilesystemStore::create_parent_dirs) first.
+    ///
+    /// # Errors
+    /// Returns [`StorageError::Unsupported`] if file locking is enabled on a platform without advisory file locking.
+    fn lock_file(&self, key: &StoreKey, exclusive: bool) -> Result<Option<FileLock>, StorageError> {
+        if !self.options.file_locking || self.readonly {
+            return Ok(None);
+        }
+
+        match FileLock::new(&lock_fspath(&self.key_to_fspath(key)), exclusive) {
+            Ok(lock) => Ok(Some(lock)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) if err.kind() == std::io::ErrorKind::Unsupported => {
+                Err(StorageError::Unsupported(err.to_string()))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
     fn set_impl(
         &self,
         key: &StoreKey,
@@ -196,15 +416,88 @@ impl FilesystemStore {
     ) -> Result<(), StorageError> {
         let file = self.get_file_mutex(key);
         let _lock = file.write();
+        self.create_parent_dirs(key)?;
+        let _file_lock = self.lock_file(key, true)?;
+        self.set_impl_locked(key, value, offset, truncate)
+    }
 
+    /// Erase a value. The caller must hold the write lock of the file.
+    fn erase_impl_locked(&self, key: &StoreKey) -> Result<(), StorageError> {
+        let key_path = self.key_to_fspath(key);
+        let result = std::fs::remove_file(key_path);
+        if let Err(err) = result {
+            match err.kind() {
+                std::io::ErrorKind::NotFound => Ok(()),
+                _ => Err(err.into()),
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Write a value. The caller must hold the write lock of the file.
+    fn set_impl_locked(
+        &self,
+        key: &StoreKey,
+        value: &[u8],
+        offset: ByteOffset,
+        truncate: bool,
+    ) -> Result<(), StorageError> {
         // Create directories
         let key_path = self.key_to_fspath(key);
-        if let Some(parent) = key_path.parent() {
-            if !parent.exists() {
-                std::fs::create_dir_all(parent)?;
+        self.create_parent_dirs(key)?;
+
+        if self.options.atomic_writes {
+            let value = if offset == 0 && truncate {
+                Cow::Borrowed(value)
+            } else {
+                // Copy-on-write, apply the partial write to the existing value
+                let mut value_new = match std::fs::read(&key_path) {
+                    Ok(value_existing) => value_existing,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                    Err(err) => return Err(err.into()),
+                };
+                let offset = usize::try_from(offset).unwrap();
+                let end = offset + value.len();
+                if value_new.len() < end {
+                    value_new.resize(end, 0);
+                } else if truncate {
+                    value_new.truncate(end);
+                }
+                value_new[offset..end].copy_from_slice(value);
+                Cow::Owned(value_new)
+            };
+            let temporary_path = temporary_fspath(&key_path);
+            let result = self
+                .write_file(&temporary_path, &value, 0, true)
+                .and_then(|()| {
+                    std::fs::rename(&temporary_path, &key_path).map_err(StorageError::from)
+                });
+            if result.is_err() {
+                let _ = std::fs::remove_file(&temporary_path);
+            }
+            result?;
+        } else {
+            self.write_file(&key_path, value, offset, truncate)?;
+        }
+
+        if self.options.fsync {
+            if let Some(parent) = key_path.parent() {
+                sync_dir(parent)?;
             }
         }
 
+        Ok(())
+    }
+
+    /// Write a value to the file at `path`, syncing it to disk if enabled.
+    fn write_file(
+        &self,
+        path: &Path,
+        value: &[u8],
+        offset: ByteOffset,
+        truncate: bool,
+    ) -> Result<(), StorageError> {
         let mut flags = OpenOptions::new();
         flags.write(true).create(true).truncate(truncate);
 
@@ -221,7 +514,7 @@ impl FilesystemStore {
             flags.custom_flags(O_DIRECT);
         }
 
-        let mut file = flags.open(key_path)?;
+        let mut file = flags.open(path)?;
 
         // Write
         if enable_direct {
@@ -245,8 +538,24 @@ impl FilesystemStore {
             file.write_all(value)?;
         }
 
+        if self.options.fsync {
+            file.sync_all()?;
+        }
+
         Ok(())
     }
+
+    /// Read a value. The caller must hold a lock of the file.
+    fn get_impl_locked(&self, key: &StoreKey) -> Result<Option<Vec<u8>>, StorageError> {
+        match std::fs::read(self.key_to_fspath(key)) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) if err.kind() == std::io::ErrorKind::Unsupported => {
+                Err(StorageError::Unsupported(err.to_string()))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
 }
 
 impl ReadableStorageTraits for FilesystemStore {
@@ -257,6 +566,7 @@ impl ReadableStorageTraits for FilesystemStore {
     ) -> Result<Option<Vec<Bytes>>, StorageError> {
         let file = self.get_file_mutex(key);
         let _lock = file.read();
+        let _file_lock = self.lock_file(key, false)?;
 
         let mut file = match File::open(self.key_to_fspath(key)) {
             Ok(file) => file,
@@ -304,6 +614,20 @@ impl ReadableStorageTraits for FilesystemStore {
         let key_path = self.key_to_fspath(key);
         std::fs::metadata(key_path).map_or_else(|_| Ok(None), |metadata| Ok(Some(metadata.len())))
     }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        let file = self.get_file_mutex(key);
+        let _lock = file.read();
+        let _file_lock = self.lock_file(key, false)?;
+
+        Ok(self.get_impl_locked(key)?.map(|value| {
+            let version = StoreValueVersion::from_value(&value);
+            (Bytes::from(value), version)
+        }))
+    }
 }
 
 impl WritableStorageTraits for FilesystemStore {
@@ -315,6 +639,31 @@ impl WritableStorageTraits for FilesystemStore {
         }
     }
 
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+
+        let file = self.get_file_mutex(key);
+        let _lock = file.write();
+        self.create_parent_dirs(key)?;
+        let _file_lock = self.lock_file(key, true)?;
+
+        let current = self
+            .get_impl_locked(key)?
+            .map(|value| StoreValueVersion::from_value(&value));
+        if current.as_ref() != version {
+            return Err(StorageError::PreconditionFailed(key.clone()));
+        }
+        self.set_impl_locked(key, &value, 0, true)?;
+        Ok(StoreValueVersion::from_value(&value))
+    }
+
     fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -323,7 +672,31 @@ impl WritableStorageTraits for FilesystemStore {
             return Err(StorageError::ReadOnly);
         }
 
-        store_set_partial_values(self, key_offset_values)
+        // Group by key
+        let key_offset_values = key_offset_values
+            .iter()
+            .chunk_by(|key_offset_value| key_offset_value.key());
+        for (key, group) in &key_offset_values {
+            // Lock the file for the read-modify-write
+            let file = self.get_file_mutex(key);
+            let _lock = file.write();
+            self.create_parent_dirs(key)?;
+            let _file_lock = self.lock_file(key, true)?;
+
+            // Update the value
+            let mut value = self.get_impl_locked(key)?.unwrap_or_default();
+            for key_offset_value in group {
+                let start = usize::try_from(key_offset_value.offset()).unwrap();
+                let end = start + key_offset_value.value().len();
+                if value.len() < end {
+                    value.resize(end, 0);
+                }
+                value[start..end].copy_from_slice(key_offset_value.value());
+            }
+
+            self.set_impl_locked(key, &value, 0, true)?;
+        }
+        Ok(())
     }
 
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
@@ -333,17 +706,30 @@ impl WritableStorageTraits for FilesystemStore {
 
         let file = self.get_file_mutex(key);
         let _lock = file.write();
+        let _file_lock = self.lock_file(key, true)?;
+        self.erase_impl_locked(key)
+    }
 
-        let key_path = self.key_to_fspath(key);
-        let result = std::fs::remove_file(key_path);
-        if let Err(err) = result {
-            match err.kind() {
-                std::io::ErrorKind::NotFound => Ok(()),
-                _ => Err(err.into()),
-            }
-        } else {
-            Ok(())
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
         }
+
+        let file = self.get_file_mutex(key);
+        let _lock = file.write();
+        let _file_lock = self.lock_file(key, true)?;
+
+        let current = self
+            .get_impl_locked(key)?
+            .map(|value| StoreValueVersion::from_value(&value));
+        if current.as_ref() != Some(version) {
+            return Err(StorageError::PreconditionFailed(key.clone()));
+        }
+        self.erase_impl_locked(key)
     }
 
     fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
@@ -372,7 +758,7 @@ impl ListableStorageTraits for FilesystemStore {
             .sort_by_file_name()
             .into_iter()
             .filter_map(std::result::Result::ok)
-            .filter(|v| v.path().is_file())
+            .filter(|v| v.path().is_file() && !is_internal_fspath(v.path()))
             .filter_map(|v| self.fspath_to_key(v.path()).ok())
             .collect())
     }
@@ -382,7 +768,7 @@ impl ListableStorageTraits for FilesystemStore {
             .sort_by_file_name()
             .into_iter()
             .filter_map(std::result::Result::ok)
-            .filter(|v| v.path().is_file())
+            .filter(|v| v.path().is_file() && !is_internal_fspath(v.path()))
             .filter_map(|v| self.fspath_to_key(v.path()).ok())
             .collect())
     }
@@ -397,6 +783,9 @@ impl ListableStorageTraits for FilesystemStore {
                 let entry = entry?;
                 let fs_path = entry.path();
                 let path = fs_path.file_name().unwrap();
+                if is_internal_fspath(&fs_path) {
+                    continue;
+                }
                 if fs_path.is_dir() {
                     prefixes.push(StorePrefix::new(
                         prefix.as_str().to_string() + path.to_str().unwrap() + "/",
@@ -421,7 +810,7 @@ impl ListableStorageTraits for FilesystemStore {
             .into_iter()
             .filter_map(std::result::Result::ok)
             .filter_map(|v| {
-                if v.path().is_file() {
+                if v.path().is_file() && !is_internal_fspath(v.path()) {
                     Some(std::fs::metadata(v.path()).unwrap().len())
                 } else {
                     None
@@ -466,6 +855,121 @@ mod tests {
         zarrs_storage::store_test::store_write(&store)?;
         zarrs_storage::store_test::store_read(&store)?;
         zarrs_storage::store_test::store_list(&store)?;
+        zarrs_storage::store_test::store_set_if_version(&store)?;
+        zarrs_storage::store_test::store_erase_if_version(&store)?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn atomic_writes() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.atomic_writes(true).fsync(true);
+
+        let store = FilesystemStore::new_with_options(path.path(), opts)?.sorted();
+        zarrs_storage::store_test::store_write(&store)?;
+        zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_list(&store)?;
+        zarrs_storage::store_test::store_set_if_version(&store)?;
+
+        // No temporary files are left behind
+        assert!(WalkDir::new(path.path())
+            .into_iter()
+            .filter_map(std::result::Result::ok)
+            .all(|v| !is_internal_fspath(v.path())));
+
+        // Temporary files of interrupted writes are not listed
+        store.erase_prefix(&StorePrefix::root())?;
+        let key = StoreKey::new("a/b")?;
+        store.set(&key, vec![0].into())?;
+        std::fs::write(temporary_fspath(&store.key_to_fspath(&key)), [0, 1])?;
+        assert_eq!(store.list()?, vec![key.clone()]);
+        assert_eq!(
+            store.list_dir(&StorePrefix::new("a/")?)?.keys(),
+            &vec![key.clone()]
+        );
+        assert_eq!(store.size()?, 1);
+
+        // Partial writes replace the file
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let inode = std::fs::metadata(store.key_to_fspath(&key))?.ino();
+            store.set_partial_values(&[StoreKeyOffsetValue::new(key.clone(), 2, &[3])])?;
+            assert_ne!(std::fs::metadata(store.key_to_fspath(&key))?.ino(), inode);
+            assert_eq!(store.get(&key)?.unwrap(), vec![0, 0, 3]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn file_locking() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.file_locking(true);
+
+        let store = FilesystemStore::new_with_options(path.path(), opts.clone())?.sorted();
+        zarrs_storage::store_test::store_write(&store)?;
+        zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_list(&store)?;
+        zarrs_storage::store_test::store_set_if_version(&store)?;
+
+        // Separate store instances do not share in-process locks, like stores in separate processes
+        store.erase_prefix(&StorePrefix::root())?;
+        let key = StoreKey::new("a/b")?;
+        std::thread::scope(|scope| {
+            for i in 0..8u8 {
+                let store = FilesystemStore::new_with_options(path.path(), opts.clone()).unwrap();
+                let key = &key;
+                scope.spawn(move || {
+                    // Partial writes
+                    store
+                        .set_partial_values(&[StoreKeyOffsetValue::new(
+                            key.clone(),
+                            u64::from(i),
+                            &[i + 1],
+                        )])
+                        .unwrap();
+
+                    // Conditional increments
+                    let counter = StoreKey::new("counter").unwrap();
+                    for _ in 0..4 {
+                        loop {
+                            let (value, version) = match store.get_versioned(&counter).unwrap() {
+                                Some((value, version)) => (value[0], Some(version)),
+                                None => (0, None),
+                            };
+                            match store.set_if_version(
+                                &counter,
+                                vec![value + 1].into(),
+                                version.as_ref(),
+                            ) {
+                                Ok(_) => break,
+                                Err(StorageError::PreconditionFailed(_)) => {}
+                                Err(err) => panic!("{err}"),
+                            }
+                        }
+                    }
+                });
+            }
+        });
+        assert_eq!(store.get(&key)?, Some(vec![1, 2, 3, 4, 5, 6, 7, 8].into()));
+        assert_eq!(
+            store.get(&StoreKey::new("counter")?)?,
+            Some(vec![32].into())
+        );
+
+        // Lock files are not listed
+        assert!(path.path().join("a").join(".b.zarrs-lock").exists());
+        assert_eq!(store.list_dir(&StorePrefix::new("a/")?)?.keys(), &vec![key]);
+
+        // Reads do not create lock files
+        let key_missing = StoreKey::new("a/c")?;
+        assert!(store.get(&key_missing)?.is_none());
+        assert!(store.get_versioned(&key_missing)?.is_none());
+        assert!(!path.path().join("a").join(".c.zarrs-lock").exists());
         Ok(())
     }
 
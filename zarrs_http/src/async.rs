@@ -0,0 +1,277 @@
+use zarrs_storage::{
+    byte_range::ByteRange, AsyncBytes, AsyncReadableStorageTraits, MaybeAsyncBytes, StorageError,
+    StoreKey, StoreValueVersion,
+};
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue, RANGE},
+    Method, StatusCode, Url,
+};
+use tokio::sync::Semaphore;
+
+use crate::{
+    etag_version, handle_reqwest_error, handle_url_error, is_retryable_error, is_retryable_status,
+    key_to_url, parse_base_url, range_header, range_response_bytes, size_response,
+    version_response, HTTPAuth, HTTPRetryPolicy, HTTPStoreBuilder, HTTPStoreCreateError,
+};
+
+/// An asynchronous HTTP store.
+///
+/// Requests are made with a [`reqwest::Client`], which pools connections.
+/// Unbatched byte range requests are made concurrently.
+///
+/// Use [`HTTPStoreBuilder`] to configure headers, authentication, timeouts, retries, and the maximum number of concurrent requests.
+/// Retries are delayed with [`tokio::time::sleep`], so the store must be used within a `tokio` runtime (as required by [`reqwest::Client`]).
+#[derive(Debug)]
+pub struct AsyncHTTPStore {
+    base_url: Url,
+    batch_range_requests: bool,
+    client: reqwest::Client,
+    auth: Option<HTTPAuth>,
+    retry_policy: HTTPRetryPolicy,
+    request_limiter: Option<Semaphore>,
+}
+
+impl AsyncHTTPStore {
+    /// Create a new asynchronous HTTP store at a given `base_url`.
+    ///
+    /// Use [`HTTPStoreBuilder`] to create a HTTP store with additional configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HTTPStoreCreateError`] if `base_url` is not a valid URL.
+    pub fn new(base_url: &str) -> Result<Self, HTTPStoreCreateError> {
+        HTTPStoreBuilder::new(base_url).build_async()
+    }
+
+    /// Create a new asynchronous HTTP store from a [`HTTPStoreBuilder`].
+    pub(crate) fn new_with_builder(
+        builder: &HTTPStoreBuilder,
+    ) -> Result<Self, HTTPStoreCreateError> {
+        let base_url = parse_base_url(&builder.base_url)?;
+        let mut client = reqwest::Client::builder().default_headers(builder.headers.clone());
+        if let Some(timeout) = builder.timeout {
+            client = client.timeout(timeout);
+        }
+        Ok(Self {
+            base_url,
+            batch_range_requests: builder.batch_range_requests,
+            client: client.build()?,
+            auth: builder.auth.clone(),
+            retry_policy: builder.retry_policy,
+            request_limiter: builder
+                .max_concurrent_requests
+                .map(|limit| Semaphore::new(limit.get())),
+        })
+    }
+
+    /// Set whether to batch range requests.
+    ///
+    /// See [`HTTPStore::set_batch_range_requests`](crate::HTTPStore::set_batch_range_requests).
+    pub fn set_batch_range_requests(&mut self, batch_range_requests: bool) {
+        self.batch_range_requests = batch_range_requests;
+    }
+
+    /// Maps a [`StoreKey`] to a HTTP [`Url`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid.
+    pub fn key_to_url(&self, key: &StoreKey) -> Result<Url, url::ParseError> {
+        key_to_url(&self.base_url, key)
+    }
+
+    /// Make a request and return the status, headers, and body of the response.
+    ///
+    /// The request is retried according to the retry policy.
+    async fn fetch(
+        &self,
+        method: &Method,
+        url: &Url,
+        range: Option<&HeaderValue>,
+    ) -> Result<(StatusCode, HeaderMap, AsyncBytes), StorageError> {
+        let _permit = match &self.request_limiter {
+            Some(request_limiter) => Some(
+                request_limiter
+                    .acquire()
+                    .await
+                    .map_err(|err| StorageError::Other(err.to_string()))?,
+            ),
+            None => None,
+        };
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.request(method.clone(), url.clone());
+            if let Some(range) = range {
+                request = request.header(RANGE, range);
+            }
+            request = match &self.auth {
+                Some(HTTPAuth::Basic { username, password }) => {
+                    request.basic_auth(username, password.as_ref())
+                }
+                Some(HTTPAuth::Bearer(token)) => request.bearer_auth(token),
+                None => request,
+            };
+            let response = request.send().await;
+            let retry = match &response {
+                Ok(response) => is_retryable_status(response.status()),
+                Err(err) => is_retryable_error(err),
+            };
+            if retry {
+                if let Some(backoff) = self.retry_policy.backoff(attempt) {
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                    continue;
+                }
+            }
+            let response = response.map_err(handle_reqwest_error)?;
+            let status = response.status();
+            if is_retryable_status(status) {
+                return Err(StorageError::Transient(format!(
+                    "the http server responded with status {status}"
+                )));
+            }
+            let headers = response.headers().clone();
+            let bytes = response.bytes().await.map_err(handle_reqwest_error)?;
+            return Ok((status, headers, bytes));
+        }
+    }
+
+    /// Retrieve `byte_ranges` of the value at `url` with `size` in a single request.
+    async fn get_byte_ranges(
+        &self,
+        url: &Url,
+        byte_ranges: &[ByteRange],
+        size: u64,
+    ) -> Result<Vec<AsyncBytes>, StorageError> {
+        let range = range_header(byte_ranges, size)?;
+        let (status, _headers, bytes) = self.fetch(&Method::GET, url, Some(&range)).await?;
+        range_response_bytes(status, bytes, byte_ranges, size)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncReadableStorageTraits for AsyncHTTPStore {
+    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, _headers, bytes) = self.fetch(&Method::GET, &url, None).await?;
+        match status {
+            StatusCode::OK => Ok(Some(bytes)),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(StorageError::from(format!(
+                "http unexpected status code: {status}"
+            ))),
+        }
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let Some(size) = self.size_key(key).await? else {
+            return Ok(None);
+        };
+        if self.batch_range_requests {
+            Ok(Some(self.get_byte_ranges(&url, byte_ranges, size).await?))
+        } else {
+            let futures = byte_ranges.iter().map(|byte_range| {
+                self.get_byte_ranges(&url, std::slice::from_ref(byte_range), size)
+            });
+            let out = futures::future::try_join_all(futures).await?;
+            Ok(Some(out.into_iter().flatten().collect()))
+        }
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, headers, _bytes) = self.fetch(&Method::HEAD, &url, None).await?;
+        size_response(status, &headers)
+    }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, headers, bytes) = self.fetch(&Method::GET, &url, None).await?;
+        match status {
+            StatusCode::OK => Ok(Some((bytes, etag_version(&headers)?))),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(StorageError::from(format!(
+                "http unexpected status code: {status}"
+            ))),
+        }
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, headers, _bytes) = self.fetch(&Method::HEAD, &url, None).await?;
+        version_response(status, &headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn async_http_store_local() -> Result<(), Box<dyn Error>> {
+        let server = crate::test_server::TestServer::start();
+        let mut store = AsyncHTTPStore::new(&server.base_url())?;
+        zarrs_storage::store_test::async_store_read(&store).await?;
+        store.set_batch_range_requests(false);
+        zarrs_storage::store_test::async_store_read(&store).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn async_http_store_versioned() -> Result<(), Box<dyn Error>> {
+        let server = crate::test_server::TestServer::start();
+        let store = AsyncHTTPStore::new(&server.base_url())?;
+        let key = "a/b".try_into()?;
+        let (_bytes, version) = store.get_versioned(&key).await?.unwrap();
+        assert_eq!(store.version_key(&key).await?, Some(version));
+        assert_eq!(store.version_key(&"a/missing".try_into()?).await?, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn async_http_store_builder() -> Result<(), Box<dyn Error>> {
+        use crate::{HTTPRetryPolicy, HTTPStoreBuilder};
+        use std::{num::NonZeroUsize, time::Duration};
+
+        // Authentication and concurrency limits
+        let server = crate::test_server::TestServer::start_with(Some("Bearer token"), 0);
+        let store = AsyncHTTPStore::new(&server.base_url())?;
+        assert!(store.get(&"a/b".try_into()?).await.is_err());
+        let store = HTTPStoreBuilder::new(&server.base_url())
+            .bearer_auth("token")
+            .timeout(Duration::from_secs(10))
+            .max_concurrent_requests(NonZeroUsize::new(1).unwrap())
+            .batch_range_requests(false)
+            .build_async()?;
+        zarrs_storage::store_test::async_store_read(&store).await?;
+
+        // Retries
+        let retry_policy =
+            HTTPRetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(1));
+        let server = crate::test_server::TestServer::start_with(None, 3);
+        let store = HTTPStoreBuilder::new(&server.base_url())
+            .retry_policy(retry_policy)
+            .build_async()?;
+        assert!(store.get(&"a/b".try_into()?).await.is_err());
+        assert_eq!(server.requests(), 3);
+        assert_eq!(
+            store.get(&"a/b".try_into()?).await?,
+            Some(vec![0, 1, 2, 3].into())
+        );
+
+        Ok(())
+    }
+}
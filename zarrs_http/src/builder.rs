@@ -0,0 +1,232 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+#[cfg(feature = "async")]
+use crate::AsyncHTTPStore;
+use crate::{HTTPStore, HTTPStoreCreateError};
+
+/// HTTP authentication.
+#[derive(Clone)]
+pub enum HTTPAuth {
+    /// HTTP basic authentication.
+    Basic {
+        /// The username.
+        username: String,
+        /// The password.
+        password: Option<String>,
+    },
+    /// HTTP bearer authentication.
+    Bearer(String),
+}
+
+impl core::fmt::Debug for HTTPAuth {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Credentials are redacted
+        match self {
+            Self::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .finish_non_exhaustive(),
+            Self::Bearer(_) => f.write_str("Bearer(..)"),
+        }
+    }
+}
+
+/// A HTTP retry policy with exponential backoff.
+///
+/// A request is retried if it fails to connect, times out, or the server responds with a `429 Too Many Requests` or `5xx` status.
+/// The backoff before each retry starts at the initial backoff and doubles with each retry up to the maximum backoff.
+///
+/// The default policy does not retry requests.
+#[derive(Clone, Copy, Debug)]
+pub struct HTTPRetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for HTTPRetryPolicy {
+    fn default() -> Self {
+        Self::new(0, Duration::from_millis(100), Duration::from_secs(10))
+    }
+}
+
+impl HTTPRetryPolicy {
+    /// Create a new retry policy.
+    #[must_use]
+    pub const fn new(max_retries: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Return the maximum number of retries.
+    #[must_use]
+    pub const fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Return the backoff before retry `attempt` (starting from zero), or [`None`] if the retries are exhausted.
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Option<Duration> {
+        (attempt < self.max_retries).then(|| {
+            self.initial_backoff
+                .saturating_mul(2u32.saturating_pow(attempt))
+                .min(self.max_backoff)
+        })
+    }
+}
+
+/// A [`HTTPStore`] and `AsyncHTTPStore` builder.
+///
+/// The configuration applies to every request made by the store.
+///
+/// ```rust
+/// # use std::{num::NonZeroUsize, time::Duration};
+/// use zarrs_http::{HTTPRetryPolicy, HTTPStoreBuilder};
+///
+/// let http_store = HTTPStoreBuilder::new("http://...")
+///     .bearer_auth("token")
+///     .timeout(Duration::from_secs(30))
+///     .retry_policy(HTTPRetryPolicy::new(
+///         3,
+///         Duration::from_millis(100),
+///         Duration::from_secs(10),
+///     ))
+///     .max_concurrent_requests(NonZeroUsize::new(16).unwrap())
+///     .build()?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct HTTPStoreBuilder {
+    pub(crate) base_url: String,
+    pub(crate) headers: HeaderMap,
+    pub(crate) auth: Option<HTTPAuth>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) retry_policy: HTTPRetryPolicy,
+    pub(crate) max_concurrent_requests: Option<NonZeroUsize>,
+    pub(crate) batch_range_requests: bool,
+}
+
+impl HTTPStoreBuilder {
+    /// Create a new HTTP store builder for a store at a given `base_url`.
+    #[must_use]
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            headers: HeaderMap::new(),
+            auth: None,
+            timeout: None,
+            retry_policy: HTTPRetryPolicy::default(),
+            max_concurrent_requests: None,
+            batch_range_requests: true,
+        }
+    }
+
+    /// Add a header to every request.
+    pub fn header(&mut self, name: HeaderName, value: HeaderValue) -> &mut Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Add headers to every request.
+    pub fn headers(&mut self, headers: HeaderMap) -> &mut Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Use HTTP basic authentication.
+    pub fn basic_auth(&mut self, username: &str, password: Option<&str>) -> &mut Self {
+        self.auth = Some(HTTPAuth::Basic {
+            username: username.to_string(),
+            password: password.map(str::to_string),
+        });
+        self
+    }
+
+    /// Use HTTP bearer authentication.
+    pub fn bearer_auth(&mut self, token: &str) -> &mut Self {
+        self.auth = Some(HTTPAuth::Bearer(token.to_string()));
+        self
+    }
+
+    /// Set the timeout of each request.
+    ///
+    /// The timeout applies from when a request starts connecting until the response body has finished.
+    /// Defaults to the default timeout of the `reqwest` client, which is 30 seconds for [`HTTPStore`] and none for `AsyncHTTPStore`.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the retry policy.
+    ///
+    /// Defaults to no retries.
+    pub fn retry_policy(&mut self, retry_policy: HTTPRetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the maximum number of concurrent requests.
+    ///
+    /// Defaults to unlimited.
+    pub fn max_concurrent_requests(&mut self, max_concurrent_requests: NonZeroUsize) -> &mut Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests);
+        self
+    }
+
+    /// Set whether to batch range requests.
+    ///
+    /// See [`HTTPStore::set_batch_range_requests`].
+    pub fn batch_range_requests(&mut self, batch_range_requests: bool) -> &mut Self {
+        self.batch_range_requests = batch_range_requests;
+        self
+    }
+
+    /// Build a [`HTTPStore`].
+    ///
+    /// # Errors
+    /// Returns a [`HTTPStoreCreateError`] if the base URL is not valid or the HTTP client cannot be initialised.
+    pub fn build(&self) -> Result<HTTPStore, HTTPStoreCreateError> {
+        HTTPStore::new_with_builder(self)
+    }
+
+    #[cfg(feature = "async")]
+    /// Build an [`AsyncHTTPStore`].
+    ///
+    /// # Errors
+    /// Returns a [`HTTPStoreCreateError`] if the base URL is not valid or the HTTP client cannot be initialised.
+    pub fn build_async(&self) -> Result<AsyncHTTPStore, HTTPStoreCreateError> {
+        AsyncHTTPStore::new_with_builder(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_retry_policy() {
+        let retry_policy =
+            HTTPRetryPolicy::new(4, Duration::from_millis(100), Duration::from_millis(300));
+        assert_eq!(retry_policy.backoff(0), Some(Duration::from_millis(100)));
+        assert_eq!(retry_policy.backoff(1), Some(Duration::from_millis(200)));
+        assert_eq!(retry_policy.backoff(2), Some(Duration::from_millis(300)));
+        assert_eq!(retry_policy.backoff(3), Some(Duration::from_millis(300)));
+        assert_eq!(retry_policy.backoff(4), None);
+        assert_eq!(HTTPRetryPolicy::default().backoff(0), None);
+    }
+
+    #[test]
+    fn http_auth_debug_redacted() {
+        let auth = HTTPAuth::Basic {
+            username: "user".to_string(),
+            password: Some("secret".to_string()),
+        };
+        assert!(!format!("{auth:?}").contains("secret"));
+        assert!(!format!("{:?}", HTTPAuth::Bearer("secret".to_string())).contains("secret"));
+    }
+}
@@ -0,0 +1,339 @@
+use std::sync::{Condvar, Mutex};
+
+use zarrs_storage::{
+    byte_range::ByteRange, Bytes, MaybeBytes, ReadableStorageTraits, StorageError, StoreKey,
+    StoreValueVersion,
+};
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue, RANGE},
+    Method, StatusCode, Url,
+};
+
+use crate::{
+    etag_version, handle_reqwest_error, handle_url_error, is_retryable_error, is_retryable_status,
+    key_to_url, parse_base_url, range_header, range_response_bytes, size_response,
+    version_response, HTTPAuth, HTTPRetryPolicy, HTTPStoreBuilder, HTTPStoreCreateError,
+};
+
+/// A synchronous HTTP store.
+///
+/// Use [`HTTPStoreBuilder`] to configure headers, authentication, timeouts, retries, and the maximum number of concurrent requests.
+#[derive(Debug)]
+pub struct HTTPStore {
+    base_url: Url,
+    batch_range_requests: bool,
+    client: reqwest::blocking::Client,
+    auth: Option<HTTPAuth>,
+    retry_policy: HTTPRetryPolicy,
+    request_limiter: Option<RequestLimiter>,
+}
+
+/// Limits the number of concurrent requests.
+#[derive(Debug)]
+struct RequestLimiter {
+    limit: usize,
+    active: Mutex<usize>,
+    available: Condvar,
+}
+
+impl RequestLimiter {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            active: Mutex::new(0),
+            available: Condvar::new(),
+        }
+    }
+
+    /// Wait until a request can be made.
+    fn acquire(&self) -> RequestPermit<'_> {
+        let mut active = self.active.lock().unwrap();
+        while *active >= self.limit {
+            active = self.available.wait(active).unwrap();
+        }
+        *active += 1;
+        RequestPermit(self)
+    }
+}
+
+/// Permission to make a request, released on drop.
+struct RequestPermit<'a>(&'a RequestLimiter);
+
+impl Drop for RequestPermit<'_> {
+    fn drop(&mut self) {
+        *self.0.active.lock().unwrap() -= 1;
+        self.0.available.notify_one();
+    }
+}
+
+impl HTTPStore {
+    /// Create a new HTTP store at a given `base_url`.
+    ///
+    /// Use [`HTTPStoreBuilder`] to create a HTTP store with additional configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HTTPStoreCreateError`] if `base_url` is not a valid URL.
+    pub fn new(base_url: &str) -> Result<Self, HTTPStoreCreateError> {
+        HTTPStoreBuilder::new(base_url).build()
+    }
+
+    /// Create a new HTTP store from a [`HTTPStoreBuilder`].
+    pub(crate) fn new_with_builder(
+        builder: &HTTPStoreBuilder,
+    ) -> Result<Self, HTTPStoreCreateError> {
+        let base_url = parse_base_url(&builder.base_url)?;
+        let mut client =
+            reqwest::blocking::Client::builder().default_headers(builder.headers.clone());
+        if let Some(timeout) = builder.timeout {
+            client = client.timeout(timeout);
+        }
+        Ok(Self {
+            base_url,
+            batch_range_requests: builder.batch_range_requests,
+            client: client.build()?,
+            auth: builder.auth.clone(),
+            retry_policy: builder.retry_policy,
+            request_limiter: builder
+                .max_concurrent_requests
+                .map(|limit| RequestLimiter::new(limit.get())),
+        })
+    }
+
+    /// Set whether to batch range requests.
+    ///
+    /// Defaults to true.
+    /// Some servers do not fully support multipart ranges and might return an entire resource given such a request.
+    /// It may be preferable to disable batched range requests in this case, so that each range request is a single part range.
+    pub fn set_batch_range_requests(&mut self, batch_range_requests: bool) {
+        self.batch_range_requests = batch_range_requests;
+    }
+
+    /// Maps a [`StoreKey`] to a HTTP [`Url`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid.
+    pub fn key_to_url(&self, key: &StoreKey) -> Result<Url, url::ParseError> {
+        key_to_url(&self.base_url, key)
+    }
+
+    /// Make a request and return the status, headers, and body of the response.
+    ///
+    /// The request is retried according to the retry policy.
+    fn fetch(
+        &self,
+        method: &Method,
+        url: &Url,
+        range: Option<&HeaderValue>,
+    ) -> Result<(StatusCode, HeaderMap, Bytes), StorageError> {
+        let _permit = self.request_limiter.as_ref().map(RequestLimiter::acquire);
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.request(method.clone(), url.clone());
+            if let Some(range) = range {
+                request = request.header(RANGE, range);
+            }
+            request = match &self.auth {
+                Some(HTTPAuth::Basic { username, password }) => {
+                    request.basic_auth(username, password.as_ref())
+                }
+                Some(HTTPAuth::Bearer(token)) => request.bearer_auth(token),
+                None => request,
+            };
+            let response = request.send();
+            let retry = match &response {
+                Ok(response) => is_retryable_status(response.status()),
+                Err(err) => is_retryable_error(err),
+            };
+            if retry {
+                if let Some(backoff) = self.retry_policy.backoff(attempt) {
+                    std::thread::sleep(backoff);
+                    attempt += 1;
+                    continue;
+                }
+            }
+            let response = response.map_err(handle_reqwest_error)?;
+            let status = response.status();
+            if is_retryable_status(status) {
+                return Err(StorageError::Transient(format!(
+                    "the http server responded with status {status}"
+                )));
+            }
+            let headers = response.headers().clone();
+            let bytes = response.bytes().map_err(handle_reqwest_error)?;
+            return Ok((status, headers, bytes));
+        }
+    }
+
+    /// Retrieve `byte_ranges` of the value at `url` with `size` in a single request.
+    fn get_byte_ranges(
+        &self,
+        url: &Url,
+        byte_ranges: &[ByteRange],
+        size: u64,
+    ) -> Result<Vec<Bytes>, StorageError> {
+        let range = range_header(byte_ranges, size)?;
+        let (status, _headers, bytes) = self.fetch(&Method::GET, url, Some(&range))?;
+        range_response_bytes(status, bytes, byte_ranges, size)
+    }
+}
+
+impl ReadableStorageTraits for HTTPStore {
+    fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, _headers, bytes) = self.fetch(&Method::GET, &url, None)?;
+        match status {
+            StatusCode::OK => Ok(Some(bytes)),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(StorageError::from(format!(
+                "http unexpected status code: {status}"
+            ))),
+        }
+    }
+
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let Some(size) = self.size_key(key)? else {
+            return Ok(None);
+        };
+        if self.batch_range_requests {
+            Ok(Some(self.get_byte_ranges(&url, byte_ranges, size)?))
+        } else {
+            let mut out = Vec::with_capacity(byte_ranges.len());
+            for byte_range in byte_ranges {
+                out.append(&mut self.get_byte_ranges(
+                    &url,
+                    std::slice::from_ref(byte_range),
+                    size,
+                )?);
+            }
+            Ok(Some(out))
+        }
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, headers, _bytes) = self.fetch(&Method::HEAD, &url, None)?;
+        size_response(status, &headers)
+    }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, headers, bytes) = self.fetch(&Method::GET, &url, None)?;
+        match status {
+            StatusCode::OK => Ok(Some((bytes, etag_version(&headers)?))),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(StorageError::from(format!(
+                "http unexpected status code: {status}"
+            ))),
+        }
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        let url = self.key_to_url(key).map_err(handle_url_error)?;
+        let (status, headers, _bytes) = self.fetch(&Method::HEAD, &url, None)?;
+        version_response(status, &headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    const HTTP_TEST_PATH_REF: &str =
+        "https://raw.githubusercontent.com/LDeakin/zarrs/main/zarrs/tests/data/store";
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store() -> Result<(), Box<dyn Error>> {
+        let store = HTTPStore::new(HTTP_TEST_PATH_REF).unwrap();
+        zarrs_storage::store_test::store_read(&store)?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_local() -> Result<(), Box<dyn Error>> {
+        let server = crate::test_server::TestServer::start();
+        let mut store = HTTPStore::new(&server.base_url())?;
+        zarrs_storage::store_test::store_read(&store)?;
+        store.set_batch_range_requests(false);
+        zarrs_storage::store_test::store_read(&store)?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_versioned() -> Result<(), Box<dyn Error>> {
+        let server = crate::test_server::TestServer::start();
+        let store = HTTPStore::new(&server.base_url())?;
+        let key = "a/b".try_into()?;
+        let (_bytes, version) = store.get_versioned(&key)?.unwrap();
+        assert!(version.as_str().starts_with('"'));
+        assert_eq!(store.version_key(&key)?, Some(version));
+        assert_eq!(store.version_key(&"a/missing".try_into()?)?, None);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_builder() -> Result<(), Box<dyn Error>> {
+        use crate::{HTTPRetryPolicy, HTTPStoreBuilder};
+        use std::{num::NonZeroUsize, time::Duration};
+
+        // Authentication
+        let server = crate::test_server::TestServer::start_with(Some("Bearer token"), 0);
+        let store = HTTPStore::new(&server.base_url())?;
+        assert!(store.get(&"a/b".try_into()?).is_err());
+        let store = HTTPStoreBuilder::new(&server.base_url())
+            .bearer_auth("token")
+            .build()?;
+        zarrs_storage::store_test::store_read(&store)?;
+
+        let server = crate::test_server::TestServer::start_with(Some("Basic dXNlcjpwYXNz"), 0);
+        let store = HTTPStoreBuilder::new(&server.base_url())
+            .basic_auth("user", Some("pass"))
+            .build()?;
+        zarrs_storage::store_test::store_read(&store)?;
+
+        // Headers
+        let server = crate::test_server::TestServer::start_with(Some("Custom"), 0);
+        let store = HTTPStoreBuilder::new(&server.base_url())
+            .header(
+                reqwest::header::AUTHORIZATION,
+                reqwest::header::HeaderValue::from_static("Custom"),
+            )
+            .timeout(Duration::from_secs(10))
+            .max_concurrent_requests(NonZeroUsize::new(1).unwrap())
+            .batch_range_requests(false)
+            .build()?;
+        zarrs_storage::store_test::store_read(&store)?;
+
+        // Retries
+        let retry_policy =
+            HTTPRetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(1));
+        let server = crate::test_server::TestServer::start_with(None, 3);
+        let store = HTTPStoreBuilder::new(&server.base_url())
+            .retry_policy(retry_policy)
+            .build()?;
+        assert!(store.get(&"a/b".try_into()?).is_err());
+        assert_eq!(server.requests(), 3);
+        assert_eq!(
+            store.get(&"a/b".try_into()?)?,
+            Some(vec![0, 1, 2, 3].into())
+        );
+        assert_eq!(server.requests(), 4);
+
+        Ok(())
+    }
+}
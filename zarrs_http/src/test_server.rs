@@ -0,0 +1,177 @@
+//! A minimal HTTP server for testing, serving the files of `zarrs/tests/data/store`.
+//!
+//! A request with a single byte range gets a partial content response.
+//! A request with multiple byte ranges gets the entire value, as permitted by RFC 9110.
+//! The `ETag` of a value is derived from its content.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+pub(crate) struct TestServer {
+    address: SocketAddr,
+    state: Arc<TestServerState>,
+}
+
+struct TestServerState {
+    /// The required value of the `Authorization` header of each request.
+    authorization: Option<String>,
+    /// The number of remaining requests to respond to with `503 Service Unavailable`.
+    failures: AtomicUsize,
+    /// The number of requests received.
+    requests: AtomicUsize,
+}
+
+impl TestServer {
+    /// Start the server on an unused local port.
+    pub(crate) fn start() -> Self {
+        Self::start_with(None, 0)
+    }
+
+    /// Start the server on an unused local port.
+    ///
+    /// Requests must have an `Authorization` header matching `authorization` if set.
+    /// The first `failures` requests fail with `503 Service Unavailable`.
+    pub(crate) fn start_with(authorization: Option<&str>, failures: usize) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let state = Arc::new(TestServerState {
+            authorization: authorization.map(str::to_string),
+            failures: AtomicUsize::new(failures),
+            requests: AtomicUsize::new(0),
+        });
+        std::thread::spawn({
+            let state = state.clone();
+            move || {
+                for stream in listener.incoming().flatten() {
+                    let state = state.clone();
+                    std::thread::spawn(move || handle_connection(stream, &state));
+                }
+            }
+        });
+        Self { address, state }
+    }
+
+    /// The number of requests received.
+    pub(crate) fn requests(&self) -> usize {
+        self.state.requests.load(Ordering::SeqCst)
+    }
+
+    /// The base URL of the server.
+    pub(crate) fn base_url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, state: &TestServerState) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut request_line = request_line.split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+
+    let mut range = None;
+    let mut authorization = None;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            } else if name.eq_ignore_ascii_case("authorization") {
+                authorization = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    state.requests.fetch_add(1, Ordering::SeqCst);
+    if state
+        .failures
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |failures| {
+            failures.checked_sub(1)
+        })
+        .is_ok()
+    {
+        return write_response(&mut stream, "503 Service Unavailable", &[], &[], false);
+    }
+    if state.authorization.is_some() && authorization != state.authorization {
+        return write_response(&mut stream, "401 Unauthorized", &[], &[], false);
+    }
+
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../zarrs/tests/data/store");
+    let value = if path.split('/').any(|component| component == "..") {
+        None
+    } else {
+        std::fs::read(root.join(path.trim_start_matches('/'))).ok()
+    };
+    let Some(value) = value else {
+        return write_response(&mut stream, "404 Not Found", &[], &[], false);
+    };
+
+    let ranges: Vec<(usize, usize)> = range
+        .as_deref()
+        .and_then(|range| range.strip_prefix("bytes="))
+        .map(|ranges| {
+            ranges
+                .split(',')
+                .filter_map(|range| {
+                    let (start, end) = range.trim().split_once('-')?;
+                    let start: usize = start.parse().ok()?;
+                    let end: usize = end.parse().ok()?;
+                    Some((start, end.min(value.len().saturating_sub(1))))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let head = method == "HEAD";
+    let etag = format!(
+        "ETag: \"{}\"",
+        zarrs_storage::StoreValueVersion::from_value(&value)
+    );
+    if let [(start, end)] = ranges[..] {
+        let content_range = format!("Content-Range: bytes {start}-{end}/{}", value.len());
+        write_response(
+            &mut stream,
+            "206 Partial Content",
+            &[content_range, etag],
+            &value[start..=end],
+            head,
+        )
+    } else {
+        write_response(&mut stream, "200 OK", &[etag], &value, head)
+    }
+}
+
+fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    headers: &[String],
+    body: &[u8],
+    head: bool,
+) -> std::io::Result<()> {
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        body.len()
+    );
+    for header in headers {
+        response.push_str(header);
+        response.push_str("\r\n");
+    }
+    response.push_str("\r\n");
+    stream.write_all(response.as_bytes())?;
+    if !head {
+        stream.write_all(body)?;
+    }
+    stream.flush()
+}
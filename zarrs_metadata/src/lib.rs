@@ -21,12 +21,25 @@ pub mod v2;
 /// Zarr V2 to V3 conversion.
 pub mod v2_to_v3;
 
+/// Zarr V3 to V2 conversion.
+pub mod v3_to_v2;
+
 /// An alias for [`v3::MetadataV3`].
 #[deprecated(since = "0.17.0", note = "use v3::MetadataV3 explicitly")]
 pub type Metadata = v3::MetadataV3;
 
 pub use array::{ArrayShape, ChunkKeySeparator, ChunkShape, DimensionName, Endianness};
 
+/// A Zarr format version.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ZarrVersion {
+    /// Zarr Version 3.0.
+    #[default]
+    V3,
+    /// Zarr Version 2.0.
+    V2,
+}
+
 /// A wrapper to handle various versions of Zarr array metadata.
 #[derive(Deserialize, Serialize, Clone, PartialEq, Debug, Display, From)]
 #[serde(untagged)]
@@ -45,7 +58,7 @@ impl TryFrom<&str> for ArrayMetadata {
 }
 
 /// A wrapper to handle various versions of Zarr group metadata.
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display, From)]
 #[serde(untagged)]
 pub enum GroupMetadata {
     /// Zarr Version 3.0.
@@ -54,6 +67,29 @@ pub enum GroupMetadata {
     V2(v2::GroupMetadataV2),
 }
 
+impl GroupMetadata {
+    /// Return the consolidated metadata of the hierarchy below the group, if present.
+    #[must_use]
+    pub const fn consolidated_metadata(&self) -> Option<&v3::ConsolidatedMetadata> {
+        match self {
+            Self::V3(metadata) => metadata.consolidated_metadata.as_ref(),
+            Self::V2(metadata) => metadata.consolidated_metadata.as_ref(),
+        }
+    }
+
+    /// Set the consolidated metadata of the hierarchy below the group.
+    pub fn set_consolidated_metadata(
+        &mut self,
+        consolidated_metadata: Option<v3::ConsolidatedMetadata>,
+    ) -> &mut Self {
+        match self {
+            Self::V3(metadata) => metadata.consolidated_metadata = consolidated_metadata,
+            Self::V2(metadata) => metadata.consolidated_metadata = consolidated_metadata,
+        }
+        self
+    }
+}
+
 impl TryFrom<&str> for GroupMetadata {
     type Error = serde_json::Error;
     fn try_from(metadata_json: &str) -> Result<Self, Self::Error> {
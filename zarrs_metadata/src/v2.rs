@@ -5,7 +5,7 @@ pub mod group;
 pub mod array;
 
 pub use array::ArrayMetadataV2;
-pub use group::GroupMetadataV2;
+pub use group::{ConsolidatedMetadataV2, GroupMetadataV2};
 
 mod metadata;
 pub use metadata::MetadataV2;
@@ -22,7 +22,7 @@ pub enum NodeMetadataV2 {
 
 #[cfg(test)]
 mod tests {
-    use array::data_type_metadata_v2_to_endianness;
+    use array::{data_type_metadata_v2_to_endianness, DataTypeMetadataV2};
 
     use super::*;
 
@@ -81,6 +81,14 @@ mod tests {
         );
         println!("{array_metadata_v2:?}");
 
+        // Serialised Zarr V2 metadata has no node type and round trips
+        let array_metadata_v2_json = serde_json::to_value(&array_metadata_v2).unwrap();
+        assert!(array_metadata_v2_json.get("node_type").is_none());
+        assert_eq!(
+            serde_json::from_value::<crate::v2::ArrayMetadataV2>(array_metadata_v2_json).unwrap(),
+            array_metadata_v2
+        );
+
         let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2)?;
         println!("{array_metadata_v3:?}");
 
@@ -100,4 +108,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn data_type_v2_structured() {
+        let json = r#"[["x","<f4"],["id","<u8",[2]]]"#;
+        let data_type: DataTypeMetadataV2 = serde_json::from_str(json).unwrap();
+        let DataTypeMetadataV2::Structured(fields) = &data_type else {
+            panic!()
+        };
+        assert_eq!(fields[0].fieldname(), "x");
+        assert_eq!(fields[0].shape(), None);
+        assert_eq!(fields[1].datatype(), "<u8");
+        assert_eq!(fields[1].shape(), Some([2].as_slice()));
+        assert_eq!(serde_json::to_string(&data_type).unwrap(), json);
+
+        assert!(serde_json::from_str::<DataTypeMetadataV2>(r#"[["x"]]"#).is_err());
+        assert!(serde_json::from_str::<DataTypeMetadataV2>(r#"[["x","<f4",[2],1]]"#).is_err());
+    }
 }
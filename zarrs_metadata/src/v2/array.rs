@@ -8,14 +8,34 @@ use crate::{
 
 /// Zarr V2 codec metadata.
 pub mod codec {
+    /// `astype` codec metadata.
+    pub mod astype;
     /// `bitround` codec metadata.
     pub mod bitround;
     /// `blosc` codec metadata.
     pub mod blosc;
     /// `bz2` codec metadata.
     pub mod bz2;
+    /// `categorize` codec metadata.
+    pub mod categorize;
+    /// `delta` codec metadata.
+    pub mod delta;
+    /// `fixedscaleoffset` codec metadata.
+    pub mod fixedscaleoffset;
+    /// `fletcher32` codec metadata.
+    pub mod fletcher32;
     /// `gzip` codec metadata.
     pub mod gzip;
+    /// `lz4` codec metadata.
+    pub mod lz4;
+    /// `lzma` codec metadata.
+    pub mod lzma;
+    /// `packbits` codec metadata.
+    pub mod packbits;
+    /// `quantize` codec metadata.
+    pub mod quantize;
+    /// `shuffle` codec metadata.
+    pub mod shuffle;
     /// `vlen-array` codec metadata.
     pub mod vlen_array;
     /// `vlen-bytes` codec metadata.
@@ -24,6 +44,8 @@ pub mod codec {
     pub mod vlen_utf8;
     /// `zfpy` codec metadata.
     pub mod zfpy;
+    /// `zlib` codec metadata.
+    pub mod zlib;
     /// `zstd` codec metadata.
     pub mod zstd;
 }
@@ -57,7 +79,6 @@ pub mod codec {
 /// }
 /// ```
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Display)]
-#[serde(tag = "node_type", rename = "array")]
 #[display("{}", serde_json::to_string(self).unwrap_or_default())]
 pub struct ArrayMetadataV2 {
     /// An integer defining the version of the storage specification to which the array adheres. Must be `2`.
@@ -158,11 +179,10 @@ const fn chunk_key_separator_default_zarr_v2() -> ChunkKeySeparator {
 }
 
 /// Structure data type metadata.
-#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
-#[serde(
-    from = "DataTypeMetadataV2StructuredTuple",
-    into = "DataTypeMetadataV2StructuredTuple"
-)]
+///
+/// Serialised as a `[fieldname, datatype]` or `[fieldname, datatype, shape]` array.
+#[derive(Serialize, Clone, PartialEq, Eq, Debug)]
+#[serde(into = "DataTypeMetadataV2StructuredTuple")]
 pub struct DataTypeMetadataV2Structured {
     /// Field name.
     fieldname: String,
@@ -172,22 +192,78 @@ pub struct DataTypeMetadataV2Structured {
     shape: Option<Vec<u64>>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 struct DataTypeMetadataV2StructuredTuple(
     String,
     String,
     #[serde(skip_serializing_if = "Option::is_none")] Option<Vec<u64>>,
 );
 
-impl From<DataTypeMetadataV2StructuredTuple> for DataTypeMetadataV2Structured {
-    fn from(value: DataTypeMetadataV2StructuredTuple) -> Self {
-        let DataTypeMetadataV2StructuredTuple(fieldname, datatype, shape) = value;
+impl DataTypeMetadataV2Structured {
+    /// Create a new structured data type field.
+    #[must_use]
+    pub fn new(fieldname: String, datatype: String, shape: Option<Vec<u64>>) -> Self {
         Self {
             fieldname,
             datatype,
             shape,
         }
     }
+
+    /// Return the field name.
+    #[must_use]
+    pub fn fieldname(&self) -> &str {
+        &self.fieldname
+    }
+
+    /// Return the data type.
+    #[must_use]
+    pub fn datatype(&self) -> &str {
+        &self.datatype
+    }
+
+    /// Return the subarray shape.
+    #[must_use]
+    pub fn shape(&self) -> Option<&[u64]> {
+        self.shape.as_deref()
+    }
+}
+
+impl<'de> Deserialize<'de> for DataTypeMetadataV2Structured {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FieldVisitor {
+            type Value = DataTypeMetadataV2Structured;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a [fieldname, datatype] or [fieldname, datatype, shape] array")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let fieldname: String = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let datatype: String = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                let shape: Option<Vec<u64>> = seq.next_element::<Option<Vec<u64>>>()?.flatten();
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(4, &self));
+                }
+                Ok(DataTypeMetadataV2Structured {
+                    fieldname,
+                    datatype,
+                    shape,
+                })
+            }
+        }
+
+        d.deserialize_seq(FieldVisitor)
+    }
 }
 
 impl From<DataTypeMetadataV2Structured> for DataTypeMetadataV2StructuredTuple {
@@ -214,6 +290,8 @@ pub struct DataTypeMetadataV2InvalidEndiannessError(DataTypeMetadataV2);
 
 /// Get the endianness of a Zarr V2 data type.
 ///
+/// The endianness of a structured data type is the common endianness of its fields.
+///
 /// # Errors
 /// Returns a [`DataTypeMetadataV2InvalidEndiannessError`] if the data type is not supported or the endianness prefix is invalid.
 pub fn data_type_metadata_v2_to_endianness(
@@ -231,8 +309,25 @@ pub fn data_type_metadata_v2_to_endianness(
                 Err(DataTypeMetadataV2InvalidEndiannessError(data_type.clone()))
             }
         }
-        DataTypeMetadataV2::Structured(_) => {
-            Err(DataTypeMetadataV2InvalidEndiannessError(data_type.clone()))
+        DataTypeMetadataV2::Structured(fields) => {
+            let mut endianness = None;
+            for field in fields {
+                let field_endianness = data_type_metadata_v2_to_endianness(
+                    &DataTypeMetadataV2::Simple(field.datatype.clone()),
+                )
+                .map_err(|_| DataTypeMetadataV2InvalidEndiannessError(data_type.clone()))?;
+                match (endianness, field_endianness) {
+                    (Some(endianness), Some(field_endianness))
+                        if endianness != field_endianness =>
+                    {
+                        // Mixed endianness is not supported
+                        return Err(DataTypeMetadataV2InvalidEndiannessError(data_type.clone()));
+                    }
+                    (None, Some(field_endianness)) => endianness = Some(field_endianness),
+                    _ => {}
+                }
+            }
+            Ok(endianness)
         }
     }
 }
@@ -0,0 +1 @@
+pub use crate::v3::array::codec::astype::AsTypeCodecConfigurationV1;
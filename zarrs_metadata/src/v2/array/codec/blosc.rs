@@ -86,6 +86,25 @@ pub fn codec_blosc_v2_numcodecs_to_v3(
     })
 }
 
+/// Convert [`BloscCodecConfiguration`] to [`BloscCodecConfigurationNumcodecs`].
+#[must_use]
+pub fn codec_blosc_v3_to_v2_numcodecs(
+    blosc: &BloscCodecConfiguration,
+) -> BloscCodecConfigurationNumcodecs {
+    match blosc {
+        BloscCodecConfiguration::V1(blosc) => BloscCodecConfigurationNumcodecs {
+            cname: blosc.cname,
+            clevel: blosc.clevel,
+            shuffle: match blosc.shuffle {
+                BloscShuffleMode::NoShuffle => BloscShuffleModeNumCodecs::NoShuffle,
+                BloscShuffleMode::Shuffle => BloscShuffleModeNumCodecs::Shuffle,
+                BloscShuffleMode::BitShuffle => BloscShuffleModeNumCodecs::BitShuffle,
+            },
+            blocksize: blosc.blocksize,
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1 @@
+pub use crate::v3::array::codec::fletcher32::Fletcher32CodecConfigurationV1;
@@ -0,0 +1,3 @@
+pub use crate::v3::array::codec::lzma::{
+    LzmaCheck, LzmaCodecConfigurationV1, LzmaFormat, LZMA_PRESET_EXTREME,
+};
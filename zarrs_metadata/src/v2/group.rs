@@ -1,10 +1,17 @@
+use std::collections::BTreeMap;
+
 use derive_more::{Display, From};
 use serde::{Deserialize, Serialize};
 
-use crate::v3::AdditionalFields;
+use crate::{
+    v3::{AdditionalFields, ConsolidatedMetadata, ConsolidatedMetadataMetadata},
+    ArrayMetadata, GroupMetadata, NodeMetadata,
+};
+
+use super::ArrayMetadataV2;
 
 /// Zarr V2 group metadata.
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display, From)]
 #[display("{}", serde_json::to_string(self).unwrap_or_default())]
 pub struct GroupMetadataV2 {
     /// An integer defining the version of the storage specification to which the group adheres. Must be `2`.
@@ -17,6 +24,11 @@ pub struct GroupMetadataV2 {
     /// These are not part of Zarr V2, but are retained for compatibility/flexibility.
     #[serde(default, flatten)]
     pub additional_fields: AdditionalFields,
+    /// Optional consolidated metadata of the hierarchy below the group.
+    ///
+    /// This is stored separately in `.zmetadata` as [`ConsolidatedMetadataV2`].
+    #[serde(skip)]
+    pub consolidated_metadata: Option<ConsolidatedMetadata>,
 }
 
 impl Default for GroupMetadataV2 {
@@ -33,6 +45,7 @@ impl GroupMetadataV2 {
             zarr_format: monostate::MustBe!(2u64),
             attributes: serde_json::Map::new(),
             additional_fields: AdditionalFields::default(),
+            consolidated_metadata: None,
         }
     }
 
@@ -46,6 +59,16 @@ impl GroupMetadataV2 {
         self
     }
 
+    /// Set the consolidated metadata.
+    #[must_use]
+    pub fn with_consolidated_metadata(
+        mut self,
+        consolidated_metadata: Option<ConsolidatedMetadata>,
+    ) -> Self {
+        self.consolidated_metadata = consolidated_metadata;
+        self
+    }
+
     /// Set the additional fields.
     #[must_use]
     pub fn with_additional_fields(mut self, additional_fields: AdditionalFields) -> Self {
@@ -53,3 +76,189 @@ impl GroupMetadataV2 {
         self
     }
 }
+
+/// Zarr V2 consolidated metadata (`.zmetadata`).
+///
+/// The `metadata` holds the contents of the `.zgroup`, `.zarray`, and `.zattrs` documents of a hierarchy, keyed by their path relative to the consolidated group.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Display)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ConsolidatedMetadataV2 {
+    /// The metadata documents of the hierarchy.
+    pub metadata: BTreeMap<String, serde_json::Map<String, serde_json::Value>>,
+    /// The version of the consolidated metadata format. Must be `1`.
+    pub zarr_consolidated_format: monostate::MustBe!(1u64),
+}
+
+impl ConsolidatedMetadataV2 {
+    /// Create Zarr V2 consolidated metadata.
+    #[must_use]
+    pub fn new(metadata: BTreeMap<String, serde_json::Map<String, serde_json::Value>>) -> Self {
+        Self {
+            metadata,
+            zarr_consolidated_format: monostate::MustBe!(1u64),
+        }
+    }
+
+    /// Create Zarr V2 consolidated metadata from the metadata of a group, including its [`consolidated_metadata`](GroupMetadataV2::consolidated_metadata).
+    ///
+    /// # Errors
+    /// Returns a [`serde_json::Error`] if the consolidated metadata includes Zarr V3 metadata.
+    pub fn from_group_metadata(
+        group_metadata: &GroupMetadataV2,
+    ) -> Result<Self, serde_json::Error> {
+        let mut metadata = BTreeMap::new();
+        insert_node_metadata_v2(
+            &mut metadata,
+            "",
+            &NodeMetadata::Group(GroupMetadata::V2(group_metadata.clone())),
+        )?;
+        if let Some(consolidated_metadata) = &group_metadata.consolidated_metadata {
+            for (path, node_metadata) in &consolidated_metadata.metadata {
+                insert_node_metadata_v2(&mut metadata, &format!("{path}/"), node_metadata)?;
+            }
+        }
+        Ok(Self::new(metadata))
+    }
+
+    /// Convert to [`ConsolidatedMetadata`].
+    ///
+    /// The metadata of the consolidated group itself is excluded.
+    ///
+    /// # Errors
+    /// Returns a [`serde_json::Error`] if any metadata is invalid.
+    pub fn to_consolidated_metadata(&self) -> Result<ConsolidatedMetadata, serde_json::Error> {
+        let mut consolidated_metadata = ConsolidatedMetadataMetadata::new();
+        for (key, value) in &self.metadata {
+            let Some((path, file_name)) = key.rsplit_once('/') else {
+                // The metadata of the consolidated group
+                continue;
+            };
+            let value = serde_json::Value::Object(value.clone());
+            let attributes = self
+                .metadata
+                .get(&format!("{path}/.zattrs"))
+                .cloned()
+                .unwrap_or_default();
+            let node_metadata = match file_name {
+                ".zarray" => {
+                    let mut metadata: ArrayMetadataV2 = serde_json::from_value(value)?;
+                    metadata.attributes = attributes;
+                    NodeMetadata::Array(ArrayMetadata::V2(metadata))
+                }
+                ".zgroup" => {
+                    let mut metadata: GroupMetadataV2 = serde_json::from_value(value)?;
+                    metadata.attributes = attributes;
+                    NodeMetadata::Group(GroupMetadata::V2(metadata))
+                }
+                _ => continue,
+            };
+            consolidated_metadata.insert(path.to_string(), node_metadata);
+        }
+        Ok(ConsolidatedMetadata::new(consolidated_metadata))
+    }
+}
+
+fn insert_node_metadata_v2(
+    metadata: &mut BTreeMap<String, serde_json::Map<String, serde_json::Value>>,
+    prefix: &str,
+    node_metadata: &NodeMetadata,
+) -> Result<(), serde_json::Error> {
+    fn to_map(
+        value: &impl Serialize,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, serde_json::Error> {
+        match serde_json::to_value(value)? {
+            serde_json::Value::Object(map) => Ok(map),
+            _ => Err(serde::ser::Error::custom("metadata must be a JSON object")),
+        }
+    }
+
+    let (file_name, value, attributes) = match node_metadata {
+        NodeMetadata::Array(ArrayMetadata::V2(metadata)) => {
+            let mut metadata = metadata.clone();
+            let attributes = std::mem::take(&mut metadata.attributes);
+            (".zarray", to_map(&metadata)?, attributes)
+        }
+        NodeMetadata::Group(GroupMetadata::V2(metadata)) => {
+            let mut metadata = metadata.clone();
+            let attributes = std::mem::take(&mut metadata.attributes);
+            (".zgroup", to_map(&metadata)?, attributes)
+        }
+        NodeMetadata::Array(ArrayMetadata::V3(_)) | NodeMetadata::Group(GroupMetadata::V3(_)) => {
+            return Err(serde::ser::Error::custom(
+                "Zarr V3 metadata cannot be stored in Zarr V2 consolidated metadata",
+            ));
+        }
+    };
+    metadata.insert(format!("{prefix}{file_name}"), value);
+    if !attributes.is_empty() {
+        metadata.insert(format!("{prefix}.zattrs"), attributes);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consolidated_metadata_v2() {
+        let json = r#"{
+            "metadata": {
+                ".zattrs": {"root": true},
+                ".zgroup": {"zarr_format": 2},
+                "a/.zgroup": {"zarr_format": 2},
+                "a/b/.zarray": {
+                    "chunks": [2],
+                    "compressor": null,
+                    "dtype": "<u2",
+                    "fill_value": 0,
+                    "filters": null,
+                    "order": "C",
+                    "shape": [4],
+                    "zarr_format": 2
+                },
+                "a/b/.zattrs": {"key": "value"}
+            },
+            "zarr_consolidated_format": 1
+        }"#;
+        let consolidated_metadata_v2: ConsolidatedMetadataV2 = serde_json::from_str(json).unwrap();
+        let consolidated_metadata = consolidated_metadata_v2.to_consolidated_metadata().unwrap();
+        assert_eq!(
+            consolidated_metadata.metadata.keys().collect::<Vec<_>>(),
+            vec!["a", "a/b"]
+        );
+        let NodeMetadata::Array(ArrayMetadata::V2(array_metadata)) =
+            &consolidated_metadata.metadata["a/b"]
+        else {
+            panic!()
+        };
+        assert_eq!(array_metadata.attributes["key"], "value");
+
+        let mut attributes = serde_json::Map::new();
+        attributes.insert("root".to_string(), true.into());
+        let group_metadata = GroupMetadataV2::new()
+            .with_attributes(attributes)
+            .with_consolidated_metadata(Some(consolidated_metadata));
+        let consolidated_metadata_v2_roundtrip =
+            ConsolidatedMetadataV2::from_group_metadata(&group_metadata).unwrap();
+        assert_eq!(
+            consolidated_metadata_v2_roundtrip
+                .metadata
+                .keys()
+                .collect::<Vec<_>>(),
+            vec![
+                ".zattrs",
+                ".zgroup",
+                "a/.zgroup",
+                "a/b/.zarray",
+                "a/b/.zattrs"
+            ]
+        );
+        assert_eq!(
+            consolidated_metadata_v2_roundtrip
+                .to_consolidated_metadata()
+                .unwrap(),
+            consolidated_metadata_v2.to_consolidated_metadata().unwrap()
+        );
+    }
+}
@@ -20,6 +20,36 @@ pub struct MetadataV2 {
 }
 
 impl MetadataV2 {
+    /// Create metadata from `id` and `configuration`.
+    #[must_use]
+    pub fn new_with_configuration(
+        id: &str,
+        configuration: serde_json::Map<String, serde_json::Value>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            configuration,
+        }
+    }
+
+    /// Convert a serializable configuration to [`MetadataV2`].
+    ///
+    /// # Errors
+    /// Returns [`serde_json::Error`] if `configuration` cannot be converted to [`MetadataV2`].
+    pub fn new_with_serializable_configuration<TConfiguration: Serialize>(
+        id: &str,
+        configuration: &TConfiguration,
+    ) -> Result<Self, serde_json::Error> {
+        let configuration = serde_json::to_value(configuration)?;
+        if let serde_json::Value::Object(configuration) = configuration {
+            Ok(Self::new_with_configuration(id, configuration))
+        } else {
+            Err(serde::ser::Error::custom(
+                "the configuration cannot be serialized to a JSON struct",
+            ))
+        }
+    }
+
     /// Return the "id" key.
     #[must_use]
     pub fn id(&self) -> &str {
@@ -1,3 +1,6 @@
+use std::num::NonZeroU32;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
 use thiserror::Error;
 
 use crate::{
@@ -26,7 +29,9 @@ use crate::{
     },
 };
 
-use super::v3::array::data_type::DataTypeMetadataV3;
+use super::v3::array::data_type::{
+    DataTypeMetadataV3, DataTypeMetadataV3StructuredField, NumpyTimeUnit,
+};
 
 /// Convert Zarr V2 group metadata to V3.
 #[allow(clippy::too_many_lines)]
@@ -118,6 +123,19 @@ pub fn array_metadata_v2_to_v3(
         }
     }
 
+    if let (DataTypeMetadataV3::Structured(_), FillValueMetadataV3::String(string)) =
+        (&data_type, &fill_value)
+    {
+        // The fill value of a structured data type is base64 encoded
+        let bytes = BASE64_STANDARD.decode(string).map_err(|_| {
+            ArrayMetadataV2ToV3ConversionError::UnsupportedFillValue(
+                data_type.to_string(),
+                array_metadata_v2.fill_value.clone(),
+            )
+        })?;
+        fill_value = FillValueMetadataV3::ByteArray(bytes);
+    }
+
     let mut codecs: Vec<MetadataV3> = vec![];
 
     // Array-to-array codecs
@@ -268,19 +286,58 @@ pub fn data_type_metadata_v2_to_v3_data_type(
                 "<c8" | ">c8" => Ok(DataTypeMetadataV3::Complex64),
                 "<c16" | ">c16" => Ok(DataTypeMetadataV3::Complex128),
                 "|O" => Ok(DataTypeMetadataV3::String), // LEGACY: This is not part of the spec. The dtype for a PyObject, which is what zarr-python 2 uses for string arrays.
-                // TODO "|mX" timedelta
-                // TODO "|MX" datetime
                 // TODO "|SX" string (fixed length sequence of char)
                 // TODO "|UX" string (fixed length sequence of Py_UNICODE)
                 // TODO "|VX" other (void * – each item is a fixed-size chunk of memory)
-                _ => Err(DataTypeMetadataV2UnsupportedDataTypeError(
-                    data_type.clone(),
-                )),
+                _ => data_type_metadata_v2_numpy_time_to_v3(data_type_str)
+                    .ok_or_else(|| DataTypeMetadataV2UnsupportedDataTypeError(data_type.clone())),
             }
         }
-        DataTypeMetadataV2::Structured(_) => Err(DataTypeMetadataV2UnsupportedDataTypeError(
-            data_type.clone(),
-        )),
+        DataTypeMetadataV2::Structured(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    if field.shape().is_some() {
+                        // TODO: Support subarray fields
+                        return Err(DataTypeMetadataV2UnsupportedDataTypeError(
+                            data_type.clone(),
+                        ));
+                    }
+                    let field_data_type = data_type_metadata_v2_to_v3_data_type(
+                        &DataTypeMetadataV2::Simple(field.datatype().to_string()),
+                    )
+                    .map_err(|_| DataTypeMetadataV2UnsupportedDataTypeError(data_type.clone()))?;
+                    Ok(DataTypeMetadataV3StructuredField::new(
+                        field.fieldname(),
+                        field_data_type,
+                    ))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(DataTypeMetadataV3::Structured(fields))
+        }
+    }
+}
+
+/// Convert a Zarr V2 datetime (e.g. `<M8[ns]`) or timedelta (e.g. `<m8[10s]`) data type to a `numpy.datetime64` or `numpy.timedelta64` data type.
+fn data_type_metadata_v2_numpy_time_to_v3(data_type: &str) -> Option<DataTypeMetadataV3> {
+    let data_type = data_type.strip_prefix(&['<', '>'][..])?;
+    let (is_datetime, units) = if let Some(units) = data_type.strip_prefix("M8[") {
+        (true, units)
+    } else {
+        (false, data_type.strip_prefix("m8[")?)
+    };
+    let units = units.strip_suffix(']')?;
+    let unit_start = units.find(|c: char| !c.is_ascii_digit())?;
+    let scale_factor = if unit_start == 0 {
+        NonZeroU32::MIN
+    } else {
+        units[..unit_start].parse().ok()?
+    };
+    let unit = NumpyTimeUnit::from_str_opt(&units[unit_start..])?;
+    if is_datetime {
+        Some(DataTypeMetadataV3::NumpyDateTime64 { unit, scale_factor })
+    } else {
+        Some(DataTypeMetadataV3::NumpyTimeDelta64 { unit, scale_factor })
     }
 }
 
@@ -316,3 +373,36 @@ pub fn array_metadata_fill_value_v2_to_v3(
         FillValueMetadataV2::String(string) => Some(FillValueMetadataV3::String(string.clone())),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_type_v2_numpy_time() {
+        assert_eq!(
+            data_type_metadata_v2_to_v3_data_type(&DataTypeMetadataV2::Simple("<M8[ns]".into()))
+                .unwrap(),
+            DataTypeMetadataV3::NumpyDateTime64 {
+                unit: NumpyTimeUnit::Nanosecond,
+                scale_factor: NonZeroU32::MIN
+            }
+        );
+        assert_eq!(
+            data_type_metadata_v2_to_v3_data_type(&DataTypeMetadataV2::Simple(">m8[10s]".into()))
+                .unwrap(),
+            DataTypeMetadataV3::NumpyTimeDelta64 {
+                unit: NumpyTimeUnit::Second,
+                scale_factor: NonZeroU32::new(10).unwrap()
+            }
+        );
+        assert!(
+            data_type_metadata_v2_to_v3_data_type(&DataTypeMetadataV2::Simple("<M8".into()))
+                .is_err()
+        );
+        assert!(
+            data_type_metadata_v2_to_v3_data_type(&DataTypeMetadataV2::Simple("<M8[xs]".into()))
+                .is_err()
+        );
+    }
+}
@@ -5,7 +5,9 @@ pub mod group;
 pub mod array;
 
 pub use array::ArrayMetadataV3;
-pub use group::GroupMetadataV3;
+pub use group::{
+    ConsolidatedMetadata, ConsolidatedMetadataKind, ConsolidatedMetadataMetadata, GroupMetadataV3,
+};
 
 mod metadata;
 pub use metadata::{
@@ -12,8 +12,12 @@ pub mod data_type;
 
 /// Zarr V3 codec metadata.
 pub mod codec {
+    /// `astype` codec metadata.
+    pub mod astype;
     /// `bitround` codec metadata.
     pub mod bitround;
+    /// `bitshuffle` codec metadata.
+    pub mod bitshuffle;
     /// `blosc` codec metadata.
     pub mod blosc;
     /// `bytes` codec metadata.
@@ -21,16 +25,34 @@ pub mod codec {
 
     /// `bz2` codec metadata.
     pub mod bz2;
+    /// `categorize` codec metadata.
+    pub mod categorize;
     /// `crc32c` codec metadata.
     pub mod crc32c;
+    /// `delta` codec metadata.
+    pub mod delta;
+    /// `fixedscaleoffset` codec metadata.
+    pub mod fixedscaleoffset;
+    /// `fletcher32` codec metadata.
+    pub mod fletcher32;
     /// `gdeflate` codec metadata.
     pub mod gdeflate;
     /// `gzip` codec metadata.
     pub mod gzip;
+    /// `lz4` codec metadata.
+    pub mod lz4;
+    /// `lzma` codec metadata.
+    pub mod lzma;
+    /// `packbits` codec metadata.
+    pub mod packbits;
     /// `pcodec` codec metadata.
     pub mod pcodec;
+    /// `quantize` codec metadata.
+    pub mod quantize;
     /// `sharding` codec metadata.
     pub mod sharding;
+    /// `shuffle` codec metadata.
+    pub mod shuffle;
     /// `transpose` codec metadata.
     pub mod transpose;
     /// `vlen` codec metadata.
@@ -39,6 +61,8 @@ pub mod codec {
     // pub mod vlen_v2;
     /// `zfp` codec metadata.
     pub mod zfp;
+    /// `zlib` codec metadata.
+    pub mod zlib;
     /// `zstd` codec metadata.
     pub mod zstd;
 }
@@ -59,6 +83,12 @@ pub mod chunk_key_encoding {
     pub mod v2;
 }
 
+/// Zarr V3 storage transformer metadata.
+pub mod storage_transformer {
+    /// `chunk_manifest` storage transformer metadata.
+    pub mod chunk_manifest;
+}
+
 pub mod fill_value;
 pub mod nan_representations;
 
@@ -0,0 +1,60 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::v2::array::DataTypeMetadataV2;
+
+/// The identifier for the `astype` codec.
+pub const IDENTIFIER: &str = "astype";
+
+/// A wrapper to handle various versions of `astype` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum AsTypeCodecConfiguration {
+    /// Version 1.0.
+    V1(AsTypeCodecConfigurationV1),
+}
+
+/// `astype` codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `astype` codec.
+/// Data types are `numpy` type strings, as used in Zarr V2 array metadata.
+///
+/// ### Example: encode `float64` data as `int32`
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "encode_dtype": "<i4",
+///     "decode_dtype": "<f8"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::astype::AsTypeCodecConfigurationV1;
+/// # let configuration: AsTypeCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct AsTypeCodecConfigurationV1 {
+    /// The encoded data type.
+    pub encode_dtype: DataTypeMetadataV2,
+    /// The decoded data type.
+    pub decode_dtype: DataTypeMetadataV2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_astype_config() {
+        let configuration = serde_json::from_str::<AsTypeCodecConfiguration>(
+            r#"{
+                "encode_dtype": "<i4",
+                "decode_dtype": "<f8"
+            }"#,
+        )
+        .unwrap();
+        let AsTypeCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.encode_dtype, DataTypeMetadataV2::from("<i4"));
+        assert_eq!(configuration.decode_dtype, DataTypeMetadataV2::from("<f8"));
+    }
+}
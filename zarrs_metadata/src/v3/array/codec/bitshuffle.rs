@@ -0,0 +1,48 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `bitshuffle` codec.
+pub const IDENTIFIER: &str = "bitshuffle";
+
+/// A wrapper to handle various versions of `bitshuffle` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum BitshuffleCodecConfiguration {
+    /// Version 1.0.
+    V1(BitshuffleCodecConfigurationV1),
+}
+
+/// `bitshuffle` codec configuration parameters (version 1.0).
+///
+/// ### Example: bit shuffle 2 byte elements
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "elementsize": 2
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::bitshuffle::BitshuffleCodecConfigurationV1;
+/// # let configuration: BitshuffleCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct BitshuffleCodecConfigurationV1 {
+    /// The element size in bytes.
+    ///
+    /// This must match the size of the array data type.
+    pub elementsize: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_bitshuffle_config() {
+        let configuration =
+            serde_json::from_str::<BitshuffleCodecConfiguration>(r#"{"elementsize": 2}"#).unwrap();
+        let BitshuffleCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.elementsize, 2);
+    }
+}
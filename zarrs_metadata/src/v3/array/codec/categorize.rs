@@ -0,0 +1,63 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::v2::array::DataTypeMetadataV2;
+
+/// The identifier for the `categorize` codec.
+pub const IDENTIFIER: &str = "categorize";
+
+/// A wrapper to handle various versions of `categorize` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum CategorizeCodecConfiguration {
+    /// Version 1.0.
+    V1(CategorizeCodecConfigurationV1),
+}
+
+/// `categorize` codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `categorize` codec.
+/// Data types are `numpy` type strings, as used in Zarr V2 array metadata.
+///
+/// ### Example: encode strings as `uint8` category indices
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "labels": ["foo", "bar", "baz"],
+///     "dtype": "|O",
+///     "astype": "|u1"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::categorize::CategorizeCodecConfigurationV1;
+/// # let configuration: CategorizeCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct CategorizeCodecConfigurationV1 {
+    /// The category labels.
+    ///
+    /// A label is encoded as its index plus one, and values not matching a label are encoded as zero.
+    pub labels: Vec<String>,
+    /// The decoded data type.
+    pub dtype: DataTypeMetadataV2,
+    /// The encoded data type, an unsigned integer.
+    pub astype: DataTypeMetadataV2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_categorize_config() {
+        serde_json::from_str::<CategorizeCodecConfiguration>(
+            r#"{
+                "labels": ["foo", "bar", "baz"],
+                "dtype": "<U3",
+                "astype": "|u1"
+            }"#,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,69 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::v2::array::DataTypeMetadataV2;
+
+/// The identifier for the `delta` codec.
+pub const IDENTIFIER: &str = "delta";
+
+/// A wrapper to handle various versions of `delta` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum DeltaCodecConfiguration {
+    /// Version 1.0.
+    V1(DeltaCodecConfigurationV1),
+}
+
+/// `delta` codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `delta` codec.
+/// Data types are `numpy` type strings, as used in Zarr V2 array metadata.
+///
+/// ### Example: encode `int32` data as `int16` differences
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "dtype": "<i4",
+///     "astype": "<i2"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::delta::DeltaCodecConfigurationV1;
+/// # let configuration: DeltaCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct DeltaCodecConfigurationV1 {
+    /// The decoded data type.
+    pub dtype: DataTypeMetadataV2,
+    /// The encoded data type. Defaults to `dtype` if unspecified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub astype: Option<DataTypeMetadataV2>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_delta_config() {
+        let configuration = serde_json::from_str::<DeltaCodecConfiguration>(
+            r#"{
+                "dtype": "<i4",
+                "astype": "<i2"
+            }"#,
+        )
+        .unwrap();
+        let DeltaCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.dtype, DataTypeMetadataV2::from("<i4"));
+        assert_eq!(configuration.astype, Some(DataTypeMetadataV2::from("<i2")));
+    }
+
+    #[test]
+    fn codec_delta_config_no_astype() {
+        let configuration =
+            serde_json::from_str::<DeltaCodecConfiguration>(r#"{"dtype": "<f8"}"#).unwrap();
+        let DeltaCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.astype, None);
+    }
+}
@@ -0,0 +1,72 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::v2::array::DataTypeMetadataV2;
+
+/// The identifier for the `fixedscaleoffset` codec.
+pub const IDENTIFIER: &str = "fixedscaleoffset";
+
+/// A wrapper to handle various versions of `fixedscaleoffset` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum FixedScaleOffsetCodecConfiguration {
+    /// Version 1.0.
+    V1(FixedScaleOffsetCodecConfigurationV1),
+}
+
+/// `fixedscaleoffset` codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `fixedscaleoffset` codec.
+/// Data types are `numpy` type strings, as used in Zarr V2 array metadata.
+///
+/// Elements are encoded as `round((x - offset) * scale)` and decoded as `x / scale + offset`.
+///
+/// ### Example: encode `float64` data with 0.1 precision as `uint8`
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "offset": 1000.0,
+///     "scale": 10.0,
+///     "dtype": "<f8",
+///     "astype": "|u1"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::fixedscaleoffset::FixedScaleOffsetCodecConfigurationV1;
+/// # let configuration: FixedScaleOffsetCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct FixedScaleOffsetCodecConfigurationV1 {
+    /// The value subtracted from elements before scaling.
+    pub offset: f64,
+    /// The value elements are multiplied by after the offset is subtracted.
+    pub scale: f64,
+    /// The decoded data type.
+    pub dtype: DataTypeMetadataV2,
+    /// The encoded data type. Defaults to `dtype` if unspecified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub astype: Option<DataTypeMetadataV2>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_fixedscaleoffset_config() {
+        let configuration = serde_json::from_str::<FixedScaleOffsetCodecConfiguration>(
+            r#"{
+                "offset": 1000,
+                "scale": 10,
+                "dtype": "<f8",
+                "astype": "|u1"
+            }"#,
+        )
+        .unwrap();
+        let FixedScaleOffsetCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.offset, 1000.0);
+        assert_eq!(configuration.scale, 10.0);
+        assert_eq!(configuration.astype, Some(DataTypeMetadataV2::from("|u1")));
+    }
+}
@@ -0,0 +1,52 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `fletcher32` codec.
+pub const IDENTIFIER: &str = "fletcher32";
+
+/// A wrapper to handle various versions of `fletcher32` (Fletcher-32 checksum) codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum Fletcher32CodecConfiguration {
+    /// Version 1.0.
+    V1(Fletcher32CodecConfigurationV1),
+}
+
+/// `fletcher32` (Fletcher-32 checksum) codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `fletcher32` codec.
+///
+/// ### Example
+/// ```rust
+/// # let JSON = r#"
+/// {}
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::fletcher32::Fletcher32CodecConfigurationV1;
+/// # let configuration: Fletcher32CodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct Fletcher32CodecConfigurationV1 {}
+
+#[cfg(test)]
+mod tests {
+    use crate::v3::MetadataV3;
+
+    use super::*;
+
+    #[test]
+    fn codec_fletcher32_config() {
+        serde_json::from_str::<Fletcher32CodecConfiguration>(r#"{}"#).unwrap();
+    }
+
+    #[test]
+    fn codec_fletcher32_config_outer() {
+        serde_json::from_str::<MetadataV3>(
+            r#"{
+            "name": "fletcher32"
+        }"#,
+        )
+        .unwrap();
+    }
+}
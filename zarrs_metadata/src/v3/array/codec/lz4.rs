@@ -0,0 +1,86 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `lz4` codec.
+pub const IDENTIFIER: &str = "lz4";
+
+/// A wrapper to handle various versions of `lz4` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum Lz4CodecConfiguration {
+    /// Version 1.0.
+    V1(Lz4CodecConfigurationV1),
+}
+
+/// Configuration parameters for the `lz4` codec (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `lz4` codec.
+///
+/// ### Example: encode with an acceleration of 1
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "acceleration": 1
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::lz4::Lz4CodecConfigurationV1;
+/// # let configuration: Lz4CodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct Lz4CodecConfigurationV1 {
+    /// The acceleration factor.
+    ///
+    /// Higher values are faster but produce less compression.
+    /// Values less than 1 are treated as 1, which gives the most compression.
+    #[serde(default = "lz4_default_acceleration")]
+    pub acceleration: i32,
+}
+
+const fn lz4_default_acceleration() -> i32 {
+    1
+}
+
+impl Lz4CodecConfigurationV1 {
+    /// Create a new `lz4` codec configuration given an `acceleration` factor.
+    #[must_use]
+    pub const fn new(acceleration: i32) -> Self {
+        Self { acceleration }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_lz4_configuration_valid() {
+        const JSON_VALID: &str = r#"{
+            "acceleration": 3
+        }"#;
+        let configuration = serde_json::from_str::<Lz4CodecConfiguration>(JSON_VALID).unwrap();
+        assert_eq!(
+            configuration,
+            Lz4CodecConfiguration::V1(Lz4CodecConfigurationV1::new(3))
+        );
+    }
+
+    #[test]
+    fn codec_lz4_configuration_default() {
+        let configuration = serde_json::from_str::<Lz4CodecConfiguration>(r#"{}"#).unwrap();
+        assert_eq!(
+            configuration,
+            Lz4CodecConfiguration::V1(Lz4CodecConfigurationV1::new(1))
+        );
+    }
+
+    #[test]
+    fn codec_lz4_configuration_invalid() {
+        const JSON_INVALID: &str = r#"{
+            "acceleration": 1,
+            "level": 5
+        }"#;
+        assert!(serde_json::from_str::<Lz4CodecConfiguration>(JSON_INVALID).is_err());
+    }
+}
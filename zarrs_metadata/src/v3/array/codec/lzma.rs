@@ -0,0 +1,140 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// The identifier for the `lzma` codec.
+pub const IDENTIFIER: &str = "lzma";
+
+/// The `lzma` preset flag for slower compression with a potentially higher compression ratio.
+///
+/// This flag can be combined with a preset level with a bitwise or (e.g. `6 | LZMA_PRESET_EXTREME`).
+pub const LZMA_PRESET_EXTREME: u32 = 0x8000_0000;
+
+/// A wrapper to handle various versions of `lzma` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum LzmaCodecConfiguration {
+    /// Version 1.0.
+    V1(LzmaCodecConfigurationV1),
+}
+
+/// Configuration parameters for the `lzma` codec (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `lzma` codec.
+///
+/// ### Example: encode in the `xz` container format with a preset of 9
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "format": 1,
+///     "check": -1,
+///     "preset": 9,
+///     "filters": null
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::lzma::LzmaCodecConfigurationV1;
+/// # let configuration: LzmaCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct LzmaCodecConfigurationV1 {
+    /// The container format.
+    #[serde(default)]
+    pub format: LzmaFormat,
+    /// The integrity check.
+    #[serde(default)]
+    pub check: LzmaCheck,
+    /// The compression preset.
+    ///
+    /// An integer from 0 to 9 which controls the speed and level of compression, optionally combined with [`LZMA_PRESET_EXTREME`].
+    /// A preset of 0 is the fastest and produces the least compression, while 9 is slowest and produces the most compression.
+    /// Defaults to 6 if [`None`].
+    #[serde(default)]
+    pub preset: Option<u32>,
+    /// A custom filter chain, mutually exclusive with `preset`.
+    #[serde(default)]
+    pub filters: Option<Vec<serde_json::Map<String, serde_json::Value>>>,
+}
+
+impl LzmaCodecConfigurationV1 {
+    /// Create a new `lzma` codec configuration with the `xz` container format given a `preset`.
+    #[must_use]
+    pub const fn new(preset: u32) -> Self {
+        Self {
+            format: LzmaFormat::Xz,
+            check: LzmaCheck::Default,
+            preset: Some(preset),
+            filters: None,
+        }
+    }
+}
+
+/// The `lzma` container format.
+#[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[repr(u8)]
+pub enum LzmaFormat {
+    /// The `.xz` container format.
+    #[default]
+    Xz = 1,
+    /// The legacy `.lzma` container format.
+    Alone = 2,
+    /// A raw data stream, without a container.
+    Raw = 3,
+}
+
+/// The `lzma` integrity check.
+#[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[repr(i8)]
+pub enum LzmaCheck {
+    /// The default check of the container format (CRC-64 for `.xz`, none otherwise).
+    #[default]
+    Default = -1,
+    /// No integrity check.
+    None = 0,
+    /// A CRC-32 check.
+    Crc32 = 1,
+    /// A CRC-64 check.
+    Crc64 = 4,
+    /// A SHA-256 check.
+    Sha256 = 10,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_lzma_configuration_valid() {
+        const JSON_VALID: &str = r#"{
+            "format": 2,
+            "check": 0,
+            "preset": 1,
+            "filters": null
+        }"#;
+        let configuration = serde_json::from_str::<LzmaCodecConfiguration>(JSON_VALID).unwrap();
+        let LzmaCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.format, LzmaFormat::Alone);
+        assert_eq!(configuration.check, LzmaCheck::None);
+        assert_eq!(configuration.preset, Some(1));
+    }
+
+    #[test]
+    fn codec_lzma_configuration_default() {
+        let configuration = serde_json::from_str::<LzmaCodecConfigurationV1>(r#"{}"#).unwrap();
+        assert_eq!(configuration.format, LzmaFormat::Xz);
+        assert_eq!(configuration.check, LzmaCheck::Default);
+        assert_eq!(configuration.preset, None);
+        assert_eq!(
+            serde_json::to_string(&configuration).unwrap(),
+            r#"{"format":1,"check":-1,"preset":null,"filters":null}"#
+        );
+    }
+
+    #[test]
+    fn codec_lzma_configuration_invalid() {
+        assert!(serde_json::from_str::<LzmaCodecConfiguration>(r#"{"format": 0}"#).is_err());
+        assert!(serde_json::from_str::<LzmaCodecConfiguration>(r#"{"check": 2}"#).is_err());
+        assert!(serde_json::from_str::<LzmaCodecConfiguration>(r#"{"level": 2}"#).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `packbits` codec.
+pub const IDENTIFIER: &str = "packbits";
+
+/// A wrapper to handle various versions of `packbits` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum PackBitsCodecConfiguration {
+    /// Version 1.0.
+    V1(PackBitsCodecConfigurationV1),
+}
+
+/// `packbits` codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `packbits` codec.
+///
+/// ### Example
+/// ```rust
+/// # let JSON = r#"
+/// {}
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::packbits::PackBitsCodecConfigurationV1;
+/// # let configuration: PackBitsCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct PackBitsCodecConfigurationV1 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_packbits_config() {
+        serde_json::from_str::<PackBitsCodecConfiguration>(r#"{}"#).unwrap();
+        assert!(serde_json::from_str::<PackBitsCodecConfiguration>(r#"{"bits": 1}"#).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::v2::array::DataTypeMetadataV2;
+
+/// The identifier for the `quantize` codec.
+pub const IDENTIFIER: &str = "quantize";
+
+/// A wrapper to handle various versions of `quantize` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum QuantizeCodecConfiguration {
+    /// Version 1.0.
+    V1(QuantizeCodecConfigurationV1),
+}
+
+/// `quantize` codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `quantize` codec.
+/// Data types are `numpy` type strings, as used in Zarr V2 array metadata.
+///
+/// ### Example: quantize `float64` data to 2 decimal digits
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "digits": 2,
+///     "dtype": "<f8",
+///     "astype": "<f8"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::quantize::QuantizeCodecConfigurationV1;
+/// # let configuration: QuantizeCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct QuantizeCodecConfigurationV1 {
+    /// The number of decimal digits to preserve.
+    pub digits: i32,
+    /// The decoded data type.
+    pub dtype: DataTypeMetadataV2,
+    /// The encoded data type.
+    pub astype: DataTypeMetadataV2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_quantize_config() {
+        serde_json::from_str::<QuantizeCodecConfiguration>(
+            r#"{
+                "digits": 2,
+                "dtype": "<f8",
+                "astype": "<f4"
+            }"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_quantize_config_invalid() {
+        assert!(serde_json::from_str::<QuantizeCodecConfiguration>(
+            r#"{
+                "dtype": "<f8",
+                "astype": "<f4"
+            }"#,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `shuffle` codec.
+pub const IDENTIFIER: &str = "shuffle";
+
+/// A wrapper to handle various versions of `shuffle` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum ShuffleCodecConfiguration {
+    /// Version 1.0.
+    V1(ShuffleCodecConfigurationV1),
+}
+
+/// `shuffle` codec configuration parameters (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `shuffle` codec.
+///
+/// ### Example: shuffle the bytes of 4 byte elements
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "elementsize": 4
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::shuffle::ShuffleCodecConfigurationV1;
+/// # let configuration: ShuffleCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ShuffleCodecConfigurationV1 {
+    /// The element size in bytes.
+    ///
+    /// This must match the size of the array data type.
+    pub elementsize: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_shuffle_config() {
+        let configuration =
+            serde_json::from_str::<ShuffleCodecConfiguration>(r#"{"elementsize": 8}"#).unwrap();
+        let ShuffleCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.elementsize, 8);
+    }
+
+    #[test]
+    fn codec_shuffle_config_invalid() {
+        assert!(serde_json::from_str::<ShuffleCodecConfiguration>(r#"{}"#).is_err());
+        assert!(
+            serde_json::from_str::<ShuffleCodecConfiguration>(r#"{"elementsize": -1}"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `zlib` codec.
+pub const IDENTIFIER: &str = "zlib";
+
+/// A wrapper to handle various versions of `zlib` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum ZlibCodecConfiguration {
+    /// Version 1.0.
+    V1(ZlibCodecConfigurationV1),
+}
+
+/// Configuration parameters for the `zlib` codec (version 1.0).
+///
+/// The configuration is compatible with the `numcodecs` `zlib` codec.
+///
+/// ### Example: encode with a compression level of 1
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "level": 1
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::zlib::ZlibCodecConfigurationV1;
+/// # let configuration: ZlibCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ZlibCodecConfigurationV1 {
+    /// The compression level.
+    pub level: ZlibCompressionLevel,
+}
+
+impl ZlibCodecConfigurationV1 {
+    /// Create a new `zlib` codec configuration given a [`ZlibCompressionLevel`].
+    #[must_use]
+    pub const fn new(level: ZlibCompressionLevel) -> Self {
+        Self { level }
+    }
+}
+
+/// A compression level. Used by the `zlib` codec.
+///
+/// An integer from 0 to 9 which controls the speed and level of compression.
+/// A level of 1 is the fastest compression method and produces the least compressions, while 9 is slowest and produces the most compression.
+/// Compression is turned off completely when level is 0.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Display)]
+pub struct ZlibCompressionLevel(u32);
+
+/// An invalid compression level.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid compression level {0}, must be 0-9")]
+pub struct ZlibCompressionLevelError(u32);
+
+impl TryFrom<u32> for ZlibCompressionLevel {
+    type Error = ZlibCompressionLevelError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value < 10 {
+            Ok(Self(value))
+        } else {
+            Err(ZlibCompressionLevelError(value))
+        }
+    }
+}
+
+impl serde::Serialize for ZlibCompressionLevel {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u32(self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ZlibCompressionLevel {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(d)?;
+        if let serde_json::Value::Number(level) = value {
+            if let Some(level) = level.as_u64().and_then(|level| u32::try_from(level).ok()) {
+                if level < 10 {
+                    return Ok(Self(level));
+                }
+            }
+        }
+        Err(serde::de::Error::custom(
+            "compression level must be an integer between 0 and 9.",
+        ))
+    }
+}
+
+impl ZlibCompressionLevel {
+    /// The underlying integer compression level.
+    #[must_use]
+    pub const fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_zlib_configuration_valid() {
+        const JSON_VALID: &str = r#"{
+            "level": 1
+        }"#;
+        serde_json::from_str::<ZlibCodecConfiguration>(JSON_VALID).unwrap();
+    }
+
+    #[test]
+    fn codec_zlib_configuration_invalid() {
+        const JSON_INVALID: &str = r#"{
+            "level": 10
+        }"#;
+        assert!(serde_json::from_str::<ZlibCodecConfiguration>(JSON_INVALID).is_err());
+    }
+}
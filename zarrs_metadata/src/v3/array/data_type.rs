@@ -2,7 +2,10 @@
 //!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#data-types>.
 
+use std::num::NonZeroU32;
+
 use derive_more::From;
+use serde::{Deserialize, Serialize};
 
 use crate::v3::MetadataV3;
 
@@ -47,6 +50,22 @@ pub enum DataTypeMetadataV3 {
     String,
     /// Variable-sized binary data.
     Bytes,
+    /// `structured` a fixed-size compound data type with named fields.
+    Structured(Vec<DataTypeMetadataV3StructuredField>),
+    /// `numpy.datetime64` a 64-bit signed integer count of `scale_factor` `unit`s since the Unix epoch. The minimum value is `NaT` (not-a-time).
+    NumpyDateTime64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The time unit scale factor.
+        scale_factor: NonZeroU32,
+    },
+    /// `numpy.timedelta64` a 64-bit signed integer count of `scale_factor` `unit`s. The minimum value is `NaT` (not-a-time).
+    NumpyTimeDelta64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The time unit scale factor.
+        scale_factor: NonZeroU32,
+    },
     /// An unknown data type.
     Unknown(MetadataV3),
 }
@@ -67,6 +86,150 @@ impl<'de> serde::Deserialize<'de> for DataTypeMetadataV3 {
     }
 }
 
+/// A field of a `structured` data type.
+///
+/// Serialised as a `[name, data_type]` pair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataTypeMetadataV3StructuredField {
+    /// The field name.
+    pub name: String,
+    /// The field data type.
+    pub data_type: DataTypeMetadataV3,
+}
+
+impl DataTypeMetadataV3StructuredField {
+    /// Create a new `structured` data type field.
+    #[must_use]
+    pub fn new(name: impl Into<String>, data_type: DataTypeMetadataV3) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+        }
+    }
+}
+
+impl serde::Serialize for DataTypeMetadataV3StructuredField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&(&self.name, &self.data_type), serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DataTypeMetadataV3StructuredField {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let (name, data_type) = <(String, DataTypeMetadataV3)>::deserialize(d)?;
+        Ok(Self { name, data_type })
+    }
+}
+
+/// The unit of a `numpy.datetime64` or `numpy.timedelta64` data type.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumpyTimeUnit {
+    /// Generic (unitless).
+    #[serde(rename = "generic")]
+    Generic,
+    /// Years.
+    #[serde(rename = "Y")]
+    Year,
+    /// Months.
+    #[serde(rename = "M")]
+    Month,
+    /// Weeks.
+    #[serde(rename = "W")]
+    Week,
+    /// Days.
+    #[serde(rename = "D")]
+    Day,
+    /// Hours.
+    #[serde(rename = "h")]
+    Hour,
+    /// Minutes.
+    #[serde(rename = "m")]
+    Minute,
+    /// Seconds.
+    #[serde(rename = "s")]
+    Second,
+    /// Milliseconds.
+    #[serde(rename = "ms")]
+    Millisecond,
+    /// Microseconds.
+    #[serde(rename = "us", alias = "μs")]
+    Microsecond,
+    /// Nanoseconds.
+    #[serde(rename = "ns")]
+    Nanosecond,
+    /// Picoseconds.
+    #[serde(rename = "ps")]
+    Picosecond,
+    /// Femtoseconds.
+    #[serde(rename = "fs")]
+    Femtosecond,
+    /// Attoseconds.
+    #[serde(rename = "as")]
+    Attosecond,
+}
+
+impl NumpyTimeUnit {
+    /// Returns the `NumPy` unit code (e.g. `ns`).
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "generic",
+            Self::Year => "Y",
+            Self::Month => "M",
+            Self::Week => "W",
+            Self::Day => "D",
+            Self::Hour => "h",
+            Self::Minute => "m",
+            Self::Second => "s",
+            Self::Millisecond => "ms",
+            Self::Microsecond => "us",
+            Self::Nanosecond => "ns",
+            Self::Picosecond => "ps",
+            Self::Femtosecond => "fs",
+            Self::Attosecond => "as",
+        }
+    }
+
+    /// Create a unit from a `NumPy` unit code (e.g. `ns`).
+    #[must_use]
+    pub fn from_str_opt(unit: &str) -> Option<Self> {
+        match unit {
+            "generic" => Some(Self::Generic),
+            "Y" => Some(Self::Year),
+            "M" => Some(Self::Month),
+            "W" => Some(Self::Week),
+            "D" => Some(Self::Day),
+            "h" => Some(Self::Hour),
+            "m" => Some(Self::Minute),
+            "s" => Some(Self::Second),
+            "ms" => Some(Self::Millisecond),
+            "us" | "μs" => Some(Self::Microsecond),
+            "ns" => Some(Self::Nanosecond),
+            "ps" => Some(Self::Picosecond),
+            "fs" => Some(Self::Femtosecond),
+            "as" => Some(Self::Attosecond),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for NumpyTimeUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The configuration of a `numpy.datetime64` or `numpy.timedelta64` data type.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+struct NumpyTimeConfiguration {
+    unit: NumpyTimeUnit,
+    scale_factor: NonZeroU32,
+}
+
 // /// A data type plugin.
 // pub type DataTypePlugin = Plugin<Box<dyn DataTypeExtension>>;
 // inventory::collect!(DataTypePlugin);
@@ -107,14 +270,36 @@ impl DataTypeMetadataV3 {
             Self::String => "string".to_string(),
             Self::Bytes => "bytes".to_string(),
             Self::RawBits(size) => format!("r{}", size * 8),
+            Self::Structured(_) => "structured".to_string(),
+            Self::NumpyDateTime64 { .. } => "numpy.datetime64".to_string(),
+            Self::NumpyTimeDelta64 { .. } => "numpy.timedelta64".to_string(),
             Self::Unknown(metadata) => metadata.name().to_string(),
         }
     }
 
     /// Returns the metadata.
+    ///
+    /// # Panics
+    /// Panics if the configuration of a `structured`, `numpy.datetime64`, or `numpy.timedelta64` data type cannot be serialised, which should not happen.
     #[must_use]
     pub fn metadata(&self) -> MetadataV3 {
         match self {
+            Self::Structured(fields) => {
+                let mut configuration = serde_json::Map::new();
+                configuration.insert("fields".to_string(), serde_json::to_value(fields).unwrap());
+                MetadataV3::new_with_configuration(&self.name(), configuration)
+            }
+            Self::NumpyDateTime64 { unit, scale_factor }
+            | Self::NumpyTimeDelta64 { unit, scale_factor } => {
+                MetadataV3::new_with_serializable_configuration(
+                    &self.name(),
+                    &NumpyTimeConfiguration {
+                        unit: *unit,
+                        scale_factor: *scale_factor,
+                    },
+                )
+                .unwrap()
+            }
             Self::Unknown(metadata) => metadata.clone(),
             _ => MetadataV3::new(&self.name()),
         }
@@ -122,26 +307,39 @@ impl DataTypeMetadataV3 {
 
     /// Returns the [`DataTypeSize`]. Returns [`None`] for an unknown data type.
     #[must_use]
-    pub const fn size(&self) -> Option<DataTypeSize> {
+    pub fn size(&self) -> Option<DataTypeSize> {
         match self {
             Self::Bool | Self::Int8 | Self::UInt8 => Some(DataTypeSize::Fixed(1)),
             Self::Int16 | Self::UInt16 | Self::Float16 | Self::BFloat16 => {
                 Some(DataTypeSize::Fixed(2))
             }
             Self::Int32 | Self::UInt32 | Self::Float32 => Some(DataTypeSize::Fixed(4)),
-            Self::Int64 | Self::UInt64 | Self::Float64 | Self::Complex64 => {
-                Some(DataTypeSize::Fixed(8))
-            }
+            Self::Int64
+            | Self::UInt64
+            | Self::Float64
+            | Self::Complex64
+            | Self::NumpyDateTime64 { .. }
+            | Self::NumpyTimeDelta64 { .. } => Some(DataTypeSize::Fixed(8)),
             Self::Complex128 => Some(DataTypeSize::Fixed(16)),
             Self::RawBits(size) => Some(DataTypeSize::Fixed(*size)),
             Self::String | Self::Bytes => Some(DataTypeSize::Variable),
+            Self::Structured(fields) => {
+                let mut size = 0;
+                for field in fields {
+                    match field.data_type.size()? {
+                        DataTypeSize::Fixed(field_size) => size += field_size,
+                        DataTypeSize::Variable => return Some(DataTypeSize::Variable),
+                    }
+                }
+                Some(DataTypeSize::Fixed(size))
+            }
             Self::Unknown(_) => None,
         }
     }
 
     /// Returns the size in bytes of a known fixed-size data type, otherwise returns [`None`].
     #[must_use]
-    pub const fn fixed_size(&self) -> Option<usize> {
+    pub fn fixed_size(&self) -> Option<usize> {
         match self.size() {
             Some(DataTypeSize::Fixed(size)) => Some(size),
             Some(DataTypeSize::Variable) | None => None,
@@ -172,7 +370,27 @@ impl DataTypeMetadataV3 {
             "string" => return Self::String,
             "bytes" => return Self::Bytes,
             _ => {}
-        };
+        }
+
+        if name == "numpy.datetime64" || name == "numpy.timedelta64" {
+            if let Ok(NumpyTimeConfiguration { unit, scale_factor }) = metadata.to_configuration() {
+                return if name == "numpy.datetime64" {
+                    Self::NumpyDateTime64 { unit, scale_factor }
+                } else {
+                    Self::NumpyTimeDelta64 { unit, scale_factor }
+                };
+            }
+        }
+
+        if name == "structured" {
+            if let Some(configuration) = metadata.configuration() {
+                if let (1, Some(fields)) = (configuration.len(), configuration.get("fields")) {
+                    if let Ok(fields) = serde_json::from_value(fields.clone()) {
+                        return Self::Structured(fields);
+                    }
+                }
+            }
+        }
 
         if name.starts_with('r') && name.len() > 1 {
             if let Ok(size_bits) = metadata.name()[1..].parse::<usize>() {
@@ -198,3 +416,74 @@ impl core::fmt::Display for DataTypeMetadataV3 {
         write!(f, "{}", self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_type_structured() {
+        let json =
+            r#"{"name":"structured","configuration":{"fields":[["x","float32"],["id","uint64"]]}}"#;
+        let data_type: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            data_type,
+            DataTypeMetadataV3::Structured(vec![
+                DataTypeMetadataV3StructuredField::new("x", DataTypeMetadataV3::Float32),
+                DataTypeMetadataV3StructuredField::new("id", DataTypeMetadataV3::UInt64),
+            ])
+        );
+        assert_eq!(data_type.fixed_size(), Some(12));
+        assert_eq!(serde_json::to_string(&data_type).unwrap(), json);
+
+        let json =
+            r#"{"name":"structured","configuration":{"fields":[["x","float32"],["s","string"]]}}"#;
+        let data_type: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert_eq!(data_type.size(), Some(DataTypeSize::Variable));
+
+        let json = r#"{"name":"structured","configuration":{"fields":"invalid"}}"#;
+        let data_type: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(matches!(data_type, DataTypeMetadataV3::Unknown(_)));
+    }
+
+    #[test]
+    fn data_type_numpy_datetime64() {
+        let json = r#"{"name":"numpy.datetime64","configuration":{"unit":"ns","scale_factor":1}}"#;
+        let data_type: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            data_type,
+            DataTypeMetadataV3::NumpyDateTime64 {
+                unit: NumpyTimeUnit::Nanosecond,
+                scale_factor: NonZeroU32::new(1).unwrap()
+            }
+        );
+        assert_eq!(data_type.fixed_size(), Some(8));
+        assert_eq!(serde_json::to_string(&data_type).unwrap(), json);
+
+        let json = r#"{"name":"numpy.timedelta64","configuration":{"unit":"s","scale_factor":10}}"#;
+        let data_type: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            data_type,
+            DataTypeMetadataV3::NumpyTimeDelta64 {
+                unit: NumpyTimeUnit::Second,
+                scale_factor: NonZeroU32::new(10).unwrap()
+            }
+        );
+        assert_eq!(serde_json::to_string(&data_type).unwrap(), json);
+
+        let json =
+            r#"{"name":"numpy.timedelta64","configuration":{"unit":"invalid","scale_factor":1}}"#;
+        let data_type: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(matches!(data_type, DataTypeMetadataV3::Unknown(_)));
+
+        assert_eq!(
+            NumpyTimeUnit::from_str_opt("us"),
+            Some(NumpyTimeUnit::Microsecond)
+        );
+        assert_eq!(
+            NumpyTimeUnit::from_str_opt("μs"),
+            Some(NumpyTimeUnit::Microsecond)
+        );
+        assert_eq!(NumpyTimeUnit::from_str_opt("x"), None);
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `chunk_manifest` storage transformer.
+pub const IDENTIFIER: &str = "chunk_manifest";
+
+/// A wrapper to handle various versions of `chunk_manifest` storage transformer configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum ChunkManifestStorageTransformerConfiguration {
+    /// Version 1.0.
+    V1(ChunkManifestStorageTransformerConfigurationV1),
+}
+
+/// Configuration parameters for the `chunk_manifest` storage transformer (version 1.0).
+///
+/// An example `JSON` document:
+/// ```json
+/// {
+///     "manifest": {
+///         "c/0/0": {"path": "data.nc", "offset": 8192, "length": 4000},
+///         "c/0/1": {"path": "data.nc", "offset": 12192, "length": 4000}
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ChunkManifestStorageTransformerConfigurationV1 {
+    /// A map from chunk keys (relative to the array) to byte ranges of values in the store.
+    pub manifest: BTreeMap<String, ChunkManifestEntry>,
+}
+
+impl ChunkManifestStorageTransformerConfigurationV1 {
+    /// Create a new `chunk_manifest` storage transformer configuration given a `manifest`.
+    #[must_use]
+    pub const fn new(manifest: BTreeMap<String, ChunkManifestEntry>) -> Self {
+        Self { manifest }
+    }
+}
+
+/// A `chunk_manifest` entry. A reference to a byte range of a value in a store.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{path}[{offset}..{}]", offset + length)]
+pub struct ChunkManifestEntry {
+    /// The key of the referenced value, relative to the root of the store.
+    pub path: String,
+    /// The byte offset of the chunk in the referenced value.
+    pub offset: u64,
+    /// The byte length of the chunk.
+    pub length: u64,
+}
+
+impl ChunkManifestEntry {
+    /// Create a new `chunk_manifest` entry.
+    #[must_use]
+    pub fn new(path: impl Into<String>, offset: u64, length: u64) -> Self {
+        Self {
+            path: path.into(),
+            offset,
+            length,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_transformer_chunk_manifest_configuration() {
+        let json = r#"{
+            "manifest": {
+                "c/0/0": {"path": "data.nc", "offset": 8192, "length": 4000},
+                "c/0/1": {"path": "data.nc", "offset": 12192, "length": 4000}
+            }
+        }"#;
+        let configuration: ChunkManifestStorageTransformerConfiguration =
+            serde_json::from_str(json).unwrap();
+        let ChunkManifestStorageTransformerConfiguration::V1(configuration) = configuration;
+        assert_eq!(
+            configuration.manifest["c/0/1"],
+            ChunkManifestEntry::new("data.nc", 12192, 4000)
+        );
+    }
+
+    #[test]
+    fn storage_transformer_chunk_manifest_configuration_invalid() {
+        let json = r#"{
+            "manifest": {
+                "c/0/0": {"path": "data.nc", "offset": 8192}
+            }
+        }"#;
+        assert!(
+            serde_json::from_str::<ChunkManifestStorageTransformerConfiguration>(json).is_err()
+        );
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::NodeMetadata;
+
 use super::AdditionalFields;
 
 /// Zarr group metadata (storage specification v3).
@@ -17,8 +21,9 @@ use super::AdditionalFields;
 ///         "eggs": 42,
 ///     }
 /// }
+/// ```
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display)]
 #[display("{}", serde_json::to_string(self).unwrap_or_default())]
 pub struct GroupMetadataV3 {
     /// An integer defining the version of the storage specification to which the group adheres. Must be `3`.
@@ -28,6 +33,9 @@ pub struct GroupMetadataV3 {
     /// Optional user metadata.
     #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
     pub attributes: serde_json::Map<String, serde_json::Value>,
+    /// Optional consolidated metadata of the hierarchy below the group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consolidated_metadata: Option<ConsolidatedMetadata>,
     /// Additional fields.
     #[serde(flatten)]
     pub additional_fields: AdditionalFields,
@@ -47,6 +55,7 @@ impl GroupMetadataV3 {
             zarr_format: monostate::MustBe!(3u64),
             node_type: monostate::MustBe!("group"),
             attributes: serde_json::Map::new(),
+            consolidated_metadata: None,
             additional_fields: AdditionalFields::default(),
         }
     }
@@ -61,6 +70,16 @@ impl GroupMetadataV3 {
         self
     }
 
+    /// Set the consolidated metadata.
+    #[must_use]
+    pub fn with_consolidated_metadata(
+        mut self,
+        consolidated_metadata: Option<ConsolidatedMetadata>,
+    ) -> Self {
+        self.consolidated_metadata = consolidated_metadata;
+        self
+    }
+
     /// Set the additional fields.
     #[must_use]
     pub fn with_additional_fields(mut self, additional_fields: AdditionalFields) -> Self {
@@ -68,3 +87,109 @@ impl GroupMetadataV3 {
         self
     }
 }
+
+/// The metadata of the nodes in a hierarchy below a group, keyed by their path relative to the group.
+pub type ConsolidatedMetadataMetadata = BTreeMap<String, NodeMetadata>;
+
+/// Consolidated metadata of a Zarr hierarchy.
+///
+/// This is stored in the `consolidated_metadata` field of Zarr V3 group metadata, and holds the metadata of all nodes in the hierarchy below the group.
+/// Nodes can be discovered without listing the store, which is slow (or impossible) for some stores.
+///
+/// An example `JSON` document:
+/// ```json
+/// {
+///     "kind": "inline",
+///     "must_understand": false,
+///     "metadata": {
+///         "array": {
+///             "zarr_format": 3,
+///             "node_type": "array",
+///             ...
+///         },
+///         "group": {
+///             "zarr_format": 3,
+///             "node_type": "group"
+///         }
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ConsolidatedMetadata {
+    /// The metadata of the nodes below the group.
+    pub metadata: ConsolidatedMetadataMetadata,
+    /// The kind of consolidated metadata.
+    pub kind: ConsolidatedMetadataKind,
+    /// Must be `false`.
+    #[serde(default)]
+    pub must_understand: monostate::MustBe!(false),
+}
+
+impl ConsolidatedMetadata {
+    /// Create new inline consolidated metadata.
+    #[must_use]
+    pub fn new(metadata: ConsolidatedMetadataMetadata) -> Self {
+        Self {
+            metadata,
+            kind: ConsolidatedMetadataKind::Inline,
+            must_understand: monostate::MustBe!(false),
+        }
+    }
+}
+
+/// The kind of consolidated metadata.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsolidatedMetadataKind {
+    /// Consolidated metadata is stored inline in the group metadata.
+    Inline,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_metadata_consolidated() {
+        let json = r#"{
+            "zarr_format": 3,
+            "node_type": "group",
+            "consolidated_metadata": {
+                "kind": "inline",
+                "must_understand": false,
+                "metadata": {
+                    "a": {
+                        "zarr_format": 3,
+                        "node_type": "group",
+                        "consolidated_metadata": null
+                    },
+                    "a/b": {
+                        "zarr_format": 3,
+                        "node_type": "group",
+                        "attributes": {"key": "value"}
+                    }
+                }
+            }
+        }"#;
+        let metadata: GroupMetadataV3 = serde_json::from_str(json).unwrap();
+        let consolidated_metadata = metadata.consolidated_metadata.unwrap();
+        assert_eq!(consolidated_metadata.kind, ConsolidatedMetadataKind::Inline);
+        assert_eq!(
+            consolidated_metadata.metadata.keys().collect::<Vec<_>>(),
+            vec!["a", "a/b"]
+        );
+        assert!(matches!(
+            consolidated_metadata.metadata["a/b"],
+            NodeMetadata::Group(crate::GroupMetadata::V3(_))
+        ));
+
+        let json = r#"{
+            "zarr_format": 3,
+            "node_type": "group",
+            "consolidated_metadata": null
+        }"#;
+        let metadata: GroupMetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(metadata.consolidated_metadata.is_none());
+    }
+}
@@ -0,0 +1,554 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use half::f16;
+use thiserror::Error;
+
+use crate::{
+    v2::{
+        array::{
+            codec::blosc::codec_blosc_v3_to_v2_numcodecs, ArrayMetadataV2Order, DataTypeMetadataV2,
+            DataTypeMetadataV2Structured, FillValueMetadataV2,
+        },
+        ArrayMetadataV2, GroupMetadataV2, MetadataV2,
+    },
+    v3::{
+        array::{
+            chunk_grid::regular::RegularChunkGridConfiguration,
+            chunk_key_encoding::v2::V2ChunkKeyEncodingConfiguration,
+            codec::{
+                blosc::BloscCodecConfiguration, bytes::BytesCodecConfiguration,
+                transpose::TransposeCodecConfiguration,
+            },
+            data_type::{DataTypeMetadataV3, NumpyTimeUnit},
+            fill_value::{FillValueFloat, FillValueFloatStringNonFinite, FillValueMetadataV3},
+        },
+        ArrayMetadataV3, ConfigurationInvalidError, GroupMetadataV3,
+    },
+    Endianness,
+};
+
+/// Convert Zarr V3 group metadata to V2.
+#[must_use]
+pub fn group_metadata_v3_to_v2(group_metadata_v3: &GroupMetadataV3) -> GroupMetadataV2 {
+    GroupMetadataV2::new()
+        .with_attributes(group_metadata_v3.attributes.clone())
+        .with_additional_fields(group_metadata_v3.additional_fields.clone())
+}
+
+/// An error converting Zarr V3 array metadata to V2.
+#[derive(Debug, Error)]
+pub enum ArrayMetadataV3ToV2ConversionError {
+    /// Unsupported data type.
+    #[error(transparent)]
+    UnsupportedDataType(#[from] DataTypeMetadataV3UnsupportedDataTypeError),
+    /// An unsupported chunk grid.
+    #[error("chunk grid {_0} is not supported by Zarr V2")]
+    UnsupportedChunkGrid(String),
+    /// An unsupported chunk key encoding.
+    #[error("chunk key encoding {_0} is not supported by Zarr V2")]
+    UnsupportedChunkKeyEncoding(String),
+    /// An unsupported codec.
+    #[error("codec {_0} is not supported by Zarr V2")]
+    UnsupportedCodec(String),
+    /// An unsupported fill value.
+    #[error("unsupported fill value {_1} for data type {_0}")]
+    UnsupportedFillValue(String, FillValueMetadataV3),
+    /// Storage transformers are not supported.
+    #[error("storage transformers are not supported by Zarr V2")]
+    UnsupportedStorageTransformers,
+    /// Dimension names are not supported.
+    #[error("dimension names are not supported by Zarr V2")]
+    UnsupportedDimensionNames,
+    /// An invalid configuration.
+    #[error(transparent)]
+    InvalidConfiguration(#[from] ConfigurationInvalidError),
+    /// Serialization/deserialization error.
+    #[error("JSON serialization or deserialization error: {_0}")]
+    SerdeError(#[from] serde_json::Error),
+    /// Other.
+    #[error("{_0}")]
+    Other(String),
+}
+
+/// Convert Zarr V3 array metadata to V2.
+///
+/// The V3 metadata must be a compatible subset of V2:
+///  - the chunk grid must be `regular` and the chunk key encoding must be `v2`,
+///  - a leading `transpose` codec is only supported if it reverses the dimensions (order `F`),
+///  - array to array codecs are mapped to filters,
+///  - the `bytes` codec endianness is mapped to the data type, `vlen` is mapped to a `vlen-utf8` filter and `pcodec` is mapped to the compressor,
+///  - the last bytes to bytes codec is mapped to the compressor and any preceding bytes to bytes codecs are mapped to filters,
+///  - a `numcodecs.` codec name prefix (as used by `numcodecs.zarr3`) is removed, and
+///  - storage transformers and dimension names are not supported.
+///
+/// # Errors
+/// Returns a [`ArrayMetadataV3ToV2ConversionError`] if the metadata is invalid or is not compatible with Zarr V2 metadata.
+#[allow(clippy::too_many_lines)]
+pub fn array_metadata_v3_to_v2(
+    array_metadata_v3: &ArrayMetadataV3,
+) -> Result<ArrayMetadataV2, ArrayMetadataV3ToV2ConversionError> {
+    let shape = array_metadata_v3.shape.clone();
+
+    if !array_metadata_v3.storage_transformers.is_empty() {
+        return Err(ArrayMetadataV3ToV2ConversionError::UnsupportedStorageTransformers);
+    }
+    if let Some(dimension_names) = &array_metadata_v3.dimension_names {
+        if dimension_names.iter().any(|name| name.as_str().is_some()) {
+            return Err(ArrayMetadataV3ToV2ConversionError::UnsupportedDimensionNames);
+        }
+    }
+
+    // Chunk grid
+    if array_metadata_v3.chunk_grid.name() != crate::v3::array::chunk_grid::regular::IDENTIFIER {
+        return Err(ArrayMetadataV3ToV2ConversionError::UnsupportedChunkGrid(
+            array_metadata_v3.chunk_grid.name().to_string(),
+        ));
+    }
+    let chunks = array_metadata_v3
+        .chunk_grid
+        .to_configuration::<RegularChunkGridConfiguration>()?
+        .chunk_shape;
+
+    // Chunk key encoding
+    if array_metadata_v3.chunk_key_encoding.name()
+        != crate::v3::array::chunk_key_encoding::v2::IDENTIFIER
+    {
+        return Err(
+            ArrayMetadataV3ToV2ConversionError::UnsupportedChunkKeyEncoding(
+                array_metadata_v3.chunk_key_encoding.name().to_string(),
+            ),
+        );
+    }
+    let dimension_separator = array_metadata_v3
+        .chunk_key_encoding
+        .to_configuration::<V2ChunkKeyEncodingConfiguration>()?
+        .separator;
+
+    let data_type = &array_metadata_v3.data_type;
+    let mut codecs = array_metadata_v3.codecs.iter().peekable();
+
+    // Order (a leading transpose codec reversing the dimensions)
+    let mut order = ArrayMetadataV2Order::C;
+    if let Some(transpose) =
+        codecs.next_if(|codec| codec.name() == crate::v3::array::codec::transpose::IDENTIFIER)
+    {
+        let TransposeCodecConfiguration::V1(configuration) =
+            transpose.to_configuration::<TransposeCodecConfiguration>()?;
+        if configuration
+            .order
+            .0
+            .iter()
+            .copied()
+            .eq((0..shape.len()).rev())
+        {
+            order = ArrayMetadataV2Order::F;
+        } else {
+            return Err(ArrayMetadataV3ToV2ConversionError::Other(format!(
+                "transpose order {:?} is not supported by Zarr V2, only the reversed order is supported",
+                configuration.order.0
+            )));
+        }
+    }
+
+    let mut filters: Vec<MetadataV2> = vec![];
+    let mut compressor: Option<MetadataV2> = None;
+    let mut bytes_to_bytes: Vec<MetadataV2> = vec![];
+    let mut endianness: Option<Endianness> = None;
+    let mut has_array_to_bytes = false;
+    for codec in codecs {
+        let configuration = codec.configuration().cloned().unwrap_or_default();
+        let name = codec
+            .name()
+            .strip_prefix("numcodecs.")
+            .unwrap_or(codec.name());
+        match name {
+            crate::v3::array::codec::sharding::IDENTIFIER
+            | crate::v3::array::codec::zfp::IDENTIFIER
+            | crate::v3::array::codec::gdeflate::IDENTIFIER
+            | crate::v3::array::codec::transpose::IDENTIFIER => {
+                return Err(ArrayMetadataV3ToV2ConversionError::UnsupportedCodec(
+                    codec.name().to_string(),
+                ));
+            }
+            crate::v3::array::codec::bytes::IDENTIFIER => {
+                has_array_to_bytes = true;
+                let BytesCodecConfiguration::V1(configuration) =
+                    codec.to_configuration::<BytesCodecConfiguration>()?;
+                endianness = configuration.endian;
+            }
+            crate::v3::array::codec::vlen::IDENTIFIER => {
+                has_array_to_bytes = true;
+                let id = match data_type {
+                    DataTypeMetadataV3::String => crate::v2::array::codec::vlen_utf8::IDENTIFIER,
+                    _ => {
+                        return Err(ArrayMetadataV3ToV2ConversionError::UnsupportedCodec(
+                            codec.name().to_string(),
+                        ))
+                    }
+                };
+                filters.push(MetadataV2::new_with_configuration(
+                    id,
+                    serde_json::Map::default(),
+                ));
+            }
+            crate::v2::array::codec::vlen_array::IDENTIFIER
+            | crate::v2::array::codec::vlen_bytes::IDENTIFIER
+            | crate::v2::array::codec::vlen_utf8::IDENTIFIER => {
+                has_array_to_bytes = true;
+                filters.push(MetadataV2::new_with_configuration(
+                    codec.name(),
+                    serde_json::Map::default(),
+                ));
+            }
+            crate::v3::array::codec::pcodec::IDENTIFIER => {
+                // pcodec is v2/v3 compatible
+                has_array_to_bytes = true;
+                compressor = Some(MetadataV2::new_with_configuration(name, configuration));
+            }
+            name if !has_array_to_bytes => {
+                // Array to array codecs
+                filters.push(MetadataV2::new_with_configuration(name, configuration));
+            }
+            crate::v3::array::codec::blosc::IDENTIFIER => {
+                let blosc = codec.to_configuration::<BloscCodecConfiguration>()?;
+                bytes_to_bytes.push(MetadataV2::new_with_serializable_configuration(
+                    crate::v3::array::codec::blosc::IDENTIFIER,
+                    &codec_blosc_v3_to_v2_numcodecs(&blosc),
+                )?);
+            }
+            name => {
+                // Bytes to bytes codecs
+                bytes_to_bytes.push(MetadataV2::new_with_configuration(name, configuration));
+            }
+        }
+    }
+
+    // Compressor (the last bytes to bytes codec), preceding bytes to bytes codecs are filters
+    if compressor.is_none() {
+        compressor = bytes_to_bytes.pop();
+    } else if !bytes_to_bytes.is_empty() {
+        return Err(ArrayMetadataV3ToV2ConversionError::Other(
+            "Zarr V2 does not support bytes to bytes codecs after an array to bytes compressor"
+                .to_string(),
+        ));
+    }
+    filters.extend(bytes_to_bytes);
+
+    // Data type
+    let dtype = data_type_metadata_v3_to_v2(data_type, endianness)?;
+
+    // Fill value
+    let fill_value =
+        array_metadata_fill_value_v3_to_v2(&array_metadata_v3.fill_value).ok_or_else(|| {
+            ArrayMetadataV3ToV2ConversionError::UnsupportedFillValue(
+                data_type.to_string(),
+                array_metadata_v3.fill_value.clone(),
+            )
+        })?;
+
+    let filters = if filters.is_empty() {
+        None
+    } else {
+        Some(filters)
+    };
+
+    Ok(
+        ArrayMetadataV2::new(shape, chunks, dtype, fill_value, compressor, filters)
+            .with_dimension_separator(dimension_separator)
+            .with_order(order)
+            .with_attributes(array_metadata_v3.attributes.clone())
+            .with_additional_fields(array_metadata_v3.additional_fields.clone()),
+    )
+}
+
+/// An unsupported Zarr V3 data type error.
+#[derive(Debug, Error)]
+#[error("V3 data type {_0} is not supported by Zarr V2")]
+pub struct DataTypeMetadataV3UnsupportedDataTypeError(DataTypeMetadataV3);
+
+/// Convert a Zarr V3 data type to a compatible V2 data type.
+///
+/// The `endianness` is encoded in the data type of multi-byte data types, and defaults to little endian if unspecified.
+///
+/// # Errors
+/// Returns a [`DataTypeMetadataV3UnsupportedDataTypeError`] if the data type is not supported.
+pub fn data_type_metadata_v3_to_v2(
+    data_type: &DataTypeMetadataV3,
+    endianness: Option<Endianness>,
+) -> Result<DataTypeMetadataV2, DataTypeMetadataV3UnsupportedDataTypeError> {
+    let prefix = match endianness {
+        Some(Endianness::Big) => '>',
+        Some(Endianness::Little) | None => '<',
+    };
+    let simple = |dtype: &str| Ok(DataTypeMetadataV2::Simple(format!("{prefix}{dtype}")));
+    match data_type {
+        DataTypeMetadataV3::Bool => Ok(DataTypeMetadataV2::Simple("|b1".to_string())),
+        DataTypeMetadataV3::Int8 => Ok(DataTypeMetadataV2::Simple("|i1".to_string())),
+        DataTypeMetadataV3::UInt8 => Ok(DataTypeMetadataV2::Simple("|u1".to_string())),
+        DataTypeMetadataV3::Int16 => simple("i2"),
+        DataTypeMetadataV3::Int32 => simple("i4"),
+        DataTypeMetadataV3::Int64 => simple("i8"),
+        DataTypeMetadataV3::UInt16 => simple("u2"),
+        DataTypeMetadataV3::UInt32 => simple("u4"),
+        DataTypeMetadataV3::UInt64 => simple("u8"),
+        DataTypeMetadataV3::Float16 => simple("f2"),
+        DataTypeMetadataV3::Float32 => simple("f4"),
+        DataTypeMetadataV3::Float64 => simple("f8"),
+        DataTypeMetadataV3::Complex64 => simple("c8"),
+        DataTypeMetadataV3::Complex128 => simple("c16"),
+        DataTypeMetadataV3::String => Ok(DataTypeMetadataV2::Simple("|O".to_string())), // LEGACY: the zarr-python 2 dtype for string arrays.
+        DataTypeMetadataV3::NumpyDateTime64 { unit, scale_factor }
+        | DataTypeMetadataV3::NumpyTimeDelta64 { unit, scale_factor } => {
+            if *unit == NumpyTimeUnit::Generic {
+                return Err(DataTypeMetadataV3UnsupportedDataTypeError(
+                    data_type.clone(),
+                ));
+            }
+            let kind = if matches!(data_type, DataTypeMetadataV3::NumpyDateTime64 { .. }) {
+                'M'
+            } else {
+                'm'
+            };
+            if scale_factor.get() == 1 {
+                simple(&format!("{kind}8[{unit}]"))
+            } else {
+                simple(&format!("{kind}8[{scale_factor}{unit}]"))
+            }
+        }
+        DataTypeMetadataV3::Structured(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    match data_type_metadata_v3_to_v2(&field.data_type, endianness) {
+                        Ok(DataTypeMetadataV2::Simple(dtype)) => Ok(
+                            DataTypeMetadataV2Structured::new(field.name.clone(), dtype, None),
+                        ),
+                        // TODO: Support nested structured fields
+                        Ok(DataTypeMetadataV2::Structured(_)) | Err(_) => Err(
+                            DataTypeMetadataV3UnsupportedDataTypeError(data_type.clone()),
+                        ),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(DataTypeMetadataV2::Structured(fields))
+        }
+        _ => Err(DataTypeMetadataV3UnsupportedDataTypeError(
+            data_type.clone(),
+        )),
+    }
+}
+
+/// Convert Zarr V3 fill value metadata to [`FillValueMetadataV2`].
+///
+/// Boolean fill values are mapped to `0`/`1` and byte array fill values (e.g. of a structured data type) are base64 encoded.
+///
+/// Returns [`None`] if the fill value is not supported by Zarr V2 (e.g. a complex fill value).
+#[must_use]
+pub fn array_metadata_fill_value_v3_to_v2(
+    fill_value: &FillValueMetadataV3,
+) -> Option<FillValueMetadataV2> {
+    match fill_value {
+        FillValueMetadataV3::Bool(bool) => {
+            Some(FillValueMetadataV2::Number(u8::from(*bool).into()))
+        }
+        FillValueMetadataV3::UInt(uint) => Some(FillValueMetadataV2::Number((*uint).into())),
+        FillValueMetadataV3::Int(int) => Some(FillValueMetadataV2::Number((*int).into())),
+        FillValueMetadataV3::Float(float) => match float {
+            FillValueFloat::Float(float) => float_fill_value_v3_to_v2(*float),
+            FillValueFloat::HexString(hex_string) => {
+                let bytes = hex_string.as_be_bytes();
+                let float = match bytes.len() {
+                    2 => f64::from(f16::from_be_bytes(bytes.try_into().ok()?)),
+                    4 => f64::from(f32::from_be_bytes(bytes.try_into().ok()?)),
+                    8 => f64::from_be_bytes(bytes.try_into().ok()?),
+                    _ => return None,
+                };
+                float_fill_value_v3_to_v2(float)
+            }
+            FillValueFloat::NonFinite(FillValueFloatStringNonFinite::NaN) => {
+                Some(FillValueMetadataV2::NaN)
+            }
+            FillValueFloat::NonFinite(FillValueFloatStringNonFinite::PosInfinity) => {
+                Some(FillValueMetadataV2::Infinity)
+            }
+            FillValueFloat::NonFinite(FillValueFloatStringNonFinite::NegInfinity) => {
+                Some(FillValueMetadataV2::NegInfinity)
+            }
+        },
+        FillValueMetadataV3::String(string) => Some(FillValueMetadataV2::String(string.clone())),
+        FillValueMetadataV3::ByteArray(bytes) => {
+            Some(FillValueMetadataV2::String(BASE64_STANDARD.encode(bytes)))
+        }
+        FillValueMetadataV3::Complex(_, _) | FillValueMetadataV3::Unsupported(_) => None,
+    }
+}
+
+fn float_fill_value_v3_to_v2(float: f64) -> Option<FillValueMetadataV2> {
+    if float.is_nan() {
+        Some(FillValueMetadataV2::NaN)
+    } else if float.is_infinite() && float.is_sign_positive() {
+        Some(FillValueMetadataV2::Infinity)
+    } else if float.is_infinite() {
+        Some(FillValueMetadataV2::NegInfinity)
+    } else {
+        serde_json::Number::from_f64(float).map(FillValueMetadataV2::Number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{v2_to_v3::array_metadata_v2_to_v3, v3::MetadataV3};
+
+    use super::*;
+
+    #[test]
+    fn array_metadata_v3_to_v2_round_trip() {
+        let json = r#"{
+            "zarr_format": 3,
+            "node_type": "array",
+            "shape": [10000, 1000],
+            "data_type": "float64",
+            "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [1000, 100]}},
+            "chunk_key_encoding": {"name": "v2", "configuration": {"separator": "/"}},
+            "fill_value": "NaN",
+            "codecs": [
+                {"name": "transpose", "configuration": {"order": [1, 0]}},
+                {"name": "bytes", "configuration": {"endian": "big"}},
+                {"name": "blosc", "configuration": {"cname": "lz4", "clevel": 5, "shuffle": "shuffle", "typesize": 8, "blocksize": 0}}
+            ],
+            "attributes": {"key": "value"}
+        }"#;
+        let array_metadata_v3: ArrayMetadataV3 = serde_json::from_str(json).unwrap();
+        let array_metadata_v2 = array_metadata_v3_to_v2(&array_metadata_v3).unwrap();
+        let compressor: MetadataV2 = serde_json::from_str(
+            r#"{"id": "blosc", "cname": "lz4", "clevel": 5, "shuffle": 1, "blocksize": 0}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            array_metadata_v2,
+            ArrayMetadataV2::new(
+                vec![10000, 1000],
+                vec![1000, 100].try_into().unwrap(),
+                DataTypeMetadataV2::Simple(">f8".to_string()),
+                FillValueMetadataV2::NaN,
+                Some(compressor),
+                None,
+            )
+            .with_dimension_separator(crate::ChunkKeySeparator::Slash)
+            .with_order(ArrayMetadataV2Order::F)
+            .with_attributes(array_metadata_v3.attributes.clone())
+        );
+        assert_eq!(
+            array_metadata_v2_to_v3(&array_metadata_v2).unwrap(),
+            array_metadata_v3
+        );
+    }
+
+    #[test]
+    fn array_metadata_v3_to_v2_string() {
+        let json = r#"{
+            "zarr_format": 3,
+            "node_type": "array",
+            "shape": [10],
+            "data_type": "string",
+            "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [5]}},
+            "chunk_key_encoding": {"name": "v2", "configuration": {"separator": "."}},
+            "fill_value": "",
+            "codecs": [{"name": "vlen", "configuration": {}}, {"name": "zstd", "configuration": {"level": 3, "checksum": false}}]
+        }"#;
+        let array_metadata_v3: ArrayMetadataV3 = serde_json::from_str(json).unwrap();
+        let array_metadata_v2 = array_metadata_v3_to_v2(&array_metadata_v3).unwrap();
+        assert_eq!(
+            array_metadata_v2.dtype,
+            DataTypeMetadataV2::Simple("|O".to_string())
+        );
+        assert_eq!(
+            array_metadata_v2.filters.as_ref().unwrap()[0].id(),
+            crate::v2::array::codec::vlen_utf8::IDENTIFIER
+        );
+        assert_eq!(array_metadata_v2.compressor.as_ref().unwrap().id(), "zstd");
+        assert_eq!(
+            array_metadata_v2.fill_value,
+            FillValueMetadataV2::String(String::new())
+        );
+    }
+
+    #[test]
+    fn array_metadata_v3_to_v2_numcodecs() {
+        let json = r#"{
+            "zarr_format": 3,
+            "node_type": "array",
+            "shape": [10],
+            "data_type": "float64",
+            "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [5]}},
+            "chunk_key_encoding": {"name": "v2", "configuration": {"separator": "."}},
+            "fill_value": 0.0,
+            "codecs": [
+                {"name": "numcodecs.astype", "configuration": {"encode_dtype": "<f4", "decode_dtype": "<f8"}},
+                {"name": "bytes", "configuration": {"endian": "little"}},
+                {"name": "numcodecs.fletcher32"},
+                {"name": "numcodecs.zlib", "configuration": {"level": 1}}
+            ]
+        }"#;
+        let array_metadata_v3: ArrayMetadataV3 = serde_json::from_str(json).unwrap();
+        let array_metadata_v2 = array_metadata_v3_to_v2(&array_metadata_v3).unwrap();
+        let filters: Vec<MetadataV2> = serde_json::from_str(
+            r#"[{"id": "astype", "encode_dtype": "<f4", "decode_dtype": "<f8"}, {"id": "fletcher32"}]"#,
+        )
+        .unwrap();
+        let compressor: MetadataV2 = serde_json::from_str(r#"{"id": "zlib", "level": 1}"#).unwrap();
+        assert_eq!(array_metadata_v2.filters, Some(filters));
+        assert_eq!(array_metadata_v2.compressor, Some(compressor));
+    }
+
+    #[test]
+    fn array_metadata_v3_to_v2_unsupported() {
+        let json = r#"{
+            "zarr_format": 3,
+            "node_type": "array",
+            "shape": [10],
+            "data_type": "uint8",
+            "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [5]}},
+            "chunk_key_encoding": {"name": "v2", "configuration": {"separator": "."}},
+            "fill_value": 0,
+            "codecs": [{"name": "sharding_indexed", "configuration": {
+                "chunk_shape": [1],
+                "codecs": [{"name": "bytes"}],
+                "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+            }}]
+        }"#;
+        let array_metadata_v3: ArrayMetadataV3 = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            array_metadata_v3_to_v2(&array_metadata_v3)
+                .unwrap_err()
+                .to_string(),
+            "codec sharding_indexed is not supported by Zarr V2"
+        );
+
+        let mut array_metadata_v3 = array_metadata_v3;
+        array_metadata_v3.codecs = vec![MetadataV3::new("bytes")];
+        assert!(array_metadata_v3_to_v2(&array_metadata_v3).is_ok());
+        array_metadata_v3.chunk_key_encoding = MetadataV3::new("default");
+        assert!(array_metadata_v3_to_v2(&array_metadata_v3).is_err());
+    }
+
+    #[test]
+    fn data_type_v3_to_v2() {
+        use std::num::NonZeroU32;
+        assert_eq!(
+            data_type_metadata_v3_to_v2(&DataTypeMetadataV3::Int16, None).unwrap(),
+            DataTypeMetadataV2::Simple("<i2".to_string())
+        );
+        assert_eq!(
+            data_type_metadata_v3_to_v2(
+                &DataTypeMetadataV3::NumpyTimeDelta64 {
+                    unit: NumpyTimeUnit::Second,
+                    scale_factor: NonZeroU32::new(10).unwrap()
+                },
+                Some(Endianness::Big)
+            )
+            .unwrap(),
+            DataTypeMetadataV2::Simple(">m8[10s]".to_string())
+        );
+        assert!(data_type_metadata_v3_to_v2(&DataTypeMetadataV3::BFloat16, None).is_err());
+    }
+}
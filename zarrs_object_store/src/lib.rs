@@ -42,7 +42,7 @@ use object_store::path::Path;
 use zarrs_storage::{
     async_store_set_partial_values, byte_range::ByteRange, AsyncBytes, AsyncListableStorageTraits,
     AsyncReadableStorageTraits, AsyncWritableStorageTraits, MaybeAsyncBytes, StorageError,
-    StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
+    StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix, StoreValueVersion,
 };
 
 /// Maps a [`StoreKey`] to an [`object_store`] path.
@@ -60,17 +60,76 @@ fn handle_result_notfound<T>(
             if matches!(err, object_store::Error::NotFound { .. }) {
                 Ok(None)
             } else {
-                Err(StorageError::Other(err.to_string()))
+                Err(handle_error(err))
             }
         }
     }
 }
 
 fn handle_result<T>(result: Result<T, object_store::Error>) -> Result<T, StorageError> {
-    result.map_err(|err| StorageError::Other(err.to_string()))
+    result.map_err(handle_error)
+}
+
+/// Map an [`object_store::Error`] to a [`StorageError`].
+///
+/// A generic error caused by a request that may succeed if retried is mapped to [`StorageError::Transient`].
+/// This includes server errors and timeouts that exhausted the retries of the object store, and transient IO errors such as reset connections.
+#[allow(clippy::needless_pass_by_value)]
+fn handle_error(err: object_store::Error) -> StorageError {
+    let transient = match &err {
+        object_store::Error::Generic { source, .. } => is_transient_source(source.as_ref()),
+        _ => false,
+    };
+    if transient {
+        StorageError::Transient(err.to_string())
+    } else {
+        StorageError::Other(err.to_string())
+    }
+}
+
+/// Returns true if `err` or any of its sources is a retryable request error.
+fn is_transient_source(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<std::io::Error>() {
+            if StorageError::IOError(std::io::Error::from(err.kind())).is_transient() {
+                return true;
+            }
+        }
+        // The request retry errors of object_store are private, so they are identified by their message
+        let message = err.to_string();
+        if message.starts_with("Server error") || message.starts_with("Error after ") {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// Map [`object_store::Error::Precondition`] and [`object_store::Error::AlreadyExists`] to [`StorageError::PreconditionFailed`], pass through other errors
+fn handle_result_precondition<T>(
+    key: &StoreKey,
+    result: Result<T, object_store::Error>,
+) -> Result<T, StorageError> {
+    result.map_err(|err| match err {
+        object_store::Error::Precondition { .. } | object_store::Error::AlreadyExists { .. } => {
+            StorageError::PreconditionFailed(key.clone())
+        }
+        object_store::Error::NotImplemented => StorageError::Unsupported(err.to_string()),
+        _ => handle_error(err),
+    })
+}
+
+fn e_tag_to_version(e_tag: Option<String>) -> Result<StoreValueVersion, StorageError> {
+    e_tag.map(StoreValueVersion::new).ok_or_else(|| {
+        StorageError::Unsupported("the object store did not return an ETag".to_string())
+    })
 }
 
 /// An asynchronous store backed by an [`object_store::ObjectStore`].
+///
+/// Value versions for conditional writes are the `ETag`s of objects.
+/// Conditional writes are only supported if the underlying object store supports [`object_store::PutMode::Create`] and [`object_store::PutMode::Update`].
 pub struct AsyncObjectStore<T> {
     object_store: T,
     // locks: AsyncStoreLocks,
@@ -144,6 +203,25 @@ impl<T: object_store::ObjectStore> AsyncReadableStorageTraits for AsyncObjectSto
                 .map(|meta| meta.size as u64),
         )
     }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        let get = handle_result_notfound(self.object_store.get(&key_to_path(key)).await)?;
+        if let Some(get) = get {
+            let version = e_tag_to_version(get.meta.e_tag.clone())?;
+            let bytes = handle_result(get.bytes().await)?;
+            Ok(Some((bytes, version)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        let meta = handle_result_notfound(self.object_store.head(&key_to_path(key)).await)?;
+        meta.map(|meta| e_tag_to_version(meta.e_tag)).transpose()
+    }
 }
 
 #[async_trait::async_trait]
@@ -153,6 +231,27 @@ impl<T: object_store::ObjectStore> AsyncWritableStorageTraits for AsyncObjectSto
         Ok(())
     }
 
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        let mode = version.map_or(object_store::PutMode::Create, |version| {
+            object_store::PutMode::Update(object_store::UpdateVersion {
+                e_tag: Some(version.as_str().to_string()),
+                version: None,
+            })
+        });
+        let put_result = handle_result_precondition(
+            key,
+            self.object_store
+                .put_opts(&key_to_path(key), value.into(), mode.into())
+                .await,
+        )?;
+        e_tag_to_version(put_result.e_tag)
+    }
+
     async fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -281,6 +380,7 @@ mod tests {
         zarrs_storage::store_test::async_store_write(&store).await?;
         zarrs_storage::store_test::async_store_read(&store).await?;
         zarrs_storage::store_test::async_store_list(&store).await?;
+        zarrs_storage::store_test::async_store_set_if_version(&store).await?;
         Ok(())
     }
 
@@ -296,4 +396,23 @@ mod tests {
         zarrs_storage::store_test::async_store_list(&store).await?;
         Ok(())
     }
+
+    #[test]
+    fn error_transient() {
+        let timeout = object_store::Error::Generic {
+            store: "test",
+            source: Box::new(std::io::Error::from(std::io::ErrorKind::TimedOut)),
+        };
+        assert!(handle_error(timeout).is_transient());
+        let other = object_store::Error::Generic {
+            store: "test",
+            source: "invalid response".into(),
+        };
+        assert!(!handle_error(other).is_transient());
+        let not_found = object_store::Error::NotFound {
+            path: "a".to_string(),
+            source: Box::new(std::io::Error::from(std::io::ErrorKind::TimedOut)),
+        };
+        assert!(!handle_error(not_found).is_transient());
+    }
 }
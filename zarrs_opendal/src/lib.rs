@@ -40,6 +40,16 @@ pub use opendal;
 
 use zarrs_storage::StorageError;
 
+/// Map an [`opendal::Error`] to a [`StorageError`], mapping temporary errors to [`StorageError::Transient`]
+#[allow(clippy::needless_pass_by_value)]
+fn handle_error(err: opendal::Error) -> StorageError {
+    if err.is_temporary() {
+        StorageError::Transient(err.to_string())
+    } else {
+        StorageError::Other(err.to_string())
+    }
+}
+
 /// Map [`opendal::ErrorKind::NotFound`] to None, pass through other errors
 fn handle_result_notfound<T>(result: Result<T, opendal::Error>) -> Result<Option<T>, StorageError> {
     match result {
@@ -48,12 +58,12 @@ fn handle_result_notfound<T>(result: Result<T, opendal::Error>) -> Result<Option
             if err.kind() == opendal::ErrorKind::NotFound {
                 Ok(None)
             } else {
-                Err(StorageError::Other(err.to_string()))
+                Err(handle_error(err))
             }
         }
     }
 }
 
 fn handle_result<T>(result: Result<T, opendal::Error>) -> Result<T, StorageError> {
-    result.map_err(|err| StorageError::Other(err.to_string()))
+    result.map_err(handle_error)
 }
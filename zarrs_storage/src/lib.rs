@@ -178,6 +178,47 @@ impl StoreKeyOffsetValue<'_> {
     }
 }
 
+/// The version of a store value.
+///
+/// A version is an opaque identifier, such as an `ETag`, that changes when the value associated with a [`StoreKey`] changes.
+/// It is used for conditional writes with [`WritableStorageTraits::set_if_version`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StoreValueVersion(String);
+
+impl StoreValueVersion {
+    /// Create a new [`StoreValueVersion`].
+    #[must_use]
+    pub fn new(version: impl Into<String>) -> Self {
+        Self(version.into())
+    }
+
+    /// Create a [`StoreValueVersion`] from the content of a value.
+    ///
+    /// The version is derived from the length and a 64-bit FNV-1a hash of the value.
+    /// This is suitable for stores that do not natively track versions.
+    #[must_use]
+    pub fn from_value(value: &[u8]) -> Self {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        let hash = value.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        });
+        Self(format!("{:x}-{hash:016x}", value.len()))
+    }
+
+    /// Returns the version as a string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for StoreValueVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// [`StoreKeys`] and [`StorePrefixes`].
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[allow(dead_code)]
@@ -245,11 +286,40 @@ pub enum StorageError {
     /// Unknown key size where the key size must be known.
     #[error("{0}")]
     UnknownKeySize(StoreKey),
+    /// A conditional write failed because the stored value has changed.
+    #[error("conditional write to {0} failed, the stored value has changed")]
+    PreconditionFailed(StoreKey),
+    /// A transient error, such as a timeout or a throttled request, which may succeed if retried.
+    #[error("transient error: {0}")]
+    Transient(String),
     /// Any other error.
     #[error("{0}")]
     Other(String),
 }
 
+impl StorageError {
+    /// Returns true if the error is transient and the operation may succeed if retried.
+    ///
+    /// This is true for [`StorageError::Transient`] and IO errors such as timeouts and interrupted or reset connections.
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Transient(_) => true,
+            Self::IOError(err) => matches!(
+                err.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::WouldBlock
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::ConnectionRefused
+                    | std::io::ErrorKind::BrokenPipe
+            ),
+            _ => false,
+        }
+    }
+}
+
 impl From<&str> for StorageError {
     fn from(err: &str) -> Self {
         Self::Other(err.to_string())
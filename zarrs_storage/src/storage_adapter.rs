@@ -6,4 +6,7 @@
 pub mod async_to_sync;
 
 pub mod performance_metrics;
+pub mod retry;
+#[cfg(feature = "async")]
+pub mod sync_to_async;
 pub mod usage_log;
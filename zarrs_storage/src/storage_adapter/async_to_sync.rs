@@ -7,7 +7,7 @@
 use crate::{
     byte_range::ByteRange, AsyncListableStorageTraits, AsyncReadableStorageTraits,
     AsyncWritableStorageTraits, Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError,
-    StoreKey, StoreKeys, StoreKeysPrefixes, StorePrefix, WritableStorageTraits,
+    StoreKey, StoreKeys, StoreKeysPrefixes, StorePrefix, StoreValueVersion, WritableStorageTraits,
 };
 
 use std::sync::Arc;
@@ -67,6 +67,17 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits, TBlockOn: AsyncToSyncBlockOn
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
         self.block_on(self.storage.size_key(key))
     }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        self.block_on(self.storage.get_versioned(key))
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.block_on(self.storage.version_key(key))
+    }
 }
 
 impl<TStorage: ?Sized + AsyncListableStorageTraits, TBlockOn: AsyncToSyncBlockOn>
@@ -96,6 +107,15 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits, TBlockOn: AsyncToSyncBlockOn
         self.block_on(self.storage.set(key, value))
     }
 
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.block_on(self.storage.set_if_version(key, value, version))
+    }
+
     fn set_partial_values(
         &self,
         key_offset_values: &[crate::StoreKeyOffsetValue],
@@ -107,6 +127,14 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits, TBlockOn: AsyncToSyncBlockOn
         self.block_on(self.storage.erase(key))
     }
 
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.block_on(self.storage.erase_if_version(key, version))
+    }
+
     fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
         self.block_on(self.storage.erase_values(keys))
     }
@@ -3,7 +3,7 @@
 use crate::{
     Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError, StoreKey,
     StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes, StorePrefix,
-    WritableStorageTraits,
+    StoreValueVersion, WritableStorageTraits,
 };
 
 #[cfg(feature = "async")]
@@ -124,6 +124,21 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
         self.storage.size_key(key)
     }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        let value = self.storage.get_versioned(key)?;
+        let bytes_read = value.as_ref().map_or(0, |(bytes, _)| bytes.len());
+        self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        Ok(value)
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.storage.version_key(key)
+    }
 }
 
 impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
@@ -159,6 +174,17 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         self.storage.set(key, value)
     }
 
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.bytes_written.fetch_add(value.len(), Ordering::Relaxed);
+        self.writes.fetch_add(1, Ordering::Relaxed);
+        self.storage.set_if_version(key, value, version)
+    }
+
     fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -179,6 +205,15 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         self.storage.erase(key)
     }
 
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.keys_erased.fetch_add(1, Ordering::Relaxed);
+        self.storage.erase_if_version(key, version)
+    }
+
     fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
         self.keys_erased.fetch_add(keys.len(), Ordering::Relaxed);
         self.storage.erase_values(keys)
@@ -238,6 +273,21 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
     async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
         self.storage.size_key(key).await
     }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        let value = self.storage.get_versioned(key).await?;
+        let bytes_read = value.as_ref().map_or(0, |(bytes, _)| bytes.len());
+        self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        Ok(value)
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.storage.version_key(key).await
+    }
 }
 
 #[cfg(feature = "async")]
@@ -277,6 +327,17 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         self.storage.set(key, value).await
     }
 
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.bytes_written.fetch_add(value.len(), Ordering::Relaxed);
+        self.writes.fetch_add(1, Ordering::Relaxed);
+        self.storage.set_if_version(key, value, version).await
+    }
+
     async fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -296,6 +357,14 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         self.storage.erase(key).await
     }
 
+    async fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.storage.erase_if_version(key, version).await
+    }
+
     async fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
         self.storage.erase_values(keys).await
     }
@@ -0,0 +1,566 @@
+//! A storage transformer which retries operations that fail with transient errors.
+
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    byte_range::ByteRange, Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits,
+    StorageError, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes,
+    StorePrefix, StoreValueVersion, WritableStorageTraits,
+};
+
+#[cfg(feature = "async")]
+use crate::{
+    AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits,
+    MaybeAsyncBytes,
+};
+
+#[cfg(feature = "async")]
+use futures::future::BoxFuture;
+
+/// A retry policy with exponential backoff.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Retry up to 3 times with an initial backoff of 100ms and a maximum backoff of 10s.
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(100), Duration::from_secs(10))
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy.
+    ///
+    /// The backoff starts at `initial_backoff` and doubles after each retry up to `max_backoff`.
+    #[must_use]
+    pub const fn new(max_retries: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Return the maximum number of retries.
+    #[must_use]
+    pub const fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Return the backoff before retry `attempt` (starting from zero), or [`None`] if the retries are exhausted.
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Option<Duration> {
+        (attempt < self.max_retries).then(|| {
+            self.initial_backoff
+                .saturating_mul(2u32.saturating_pow(attempt))
+                .min(self.max_backoff)
+        })
+    }
+}
+
+/// A retryable error classifier.
+type RetryClassifier = Arc<dyn Fn(&StorageError) -> bool + Send + Sync>;
+
+/// An asynchronous sleep function.
+#[cfg(feature = "async")]
+type AsyncSleep = Arc<dyn Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// The retry storage transformer. Retries idempotent operations that fail with a retryable error.
+///
+/// By default, an error is retryable if it is transient (see [`StorageError::is_transient`]).
+/// This can be changed with [`with_classifier`](RetryStorageAdapter::with_classifier).
+///
+/// Every operation except [`set_if_version`](WritableStorageTraits::set_if_version) is retried.
+///
+/// ### Example
+/// ```rust
+/// # use std::{sync::Arc, time::Duration};
+/// # use zarrs_storage::store::MemoryStore;
+/// # use zarrs_storage::storage_adapter::retry::{RetryPolicy, RetryStorageAdapter};
+/// let store = Arc::new(MemoryStore::new());
+/// let policy = RetryPolicy::new(5, Duration::from_millis(50), Duration::from_secs(5));
+/// let store = Arc::new(RetryStorageAdapter::new(store, policy));
+/// ```
+pub struct RetryStorageAdapter<TStorage: ?Sized> {
+    storage: Arc<TStorage>,
+    policy: RetryPolicy,
+    classifier: RetryClassifier,
+    #[cfg(feature = "async")]
+    async_sleep: AsyncSleep,
+}
+
+impl<TStorage: ?Sized> std::fmt::Debug for RetryStorageAdapter<TStorage> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryStorageAdapter")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<TStorage: ?Sized> RetryStorageAdapter<TStorage> {
+    /// Create a new retry storage transformer with a retry `policy`.
+    #[must_use]
+    pub fn new(storage: Arc<TStorage>, policy: RetryPolicy) -> Self {
+        Self {
+            storage,
+            policy,
+            classifier: Arc::new(StorageError::is_transient),
+            #[cfg(feature = "async")]
+            async_sleep: Arc::new(default_async_sleep),
+        }
+    }
+
+    /// Set the retryable error classifier.
+    ///
+    /// An operation is retried if `classifier` returns true for its error.
+    #[must_use]
+    pub fn with_classifier(
+        mut self,
+        classifier: impl Fn(&StorageError) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.classifier = Arc::new(classifier);
+        self
+    }
+
+    /// Set the sleep function used to wait between retries of asynchronous operations.
+    ///
+    /// The default is runtime agnostic, but it spawns a thread for each backoff.
+    /// An async runtime timer should be preferred, e.g. `|duration| Box::pin(tokio::time::sleep(duration))`.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn with_async_sleep(
+        mut self,
+        sleep: impl Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync + 'static,
+    ) -> Self {
+        self.async_sleep = Arc::new(sleep);
+        self
+    }
+
+    /// Return the retry policy.
+    #[must_use]
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    /// Call `operation` until it succeeds, fails with a non-retryable error, or the retries are exhausted.
+    fn retry<T>(
+        &self,
+        mut operation: impl FnMut() -> Result<T, StorageError>,
+    ) -> Result<T, StorageError> {
+        let mut attempt = 0;
+        loop {
+            match operation() {
+                Err(err) if (self.classifier)(&err) => {
+                    let Some(backoff) = self.policy.backoff(attempt) else {
+                        return Err(err);
+                    };
+                    std::thread::sleep(backoff);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Await `operation` until it succeeds, fails with a non-retryable error, or the retries are exhausted.
+    #[cfg(feature = "async")]
+    async fn async_retry<T, F, Fut>(&self, mut operation: F) -> Result<T, StorageError>
+    where
+        F: FnMut() -> Fut + Send,
+        Fut: std::future::Future<Output = Result<T, StorageError>> + Send,
+    {
+        let mut attempt = 0;
+        loop {
+            match operation().await {
+                Err(err) if (self.classifier)(&err) => {
+                    let Some(backoff) = self.policy.backoff(attempt) else {
+                        return Err(err);
+                    };
+                    (self.async_sleep)(backoff).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Sleep for `duration` on a separate thread, without depending on an async runtime.
+#[cfg(feature = "async")]
+fn default_async_sleep(duration: Duration) -> BoxFuture<'static, ()> {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    std::thread::spawn(move || {
+        std::thread::sleep(duration);
+        let _ = sender.send(());
+    });
+    Box::pin(async move {
+        let _ = receiver.await;
+    })
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for RetryStorageAdapter<TStorage>
+{
+    fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
+        self.retry(|| self.storage.get(key))
+    }
+
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.retry(|| self.storage.get_partial_values_key(key, byte_ranges))
+    }
+
+    fn get_partial_values(
+        &self,
+        key_ranges: &[StoreKeyRange],
+    ) -> Result<Vec<MaybeBytes>, StorageError> {
+        self.retry(|| self.storage.get_partial_values(key_ranges))
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.retry(|| self.storage.size_key(key))
+    }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        self.retry(|| self.storage.get_versioned(key))
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.retry(|| self.storage.version_key(key))
+    }
+}
+
+impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
+    for RetryStorageAdapter<TStorage>
+{
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.retry(|| self.storage.list())
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.retry(|| self.storage.list_prefix(prefix))
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.retry(|| self.storage.list_dir(prefix))
+    }
+
+    fn size(&self) -> Result<u64, StorageError> {
+        self.retry(|| self.storage.size())
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.retry(|| self.storage.size_prefix(prefix))
+    }
+}
+
+impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
+    for RetryStorageAdapter<TStorage>
+{
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        self.retry(|| self.storage.set(key, value.clone()))
+    }
+
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        // Not retried, a failed conditional write may have been applied
+        self.storage.set_if_version(key, value, version)
+    }
+
+    fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        self.retry(|| self.storage.set_partial_values(key_offset_values))
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.retry(|| self.storage.erase(key))
+    }
+
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        // Not retried, a failed conditional erasure may have been applied
+        self.storage.erase_if_version(key, version)
+    }
+
+    fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        self.retry(|| self.storage.erase_values(keys))
+    }
+
+    fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.retry(|| self.storage.erase_prefix(prefix))
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
+    for RetryStorageAdapter<TStorage>
+{
+    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        self.async_retry(|| self.storage.get(key)).await
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.async_retry(|| self.storage.get_partial_values_key(key, byte_ranges))
+            .await
+    }
+
+    async fn get_partial_values(
+        &self,
+        key_ranges: &[StoreKeyRange],
+    ) -> Result<Vec<MaybeAsyncBytes>, StorageError> {
+        self.async_retry(|| self.storage.get_partial_values(key_ranges))
+            .await
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.async_retry(|| self.storage.size_key(key)).await
+    }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        self.async_retry(|| self.storage.get_versioned(key)).await
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.async_retry(|| self.storage.version_key(key)).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncListableStorageTraits> AsyncListableStorageTraits
+    for RetryStorageAdapter<TStorage>
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.async_retry(|| self.storage.list()).await
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.async_retry(|| self.storage.list_prefix(prefix)).await
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.async_retry(|| self.storage.list_dir(prefix)).await
+    }
+
+    async fn size(&self) -> Result<u64, StorageError> {
+        self.async_retry(|| self.storage.size()).await
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.async_retry(|| self.storage.size_prefix(prefix)).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
+    for RetryStorageAdapter<TStorage>
+{
+    async fn set(&self, key: &StoreKey, value: AsyncBytes) -> Result<(), StorageError> {
+        self.async_retry(|| self.storage.set(key, value.clone()))
+            .await
+    }
+
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        // Not retried, a failed conditional write may have been applied
+        self.storage.set_if_version(key, value, version).await
+    }
+
+    async fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        self.async_retry(|| self.storage.set_partial_values(key_offset_values))
+            .await
+    }
+
+    async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.async_retry(|| self.storage.erase(key)).await
+    }
+
+    async fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        // Not retried, a failed conditional erasure may have been applied
+        self.storage.erase_if_version(key, version).await
+    }
+
+    async fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        self.async_retry(|| self.storage.erase_values(keys)).await
+    }
+
+    async fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.async_retry(|| self.storage.erase_prefix(prefix)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::store::MemoryStore;
+
+    use super::*;
+
+    /// A store which fails the first `failures` reads with a transient error.
+    struct FlakyStore {
+        storage: MemoryStore,
+        failures: AtomicUsize,
+        reads: AtomicUsize,
+    }
+
+    impl FlakyStore {
+        fn new(failures: usize) -> Self {
+            Self {
+                storage: MemoryStore::new(),
+                failures: AtomicUsize::new(failures),
+                reads: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl ReadableStorageTraits for FlakyStore {
+        fn get_partial_values_key(
+            &self,
+            key: &StoreKey,
+            byte_ranges: &[ByteRange],
+        ) -> Result<Option<Vec<Bytes>>, StorageError> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            if self
+                .failures
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |failures| {
+                    failures.checked_sub(1)
+                })
+                .is_ok()
+            {
+                Err(StorageError::Transient("flaky".to_string()))
+            } else {
+                self.storage.get_partial_values_key(key, byte_ranges)
+            }
+        }
+
+        fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+            self.storage.size_key(key)
+        }
+    }
+
+    #[test]
+    fn retry_policy() {
+        let policy = RetryPolicy::new(4, Duration::from_millis(10), Duration::from_millis(50));
+        assert_eq!(policy.backoff(0), Some(Duration::from_millis(10)));
+        assert_eq!(policy.backoff(2), Some(Duration::from_millis(40)));
+        assert_eq!(policy.backoff(3), Some(Duration::from_millis(50)));
+        assert_eq!(policy.backoff(4), None);
+    }
+
+    #[test]
+    fn storage_error_is_transient() {
+        assert!(StorageError::Transient("timeout".to_string()).is_transient());
+        assert!(StorageError::IOError(std::io::ErrorKind::TimedOut.into()).is_transient());
+        assert!(!StorageError::IOError(std::io::ErrorKind::NotFound.into()).is_transient());
+        assert!(!StorageError::ReadOnly.is_transient());
+    }
+
+    #[test]
+    fn retry_storage_adapter() -> Result<(), Box<dyn std::error::Error>> {
+        let key = StoreKey::new("a")?;
+        let flaky = Arc::new(FlakyStore::new(2));
+        flaky.storage.set(&key, vec![1, 2, 3].into())?;
+        let policy = RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(1));
+
+        let store = RetryStorageAdapter::new(flaky.clone(), policy);
+        assert_eq!(
+            ReadableStorageTraits::get(&store, &key)?,
+            Some(vec![1, 2, 3].into())
+        );
+        assert_eq!(flaky.reads.load(Ordering::Relaxed), 3);
+
+        flaky.failures.store(3, Ordering::Relaxed);
+        assert!(matches!(
+            ReadableStorageTraits::get(&store, &key),
+            Err(StorageError::Transient(_))
+        ));
+
+        flaky.failures.store(1, Ordering::Relaxed);
+        flaky.reads.store(0, Ordering::Relaxed);
+        let store = RetryStorageAdapter::new(flaky.clone(), policy).with_classifier(|_| false);
+        assert!(ReadableStorageTraits::get(&store, &key).is_err());
+        assert_eq!(flaky.reads.load(Ordering::Relaxed), 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[async_trait::async_trait]
+    impl AsyncReadableStorageTraits for FlakyStore {
+        async fn get_partial_values_key(
+            &self,
+            key: &StoreKey,
+            byte_ranges: &[ByteRange],
+        ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+            ReadableStorageTraits::get_partial_values_key(self, key, byte_ranges)
+        }
+
+        async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+            ReadableStorageTraits::size_key(self, key)
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_retry_storage_adapter() -> Result<(), Box<dyn std::error::Error>> {
+        futures::executor::block_on(async {
+            let key = StoreKey::new("a")?;
+            let flaky = Arc::new(FlakyStore::new(2));
+            flaky.storage.set(&key, vec![1, 2, 3].into())?;
+            let policy = RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(1));
+
+            let store = RetryStorageAdapter::new(flaky.clone(), policy);
+            assert_eq!(
+                AsyncReadableStorageTraits::get(&store, &key).await?,
+                Some(vec![1, 2, 3].into())
+            );
+            assert_eq!(flaky.reads.load(Ordering::Relaxed), 3);
+
+            flaky.failures.store(3, Ordering::Relaxed);
+            assert!(matches!(
+                AsyncReadableStorageTraits::get(&store, &key).await,
+                Err(StorageError::Transient(_))
+            ));
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,261 @@
+//! A sync to async storage adapter.
+//!
+//! The docs for the [`SyncToAsyncSpawnBlocking`] trait include an example implementation for the `tokio` runtime.
+
+use crate::{
+    byte_range::ByteRange, AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits,
+    AsyncWritableStorageTraits, ListableStorageTraits, MaybeAsyncBytes, ReadableStorageTraits,
+    StorageError, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes,
+    StorePrefix, StoreValueVersion, WritableStorageTraits,
+};
+
+use std::{future::Future, sync::Arc};
+
+/// Trait for an asynchronous runtime implementing `spawn_blocking`.
+///
+/// ### Example `tokio` implementation of [`SyncToAsyncSpawnBlocking`].
+/// ```rust,ignore
+/// # use zarrs_storage::storage_adapter::sync_to_async::SyncToAsyncSpawnBlocking;
+/// struct TokioSpawnBlocking;
+///
+/// impl SyncToAsyncSpawnBlocking for TokioSpawnBlocking {
+///     fn spawn_blocking<F, R>(&self, f: F) -> impl core::future::Future<Output = R> + Send
+///     where
+///         F: FnOnce() -> R + Send + 'static,
+///         R: Send + 'static,
+///     {
+///         let handle = tokio::task::spawn_blocking(f);
+///         async move { handle.await.unwrap() }
+///     }
+/// }
+pub trait SyncToAsyncSpawnBlocking: Send + Sync {
+    /// Runs a blocking function without blocking the asynchronous execution context.
+    fn spawn_blocking<F, R>(&self, f: F) -> impl Future<Output = R> + Send
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static;
+}
+
+/// A sync to async storage adapter.
+///
+/// Each storage operation is run by a [`SyncToAsyncSpawnBlocking`] implementation, such as on the blocking thread pool of an asynchronous runtime.
+/// This enables synchronous stores (e.g. a filesystem store) to be used with the asynchronous API.
+pub struct SyncToAsyncStorageAdapter<TStorage: ?Sized, TSpawnBlocking: SyncToAsyncSpawnBlocking> {
+    storage: Arc<TStorage>,
+    spawn_blocking: TSpawnBlocking,
+}
+
+impl<TStorage: ?Sized, TSpawnBlocking: SyncToAsyncSpawnBlocking>
+    SyncToAsyncStorageAdapter<TStorage, TSpawnBlocking>
+{
+    /// Create a new sync to async storage adapter.
+    #[must_use]
+    pub fn new(storage: Arc<TStorage>, spawn_blocking: TSpawnBlocking) -> Self {
+        Self {
+            storage,
+            spawn_blocking,
+        }
+    }
+
+    /// Run `f` with the underlying store.
+    async fn spawn_blocking<F, R>(&self, f: F) -> R
+    where
+        TStorage: Send + Sync + 'static,
+        F: FnOnce(&TStorage) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let storage = self.storage.clone();
+        self.spawn_blocking
+            .spawn_blocking(move || f(storage.as_ref()))
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<TStorage, TSpawnBlocking> AsyncReadableStorageTraits
+    for SyncToAsyncStorageAdapter<TStorage, TSpawnBlocking>
+where
+    TStorage: ?Sized + ReadableStorageTraits + 'static,
+    TSpawnBlocking: SyncToAsyncSpawnBlocking,
+{
+    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        let key = key.clone();
+        self.spawn_blocking(move |storage| storage.get(&key)).await
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        let key = key.clone();
+        let byte_ranges = byte_ranges.to_vec();
+        self.spawn_blocking(move |storage| storage.get_partial_values_key(&key, &byte_ranges))
+            .await
+    }
+
+    async fn get_partial_values(
+        &self,
+        key_ranges: &[StoreKeyRange],
+    ) -> Result<Vec<MaybeAsyncBytes>, StorageError> {
+        let key_ranges = key_ranges.to_vec();
+        self.spawn_blocking(move |storage| storage.get_partial_values(&key_ranges))
+            .await
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let key = key.clone();
+        self.spawn_blocking(move |storage| storage.size_key(&key))
+            .await
+    }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        let key = key.clone();
+        self.spawn_blocking(move |storage| storage.get_versioned(&key))
+            .await
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        let key = key.clone();
+        self.spawn_blocking(move |storage| storage.version_key(&key))
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<TStorage, TSpawnBlocking> AsyncListableStorageTraits
+    for SyncToAsyncStorageAdapter<TStorage, TSpawnBlocking>
+where
+    TStorage: ?Sized + ListableStorageTraits + 'static,
+    TSpawnBlocking: SyncToAsyncSpawnBlocking,
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.spawn_blocking(ListableStorageTraits::list).await
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        let prefix = prefix.clone();
+        self.spawn_blocking(move |storage| storage.list_prefix(&prefix))
+            .await
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        let prefix = prefix.clone();
+        self.spawn_blocking(move |storage| storage.list_dir(&prefix))
+            .await
+    }
+
+    async fn size(&self) -> Result<u64, StorageError> {
+        self.spawn_blocking(ListableStorageTraits::size).await
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        let prefix = prefix.clone();
+        self.spawn_blocking(move |storage| storage.size_prefix(&prefix))
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<TStorage, TSpawnBlocking> AsyncWritableStorageTraits
+    for SyncToAsyncStorageAdapter<TStorage, TSpawnBlocking>
+where
+    TStorage: ?Sized + WritableStorageTraits + 'static,
+    TSpawnBlocking: SyncToAsyncSpawnBlocking,
+{
+    async fn set(&self, key: &StoreKey, value: AsyncBytes) -> Result<(), StorageError> {
+        let key = key.clone();
+        self.spawn_blocking(move |storage| storage.set(&key, value))
+            .await
+    }
+
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        let key = key.clone();
+        let version = version.cloned();
+        self.spawn_blocking(move |storage| storage.set_if_version(&key, value, version.as_ref()))
+            .await
+    }
+
+    async fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        // The values are copied, since they must outlive the blocking function
+        let key_offset_values: Vec<_> = key_offset_values
+            .iter()
+            .map(|key_offset_value| {
+                (
+                    key_offset_value.key().clone(),
+                    key_offset_value.offset(),
+                    key_offset_value.value().to_vec(),
+                )
+            })
+            .collect();
+        self.spawn_blocking(move |storage| {
+            let key_offset_values: Vec<_> = key_offset_values
+                .iter()
+                .map(|(key, offset, value)| StoreKeyOffsetValue::new(key.clone(), *offset, value))
+                .collect();
+            storage.set_partial_values(&key_offset_values)
+        })
+        .await
+    }
+
+    async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        let key = key.clone();
+        self.spawn_blocking(move |storage| storage.erase(&key))
+            .await
+    }
+
+    async fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        let key = key.clone();
+        let version = version.clone();
+        self.spawn_blocking(move |storage| storage.erase_if_version(&key, &version))
+            .await
+    }
+
+    async fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        let keys = keys.to_vec();
+        self.spawn_blocking(move |storage| storage.erase_values(&keys))
+            .await
+    }
+
+    async fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        let prefix = prefix.clone();
+        self.spawn_blocking(move |storage| storage.erase_prefix(&prefix))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{store::MemoryStore, store_test::ThreadSpawnBlocking};
+    use std::error::Error;
+
+    #[test]
+    fn sync_to_async() -> Result<(), Box<dyn Error>> {
+        let store =
+            SyncToAsyncStorageAdapter::new(Arc::new(MemoryStore::new()), ThreadSpawnBlocking);
+        futures::executor::block_on(async {
+            crate::store_test::async_store_write(&store).await?;
+            crate::store_test::async_store_read(&store).await?;
+            crate::store_test::async_store_list(&store).await?;
+            crate::store_test::async_store_set_if_version(&store).await?;
+            crate::store_test::async_store_erase_if_version(&store).await?;
+            Ok(())
+        })
+    }
+}
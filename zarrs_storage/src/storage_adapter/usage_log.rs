@@ -10,7 +10,7 @@ use itertools::Itertools;
 use crate::{
     byte_range::ByteRange, Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits,
     StorageError, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes,
-    StorePrefix, WritableStorageTraits,
+    StorePrefix, StoreValueVersion, WritableStorageTraits,
 };
 
 #[cfg(feature = "async")]
@@ -138,6 +138,35 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
         )?;
         result
     }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        let result = self.storage.get_versioned(key);
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}get_versioned({key}) -> {:?}",
+            (self.prefix_func)(),
+            result.as_ref().map(|v| v
+                .as_ref()
+                .map(|(bytes, version)| (bytes.len(), version.as_str())))
+        )?;
+        result
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        let result = self.storage.version_key(key);
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}version_key({key}) -> {:?}",
+            (self.prefix_func)(),
+            result
+                .as_ref()
+                .map(|v| v.as_ref().map(StoreValueVersion::as_str))
+        )?;
+        result
+    }
 }
 
 impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
@@ -221,6 +250,22 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         result
     }
 
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        let len = value.len();
+        let result = self.storage.set_if_version(key, value, version);
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}set_if_version({key}, len={len}, {version:?}) -> {result:?}",
+            (self.prefix_func)()
+        )?;
+        result
+    }
+
     fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -260,6 +305,20 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         result
     }
 
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        let result = self.storage.erase_if_version(key, version);
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}erase_if_version({key}, {version:?}) -> {result:?}",
+            (self.prefix_func)()
+        )?;
+        result
+    }
+
     fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
         let result = self.storage.erase_values(keys);
         writeln!(
@@ -346,6 +405,35 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
         )?;
         result
     }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        let result = self.storage.get_versioned(key).await;
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}get_versioned({key}) -> {:?}",
+            (self.prefix_func)(),
+            result.as_ref().map(|v| v
+                .as_ref()
+                .map(|(bytes, version)| (bytes.len(), version.as_str())))
+        )?;
+        result
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        let result = self.storage.version_key(key).await;
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}version_key({key}) -> {:?}",
+            (self.prefix_func)(),
+            result
+                .as_ref()
+                .map(|v| v.as_ref().map(StoreValueVersion::as_str))
+        )?;
+        result
+    }
 }
 
 #[cfg(feature = "async")]
@@ -432,6 +520,22 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         result
     }
 
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        let len = value.len();
+        let result = self.storage.set_if_version(key, value, version).await;
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}set_if_version({key}, len={len}, {version:?}) -> {result:?}",
+            (self.prefix_func)()
+        )?;
+        result
+    }
+
     async fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -455,6 +559,20 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         result
     }
 
+    async fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        let result = self.storage.erase_if_version(key, version).await;
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}erase_if_version({key}, {version:?}) -> {result:?}",
+            (self.prefix_func)()
+        )?;
+        result
+    }
+
     async fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
         let result = self.storage.erase_values(keys).await;
         writeln!(
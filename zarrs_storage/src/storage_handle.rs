@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use super::{
     byte_range::ByteRange, Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits,
-    StorageError, StoreKey, StorePrefix, WritableStorageTraits,
+    StorageError, StoreKey, StorePrefix, StoreValueVersion, WritableStorageTraits,
 };
 
 #[cfg(feature = "async")]
@@ -47,6 +47,17 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits for Storage
     fn size_key(&self, key: &super::StoreKey) -> Result<Option<u64>, super::StorageError> {
         self.0.size_key(key)
     }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        self.0.get_versioned(key)
+    }
+
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.0.version_key(key)
+    }
 }
 
 impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits for StorageHandle<TStorage> {
@@ -82,6 +93,15 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits for Storage
         self.0.set(key, value)
     }
 
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.0.set_if_version(key, value, version)
+    }
+
     fn set_partial_values(
         &self,
         key_offset_values: &[super::StoreKeyOffsetValue],
@@ -93,6 +113,14 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits for Storage
         self.0.erase(key)
     }
 
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.0.erase_if_version(key, version)
+    }
+
     fn erase_values(&self, keys: &[super::StoreKey]) -> Result<(), super::StorageError> {
         self.0.erase_values(keys)
     }
@@ -129,6 +157,17 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
     async fn size_key(&self, key: &super::StoreKey) -> Result<Option<u64>, super::StorageError> {
         self.0.size_key(key).await
     }
+
+    async fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(AsyncBytes, StoreValueVersion)>, StorageError> {
+        self.0.get_versioned(key).await
+    }
+
+    async fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        self.0.version_key(key).await
+    }
 }
 
 #[cfg(feature = "async")]
@@ -172,6 +211,15 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         self.0.set(key, value).await
     }
 
+    async fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        self.0.set_if_version(key, value, version).await
+    }
+
     async fn set_partial_values(
         &self,
         key_offset_values: &[super::StoreKeyOffsetValue],
@@ -183,6 +231,14 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         self.0.erase(key).await
     }
 
+    async fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        self.0.erase_if_version(key, version).await
+    }
+
     async fn erase_values(&self, keys: &[super::StoreKey]) -> Result<(), super::StorageError> {
         self.0.erase_values(keys).await
     }
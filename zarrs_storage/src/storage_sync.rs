@@ -4,7 +4,7 @@ use itertools::Itertools;
 
 use super::{
     byte_range::ByteRange, Bytes, MaybeBytes, StorageError, StoreKey, StoreKeyOffsetValue,
-    StoreKeyRange, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
+    StoreKeyRange, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes, StoreValueVersion,
 };
 
 /// Readable storage traits.
@@ -58,6 +58,34 @@ pub trait ReadableStorageTraits: Send + Sync {
     /// Returns a [`StorageError`] if there is an underlying storage error.
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError>;
 
+    /// Retrieve the value (bytes) and [`StoreValueVersion`] associated with a given [`StoreKey`].
+    ///
+    /// Returns [`None`] if the key is not found.
+    /// Stores do not support versioned values by default.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if the store does not support versioned values or there is an underlying storage error.
+    fn get_versioned(
+        &self,
+        _key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        Err(StorageError::Unsupported(
+            "the store does not support versioned values".to_string(),
+        ))
+    }
+
+    /// Retrieve the [`StoreValueVersion`] of the value associated with a given [`StoreKey`].
+    ///
+    /// Returns [`None`] if the key is not found.
+    /// The version is the same as that returned by [`get_versioned`](ReadableStorageTraits::get_versioned).
+    /// The default implementation retrieves the value with [`get_versioned`](ReadableStorageTraits::get_versioned), stores that track versions natively should retrieve the version alone.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if the store does not support versioned values or there is an underlying storage error.
+    fn version_key(&self, key: &StoreKey) -> Result<Option<StoreValueVersion>, StorageError> {
+        Ok(self.get_versioned(key)?.map(|(_, version)| version))
+    }
+
     /// A utility method with the same input and output as [`get_partial_values`](ReadableStorageTraits::get_partial_values) that internally calls [`get_partial_values_key`](ReadableStorageTraits::get_partial_values_key) with byte ranges grouped by key.
     ///
     /// Readable storage can use this function in the implementation of [`get_partial_values`](ReadableStorageTraits::get_partial_values) if that is optimal.
@@ -211,6 +239,28 @@ pub trait WritableStorageTraits: Send + Sync {
     /// Returns a [`StorageError`] on failure to store.
     fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError>;
 
+    /// Store bytes at a [`StoreKey`] if the stored value is unchanged.
+    ///
+    /// The value is only stored if the [`StoreValueVersion`] of the stored value matches `version`, or if `version` is [`None`] and the key does not exist.
+    /// This enables safe concurrent updates of a value (compare-and-swap).
+    /// Stores do not support conditional writes by default.
+    ///
+    /// Returns the version of the stored value.
+    ///
+    /// # Errors
+    /// Returns [`StorageError::PreconditionFailed`] if the stored value has changed.
+    /// Returns a [`StorageError`] if the store does not support conditional writes or there is an underlying storage error.
+    fn set_if_version(
+        &self,
+        _key: &StoreKey,
+        _value: Bytes,
+        _version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        Err(StorageError::Unsupported(
+            "the store does not support conditional writes".to_string(),
+        ))
+    }
+
     /// Store bytes according to a list of [`StoreKeyOffsetValue`].
     ///
     /// # Errors
@@ -226,6 +276,24 @@ pub trait WritableStorageTraits: Send + Sync {
     /// Returns a [`StorageError`] if there is an underlying storage error.
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError>;
 
+    /// Erase a [`StoreKey`] if the stored value is unchanged.
+    ///
+    /// The value is only erased if the [`StoreValueVersion`] of the stored value matches `version`.
+    /// Stores do not support conditional erasure by default.
+    ///
+    /// # Errors
+    /// Returns [`StorageError::PreconditionFailed`] if the stored value has changed or does not exist.
+    /// Returns a [`StorageError`] if the store does not support conditional erasure or there is an underlying storage error.
+    fn erase_if_version(
+        &self,
+        _key: &StoreKey,
+        _version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        Err(StorageError::Unsupported(
+            "the store does not support conditional erasure".to_string(),
+        ))
+    }
+
     /// Erase a list of [`StoreKey`].
     ///
     /// # Errors
@@ -6,7 +6,8 @@ use std::sync::Mutex;
 use crate::{
     byte_range::{ByteOffset, ByteRange, InvalidByteRangeError},
     Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError, StoreKey,
-    StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix, WritableStorageTraits,
+    StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix, StoreValueVersion,
+    WritableStorageTraits,
 };
 
 use std::{
@@ -15,6 +16,8 @@ use std::{
 };
 
 /// A synchronous in-memory store.
+///
+/// Value versions for conditional writes are derived from the content of values.
 #[derive(Debug)]
 pub struct MemoryStore {
     data_map: Mutex<BTreeMap<StoreKey, Arc<RwLock<Vec<u8>>>>>,
@@ -108,6 +111,23 @@ impl ReadableStorageTraits for MemoryStore {
             .get(key)
             .map_or_else(|| Ok(None), |entry| Ok(Some(entry.read().len() as u64)))
     }
+
+    fn get_versioned(
+        &self,
+        key: &StoreKey,
+    ) -> Result<Option<(Bytes, StoreValueVersion)>, StorageError> {
+        let data_map = self.data_map.lock().unwrap();
+        let data = data_map.get(key);
+        if let Some(data) = data {
+            let data = data.clone();
+            drop(data_map);
+            let data = data.read();
+            let version = StoreValueVersion::from_value(&data);
+            Ok(Some((data.clone().into(), version)))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl WritableStorageTraits for MemoryStore {
@@ -116,6 +136,27 @@ impl WritableStorageTraits for MemoryStore {
         Ok(())
     }
 
+    fn set_if_version(
+        &self,
+        key: &StoreKey,
+        value: Bytes,
+        version: Option<&StoreValueVersion>,
+    ) -> Result<StoreValueVersion, StorageError> {
+        // Hold the map lock so that the check and the write are atomic
+        let mut data_map = self.data_map.lock().unwrap();
+        let current = data_map
+            .get(key)
+            .map(|data| StoreValueVersion::from_value(&data.read()));
+        if current.as_ref() != version {
+            return Err(StorageError::PreconditionFailed(key.clone()));
+        }
+        let data = data_map
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(RwLock::default()));
+        *data.write() = value.to_vec();
+        Ok(StoreValueVersion::from_value(&value))
+    }
+
     fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -148,6 +189,23 @@ impl WritableStorageTraits for MemoryStore {
         Ok(())
     }
 
+    fn erase_if_version(
+        &self,
+        key: &StoreKey,
+        version: &StoreValueVersion,
+    ) -> Result<(), StorageError> {
+        // Hold the map lock so that the check and the erasure are atomic
+        let mut data_map = self.data_map.lock().unwrap();
+        let current = data_map
+            .get(key)
+            .map(|data| StoreValueVersion::from_value(&data.read()));
+        if current.as_ref() != Some(version) {
+            return Err(StorageError::PreconditionFailed(key.clone()));
+        }
+        data_map.remove(key);
+        Ok(())
+    }
+
     fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
         let mut data_map = self.data_map.lock().unwrap();
         let keys: Vec<StoreKey> = data_map.keys().cloned().collect();
@@ -222,6 +280,8 @@ mod tests {
         crate::store_test::store_write(&store)?;
         crate::store_test::store_read(&store)?;
         crate::store_test::store_list(&store)?;
+        crate::store_test::store_set_if_version(&store)?;
+        crate::store_test::store_erase_if_version(&store)?;
         Ok(())
     }
 }
@@ -1,12 +1,36 @@
 use std::error::Error;
 
 use crate::{
-    byte_range::ByteRange, ListableStorageTraits, ReadableStorageTraits, StoreKeyOffsetValue,
-    StoreKeyRange, StorePrefix, WritableStorageTraits,
+    byte_range::ByteRange, ListableStorageTraits, ReadableStorageTraits, StorageError,
+    StoreKeyOffsetValue, StoreKeyRange, StorePrefix, WritableStorageTraits,
 };
 
 #[cfg(feature = "async")]
-use crate::{AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits};
+use crate::{
+    storage_adapter::sync_to_async::SyncToAsyncSpawnBlocking, AsyncListableStorageTraits,
+    AsyncReadableStorageTraits, AsyncWritableStorageTraits,
+};
+
+#[cfg(feature = "async")]
+/// Runs blocking functions on a new thread.
+///
+/// A runtime agnostic [`SyncToAsyncSpawnBlocking`] implementation for testing the synchronous to asynchronous storage adapter.
+pub struct ThreadSpawnBlocking;
+
+#[cfg(feature = "async")]
+impl SyncToAsyncSpawnBlocking for ThreadSpawnBlocking {
+    fn spawn_blocking<F, R>(&self, f: F) -> impl std::future::Future<Output = R> + Send
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(f());
+        });
+        async move { receiver.await.unwrap() }
+    }
+}
 
 #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// Create a store with the following data
@@ -150,6 +174,68 @@ pub fn store_list<T: ListableStorageTraits>(store: &T) -> Result<(), Box<dyn Err
     Ok(())
 }
 
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Check conditional writes with [`WritableStorageTraits::set_if_version`].
+///
+/// The key `v` is erased on completion.
+pub fn store_set_if_version<T: ReadableStorageTraits + WritableStorageTraits>(
+    store: &T,
+) -> Result<(), Box<dyn Error>> {
+    let key = "v".try_into()?;
+    store.erase(&key)?;
+    assert!(store.get_versioned(&key)?.is_none());
+
+    // Create only if the key does not exist
+    let version = store.set_if_version(&key, vec![0, 1].into(), None)?;
+    assert!(matches!(
+        store.set_if_version(&key, vec![2].into(), None),
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    let (value, version_get) = store.get_versioned(&key)?.unwrap();
+    assert_eq!(value, vec![0, 1]);
+    assert_eq!(version_get, version);
+
+    // Update only if the version matches
+    let version_new = store.set_if_version(&key, vec![2, 3, 4].into(), Some(&version))?;
+    assert_ne!(version_new, version);
+    assert!(matches!(
+        store.set_if_version(&key, vec![5].into(), Some(&version)),
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    assert_eq!(store.get(&key)?.unwrap(), vec![2, 3, 4]);
+
+    store.erase(&key)?;
+    Ok(())
+}
+
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Check conditional erasure with [`WritableStorageTraits::erase_if_version`].
+pub fn store_erase_if_version<T: ReadableStorageTraits + WritableStorageTraits>(
+    store: &T,
+) -> Result<(), Box<dyn Error>> {
+    let key = "v".try_into()?;
+    store.set(&key, vec![0, 1].into())?;
+    let (_, version) = store.get_versioned(&key)?.unwrap();
+
+    // Erase only if the version matches
+    store.set(&key, vec![2].into())?;
+    assert!(matches!(
+        store.erase_if_version(&key, &version),
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    assert_eq!(store.get(&key)?.unwrap(), vec![2]);
+    let (_, version) = store.get_versioned(&key)?.unwrap();
+    store.erase_if_version(&key, &version)?;
+    assert!(store.get(&key)?.is_none());
+
+    // Erasing a missing key fails
+    assert!(matches!(
+        store.erase_if_version(&key, &version),
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    Ok(())
+}
+
 #[cfg(feature = "async")]
 #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// Create a store with the following data
@@ -323,3 +409,75 @@ pub async fn async_store_list<T: AsyncListableStorageTraits>(
     }
     Ok(())
 }
+
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Check conditional writes with [`AsyncWritableStorageTraits::set_if_version`].
+///
+/// The key `v` is erased on completion.
+pub async fn async_store_set_if_version<
+    T: AsyncReadableStorageTraits + AsyncWritableStorageTraits,
+>(
+    store: &T,
+) -> Result<(), Box<dyn Error>> {
+    let key = "v".try_into()?;
+    store.erase(&key).await?;
+    assert!(store.get_versioned(&key).await?.is_none());
+
+    // Create only if the key does not exist
+    let version = store.set_if_version(&key, vec![0, 1].into(), None).await?;
+    assert!(matches!(
+        store.set_if_version(&key, vec![2].into(), None).await,
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    let (value, version_get) = store.get_versioned(&key).await?.unwrap();
+    assert_eq!(value, vec![0, 1]);
+    assert_eq!(version_get, version);
+
+    // Update only if the version matches
+    let version_new = store
+        .set_if_version(&key, vec![2, 3, 4].into(), Some(&version))
+        .await?;
+    assert_ne!(version_new, version);
+    assert!(matches!(
+        store
+            .set_if_version(&key, vec![5].into(), Some(&version))
+            .await,
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    assert_eq!(store.get(&key).await?.unwrap(), vec![2, 3, 4]);
+
+    store.erase(&key).await?;
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Check conditional erasure with [`AsyncWritableStorageTraits::erase_if_version`].
+pub async fn async_store_erase_if_version<
+    T: AsyncReadableStorageTraits + AsyncWritableStorageTraits,
+>(
+    store: &T,
+) -> Result<(), Box<dyn Error>> {
+    let key = "v".try_into()?;
+    store.set(&key, vec![0, 1].into()).await?;
+    let (_, version) = store.get_versioned(&key).await?.unwrap();
+
+    // Erase only if the version matches
+    store.set(&key, vec![2].into()).await?;
+    assert!(matches!(
+        store.erase_if_version(&key, &version).await,
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    assert_eq!(store.get(&key).await?.unwrap(), vec![2]);
+    let (_, version) = store.get_versioned(&key).await?.unwrap();
+    store.erase_if_version(&key, &version).await?;
+    assert!(store.get(&key).await?.is_none());
+
+    // Erasing a missing key fails
+    assert!(matches!(
+        store.erase_if_version(&key, &version).await,
+        Err(StorageError::PreconditionFailed(_))
+    ));
+    Ok(())
+}
@@ -15,6 +15,22 @@
 //! # Ok::<_, Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! A zip file can be written with a [`ZipStorageWriter`].
+//! Entries are appended to the archive as they are stored, and the central directory is written when the writer is [finished](ZipStorageWriter::finish) or dropped.
+//! ```
+//! # use std::sync::Arc;
+//! use zarrs_storage::{StoreKey, WritableStorageTraits};
+//! use zarrs_zip::ZipStorageWriter;
+//!
+//! # let dir = tempfile::TempDir::new()?;
+//! # let zip_path = dir.path().join("zarr.zip");
+//! let file = std::fs::File::create(zip_path)?;
+//! let zip_store = Arc::new(ZipStorageWriter::new(file));
+//! zip_store.set(&StoreKey::new("zarr.json")?, vec![].into())?;
+//! zip_store.finish()?;
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! ## Licence
 //! `zarrs_zip` is licensed under either of
 //! - the Apache License, Version 2.0 [LICENSE-APACHE](https://docs.rs/crate/zarrs_zip/latest/source/LICENCE-APACHE) or <http://www.apache.org/licenses/LICENSE-2.0> or
@@ -23,15 +39,34 @@
 use zarrs_storage::{
     byte_range::{extract_byte_ranges_read, ByteRange},
     Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError, StorageValueIO, StoreKey,
-    StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
+    StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
+    WritableStorageTraits,
 };
 
 use itertools::Itertools;
-use std::sync::Mutex;
+use std::{
+    collections::HashSet,
+    io::{Seek, Write},
+    sync::Mutex,
+};
 use thiserror::Error;
-use zip::{result::ZipError, ZipArchive};
+use zip::{result::ZipError, write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use std::{path::PathBuf, sync::Arc};
+fn key_str_to_zip_path(zip_path: &Path, key: &str) -> String {
+    let mut zip_name = zip_path.to_path_buf();
+    zip_name.push(key);
+
+    let mut zip_name = zip_name.to_string_lossy();
+    if cfg!(windows) {
+        zip_name = zip_name.replace('\\', "/").into();
+    }
+    zip_name.to_string()
+}
 
 /// A zip storage adapter.
 pub struct ZipStorageAdapter<TStorage: ?Sized> {
@@ -82,14 +117,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ZipStorageAdapter<TStorage> {
     }
 
     fn key_str_to_zip_path(&self, key: &str) -> String {
-        let mut zip_name = self.zip_path.clone();
-        zip_name.push(key);
-
-        let mut zip_name = zip_name.to_string_lossy();
-        if cfg!(windows) {
-            zip_name = zip_name.replace('\\', "/").into();
-        }
-        zip_name.to_string()
+        key_str_to_zip_path(&self.zip_path, key)
     }
 
     fn get_impl(
@@ -234,6 +262,128 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ListableStorageTraits
     }
 }
 
+/// A zip storage writer.
+///
+/// Values are appended to the zip archive as entries when they are stored.
+/// The central directory of the archive is written by [`finish`](ZipStorageWriter::finish), or when the writer is dropped.
+///
+/// Entries are stored uncompressed by default, so that a [`ZipStorageAdapter`] can efficiently read byte ranges of the archive entries.
+///
+/// A zip archive is append only.
+/// Storing a value for a key that has already been written, or erasing a written key, is unsupported.
+/// Erasing a key that has not been written is a no-op, so that empty chunks can be skipped.
+pub struct ZipStorageWriter<W: Write + Seek> {
+    zip_writer: Mutex<Option<ZipWriter<W>>>,
+    zip_path: PathBuf,
+    compression_method: CompressionMethod,
+    written: Mutex<HashSet<String>>,
+}
+
+impl<W: Write + Seek> ZipStorageWriter<W> {
+    /// Create a new zip storage writer.
+    ///
+    /// Entries are stored uncompressed.
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self::new_with_options(writer, "", CompressionMethod::Stored)
+    }
+
+    /// Create a new zip storage writer with entries written to `path` within the zip file and compressed with `compression_method`.
+    #[must_use]
+    pub fn new_with_options<T: Into<PathBuf>>(
+        writer: W,
+        path: T,
+        compression_method: CompressionMethod,
+    ) -> Self {
+        Self {
+            zip_writer: Mutex::new(Some(ZipWriter::new(writer))),
+            zip_path: path.into(),
+            compression_method,
+            written: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Finish writing the zip archive and return the underlying writer.
+    ///
+    /// This writes the central directory of the zip archive.
+    /// Subsequent writes will fail.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if the archive has already been finished or writing the central directory fails.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn finish(&self) -> Result<W, StorageError> {
+        let zip_writer =
+            self.zip_writer.lock().unwrap().take().ok_or_else(|| {
+                StorageError::Other("the zip archive has been finished".to_string())
+            })?;
+        zip_writer
+            .finish()
+            .map_err(|err| StorageError::Other(err.to_string()))
+    }
+}
+
+impl<W: Write + Seek + Send> WritableStorageTraits for ZipStorageWriter<W> {
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        let zip_name = key_str_to_zip_path(&self.zip_path, key.as_str());
+        let mut zip_writer = self.zip_writer.lock().unwrap();
+        let zip_writer = zip_writer
+            .as_mut()
+            .ok_or_else(|| StorageError::Other("the zip archive has been finished".to_string()))?;
+        let mut written = self.written.lock().unwrap();
+        if written.contains(&zip_name) {
+            return Err(StorageError::Unsupported(format!(
+                "{key} has already been written to the zip archive"
+            )));
+        }
+        let options = SimpleFileOptions::default()
+            .compression_method(self.compression_method)
+            .large_file(value.len() as u64 >= u64::from(u32::MAX));
+        zip_writer
+            .start_file(zip_name.as_str(), options)
+            .map_err(|err| StorageError::Other(err.to_string()))?;
+        written.insert(zip_name);
+        zip_writer.write_all(&value)?;
+        Ok(())
+    }
+
+    fn set_partial_values(
+        &self,
+        _key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        Err(StorageError::Unsupported(
+            "partial writes are not supported by the zip storage writer".to_string(),
+        ))
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        let zip_name = key_str_to_zip_path(&self.zip_path, key.as_str());
+        if self.written.lock().unwrap().contains(&zip_name) {
+            Err(StorageError::Unsupported(format!(
+                "{key} cannot be erased from the zip archive"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        let zip_prefix = key_str_to_zip_path(&self.zip_path, prefix.as_str());
+        if self
+            .written
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|name| name.starts_with(&zip_prefix))
+        {
+            Err(StorageError::Unsupported(format!(
+                "{prefix} cannot be erased from the zip archive"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 /// A zip store creation error.
 #[derive(Debug, Error)]
 pub enum ZipStorageAdapterCreateError {
@@ -462,4 +612,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn zip_writer() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let store = Arc::new(FilesystemStore::new(path.path())?);
+
+        let file = File::create(path.path().join("test.zip"))?;
+        let writer = ZipStorageWriter::new(file);
+        writer.set(&"a/b/zarr.json".try_into()?, vec![0, 1, 2, 3].into())?;
+        writer.set(&"a/c/zarr.json".try_into()?, vec![].into())?;
+        writer.set(&"b/zarr.json".try_into()?, vec![4, 5].into())?;
+        assert!(writer
+            .set(&"b/zarr.json".try_into()?, vec![6].into())
+            .is_err());
+        assert!(writer.set_partial_values(&[]).is_err());
+        assert!(writer.erase(&"b/zarr.json".try_into()?).is_err());
+        assert!(writer.erase_prefix(&"a/".try_into()?).is_err());
+        writer.erase(&"c/zarr.json".try_into()?)?;
+        writer.erase_prefix(&"c/".try_into()?)?;
+        writer.finish()?;
+        assert!(writer.finish().is_err());
+        assert!(writer
+            .set(&"c/zarr.json".try_into()?, vec![].into())
+            .is_err());
+
+        let store = Arc::new(ZipStorageAdapter::new(store, StoreKey::new("test.zip")?)?);
+        assert_eq!(
+            store.list()?,
+            &[
+                "a/b/zarr.json".try_into()?,
+                "a/c/zarr.json".try_into()?,
+                "b/zarr.json".try_into()?,
+            ]
+        );
+        assert_eq!(
+            store.get(&"a/b/zarr.json".try_into()?)?.unwrap(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            store
+                .get_partial_values_key(&"b/zarr.json".try_into()?, &[ByteRange::Suffix(1)])?
+                .unwrap(),
+            vec![Bytes::from(vec![5])]
+        );
+
+        Ok(())
+    }
 }